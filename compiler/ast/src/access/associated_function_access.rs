@@ -37,7 +37,13 @@ pub struct AssociatedFunction {
 
 impl fmt::Display for AssociatedFunction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}::{}", self.ty, self.name)
+        write!(
+            f,
+            "{}::{}({})",
+            self.ty,
+            self.name,
+            self.arguments.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ")
+        )
     }
 }
 
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Identifier, Node, NodeID};
+use crate::{Expression, Identifier, Node, NodeID, Precedence};
 use leo_span::Span;
 
 use serde::{Deserialize, Serialize};
@@ -35,7 +35,7 @@ pub struct MemberAccess {
 
 impl fmt::Display for MemberAccess {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}.{}", self.inner, self.name)
+        write!(f, "{}.{}", self.inner.operand(Precedence::Postfix), self.name)
     }
 }
 
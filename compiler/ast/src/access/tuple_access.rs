@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Node, NodeID, NonNegativeNumber};
+use crate::{Expression, Node, NodeID, NonNegativeNumber, Precedence};
 use leo_span::Span;
 
 use serde::{Deserialize, Serialize};
@@ -35,7 +35,7 @@ pub struct TupleAccess {
 
 impl fmt::Display for TupleAccess {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}.{}", self.tuple, self.index)
+        write!(f, "{}.{}", self.tuple.operand(Precedence::Postfix), self.index)
     }
 }
 
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::{span::BytePos, Span};
+
+use serde::{Deserialize, Serialize};
+
+/// A comment in the source code, e.g. `// note` or `/* note */`.
+///
+/// The `@cfg(...)` annotation of a statement is not kept in the AST either, so it is recorded as a comment too.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Comment {
+    /// The text of the comment, including its delimiters but not the line feed that ends a line comment.
+    pub text: String,
+    /// The span of the comment.
+    pub span: Span,
+    /// Whether the comment follows code on the same line, e.g. `let a: u8 = 1u8; // note`.
+    pub trailing: bool,
+}
+
+/// The comments of a source file, ordered by their position.
+///
+/// The parser does not keep comments in the AST, so they are kept in this side table instead, keyed by their spans.
+/// Tools that print source code from the AST, such as the formatter, take the comments that precede each node
+/// as they print it, so that no comment is lost.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Comments {
+    comments: Vec<Comment>,
+}
+
+impl Comments {
+    /// Returns the side table of the given comments.
+    pub fn new(mut comments: Vec<Comment>) -> Self {
+        comments.sort_by_key(|comment| comment.span.lo);
+        Self { comments }
+    }
+
+    /// Returns `true` if there are no comments.
+    pub fn is_empty(&self) -> bool {
+        self.comments.is_empty()
+    }
+
+    /// Returns the comments, ordered by their position.
+    pub fn iter(&self) -> impl Iterator<Item = &Comment> {
+        self.comments.iter()
+    }
+
+    /// Removes and returns the comments that start before `pos`.
+    pub fn take_before(&mut self, pos: BytePos) -> Vec<Comment> {
+        let count = self.comments.partition_point(|comment| comment.span.lo < pos);
+        self.comments.drain(..count).collect()
+    }
+
+    /// Removes and returns all remaining comments.
+    pub fn take_all(&mut self) -> Vec<Comment> {
+        std::mem::take(&mut self.comments)
+    }
+}
@@ -36,6 +36,11 @@ impl NodeBuilder {
     pub fn next_id(&self) -> NodeID {
         self.inner.borrow_mut().next_id()
     }
+
+    /// Returns the number of `NodeID`s handed out so far, counting from zero.
+    pub fn num_ids(&self) -> usize {
+        self.inner.borrow().next
+    }
 }
 
 impl Default for NodeBuilder {
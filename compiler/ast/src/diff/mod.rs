@@ -0,0 +1,322 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A semantic diff of two programs, which reports the expressions that were added, removed, or changed.
+//!
+//! Program scopes and their items are matched by name. Within each function and finalize block, the expressions that
+//! the statements are made of, e.g. the value of a definition or the condition of a conditional, are listed in order and
+//! aligned by a longest common subsequence, comparing them structurally. A removed expression that is aligned with an
+//! added one is reported as changed. Spans and node IDs are ignored, so code that only moved is not reported.
+
+use crate::{
+    printer::signature,
+    AssertVariant,
+    Block,
+    ConsoleFunction,
+    Expression,
+    Function,
+    Macro,
+    Program,
+    ProgramScope,
+    Statement,
+    Struct,
+    Variant,
+};
+
+use std::fmt;
+
+/// A difference between two programs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    /// Where the change is, e.g. ``function `main` ``.
+    pub location: String,
+    /// What changed.
+    pub kind: ChangeKind,
+}
+
+/// What changed, as printed in source code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// An expression or item that is only in the new program.
+    Added(String),
+    /// An expression or item that is only in the old program.
+    Removed(String),
+    /// An expression or item of the old program, and what it became in the new program.
+    Changed(String, String),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ChangeKind::Added(new) => write!(f, "{}: added {new}", self.location),
+            ChangeKind::Removed(old) => write!(f, "{}: removed {old}", self.location),
+            ChangeKind::Changed(old, new) => write!(f, "{}: changed {old} to {new}", self.location),
+        }
+    }
+}
+
+/// Returns the changes from the `old` program to the `new` one.
+pub fn diff_programs(old: &Program, new: &Program) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let location = String::from("program");
+    diff_items(
+        &mut changes,
+        &location,
+        old.program_scopes.values().map(|scope| (scope.program_id.to_string(), scope)),
+        new.program_scopes.values().map(|scope| (scope.program_id.to_string(), scope)),
+        |name, _| format!("program scope `{name}`"),
+        diff_program_scopes,
+    );
+    changes
+}
+
+/// Records the changes from the `old` program scope to the `new` one.
+fn diff_program_scopes(changes: &mut Vec<Change>, old: &ProgramScope, new: &ProgramScope) {
+    let location = format!("program scope `{}`", new.program_id);
+
+    diff_items(
+        changes,
+        &location,
+        old.consts.iter().map(|(name, constant)| (name.to_string(), constant)),
+        new.consts.iter().map(|(name, constant)| (name.to_string(), constant)),
+        |name, _| format!("constant `{name}`"),
+        |changes, old, new| {
+            if old.type_ != new.type_ || !old.value.eq_structural(&new.value) {
+                let location = format!("constant `{}`", new.place);
+                changes.push(Change { location, kind: ChangeKind::Changed(format!("`{old}`"), format!("`{new}`")) });
+            }
+        },
+    );
+    diff_items(
+        changes,
+        &location,
+        old.structs.iter().map(|(name, struct_)| (name.to_string(), struct_)),
+        new.structs.iter().map(|(name, struct_)| (name.to_string(), struct_)),
+        |name, struct_| format!("{} `{name}`", if struct_.is_record { "record" } else { "struct" }),
+        |changes, old, new| {
+            let members = |struct_: &Struct| {
+                struct_.members.iter().map(|member| member.to_string()).collect::<Vec<_>>().join(", ")
+            };
+            if old.is_record != new.is_record || members(old) != members(new) {
+                let location = format!("{} `{}`", if new.is_record { "record" } else { "struct" }, new.identifier);
+                let kind = ChangeKind::Changed(format!("`{{{}}}`", members(old)), format!("`{{{}}}`", members(new)));
+                changes.push(Change { location, kind });
+            }
+        },
+    );
+    diff_items(
+        changes,
+        &location,
+        old.mappings.iter().map(|(name, mapping)| (name.to_string(), mapping)),
+        new.mappings.iter().map(|(name, mapping)| (name.to_string(), mapping)),
+        |name, _| format!("mapping `{name}`"),
+        |changes, old, new| {
+            if old.key_type != new.key_type || old.value_type != new.value_type {
+                let location = format!("mapping `{}`", new.identifier);
+                changes.push(Change { location, kind: ChangeKind::Changed(format!("`{old}`"), format!("`{new}`")) });
+            }
+        },
+    );
+    diff_items(
+        changes,
+        &location,
+        old.functions.iter().map(|(name, function)| (name.to_string(), function)),
+        new.functions.iter().map(|(name, function)| (name.to_string(), function)),
+        |name, function| format!("{} `{name}`", keyword(function)),
+        diff_functions,
+    );
+    diff_items(
+        changes,
+        &location,
+        old.macros.iter().map(|(name, macro_)| (name.to_string(), macro_)),
+        new.macros.iter().map(|(name, macro_)| (name.to_string(), macro_)),
+        |name, _| format!("macro `{name}`"),
+        |changes, old, new| {
+            let names = |macro_: &Macro| macro_.parameters.iter().map(|parameter| parameter.name).collect::<Vec<_>>();
+            if names(old) != names(new) || !old.body.eq_structural(&new.body) {
+                let location = format!("macro `{}`", new.identifier);
+                changes.push(Change { location, kind: ChangeKind::Changed(format!("`{old}`"), format!("`{new}`")) });
+            }
+        },
+    );
+}
+
+/// Records the changes from the `old` function to the `new` one.
+fn diff_functions(changes: &mut Vec<Change>, old: &Function, new: &Function) {
+    let location = format!("{} `{}`", keyword(new), new.identifier);
+
+    let (old_signature, new_signature) = (
+        format!("{} {}", keyword(old), signature(&old.identifier.to_string(), &old.input, &old.output)),
+        format!("{} {}", keyword(new), signature(&new.identifier.to_string(), &new.input, &new.output)),
+    );
+    if old_signature != new_signature {
+        changes.push(Change {
+            location: location.clone(),
+            kind: ChangeKind::Changed(format!("`{old_signature}`"), format!("`{new_signature}`")),
+        });
+    }
+    diff_blocks(changes, &location, &old.block, &new.block);
+
+    let finalize_location = format!("finalize `{}`", new.identifier);
+    match (&old.finalize, &new.finalize) {
+        (Some(old), Some(new)) => {
+            let old_signature = signature(&old.identifier.to_string(), &old.input, &old.output);
+            let new_signature = signature(&new.identifier.to_string(), &new.input, &new.output);
+            if old_signature != new_signature {
+                changes.push(Change {
+                    location: finalize_location.clone(),
+                    kind: ChangeKind::Changed(format!("`{old_signature}`"), format!("`{new_signature}`")),
+                });
+            }
+            diff_blocks(changes, &finalize_location, &old.block, &new.block);
+        }
+        (None, Some(_)) => changes.push(Change { location, kind: ChangeKind::Added(finalize_location) }),
+        (Some(_), None) => changes.push(Change { location, kind: ChangeKind::Removed(finalize_location) }),
+        (None, None) => {}
+    }
+}
+
+/// Records the items that are only in `old` or only in `new`, named by `describe`, and the changes between those of
+/// the same name, as found by `diff`.
+fn diff_items<'a, T: 'a>(
+    changes: &mut Vec<Change>,
+    location: &str,
+    old: impl Iterator<Item = (String, &'a T)>,
+    new: impl Iterator<Item = (String, &'a T)>,
+    describe: impl Fn(&str, &T) -> String,
+    diff: impl Fn(&mut Vec<Change>, &T, &T),
+) {
+    let old = old.collect::<Vec<_>>();
+    let new = new.collect::<Vec<_>>();
+    for (name, old_item) in old.iter() {
+        match new.iter().find(|(new_name, _)| new_name == name) {
+            Some((_, new_item)) => diff(changes, old_item, new_item),
+            None => changes
+                .push(Change { location: location.to_string(), kind: ChangeKind::Removed(describe(name, old_item)) }),
+        }
+    }
+    for (name, new_item) in new.iter().filter(|(name, _)| !old.iter().any(|(old_name, _)| old_name == name)) {
+        changes.push(Change { location: location.to_string(), kind: ChangeKind::Added(describe(name, new_item)) });
+    }
+}
+
+/// Records the changes from the expressions of the `old` block to those of the `new` one.
+fn diff_blocks(changes: &mut Vec<Change>, location: &str, old: &Block, new: &Block) {
+    let (mut old_expressions, mut new_expressions) = (Vec::new(), Vec::new());
+    block_expressions(old, &mut old_expressions);
+    block_expressions(new, &mut new_expressions);
+
+    // `lengths[i][j]` is the length of the longest common subsequence of `old[i..]` and `new[j..]`.
+    let (n, m) = (old_expressions.len(), new_expressions.len());
+    let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = match old_expressions[i].eq_structural(new_expressions[j]) {
+                true => lengths[i + 1][j + 1] + 1,
+                false => lengths[i + 1][j].max(lengths[i][j + 1]),
+            };
+        }
+    }
+
+    // Walk the subsequence, pairing the expressions removed and added between two common ones as changes.
+    let (mut i, mut j) = (0, 0);
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    loop {
+        let common = i < n && j < m && old_expressions[i].eq_structural(new_expressions[j]);
+        if common || (i == n && j == m) {
+            for (old, new) in removed.iter().zip(added.iter()) {
+                changes.push(Change {
+                    location: location.to_string(),
+                    kind: ChangeKind::Changed(format!("`{old}`"), format!("`{new}`")),
+                });
+            }
+            for old in removed.iter().skip(added.len()) {
+                changes.push(Change { location: location.to_string(), kind: ChangeKind::Removed(format!("`{old}`")) });
+            }
+            for new in added.iter().skip(removed.len()) {
+                changes.push(Change { location: location.to_string(), kind: ChangeKind::Added(format!("`{new}`")) });
+            }
+            removed.clear();
+            added.clear();
+            if !common {
+                break;
+            }
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            removed.push(old_expressions[i]);
+            i += 1;
+        } else {
+            added.push(new_expressions[j]);
+            j += 1;
+        }
+    }
+}
+
+/// Appends the expressions of the statements of `block` to `expressions`, in order.
+fn block_expressions<'a>(block: &'a Block, expressions: &mut Vec<&'a Expression>) {
+    for statement in block.statements.iter() {
+        statement_expressions(statement, expressions);
+    }
+}
+
+/// Appends the expressions that `statement` is made of to `expressions`, in order.
+fn statement_expressions<'a>(statement: &'a Statement, expressions: &mut Vec<&'a Expression>) {
+    match statement {
+        Statement::Assert(assert) => match &assert.variant {
+            AssertVariant::Assert(expression) | AssertVariant::ConstAssert(expression) => expressions.push(expression),
+            AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
+                expressions.extend([left, right])
+            }
+        },
+        Statement::Assign(assign) => expressions.extend([&assign.place, &assign.value]),
+        Statement::Block(block) => block_expressions(block, expressions),
+        Statement::Conditional(conditional) => {
+            expressions.push(&conditional.condition);
+            block_expressions(&conditional.then, expressions);
+            if let Some(otherwise) = &conditional.otherwise {
+                statement_expressions(otherwise, expressions);
+            }
+        }
+        Statement::Console(console) => match &console.function {
+            ConsoleFunction::Assert(expression) => expressions.push(expression),
+            ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                expressions.extend([left, right])
+            }
+        },
+        Statement::Const(constant) => expressions.push(&constant.value),
+        Statement::Definition(definition) => expressions.push(&definition.value),
+        Statement::Expression(statement) => expressions.push(&statement.expression),
+        Statement::Iteration(iteration) => {
+            expressions.extend([&iteration.start, &iteration.stop]);
+            expressions.extend(iteration.step.iter());
+            block_expressions(&iteration.block, expressions);
+        }
+        Statement::Return(return_) => {
+            expressions.push(&return_.expression);
+            expressions.extend(return_.finalize_arguments.iter().flatten());
+        }
+    }
+}
+
+/// Returns the keyword that declares `function`.
+fn keyword(function: &Function) -> &'static str {
+    match function.variant {
+        Variant::Inline => "inline",
+        Variant::Standard => "function",
+        Variant::Transition => "transition",
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generators of random expressions, statements, and programs for property tests, enabled by the `arbitrary` feature.
+//!
+//! The generated trees are limited to the forms the parser produces, so that printing and reparsing them gives the
+//! same tree, but they are not type checked: operands of operations and arguments of calls have arbitrary types,
+//! and the called functions need not exist.
+//! Like the builders, the generators intern names, so the values must be generated within a session, e.g. by running
+//! a `proptest::test_runner::TestRunner` in `leo_span::symbol::create_session_if_not_set_then`.
+
+use super::{
+    sexpr::{BINARY_OPERATIONS, UNARY_OPERATIONS},
+    *,
+};
+use crate::{
+    AssertStatement,
+    AssertVariant,
+    AssignStatement,
+    Block,
+    ConditionalStatement,
+    DeclarationType,
+    DefinitionStatement,
+    ExpressionStatement,
+    Function,
+    FunctionInput,
+    FunctionOutput,
+    Input,
+    Mode,
+    Output,
+    Program,
+    ProgramId,
+    ProgramScope,
+    ReturnStatement,
+    Statement,
+    Variant,
+};
+
+use indexmap::IndexMap;
+use proptest::{collection::vec, prelude::*, sample::select};
+
+/// The variables used in generated expressions.
+const VARIABLES: &[&str] = &["a", "b", "c", "x", "y"];
+
+/// The functions called in generated expressions.
+const FUNCTIONS: &[&str] = &["f", "g"];
+
+/// The members accessed in generated expressions.
+const MEMBERS: &[&str] = &["owner", "amount"];
+
+const INTEGER_TYPES: &[IntegerType] = &[
+    IntegerType::U8,
+    IntegerType::U16,
+    IntegerType::U32,
+    IntegerType::U64,
+    IntegerType::U128,
+    IntegerType::I8,
+    IntegerType::I16,
+    IntegerType::I32,
+    IntegerType::I64,
+    IntegerType::I128,
+];
+
+/// Limits on the size of generated trees.
+#[derive(Clone, Copy, Debug)]
+pub struct Bounds {
+    /// The maximum number of nested expressions, and separately of nested statements.
+    pub depth: u32,
+    /// The maximum number of elements of a tuple or array, arguments of a call, statements of a block,
+    /// and functions of a program.
+    pub width: usize,
+}
+
+impl Default for Bounds {
+    fn default() -> Self {
+        Self { depth: 4, width: 3 }
+    }
+}
+
+/// Returns a primitive type, i.e. a type that an expression can be cast to.
+fn primitive_type() -> impl Strategy<Value = Type> {
+    prop_oneof![
+        Just(Type::Address),
+        Just(Type::Boolean),
+        Just(Type::Field),
+        Just(Type::Group),
+        Just(Type::Scalar),
+        select(INTEGER_TYPES).prop_map(Type::Integer),
+    ]
+}
+
+/// Returns a variable or literal.
+fn leaf() -> impl Strategy<Value = Expression> {
+    prop_oneof![
+        3 => select(VARIABLES).prop_map(ident),
+        1 => any::<bool>().prop_map(boolean),
+        // Every integer type has the values from `-128` or `0` up to `127`.
+        3 => (select(INTEGER_TYPES), -128i16..=127).prop_map(|(type_, value)| match type_.is_signed() {
+            true => integer(type_, value),
+            false => integer(type_, value.unsigned_abs()),
+        }),
+        1 => (0u64..1000).prop_map(|value| field(&value.to_string())),
+    ]
+}
+
+impl Arbitrary for Expression {
+    type Parameters = Bounds;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(bounds: Bounds) -> Self::Strategy {
+        let width = bounds.width.max(2);
+        leaf()
+            .prop_recursive(bounds.depth, 16 * bounds.depth, width as u32, move |inner| {
+                prop_oneof![
+                    3 => (select(BINARY_OPERATIONS), inner.clone(), inner.clone())
+                        .prop_map(|((_, op), left, right)| binary(op, left, right)),
+                    2 => (select(UNARY_OPERATIONS), inner.clone()).prop_map(|((_, op), receiver)| unary(op, receiver)),
+                    1 => (inner.clone(), inner.clone(), inner.clone())
+                        .prop_map(|(condition, if_true, if_false)| ternary(condition, if_true, if_false)),
+                    1 => (inner.clone(), primitive_type()).prop_map(|(expression, type_)| cast(expression, type_)),
+                    1 => (select(FUNCTIONS), vec(inner.clone(), 0..=width))
+                        .prop_map(|(function, arguments)| call(function, arguments)),
+                    1 => vec(inner.clone(), 2..=width).prop_map(tuple),
+                    1 => vec(inner.clone(), 1..=width).prop_map(array),
+                    1 => (inner.clone(), inner).prop_map(|(array, index)| array_access(array, index)),
+                    // Tuple and member accesses are only generated on variables, whose types are unknown,
+                    // rather than on expressions that clearly have no members.
+                    1 => (select(VARIABLES), 0..width)
+                        .prop_map(|(variable, index)| tuple_access(ident(variable), index)),
+                    1 => (select(VARIABLES), select(MEMBERS))
+                        .prop_map(|(variable, name)| member(ident(variable), name)),
+                ]
+            })
+            .boxed()
+    }
+}
+
+/// Returns a block of at most `width` statements generated by `statement`.
+fn block(statement: impl Strategy<Value = Statement>, width: usize) -> impl Strategy<Value = Block> {
+    vec(statement, 0..=width).prop_map(|statements| Block { statements, span: Span::default(), id: ID })
+}
+
+impl Arbitrary for Statement {
+    type Parameters = Bounds;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(bounds: Bounds) -> Self::Strategy {
+        let expression = move || Expression::arbitrary_with(bounds);
+        let leaf = prop_oneof![
+            2 => (select(VARIABLES), primitive_type(), expression()).prop_map(|(variable, type_, value)| {
+                Statement::Definition(DefinitionStatement {
+                    declaration_type: DeclarationType::Let,
+                    place: ident(variable),
+                    type_,
+                    value,
+                    span: Span::default(),
+                    id: ID,
+                })
+            }),
+            2 => (select(VARIABLES), expression()).prop_map(|(variable, value)| {
+                let place = ident(variable);
+                Statement::Assign(Box::new(AssignStatement { place, value, span: Span::default(), id: ID }))
+            }),
+            1 => prop_oneof![
+                expression().prop_map(AssertVariant::Assert),
+                (expression(), expression()).prop_map(|(left, right)| AssertVariant::AssertEq(left, right)),
+                (expression(), expression()).prop_map(|(left, right)| AssertVariant::AssertNeq(left, right)),
+            ]
+            .prop_map(|variant| Statement::Assert(AssertStatement { variant, span: Span::default(), id: ID })),
+            1 => (select(FUNCTIONS), vec(expression(), 0..=bounds.width)).prop_map(|(function, arguments)| {
+                Statement::Expression(ExpressionStatement {
+                    expression: call(function, arguments),
+                    span: Span::default(),
+                    id: ID,
+                })
+            }),
+            1 => expression().prop_map(|expression| {
+                let span = Span::default();
+                Statement::Return(ReturnStatement { expression, finalize_arguments: None, span, id: ID })
+            }),
+        ];
+        leaf.prop_recursive(bounds.depth, 4 * bounds.depth, bounds.width as u32, move |inner| {
+            let width = bounds.width;
+            // The parser only produces a block or another conditional as the `else` branch.
+            let otherwise = prop_oneof![
+                Just(None),
+                block(inner.clone(), width).prop_map(|block| Some(Box::new(Statement::Block(block)))),
+                (expression(), block(inner.clone(), width)).prop_map(|(condition, then)| {
+                    Some(Box::new(Statement::Conditional(ConditionalStatement {
+                        condition,
+                        then,
+                        otherwise: None,
+                        span: Span::default(),
+                        id: ID,
+                    })))
+                }),
+            ];
+            prop_oneof![
+                block(inner.clone(), width).prop_map(Statement::Block),
+                (expression(), block(inner, width), otherwise).prop_map(|(condition, then, otherwise)| {
+                    Statement::Conditional(ConditionalStatement {
+                        condition,
+                        then,
+                        otherwise,
+                        span: Span::default(),
+                        id: ID,
+                    })
+                }),
+            ]
+        })
+        .boxed()
+    }
+}
+
+/// Returns the transition `function(a: u32, b: u32) -> u32` with the given body.
+fn transition(function: &str, block: Block) -> Function {
+    let input = |variable| {
+        Input::Internal(FunctionInput {
+            identifier: name(variable),
+            mode: Mode::None,
+            type_: Type::Integer(IntegerType::U32),
+            reference: false,
+            span: Span::default(),
+            id: ID,
+        })
+    };
+    let output = Output::Internal(FunctionOutput {
+        mode: Mode::None,
+        type_: Type::Integer(IntegerType::U32),
+        span: Span::default(),
+        id: ID,
+    });
+    Function::new(
+        Vec::new(),
+        Variant::Transition,
+        name(function),
+        vec![input("a"), input("b")],
+        vec![output],
+        block,
+        None,
+        Span::default(),
+        ID,
+    )
+}
+
+impl Arbitrary for Program {
+    type Parameters = Bounds;
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Returns the program `test.aleo`, with at least one and at most `width` transitions of generated statements.
+    fn arbitrary_with(bounds: Bounds) -> Self::Strategy {
+        vec(block(Statement::arbitrary_with(bounds), bounds.width), 1..=bounds.width.max(1))
+            .prop_map(|blocks| {
+                let functions = blocks
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, block)| transition(&format!("main{i}"), block))
+                    .map(|function| (function.identifier.name, function))
+                    .collect();
+                let program_id = ProgramId { name: name("test"), network: name("aleo") };
+                let scope = ProgramScope {
+                    program_id,
+                    consts: Vec::new(),
+                    structs: Vec::new(),
+                    mappings: Vec::new(),
+                    functions,
+                    macros: Vec::new(),
+                    span: Span::default(),
+                };
+                Program { program_scopes: IndexMap::from_iter([(program_id.name.name, scope)]), ..Default::default() }
+            })
+            .boxed()
+    }
+}
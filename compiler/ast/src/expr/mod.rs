@@ -0,0 +1,269 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Builder functions for constructing expressions by hand, intended for compiler unit tests.
+//!
+//! There is a builder for each kind of expression that a test is likely to need:
+//! - [`name`] and [`ident`] for identifiers and variables;
+//! - [`boolean`], [`field`], [`scalar`], [`address`], and [`integer`] for literals, with a shorthand for each integer
+//!   type, e.g. [`int_u8`] and [`int_i128`];
+//! - [`binary`] and [`unary`] for operations, with a shorthand for each operation written with an operator,
+//!   e.g. [`add`], [`bit_and`], and [`not`];
+//! - [`ternary`], [`cast`], [`call`], [`tuple`], [`array`], and [`unit`];
+//! - [`member`], [`tuple_access`], and [`array_access`] for accesses.
+//!
+//! Every node is given a default span and node ID, so expressions built here should be compared
+//! with [`Expression::eq_structural`] rather than `==`.
+//! Functions taking names intern them, and so must be called within a session, e.g. in
+//! `leo_span::symbol::create_session_if_not_set_then`.
+//!
+//! ```
+//! use leo_ast::expr;
+//! use leo_span::symbol::create_session_if_not_set_then;
+//!
+//! create_session_if_not_set_then(|_| {
+//!     let e = expr::add(expr::ident("a"), expr::mul(expr::ident("b"), expr::int_u8(3)));
+//!     assert_eq!(e.to_string(), "a + b * 3u8");
+//! });
+//! ```
+//!
+//! With the `arbitrary` feature, the module also implements `proptest::arbitrary::Arbitrary` for expressions,
+//! statements, and programs, with sizes limited by `Bounds`.
+
+use crate::{
+    AccessExpression,
+    ArrayAccess,
+    ArrayExpression,
+    BinaryExpression,
+    BinaryOperation,
+    CallExpression,
+    CastExpression,
+    Expression,
+    Identifier,
+    IntegerType,
+    Literal,
+    MemberAccess,
+    NodeID,
+    TernaryExpression,
+    TupleAccess,
+    TupleExpression,
+    Type,
+    UnaryExpression,
+    UnaryOperation,
+    UnitExpression,
+};
+use leo_span::{Span, Symbol};
+
+mod sexpr;
+pub use sexpr::*;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arbitrary")]
+pub use arbitrary::*;
+
+/// The node ID given to every built node.
+const ID: NodeID = 0;
+
+/// Returns an identifier named `name`.
+pub fn name(name: &str) -> Identifier {
+    Identifier::new(Symbol::intern(name), ID)
+}
+
+/// Returns the variable `name`.
+pub fn ident(name: &str) -> Expression {
+    Expression::Identifier(self::name(name))
+}
+
+/// Returns the boolean literal `value`.
+pub fn boolean(value: bool) -> Expression {
+    Expression::Literal(Literal::Boolean(value, Span::default(), ID))
+}
+
+/// Returns the field literal `{value}field`.
+pub fn field(value: &str) -> Expression {
+    Expression::Literal(Literal::Field(value.to_string(), Span::default(), ID))
+}
+
+/// Returns the scalar literal `{value}scalar`.
+pub fn scalar(value: &str) -> Expression {
+    Expression::Literal(Literal::Scalar(value.to_string(), Span::default(), ID))
+}
+
+/// Returns the address literal `value`.
+pub fn address(value: &str) -> Expression {
+    Expression::Literal(Literal::Address(value.to_string(), Span::default(), ID))
+}
+
+/// Returns the integer literal `value` of type `type_`.
+pub fn integer(type_: IntegerType, value: impl ToString) -> Expression {
+    Expression::Literal(Literal::Integer(type_, value.to_string(), Span::default(), ID))
+}
+
+macro_rules! integer_builders {
+    ($($name:ident: $ty:ty => $variant:ident,)*) => {
+        $(
+            #[doc = concat!("Returns the `", stringify!($ty), "` literal `value`.")]
+            pub fn $name(value: $ty) -> Expression {
+                integer(IntegerType::$variant, value)
+            }
+        )*
+    };
+}
+
+integer_builders! {
+    int_u8: u8 => U8,
+    int_u16: u16 => U16,
+    int_u32: u32 => U32,
+    int_u64: u64 => U64,
+    int_u128: u128 => U128,
+    int_i8: i8 => I8,
+    int_i16: i16 => I16,
+    int_i32: i32 => I32,
+    int_i64: i64 => I64,
+    int_i128: i128 => I128,
+}
+
+/// Returns the unit expression `()`.
+pub fn unit() -> Expression {
+    Expression::Unit(UnitExpression { span: Span::default(), id: ID })
+}
+
+/// Returns the binary expression `left op right`.
+pub fn binary(op: BinaryOperation, left: Expression, right: Expression) -> Expression {
+    Expression::Binary(BinaryExpression {
+        left: Box::new(left),
+        right: Box::new(right),
+        op,
+        span: Span::default(),
+        id: ID,
+    })
+}
+
+macro_rules! binary_builders {
+    ($($name:ident => $op:ident,)*) => {
+        $(
+            #[doc = concat!("Returns the `", stringify!($op), "` expression of `left` and `right`.")]
+            pub fn $name(left: Expression, right: Expression) -> Expression {
+                binary(BinaryOperation::$op, left, right)
+            }
+        )*
+    };
+}
+
+binary_builders! {
+    add => Add,
+    and => And,
+    bit_and => BitwiseAnd,
+    bit_or => BitwiseOr,
+    div => Div,
+    eq => Eq,
+    gt => Gt,
+    gte => Gte,
+    lt => Lt,
+    lte => Lte,
+    mul => Mul,
+    neq => Neq,
+    or => Or,
+    pow => Pow,
+    rem => Rem,
+    shl => Shl,
+    shr => Shr,
+    sub => Sub,
+    xor => Xor,
+}
+
+/// Returns the unary expression applying `op` to `receiver`.
+pub fn unary(op: UnaryOperation, receiver: Expression) -> Expression {
+    Expression::Unary(UnaryExpression { receiver: Box::new(receiver), op, span: Span::default(), id: ID })
+}
+
+/// Returns the expression `!receiver`.
+pub fn not(receiver: Expression) -> Expression {
+    unary(UnaryOperation::Not, receiver)
+}
+
+/// Returns the expression `-receiver`.
+pub fn neg(receiver: Expression) -> Expression {
+    unary(UnaryOperation::Negate, receiver)
+}
+
+/// Returns the expression `condition ? if_true : if_false`.
+pub fn ternary(condition: Expression, if_true: Expression, if_false: Expression) -> Expression {
+    Expression::Ternary(TernaryExpression {
+        condition: Box::new(condition),
+        if_true: Box::new(if_true),
+        if_false: Box::new(if_false),
+        span: Span::default(),
+        id: ID,
+    })
+}
+
+/// Returns the expression `expression as type_`.
+pub fn cast(expression: Expression, type_: Type) -> Expression {
+    Expression::Cast(CastExpression { expression: Box::new(expression), type_, span: Span::default(), id: ID })
+}
+
+/// Returns the call `function(arguments)` of a function in the current program.
+pub fn call(function: &str, arguments: Vec<Expression>) -> Expression {
+    Expression::Call(CallExpression {
+        function: Box::new(ident(function)),
+        arguments,
+        external: None,
+        span: Span::default(),
+        id: ID,
+    })
+}
+
+/// Returns the tuple `(elements)`.
+pub fn tuple(elements: Vec<Expression>) -> Expression {
+    Expression::Tuple(TupleExpression { elements, span: Span::default(), id: ID })
+}
+
+/// Returns the array `[elements]`.
+pub fn array(elements: Vec<Expression>) -> Expression {
+    Expression::Array(ArrayExpression { elements, span: Span::default(), id: ID })
+}
+
+/// Returns the member access `inner.member`.
+pub fn member(inner: Expression, member: &str) -> Expression {
+    Expression::Access(AccessExpression::Member(MemberAccess {
+        inner: Box::new(inner),
+        name: name(member),
+        span: Span::default(),
+        id: ID,
+    }))
+}
+
+/// Returns the tuple access `tuple.index`.
+pub fn tuple_access(tuple: Expression, index: usize) -> Expression {
+    Expression::Access(AccessExpression::Tuple(TupleAccess {
+        tuple: Box::new(tuple),
+        index: index.into(),
+        span: Span::default(),
+        id: ID,
+    }))
+}
+
+/// Returns the array access `array[index]`.
+pub fn array_access(array: Expression, index: Expression) -> Expression {
+    Expression::Access(AccessExpression::Array(ArrayAccess {
+        array: Box::new(array),
+        index: Box::new(index),
+        span: Span::default(),
+        id: ID,
+    }))
+}
@@ -0,0 +1,600 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A compact, Lisp-style text format for expressions, e.g. `(add (mul a b) 3u8)`.
+//!
+//! Unlike the Leo syntax, the format has no precedence or associativity, so it states the shape of a tree exactly.
+//! This makes it suitable for snapshot tests of passes that rewrite expressions.
+//!
+//! The format consists of:
+//! - identifiers and literals as atoms, e.g. `a`, `true`, `3u8`, `1field`, `"text"`, where a `\` or `"` in a string
+//!   is escaped with a `\`, and `(ident name)` for an identifier that would otherwise be read as a literal,
+//!   e.g. `(ident aleo1x)`;
+//! - types as atoms, e.g. `u8`, `address`, `Point`, and `(array_type type length)`, `(tuple_type types...)`, and `()`
+//!   for the composite types;
+//! - `()` for the unit expression;
+//! - `(op left right)` and `(op receiver)` for binary and unary operations, using the method names of the operators,
+//!   e.g. `add`, `bit_and`, `shl_wrapped`, `not`, `square_root`;
+//! - `(ternary condition if_true if_false)` and `(cast expression type)`;
+//! - `(call function arguments...)`, where an external function is written `program/function`;
+//! - `(tuple elements...)`, `(array elements...)`, and `(struct Name (member value) (shorthand)...)`;
+//! - `(member inner name)`, `(tuple_access tuple index)`, `(array_access array index)`;
+//! - `(associated_constant type name)` and `(associated_function type name arguments...)`;
+//! - `(group x y)` for group literals given by coordinates, and `(err)` for the error expression.
+
+use super::*;
+use crate::{
+    ArrayType,
+    AssociatedConstant,
+    AssociatedFunction,
+    GroupCoordinate,
+    GroupLiteral,
+    GroupTuple,
+    StructExpression,
+    StructVariableInitializer,
+    TupleType,
+};
+use leo_errors::{AstError, Result};
+
+use std::fmt::{self, Write};
+
+/// The names of the binary operations.
+pub(super) const BINARY_OPERATIONS: &[(&str, BinaryOperation)] = &[
+    ("add", BinaryOperation::Add),
+    ("add_wrapped", BinaryOperation::AddWrapped),
+    ("and", BinaryOperation::And),
+    ("bit_and", BinaryOperation::BitwiseAnd),
+    ("div", BinaryOperation::Div),
+    ("div_wrapped", BinaryOperation::DivWrapped),
+    ("eq", BinaryOperation::Eq),
+    ("gte", BinaryOperation::Gte),
+    ("gt", BinaryOperation::Gt),
+    ("lte", BinaryOperation::Lte),
+    ("lt", BinaryOperation::Lt),
+    ("mod", BinaryOperation::Mod),
+    ("mul", BinaryOperation::Mul),
+    ("mul_wrapped", BinaryOperation::MulWrapped),
+    ("nand", BinaryOperation::Nand),
+    ("neq", BinaryOperation::Neq),
+    ("nor", BinaryOperation::Nor),
+    ("or", BinaryOperation::Or),
+    ("bit_or", BinaryOperation::BitwiseOr),
+    ("pow", BinaryOperation::Pow),
+    ("pow_wrapped", BinaryOperation::PowWrapped),
+    ("rem", BinaryOperation::Rem),
+    ("rem_wrapped", BinaryOperation::RemWrapped),
+    ("shl", BinaryOperation::Shl),
+    ("shl_wrapped", BinaryOperation::ShlWrapped),
+    ("shr", BinaryOperation::Shr),
+    ("shr_wrapped", BinaryOperation::ShrWrapped),
+    ("sub", BinaryOperation::Sub),
+    ("sub_wrapped", BinaryOperation::SubWrapped),
+    ("xor", BinaryOperation::Xor),
+];
+
+/// The names of the unary operations.
+pub(super) const UNARY_OPERATIONS: &[(&str, UnaryOperation)] = &[
+    ("abs", UnaryOperation::Abs),
+    ("abs_wrapped", UnaryOperation::AbsWrapped),
+    ("double", UnaryOperation::Double),
+    ("inv", UnaryOperation::Inverse),
+    ("neg", UnaryOperation::Negate),
+    ("not", UnaryOperation::Not),
+    ("square", UnaryOperation::Square),
+    ("square_root", UnaryOperation::SquareRoot),
+    ("to_x_coordinate", UnaryOperation::ToXCoordinate),
+    ("to_y_coordinate", UnaryOperation::ToYCoordinate),
+];
+
+fn binary_name(op: BinaryOperation) -> &'static str {
+    BINARY_OPERATIONS.iter().find(|(_, candidate)| *candidate == op).map(|(name, _)| *name).unwrap()
+}
+
+fn unary_name(op: UnaryOperation) -> &'static str {
+    UNARY_OPERATIONS.iter().find(|(_, candidate)| *candidate == op).map(|(name, _)| *name).unwrap()
+}
+
+impl Expression {
+    /// Returns the expression in the S-expression format of [`parse_sexpr`].
+    pub fn to_sexpr(&self) -> String {
+        let mut output = String::new();
+        write_sexpr(&mut output, self).expect("failed to write to string");
+        output
+    }
+}
+
+fn write_sexpr(f: &mut String, expression: &Expression) -> fmt::Result {
+    // Writes `(head children...)`.
+    let list = |f: &mut String, head: &str, children: &[&Expression]| -> fmt::Result {
+        write!(f, "({head}")?;
+        for child in children {
+            f.push(' ');
+            write_sexpr(f, child)?;
+        }
+        f.push(')');
+        Ok(())
+    };
+
+    match expression {
+        Expression::Access(AccessExpression::Array(access)) => {
+            list(f, "array_access", &[access.array.as_ref(), access.index.as_ref()])
+        }
+        Expression::Access(AccessExpression::AssociatedConstant(access)) => {
+            f.push_str("(associated_constant ");
+            write_type(f, &access.ty)?;
+            write!(f, " {})", access.name)
+        }
+        Expression::Access(AccessExpression::AssociatedFunction(access)) => {
+            let mut head = String::from("associated_function ");
+            write_type(&mut head, &access.ty)?;
+            write!(head, " {}", access.name)?;
+            list(f, &head, &access.arguments.iter().collect::<Vec<_>>())
+        }
+        Expression::Access(AccessExpression::Member(access)) => {
+            f.push_str("(member ");
+            write_sexpr(f, &access.inner)?;
+            write!(f, " {})", access.name)
+        }
+        Expression::Access(AccessExpression::Tuple(access)) => {
+            f.push_str("(tuple_access ");
+            write_sexpr(f, &access.tuple)?;
+            write!(f, " {})", access.index)
+        }
+        Expression::Array(array) => list(f, "array", &array.elements.iter().collect::<Vec<_>>()),
+        Expression::Binary(binary) => list(f, binary_name(binary.op), &[binary.left.as_ref(), binary.right.as_ref()]),
+        Expression::Call(call) => {
+            let function = match &call.external {
+                Some(external) => format!("call {external}/{}", call.function),
+                None => format!("call {}", call.function),
+            };
+            list(f, &function, &call.arguments.iter().collect::<Vec<_>>())
+        }
+        Expression::Cast(cast) => {
+            f.push_str("(cast ");
+            write_sexpr(f, &cast.expression)?;
+            f.push(' ');
+            write_type(f, &cast.type_)?;
+            f.push(')');
+            Ok(())
+        }
+        Expression::Struct(struct_) => {
+            write!(f, "(struct {}", struct_.name)?;
+            for member in &struct_.members {
+                write!(f, " ({}", member.identifier)?;
+                if let Some(expression) = &member.expression {
+                    f.push(' ');
+                    write_sexpr(f, expression)?;
+                }
+                f.push(')');
+            }
+            f.push(')');
+            Ok(())
+        }
+        Expression::Err(_) => list(f, "err", &[]),
+        Expression::Identifier(identifier) => match reads_as_literal(&identifier.name.to_string()) {
+            true => write!(f, "(ident {identifier})"),
+            false => write!(f, "{identifier}"),
+        },
+        Expression::Literal(Literal::Group(group)) => match group.as_ref() {
+            GroupLiteral::Single(value, ..) => write!(f, "{value}group"),
+            GroupLiteral::Tuple(tuple) => write!(f, "(group {} {})", tuple.x, tuple.y),
+        },
+        Expression::Literal(Literal::String(string, ..)) => {
+            f.push('"');
+            for c in string.chars() {
+                if matches!(c, '\\' | '"') {
+                    f.push('\\');
+                }
+                f.push(c);
+            }
+            f.push('"');
+            Ok(())
+        }
+        Expression::Literal(literal) => write!(f, "{literal}"),
+        Expression::Ternary(ternary) => {
+            list(f, "ternary", &[ternary.condition.as_ref(), ternary.if_true.as_ref(), ternary.if_false.as_ref()])
+        }
+        Expression::Tuple(tuple) => list(f, "tuple", &tuple.elements.iter().collect::<Vec<_>>()),
+        Expression::Unary(unary) => list(f, unary_name(unary.op), &[unary.receiver.as_ref()]),
+        Expression::Unit(_) => write!(f, "()"),
+    }
+}
+
+fn write_type(f: &mut String, type_: &Type) -> fmt::Result {
+    match type_ {
+        Type::Array(array) => {
+            f.push_str("(array_type ");
+            write_type(f, array.element_type())?;
+            write!(f, " {})", array.length())
+        }
+        Type::Tuple(tuple) => {
+            f.push_str("(tuple_type");
+            for element in tuple.elements() {
+                f.push(' ');
+                write_type(f, element)?;
+            }
+            f.push(')');
+            Ok(())
+        }
+        type_ => write!(f, "{type_}"),
+    }
+}
+
+/// Returns whether an atom with the given name is read as a literal rather than as an identifier.
+fn reads_as_literal(name: &str) -> bool {
+    matches!(name, "true" | "false")
+        || name.starts_with("aleo1")
+        || name.strip_prefix('-').unwrap_or(name).starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Parses an expression from the S-expression format written by [`Expression::to_sexpr`].
+///
+/// Like the other builders in this module, names are interned, so this must be called within a session,
+/// and every node is given a default span and node ID.
+pub fn parse_sexpr(input: &str) -> Result<Expression> {
+    let tokens = tokenize(input)?;
+    let mut position = 0;
+    let tree = parse_tree(&tokens, &mut position)?;
+    if position != tokens.len() {
+        return Err(AstError::invalid_sexpr("unexpected input after the end of the expression").into());
+    }
+    to_expression(&tree)
+}
+
+/// A token of the S-expression format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Open,
+    Close,
+    Atom(String),
+    String(String),
+}
+
+/// A parsed S-expression, before it is interpreted as an `Expression`.
+#[derive(Clone, Debug)]
+enum Tree {
+    Atom(String),
+    String(String),
+    List(Vec<Tree>),
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '"' => {
+                chars.next();
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c) => string.push(c),
+                            None => return Err(AstError::invalid_sexpr("unterminated string literal").into()),
+                        },
+                        Some(c) => string.push(c),
+                        None => return Err(AstError::invalid_sexpr("unterminated string literal").into()),
+                    }
+                }
+                tokens.push(Token::String(string));
+            }
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            _ => {
+                let mut atom = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || matches!(c, '(' | ')' | '"') {
+                        break;
+                    }
+                    atom.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Atom(atom));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn parse_tree(tokens: &[Token], position: &mut usize) -> Result<Tree> {
+    let token = tokens.get(*position).ok_or_else(|| AstError::invalid_sexpr("unexpected end of input"))?;
+    *position += 1;
+    match token {
+        Token::Open => {
+            let mut elements = Vec::new();
+            loop {
+                match tokens.get(*position) {
+                    Some(Token::Close) => {
+                        *position += 1;
+                        return Ok(Tree::List(elements));
+                    }
+                    Some(_) => elements.push(parse_tree(tokens, position)?),
+                    None => return Err(AstError::invalid_sexpr("unclosed `(`").into()),
+                }
+            }
+        }
+        Token::Close => Err(AstError::invalid_sexpr("unexpected `)`").into()),
+        Token::Atom(atom) => Ok(Tree::Atom(atom.clone())),
+        Token::String(string) => Ok(Tree::String(string.clone())),
+    }
+}
+
+fn to_expression(tree: &Tree) -> Result<Expression> {
+    let elements = match tree {
+        Tree::Atom(atom) => return atom_to_expression(atom),
+        Tree::String(string) => return Ok(Expression::Literal(Literal::String(string.clone(), Span::default(), ID))),
+        Tree::List(elements) => elements,
+    };
+
+    let (head, arguments) = match elements.split_first() {
+        Some(split) => split,
+        None => return Ok(unit()),
+    };
+    let head = expect_atom(head)?;
+    let expressions = |trees: &[Tree]| trees.iter().map(to_expression).collect::<Result<Vec<_>>>();
+    let arity = |count: usize| -> Result<()> {
+        match arguments.len() == count {
+            true => Ok(()),
+            false => {
+                Err(AstError::invalid_sexpr(format!("`{head}` expects {count} arguments, found {}", arguments.len()))
+                    .into())
+            }
+        }
+    };
+
+    if let Some((_, op)) = BINARY_OPERATIONS.iter().find(|(name, _)| *name == head) {
+        arity(2)?;
+        return Ok(binary(*op, to_expression(&arguments[0])?, to_expression(&arguments[1])?));
+    }
+    if let Some((_, op)) = UNARY_OPERATIONS.iter().find(|(name, _)| *name == head) {
+        arity(1)?;
+        return Ok(unary(*op, to_expression(&arguments[0])?));
+    }
+
+    Ok(match head {
+        "array" => array(expressions(arguments)?),
+        "array_access" => {
+            arity(2)?;
+            array_access(to_expression(&arguments[0])?, to_expression(&arguments[1])?)
+        }
+        "associated_constant" => {
+            arity(2)?;
+            Expression::Access(AccessExpression::AssociatedConstant(AssociatedConstant {
+                ty: to_type(&arguments[0])?,
+                name: name(expect_atom(&arguments[1])?),
+                span: Span::default(),
+                id: ID,
+            }))
+        }
+        "associated_function" => {
+            if arguments.len() < 2 {
+                return Err(AstError::invalid_sexpr("`associated_function` expects a type and a name").into());
+            }
+            Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                ty: to_type(&arguments[0])?,
+                name: name(expect_atom(&arguments[1])?),
+                arguments: expressions(&arguments[2..])?,
+                span: Span::default(),
+                id: ID,
+            }))
+        }
+        "call" => {
+            let (function, arguments) =
+                arguments.split_first().ok_or_else(|| AstError::invalid_sexpr("`call` expects a function"))?;
+            let (external, function) = match expect_atom(function)?.split_once('/') {
+                Some((program, function)) => (Some(Box::new(ident(program))), function),
+                None => (None, expect_atom(function)?),
+            };
+            Expression::Call(CallExpression {
+                function: Box::new(ident(function)),
+                arguments: expressions(arguments)?,
+                external,
+                span: Span::default(),
+                id: ID,
+            })
+        }
+        "cast" => {
+            arity(2)?;
+            cast(to_expression(&arguments[0])?, to_type(&arguments[1])?)
+        }
+        "err" => {
+            arity(0)?;
+            Expression::Err(crate::ErrExpression { span: Span::default(), id: ID })
+        }
+        "group" => {
+            arity(2)?;
+            Expression::Literal(Literal::Group(Box::new(GroupLiteral::Tuple(GroupTuple {
+                x: atom_to_coordinate(expect_atom(&arguments[0])?),
+                y: atom_to_coordinate(expect_atom(&arguments[1])?),
+                span: Span::default(),
+                id: ID,
+            }))))
+        }
+        "ident" => {
+            arity(1)?;
+            ident(expect_atom(&arguments[0])?)
+        }
+        "member" => {
+            arity(2)?;
+            member(to_expression(&arguments[0])?, expect_atom(&arguments[1])?)
+        }
+        "struct" => {
+            let (struct_name, members) =
+                arguments.split_first().ok_or_else(|| AstError::invalid_sexpr("`struct` expects a name"))?;
+            let members =
+                members
+                    .iter()
+                    .map(|member| match member {
+                        Tree::List(member) if (1..=2).contains(&member.len()) => Ok(StructVariableInitializer {
+                            identifier: name(expect_atom(&member[0])?),
+                            expression: member.get(1).map(to_expression).transpose()?,
+                            span: Span::default(),
+                            id: ID,
+                        }),
+                        _ => Err(AstError::invalid_sexpr("a struct member must be written `(name value)` or `(name)`")
+                            .into()),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+            Expression::Struct(StructExpression {
+                name: name(expect_atom(struct_name)?),
+                members,
+                span: Span::default(),
+                id: ID,
+            })
+        }
+        "ternary" => {
+            arity(3)?;
+            ternary(to_expression(&arguments[0])?, to_expression(&arguments[1])?, to_expression(&arguments[2])?)
+        }
+        "tuple" => tuple(expressions(arguments)?),
+        "tuple_access" => {
+            arity(2)?;
+            let index = expect_atom(&arguments[1])?
+                .parse::<usize>()
+                .map_err(|_| AstError::invalid_sexpr("a tuple index must be a non-negative integer"))?;
+            tuple_access(to_expression(&arguments[0])?, index)
+        }
+        _ => return Err(AstError::invalid_sexpr(format!("unknown operation `{head}`")).into()),
+    })
+}
+
+fn expect_atom(tree: &Tree) -> Result<&str> {
+    match tree {
+        Tree::Atom(atom) => Ok(atom),
+        _ => Err(AstError::invalid_sexpr("expected a name").into()),
+    }
+}
+
+/// Interprets an atom as a literal if it is one, and otherwise as an identifier.
+fn atom_to_expression(atom: &str) -> Result<Expression> {
+    match atom {
+        "true" => return Ok(boolean(true)),
+        "false" => return Ok(boolean(false)),
+        _ if atom.starts_with("aleo1") => return Ok(address(atom)),
+        _ => {}
+    }
+
+    let digits = atom.strip_prefix('-').unwrap_or(atom);
+    if !digits.starts_with(|c: char| c.is_ascii_digit()) {
+        return Ok(ident(atom));
+    }
+    let split =
+        atom.len() - digits.len() + digits.find(|c: char| !c.is_ascii_digit() && c != '_').unwrap_or(digits.len());
+    let (value, suffix) = atom.split_at(split);
+    Ok(match suffix {
+        "field" => field(value),
+        "group" => {
+            Expression::Literal(Literal::Group(Box::new(GroupLiteral::Single(value.to_string(), Span::default(), ID))))
+        }
+        "scalar" => scalar(value),
+        _ => match atom_to_type(suffix) {
+            Type::Integer(type_) => integer(type_, value),
+            _ => return Err(AstError::invalid_sexpr(format!("invalid literal `{atom}`")).into()),
+        },
+    })
+}
+
+/// Interprets a tree as a type, in the format written by `write_type`.
+fn to_type(tree: &Tree) -> Result<Type> {
+    let elements = match tree {
+        Tree::Atom(atom) => return Ok(atom_to_type(atom)),
+        Tree::String(_) => return Err(AstError::invalid_sexpr("expected a type").into()),
+        Tree::List(elements) => elements,
+    };
+    let (head, arguments) = match elements.split_first() {
+        Some(split) => split,
+        None => return Ok(Type::Unit),
+    };
+    match expect_atom(head)? {
+        "array_type" if arguments.len() == 2 => {
+            let length = expect_atom(&arguments[1])?
+                .parse::<usize>()
+                .map_err(|_| AstError::invalid_sexpr("an array length must be a non-negative integer"))?;
+            Ok(Type::Array(ArrayType::new(to_type(&arguments[0])?, length.into())))
+        }
+        "tuple_type" => Ok(Type::Tuple(TupleType::new(arguments.iter().map(to_type).collect::<Result<Vec<_>>>()?))),
+        head => Err(AstError::invalid_sexpr(format!("invalid type `({head} ...)`")).into()),
+    }
+}
+
+/// Interprets an atom as a type, where anything other than a primitive type is a named type.
+fn atom_to_type(atom: &str) -> Type {
+    match atom {
+        "address" => Type::Address,
+        "bool" | "boolean" => Type::Boolean,
+        "field" => Type::Field,
+        "group" => Type::Group,
+        "scalar" => Type::Scalar,
+        "signature" => Type::Signature,
+        "string" => Type::String,
+        "u8" => Type::Integer(IntegerType::U8),
+        "u16" => Type::Integer(IntegerType::U16),
+        "u32" => Type::Integer(IntegerType::U32),
+        "u64" => Type::Integer(IntegerType::U64),
+        "u128" => Type::Integer(IntegerType::U128),
+        "i8" => Type::Integer(IntegerType::I8),
+        "i16" => Type::Integer(IntegerType::I16),
+        "i32" => Type::Integer(IntegerType::I32),
+        "i64" => Type::Integer(IntegerType::I64),
+        "i128" => Type::Integer(IntegerType::I128),
+        _ => Type::Identifier(name(atom)),
+    }
+}
+
+fn atom_to_coordinate(atom: &str) -> GroupCoordinate {
+    match atom {
+        "+" => GroupCoordinate::SignHigh,
+        "-" => GroupCoordinate::SignLow,
+        "_" => GroupCoordinate::Inferred,
+        _ => GroupCoordinate::Number(atom.to_string(), Span::default()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_sexpr_escapes_strings() {
+        create_session_if_not_set_then(|_| {
+            for text in [r#"a "quoted" word"#, r"a \ backslash", "(parentheses)", r#"\""#] {
+                let expression = Expression::Literal(Literal::String(text.to_string(), Span::default(), ID));
+                let sexpr = expression.to_sexpr();
+                let parsed = parse_sexpr(&format!("(array {sexpr} {sexpr})")).unwrap();
+                assert!(
+                    parsed.eq_structural(&array(vec![expression.clone(), expression])),
+                    "{sexpr} did not round trip"
+                );
+            }
+            assert_eq!(
+                Expression::Literal(Literal::String(r#"say "hi" \o/"#.to_string(), Span::default(), ID)).to_sexpr(),
+                r#""say \"hi\" \\o/""#
+            );
+            assert!(parse_sexpr(r#""unterminated \""#).is_err());
+        });
+    }
+}
@@ -27,9 +27,19 @@ pub struct ArrayExpression {
     pub id: NodeID,
 }
 
+impl ArrayExpression {
+    /// Returns the fragments the array is printed as.
+    pub(crate) fn fragments(&self) -> Vec<Fragment<'_>> {
+        let mut fragments = vec![Fragment::Text("[")];
+        fragments.extend(separated(&self.elements, ","));
+        fragments.push(Fragment::Text("]"));
+        fragments
+    }
+}
+
 impl fmt::Display for ArrayExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "[{}]", self.elements.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","))
+        write_fragments(f, self.fragments())
     }
 }
 
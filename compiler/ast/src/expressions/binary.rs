@@ -157,6 +157,26 @@ impl BinaryOperation {
             _ => return None,
         })
     }
+
+    /// Returns `true` if the operands of the operation can be swapped without changing its result.
+    pub fn is_commutative(&self) -> bool {
+        matches!(
+            self,
+            Self::Add
+                | Self::AddWrapped
+                | Self::And
+                | Self::BitwiseAnd
+                | Self::Eq
+                | Self::Mul
+                | Self::MulWrapped
+                | Self::Nand
+                | Self::Neq
+                | Self::Nor
+                | Self::Or
+                | Self::BitwiseOr
+                | Self::Xor
+        )
+    }
 }
 
 /// A binary expression `left op right` of two operands separated by some operator.
@@ -175,9 +195,39 @@ pub struct BinaryExpression {
     pub id: NodeID,
 }
 
+impl BinaryExpression {
+    /// Returns the fragments the binary operation is printed as.
+    pub(crate) fn fragments(&self) -> Vec<Fragment<'_>> {
+        let precedence = self.op.precedence();
+        if let Some(method) = self.op.method_name() {
+            return vec![
+                Fragment::Operand(&self.left, Precedence::Postfix),
+                Fragment::Text("."),
+                Fragment::Text(method),
+                Fragment::Text("("),
+                Fragment::Operand(&self.right, Precedence::Ternary),
+                Fragment::Text(")"),
+            ];
+        }
+        // Equality and ordering operations cannot be chained, so neither operand may be at the same level.
+        // The remaining operations are left-associative, so only the left operand may be.
+        let left = match precedence {
+            Precedence::Equality | Precedence::Ordering => precedence.next(),
+            _ => precedence,
+        };
+        vec![
+            Fragment::Operand(&self.left, left),
+            Fragment::Text(" "),
+            Fragment::Display(&self.op),
+            Fragment::Text(" "),
+            Fragment::Operand(&self.right, precedence.next()),
+        ]
+    }
+}
+
 impl fmt::Display for BinaryExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {} {}", self.left, self.op, self.right)
+        write_fragments(f, self.fragments())
     }
 }
 
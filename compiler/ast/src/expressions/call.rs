@@ -32,24 +32,23 @@ pub struct CallExpression {
     pub id: NodeID,
 }
 
-impl fmt::Display for CallExpression {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match &self.external {
-            Some(external) => {
-                write!(f, "{external}.leo/{}(", self.function)?;
-            }
-            None => {
-                write!(f, "{}(", self.function)?;
-            }
+impl CallExpression {
+    /// Returns the fragments the call is printed as.
+    pub(crate) fn fragments(&self) -> Vec<Fragment<'_>> {
+        let mut fragments = Vec::with_capacity(2 * self.arguments.len() + 4);
+        if let Some(external) = &self.external {
+            fragments.extend([Fragment::Operand(external, Precedence::Ternary), Fragment::Text(".aleo/")]);
         }
+        fragments.extend([Fragment::Operand(&self.function, Precedence::Ternary), Fragment::Text("(")]);
+        fragments.extend(separated(&self.arguments, ", "));
+        fragments.push(Fragment::Text(")"));
+        fragments
+    }
+}
 
-        for (i, param) in self.arguments.iter().enumerate() {
-            write!(f, "{param}")?;
-            if i < self.arguments.len() - 1 {
-                write!(f, ", ")?;
-            }
-        }
-        write!(f, ")")
+impl fmt::Display for CallExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_fragments(f, self.fragments())
     }
 }
 
@@ -31,9 +31,20 @@ pub struct CastExpression {
     pub id: NodeID,
 }
 
+impl CastExpression {
+    /// Returns the fragments the cast is printed as.
+    pub(crate) fn fragments(&self) -> Vec<Fragment<'_>> {
+        vec![
+            Fragment::Operand(&self.expression, Precedence::Unary),
+            Fragment::Text(" as "),
+            Fragment::Display(&self.type_),
+        ]
+    }
+}
+
 impl fmt::Display for CastExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({} as {})", self.expression, self.type_)
+        write_fragments(f, self.fragments())
     }
 }
 
@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::metrics::operation_name;
+use crate::{Expression, ExpressionVisitor, Function, Program, ProgramVisitor, StatementVisitor};
+
+use std::fmt::Write;
+
+impl Expression {
+    /// Returns the expression tree as a Graphviz DOT graph, with one node per subexpression.
+    pub fn to_dot(&self) -> String {
+        self.to_dot_with(|_| None)
+    }
+
+    /// Returns the expression tree as a Graphviz DOT graph,
+    /// additionally labelling each node with the text returned by `annotate`, e.g. its type.
+    pub fn to_dot_with(&self, annotate: impl Fn(&Expression) -> Option<String>) -> String {
+        let mut writer = DotWriter::new(&annotate);
+        writer.write_tree(self);
+        writer.finish()
+    }
+}
+
+impl Program {
+    /// Returns every expression tree in the program as a single Graphviz DOT graph,
+    /// with the trees of each function grouped into a cluster.
+    /// Each node is additionally labelled with the text returned by `annotate`, e.g. its type.
+    pub fn to_dot_with(&self, annotate: impl Fn(&Expression) -> Option<String>) -> String {
+        let mut collector = DotCollector { writer: DotWriter::new(&annotate), clusters: 0 };
+        collector.visit_program(self);
+        collector.writer.finish()
+    }
+}
+
+/// Accumulates the nodes and edges of a DOT graph.
+struct DotWriter<'a> {
+    /// Returns the additional label of a node, if any.
+    annotate: &'a dyn Fn(&Expression) -> Option<String>,
+    /// The statements of the graph written so far.
+    body: String,
+    /// The number of nodes written so far, used to name the next node.
+    nodes: usize,
+}
+
+impl<'a> DotWriter<'a> {
+    fn new(annotate: &'a dyn Fn(&Expression) -> Option<String>) -> Self {
+        Self { annotate, body: String::new(), nodes: 0 }
+    }
+
+    /// Writes a node for `expression` and each of its subexpressions, returning the name of the root node.
+    fn write_tree(&mut self, expression: &Expression) -> usize {
+        let node = self.nodes;
+        self.nodes += 1;
+
+        let mut label = escape(&node_label(expression));
+        if let Some(annotation) = (self.annotate)(expression) {
+            label.push_str("\\n");
+            label.push_str(&escape(&annotation));
+        }
+        writeln!(self.body, "    n{node} [label=\"{label}\"];").expect("failed to write to string");
+
+        for child in expression.children() {
+            let child = self.write_tree(child);
+            writeln!(self.body, "    n{node} -> n{child};").expect("failed to write to string");
+        }
+
+        node
+    }
+
+    fn finish(self) -> String {
+        format!("digraph expression {{\n    node [shape=box];\n{}}}\n", self.body)
+    }
+}
+
+/// Writes the expression trees of each function in a program into a cluster of the graph.
+struct DotCollector<'a> {
+    writer: DotWriter<'a>,
+    /// The number of clusters written so far, used to name the next cluster.
+    clusters: usize,
+}
+
+impl<'a> ExpressionVisitor<'a> for DotCollector<'_> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_expression(&mut self, input: &'a Expression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.writer.write_tree(input);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for DotCollector<'_> {}
+
+impl<'a> ProgramVisitor<'a> for DotCollector<'_> {
+    fn visit_function(&mut self, input: &'a Function) {
+        writeln!(self.writer.body, "    subgraph cluster_{} {{", self.clusters).expect("failed to write to string");
+        writeln!(self.writer.body, "    label=\"{}\";", escape(&input.identifier.to_string()))
+            .expect("failed to write to string");
+        self.clusters += 1;
+
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.visit_block(&finalize.block);
+        }
+
+        writeln!(self.writer.body, "    }}").expect("failed to write to string");
+    }
+}
+
+/// Returns the label of a single node: the operation it performs, or the value of a leaf.
+fn node_label(expression: &Expression) -> String {
+    match expression {
+        Expression::Identifier(identifier) => identifier.to_string(),
+        Expression::Literal(literal) => literal.to_string(),
+        _ => operation_name(expression),
+    }
+}
+
+/// Escapes a string for use inside a quoted DOT identifier.
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::expr;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_to_dot() {
+        create_session_if_not_set_then(|_| {
+            let expression = expr::add(expr::ident("a"), expr::int_u8(1));
+            let expected = "digraph expression {
+    node [shape=box];
+    n0 [label=\"Add\"];
+    n1 [label=\"a\"];
+    n0 -> n1;
+    n2 [label=\"1u8\"];
+    n0 -> n2;
+}
+";
+            assert_eq!(expression.to_dot(), expected);
+        });
+    }
+
+    #[test]
+    fn test_to_dot_with_escapes_annotations() {
+        create_session_if_not_set_then(|_| {
+            let dot = expr::ident("a").to_dot_with(|_| Some("\"quoted\"".to_string()));
+            assert!(dot.contains("n0 [label=\"a\\n\\\"quoted\\\"\"];"), "{dot}");
+        });
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Expression, Identifier};
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+impl Expression {
+    /// Returns the identifiers that the expression reads from its environment.
+    ///
+    /// Leo expressions do not bind variables, so this is every identifier used as a value.
+    /// This includes struct initializer shorthands like `baz` in `Foo { baz }`, but excludes
+    /// names that are not values, such as callees, struct names, member names, and associated items.
+    ///
+    /// The identifiers are in the order they occur in, so that passes which iterate over them are deterministic.
+    /// Note that `Identifier` equality accounts for spans, so each occurrence is reported separately.
+    /// Use [`Expression::free_symbols`] to query dependencies by name.
+    pub fn free_identifiers(&self) -> IndexSet<Identifier> {
+        let mut identifiers = IndexSet::new();
+        self.collect_free_identifiers(&mut identifiers);
+        identifiers
+    }
+
+    /// Returns the names of the variables that the expression reads from its environment.
+    pub fn free_symbols(&self) -> IndexSet<Symbol> {
+        self.free_identifiers().into_iter().map(|identifier| identifier.name).collect()
+    }
+
+    /// Returns true if the expression reads the variable `symbol`.
+    pub fn references(&self, symbol: Symbol) -> bool {
+        self.free_identifiers().iter().any(|identifier| identifier.name == symbol)
+    }
+
+    /// Adds the free identifiers of the expression to `identifiers`.
+    fn collect_free_identifiers(&self, identifiers: &mut IndexSet<Identifier>) {
+        match self {
+            Expression::Identifier(identifier) => {
+                identifiers.insert(*identifier);
+            }
+            Expression::Struct(struct_) => struct_.members.iter().for_each(|member| match &member.expression {
+                Some(expression) => expression.collect_free_identifiers(identifiers),
+                None => {
+                    identifiers.insert(member.identifier);
+                }
+            }),
+            _ => self.children().into_iter().for_each(|child| child.collect_free_identifiers(identifiers)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{expr, Node, StructExpression, StructVariableInitializer};
+    use leo_span::{span::BytePos, symbol::create_session_if_not_set_then, Span};
+
+    /// Returns the struct initializer `Foo { x: c, d }`.
+    fn foo() -> Expression {
+        let initializer = |member: &str, expression| StructVariableInitializer {
+            identifier: expr::name(member),
+            expression,
+            span: Span::default(),
+            id: 0,
+        };
+        Expression::Struct(StructExpression {
+            name: expr::name("Foo"),
+            members: vec![initializer("x", Some(expr::ident("c"))), initializer("d", None)],
+            span: Span::default(),
+            id: 0,
+        })
+    }
+
+    #[test]
+    fn test_free_identifiers() {
+        create_session_if_not_set_then(|_| {
+            // `f(a, b.owner) + Foo { x: c, d }`
+            let expression =
+                expr::add(expr::call("f", vec![expr::ident("a"), expr::member(expr::ident("b"), "owner")]), foo());
+            let names =
+                |symbols: IndexSet<Symbol>| symbols.into_iter().map(|symbol| symbol.to_string()).collect::<Vec<_>>();
+            assert_eq!(names(expression.free_symbols()), ["a", "b", "c", "d"]);
+            assert!(expression.references(Symbol::intern("d")));
+            for name in ["f", "owner", "Foo", "x"] {
+                assert!(!expression.references(Symbol::intern(name)), "`{expression}` references `{name}`");
+            }
+        });
+    }
+
+    #[test]
+    fn test_free_identifiers_keeps_occurrences() {
+        create_session_if_not_set_then(|_| {
+            let mut right = expr::ident("a");
+            right.set_span(Span::new(BytePos(4), BytePos(5)));
+            let expression = expr::mul(expr::ident("a"), right);
+            assert_eq!(expression.free_identifiers().len(), 2);
+            assert_eq!(expression.free_symbols().len(), 1);
+        });
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Expression;
+
+impl Expression {
+    /// Returns an iterator over the expression and all of its subexpressions, in pre-order.
+    ///
+    /// Subexpressions are those returned by [`Expression::children`].
+    pub fn iter(&self) -> ExpressionIter<'_> {
+        ExpressionIter { stack: vec![self] }
+    }
+
+    /// Applies `f` to the expression and all of its subexpressions, in pre-order.
+    ///
+    /// This is the mutable counterpart to [`Expression::iter`].
+    /// An iterator cannot hand out a mutable reference to a node while another one to its parent is alive,
+    /// so the traversal is driven by a closure instead.
+    /// Changes that `f` makes to a node are visible when its children are visited.
+    pub fn for_each_mut(&mut self, f: &mut impl FnMut(&mut Expression)) {
+        f(self);
+        self.children_mut().into_iter().for_each(|child| child.for_each_mut(f));
+    }
+}
+
+/// A pre-order iterator over an expression tree, created by [`Expression::iter`].
+pub struct ExpressionIter<'a> {
+    /// The nodes still to be yielded, with the next one on top.
+    stack: Vec<&'a Expression>,
+}
+
+impl<'a> Iterator for ExpressionIter<'a> {
+    type Item = &'a Expression;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let expression = self.stack.pop()?;
+        // Push the children in reverse so that the leftmost child is yielded first.
+        self.stack.extend(expression.children().into_iter().rev());
+        Some(expression)
+    }
+}
+
+impl<'a> IntoIterator for &'a Expression {
+    type IntoIter = ExpressionIter<'a>;
+    type Item = &'a Expression;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{expr, BinaryOperation, Expression};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Returns `(a + b) * -c`.
+    fn example() -> Expression {
+        expr::mul(expr::add(expr::ident("a"), expr::ident("b")), expr::neg(expr::ident("c")))
+    }
+
+    #[test]
+    fn test_iter_is_pre_order() {
+        create_session_if_not_set_then(|_| {
+            let expression = example();
+            let nodes = expression.iter().map(|node| node.to_string()).collect::<Vec<_>>();
+            assert_eq!(nodes, ["(a + b) * -c", "a + b", "a", "b", "-c", "c"]);
+            assert_eq!(IntoIterator::into_iter(&expression).count(), 6);
+        });
+    }
+
+    #[test]
+    fn test_for_each_mut() {
+        create_session_if_not_set_then(|_| {
+            let mut expression = example();
+            let mut visited = Vec::new();
+            expression.for_each_mut(&mut |node| {
+                visited.push(node.to_string());
+                // Replacing a node before its children are visited means they are never visited.
+                if matches!(node, Expression::Binary(binary) if binary.op == BinaryOperation::Add) {
+                    *node = expr::ident("d");
+                }
+            });
+            assert_eq!(visited, ["(a + b) * -c", "a + b", "-c", "c"]);
+            assert_eq!(expression.to_string(), "d * -c");
+        });
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccessExpression, Expression, ExpressionVisitor, Program, ProgramVisitor, StatementVisitor};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Size and shape statistics for one or more expression trees.
+///
+/// These are a cheap proxy for the cost of a program, available before any code is generated.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpressionMetrics {
+    /// The total number of expression nodes.
+    pub node_count: usize,
+    /// The length of the longest path from a root expression to a leaf.
+    pub depth: usize,
+    /// The number of occurrences of each kind of operation, e.g. `Add` or `Ternary`.
+    pub operations: IndexMap<String, usize>,
+}
+
+impl ExpressionMetrics {
+    /// Folds the metrics of another expression tree into `self`.
+    pub fn combine(&mut self, other: ExpressionMetrics) {
+        self.node_count += other.node_count;
+        self.depth = self.depth.max(other.depth);
+        for (operation, count) in other.operations {
+            *self.operations.entry(operation).or_default() += count;
+        }
+    }
+
+    /// Records `expression` as a node at the given depth, and then its subexpressions.
+    fn record(&mut self, expression: &Expression, depth: usize) {
+        self.node_count += 1;
+        self.depth = self.depth.max(depth);
+        *self.operations.entry(operation_name(expression)).or_default() += 1;
+        expression.children().into_iter().for_each(|child| self.record(child, depth + 1));
+    }
+}
+
+impl fmt::Display for ExpressionMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} nodes, max depth {}", self.node_count, self.depth)?;
+        let mut operations = self.operations.iter().collect::<Vec<_>>();
+        operations.sort_by(|(a_name, a_count), (b_name, b_count)| b_count.cmp(a_count).then(a_name.cmp(b_name)));
+        for (operation, count) in operations {
+            write!(f, "\n  {operation}: {count}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the name under which an expression is counted in the operation histogram.
+pub(super) fn operation_name(expression: &Expression) -> String {
+    match expression {
+        Expression::Access(AccessExpression::Array(_)) => "ArrayAccess".to_string(),
+        Expression::Access(AccessExpression::AssociatedConstant(_)) => "AssociatedConstant".to_string(),
+        Expression::Access(AccessExpression::AssociatedFunction(access)) => format!("{}::{}", access.ty, access.name),
+        Expression::Access(AccessExpression::Member(_)) => "MemberAccess".to_string(),
+        Expression::Access(AccessExpression::Tuple(_)) => "TupleAccess".to_string(),
+        Expression::Array(_) => "Array".to_string(),
+        Expression::Binary(binary) => format!("{:?}", binary.op),
+        Expression::Call(_) => "Call".to_string(),
+        Expression::Cast(_) => "Cast".to_string(),
+        Expression::Struct(_) => "Struct".to_string(),
+        Expression::Err(_) => "Err".to_string(),
+        Expression::Identifier(_) => "Identifier".to_string(),
+        Expression::Literal(_) => "Literal".to_string(),
+        Expression::Ternary(_) => "Ternary".to_string(),
+        Expression::Tuple(_) => "Tuple".to_string(),
+        Expression::Unary(unary) => format!("{:?}", unary.op),
+        Expression::Unit(_) => "Unit".to_string(),
+    }
+}
+
+impl Expression {
+    /// Returns the number of nodes in the expression tree.
+    pub fn node_count(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns the depth of the expression tree, where a leaf has depth one.
+    pub fn depth(&self) -> usize {
+        1 + self.children().into_iter().map(Expression::depth).max().unwrap_or(0)
+    }
+
+    /// Returns the size, depth, and per-operation counts of the expression tree.
+    pub fn metrics(&self) -> ExpressionMetrics {
+        let mut metrics = ExpressionMetrics::default();
+        metrics.record(self, 1);
+        metrics
+    }
+}
+
+impl Program {
+    /// Returns the combined metrics of every expression tree in the program.
+    pub fn expression_metrics(&self) -> ExpressionMetrics {
+        let mut collector = MetricsCollector::default();
+        collector.visit_program(self);
+        collector.metrics
+    }
+}
+
+/// Collects the metrics of each root expression visited in a program.
+#[derive(Default)]
+struct MetricsCollector {
+    metrics: ExpressionMetrics,
+}
+
+impl<'a> ExpressionVisitor<'a> for MetricsCollector {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_expression(&mut self, input: &'a Expression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.metrics.combine(input.metrics());
+    }
+}
+
+impl<'a> StatementVisitor<'a> for MetricsCollector {}
+
+impl<'a> ProgramVisitor<'a> for MetricsCollector {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{expr, UnaryOperation};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_metrics() {
+        create_session_if_not_set_then(|_| {
+            // `(a + b) * (a + 1u8).abs()`
+            let expression = expr::mul(
+                expr::add(expr::ident("a"), expr::ident("b")),
+                expr::unary(UnaryOperation::Abs, expr::add(expr::ident("a"), expr::int_u8(1))),
+            );
+            assert_eq!(expression.node_count(), 8);
+            assert_eq!(expression.depth(), 4);
+
+            let metrics = expression.metrics();
+            assert_eq!(metrics.node_count, 8);
+            assert_eq!(metrics.depth, 4);
+            assert_eq!(metrics.operations.get("Add"), Some(&2));
+            assert_eq!(metrics.operations.get("Identifier"), Some(&3));
+            assert_eq!(metrics.operations.get("Mul"), Some(&1));
+            assert_eq!(metrics.operations.get("Abs"), Some(&1));
+            assert_eq!(
+                metrics.to_string(),
+                "8 nodes, max depth 4\n  Identifier: 3\n  Add: 2\n  Abs: 1\n  Literal: 1\n  Mul: 1"
+            );
+
+            let mut combined = metrics;
+            combined.combine(expr::ident("c").metrics());
+            assert_eq!(combined.node_count, 9);
+            assert_eq!(combined.depth, 4);
+            assert_eq!(combined.operations.get("Identifier"), Some(&4));
+        });
+    }
+}
@@ -41,6 +41,18 @@ pub use struct_init::*;
 mod err;
 pub use err::*;
 
+mod dot;
+
+mod free_identifiers;
+
+mod iter;
+pub use iter::*;
+
+mod substitute;
+
+mod structural;
+pub use structural::*;
+
 mod ternary;
 pub use ternary::*;
 
@@ -56,6 +68,12 @@ pub use unit::*;
 mod literal;
 pub use literal::*;
 
+mod metrics;
+pub use metrics::*;
+
+mod precedence;
+pub(crate) use precedence::*;
+
 /// Expression that evaluates to a value.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Expression {
@@ -88,6 +106,54 @@ pub enum Expression {
     Unit(UnitExpression),
 }
 
+impl Expression {
+    /// Returns the immediate subexpressions of this expression, in source order.
+    ///
+    /// The callee and external program of a call expression are names rather than values,
+    /// so they are not considered subexpressions.
+    pub fn children(&self) -> Vec<&Expression> {
+        use Expression::*;
+        match self {
+            Access(AccessExpression::Array(access)) => vec![&access.array, &access.index],
+            Access(AccessExpression::AssociatedConstant(_)) => vec![],
+            Access(AccessExpression::AssociatedFunction(access)) => access.arguments.iter().collect(),
+            Access(AccessExpression::Member(access)) => vec![&access.inner],
+            Access(AccessExpression::Tuple(access)) => vec![&access.tuple],
+            Array(array) => array.elements.iter().collect(),
+            Binary(binary) => vec![&binary.left, &binary.right],
+            Call(call) => call.arguments.iter().collect(),
+            Cast(cast) => vec![&cast.expression],
+            Struct(struct_) => struct_.members.iter().filter_map(|member| member.expression.as_ref()).collect(),
+            Ternary(ternary) => vec![&ternary.condition, &ternary.if_true, &ternary.if_false],
+            Tuple(tuple) => tuple.elements.iter().collect(),
+            Unary(unary) => vec![&unary.receiver],
+            Err(_) | Identifier(_) | Literal(_) | Unit(_) => vec![],
+        }
+    }
+
+    /// Returns mutable references to the immediate subexpressions of this expression, in source order.
+    /// See [`Expression::children`] for which subexpressions are included.
+    pub fn children_mut(&mut self) -> Vec<&mut Expression> {
+        use Expression::*;
+        match self {
+            Access(AccessExpression::Array(access)) => vec![&mut access.array, &mut access.index],
+            Access(AccessExpression::AssociatedConstant(_)) => vec![],
+            Access(AccessExpression::AssociatedFunction(access)) => access.arguments.iter_mut().collect(),
+            Access(AccessExpression::Member(access)) => vec![&mut access.inner],
+            Access(AccessExpression::Tuple(access)) => vec![&mut access.tuple],
+            Array(array) => array.elements.iter_mut().collect(),
+            Binary(binary) => vec![&mut binary.left, &mut binary.right],
+            Call(call) => call.arguments.iter_mut().collect(),
+            Cast(cast) => vec![&mut cast.expression],
+            Struct(struct_) => struct_.members.iter_mut().filter_map(|member| member.expression.as_mut()).collect(),
+            Ternary(ternary) => vec![&mut ternary.condition, &mut ternary.if_true, &mut ternary.if_false],
+            Tuple(tuple) => tuple.elements.iter_mut().collect(),
+            Unary(unary) => vec![&mut unary.receiver],
+            Err(_) | Identifier(_) | Literal(_) | Unit(_) => vec![],
+        }
+    }
+}
+
 impl Node for Expression {
     fn span(&self) -> Span {
         use Expression::*;
@@ -168,21 +234,6 @@ impl Node for Expression {
 
 impl fmt::Display for Expression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use Expression::*;
-        match &self {
-            Access(n) => n.fmt(f),
-            Array(n) => n.fmt(f),
-            Binary(n) => n.fmt(f),
-            Call(n) => n.fmt(f),
-            Cast(n) => n.fmt(f),
-            Struct(n) => n.fmt(f),
-            Err(n) => n.fmt(f),
-            Identifier(n) => n.fmt(f),
-            Literal(n) => n.fmt(f),
-            Ternary(n) => n.fmt(f),
-            Tuple(n) => n.fmt(f),
-            Unary(n) => n.fmt(f),
-            Unit(n) => n.fmt(f),
-        }
+        write_fragments(f, self.fragments())
     }
 }
@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// How tightly an expression binds when it is printed, from loosest to tightest.
+///
+/// The levels mirror the grammar of the parser, so that an expression printed with
+/// parentheses only where [`Precedence`] requires them is parsed back into the same tree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Precedence {
+    Ternary,
+    Or,
+    And,
+    Equality,
+    Ordering,
+    BitwiseXor,
+    BitwiseOr,
+    BitwiseAnd,
+    Shift,
+    Additive,
+    Multiplicative,
+    Exponential,
+    Cast,
+    Unary,
+    Postfix,
+}
+
+impl Precedence {
+    /// Returns the next tighter level.
+    pub(crate) fn next(self) -> Self {
+        use Precedence::*;
+        match self {
+            Ternary => Or,
+            Or => And,
+            And => Equality,
+            Equality => Ordering,
+            Ordering => BitwiseXor,
+            BitwiseXor => BitwiseOr,
+            BitwiseOr => BitwiseAnd,
+            BitwiseAnd => Shift,
+            Shift => Additive,
+            Additive => Multiplicative,
+            Multiplicative => Exponential,
+            Exponential => Cast,
+            Cast => Unary,
+            Unary | Postfix => Postfix,
+        }
+    }
+}
+
+impl BinaryOperation {
+    /// Returns the level of the operation, where operations written as method calls, e.g. `a.add_wrapped(b)`, are postfix.
+    pub(crate) fn precedence(&self) -> Precedence {
+        match self {
+            Self::Or => Precedence::Or,
+            Self::And => Precedence::And,
+            Self::Eq | Self::Neq => Precedence::Equality,
+            Self::Lt | Self::Lte | Self::Gt | Self::Gte => Precedence::Ordering,
+            Self::Xor => Precedence::BitwiseXor,
+            Self::BitwiseOr => Precedence::BitwiseOr,
+            Self::BitwiseAnd => Precedence::BitwiseAnd,
+            Self::Shl | Self::Shr => Precedence::Shift,
+            Self::Add | Self::Sub => Precedence::Additive,
+            Self::Mul | Self::Div | Self::Rem => Precedence::Multiplicative,
+            Self::Pow => Precedence::Exponential,
+            _ => Precedence::Postfix,
+        }
+    }
+
+    /// Returns the name of the method the operation is written as, if it has no infix operator.
+    pub(crate) fn method_name(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::AddWrapped => "add_wrapped",
+            Self::DivWrapped => "div_wrapped",
+            Self::Mod => "mod",
+            Self::MulWrapped => "mul_wrapped",
+            Self::Nand => "nand",
+            Self::Nor => "nor",
+            Self::PowWrapped => "pow_wrapped",
+            Self::RemWrapped => "rem_wrapped",
+            Self::ShlWrapped => "shl_wrapped",
+            Self::ShrWrapped => "shr_wrapped",
+            Self::SubWrapped => "sub_wrapped",
+            _ => return None,
+        })
+    }
+}
+
+impl Expression {
+    /// Returns the level at which the expression is printed.
+    pub(crate) fn precedence(&self) -> Precedence {
+        match self {
+            Expression::Binary(binary) => binary.op.precedence(),
+            Expression::Cast(_) => Precedence::Cast,
+            Expression::Ternary(_) => Precedence::Ternary,
+            Expression::Unary(unary) => match unary.op {
+                UnaryOperation::Not => Precedence::Unary,
+                // The negation of a literal is printed as a method call; see the `Display` implementation.
+                UnaryOperation::Negate if !matches!(*unary.receiver, Expression::Literal(_)) => Precedence::Unary,
+                _ => Precedence::Postfix,
+            },
+            // A negative literal is printed with a leading `-`.
+            Expression::Literal(literal) if literal.to_string().starts_with('-') => Precedence::Unary,
+            _ => Precedence::Postfix,
+        }
+    }
+
+    /// Returns a wrapper printing the expression in a position requiring at least the given level,
+    /// adding parentheses if the expression binds more loosely.
+    pub(crate) fn operand(&self, precedence: Precedence) -> Operand<'_> {
+        Operand { expression: self, precedence }
+    }
+}
+
+/// An expression printed as an operand; see [`Expression::operand`].
+pub(crate) struct Operand<'a> {
+    expression: &'a Expression,
+    precedence: Precedence,
+}
+
+impl fmt::Display for Operand<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_fragments(f, vec![Fragment::Operand(self.expression, self.precedence)])
+    }
+}
+
+/// A part of a printed expression.
+///
+/// Expressions nest arbitrarily deep, e.g. after loop unrolling, so the operators, ternaries, casts, calls, tuples,
+/// and arrays are split into fragments that [`write_fragments`] prints with a work stack instead of recursion.
+pub(crate) enum Fragment<'a> {
+    /// Text that is printed as is.
+    Text(&'static str),
+    /// A value printed with its own `Display` implementation.
+    Display(&'a dyn fmt::Display),
+    /// An expression printed in a position requiring at least the given level.
+    Operand(&'a Expression, Precedence),
+}
+
+impl Expression {
+    /// Returns the fragments the expression is printed as.
+    pub(crate) fn fragments(&self) -> Vec<Fragment<'_>> {
+        match self {
+            Expression::Array(array) => array.fragments(),
+            Expression::Binary(binary) => binary.fragments(),
+            Expression::Call(call) => call.fragments(),
+            Expression::Cast(cast) => cast.fragments(),
+            Expression::Ternary(ternary) => ternary.fragments(),
+            Expression::Tuple(tuple) => tuple.fragments(),
+            Expression::Unary(unary) => unary.fragments(),
+            Expression::Access(access) => vec![Fragment::Display(access)],
+            Expression::Struct(struct_) => vec![Fragment::Display(struct_)],
+            Expression::Err(err) => vec![Fragment::Display(err)],
+            Expression::Identifier(identifier) => vec![Fragment::Display(identifier)],
+            Expression::Literal(literal) => vec![Fragment::Display(literal)],
+            Expression::Unit(unit) => vec![Fragment::Display(unit)],
+        }
+    }
+}
+
+/// Returns the fragments of `elements` separated by `separator`.
+pub(crate) fn separated<'a>(elements: &'a [Expression], separator: &'static str) -> Vec<Fragment<'a>> {
+    let mut fragments = Vec::with_capacity(2 * elements.len());
+    for (i, element) in elements.iter().enumerate() {
+        if i > 0 {
+            fragments.push(Fragment::Text(separator));
+        }
+        fragments.push(Fragment::Operand(element, Precedence::Ternary));
+    }
+    fragments
+}
+
+/// Prints `fragments`, splitting the expressions among them into their own fragments until only text is left.
+pub(crate) fn write_fragments(f: &mut fmt::Formatter, fragments: Vec<Fragment<'_>>) -> fmt::Result {
+    // The fragments still to be printed, with the next one on top.
+    let mut stack = fragments;
+    stack.reverse();
+    while let Some(fragment) = stack.pop() {
+        match fragment {
+            Fragment::Text(text) => f.write_str(text)?,
+            Fragment::Display(value) => value.fmt(f)?,
+            Fragment::Operand(expression, precedence) => {
+                let parenthesize = expression.precedence() < precedence;
+                if parenthesize {
+                    f.write_str("(")?;
+                    stack.push(Fragment::Text(")"));
+                }
+                stack.extend(expression.fragments().into_iter().rev());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::expr;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_parentheses() {
+        create_session_if_not_set_then(|_| {
+            let (a, b, c) = (expr::ident("a"), expr::ident("b"), expr::ident("c"));
+            assert_eq!(expr::mul(expr::add(a.clone(), b.clone()), c.clone()).to_string(), "(a + b) * c");
+            assert_eq!(expr::add(a.clone(), expr::mul(b.clone(), c.clone())).to_string(), "a + b * c");
+            assert_eq!(expr::sub(expr::sub(a.clone(), b.clone()), c.clone()).to_string(), "a - b - c");
+            assert_eq!(expr::sub(a.clone(), expr::sub(b.clone(), c.clone())).to_string(), "a - (b - c)");
+            assert_eq!(expr::eq(expr::eq(a.clone(), b.clone()), c.clone()).to_string(), "(a == b) == c");
+            assert_eq!(expr::not(expr::add(a.clone(), b.clone())).to_string(), "!(a + b)");
+            assert_eq!(
+                expr::ternary(expr::ternary(a.clone(), b.clone(), c.clone()), b.clone(), c.clone()).to_string(),
+                "(a ? b : c) ? b : c"
+            );
+            assert_eq!(expr::call("f", vec![expr::ternary(a, b, c)]).to_string(), "f(a ? b : c)");
+        });
+    }
+
+    #[test]
+    fn test_deep_nesting() {
+        create_session_if_not_set_then(|_| {
+            const DEPTH: usize = 100_000;
+            let mut expression = expr::ident("a");
+            for _ in 0..DEPTH {
+                expression = expr::not(expr::add(expression, expr::ident("a")));
+            }
+            let printed = expression.to_string();
+            assert_eq!(printed.len(), DEPTH * "!( + a)".len() + 1);
+            assert!(printed.starts_with("!(!(!("));
+            assert!(printed.ends_with(" + a) + a)"));
+            // Dropping the tree recurses once per level, so leak it rather than overflow the stack.
+            std::mem::forget(expression);
+        });
+    }
+}
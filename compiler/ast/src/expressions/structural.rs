@@ -0,0 +1,200 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Structural equality and hashing of expressions.
+//!
+//! Two expressions are structurally equal if they are the same kind of node, hold the same data, and have
+//! structurally equal subexpressions in the same order. The data of a node is what it means apart from where it is:
+//! names, operators, types of casts, member names and tuple indices, and the text of literals, including their type.
+//! Spans and node IDs are ignored. Literals are compared as written, so `1u8` and `01u8` are not equal, and no
+//! algebraic identities are applied, so `a + b` and `b + a` are not equal either.
+
+use crate::{AccessExpression, Expression, GroupLiteral, Literal};
+
+use std::hash::{Hash, Hasher};
+
+impl Expression {
+    /// Returns `true` if the two expressions have the same structure.
+    ///
+    /// Unlike `==`, this ignores location metadata, i.e. spans and node IDs,
+    /// so two occurrences of `a * b` in different places of a program compare equal.
+    pub fn eq_structural(&self, other: &Expression) -> bool {
+        let (left_children, right_children) = (self.children(), other.children());
+        structural_label(self) == structural_label(other)
+            && left_children.len() == right_children.len()
+            && left_children.iter().zip(right_children.iter()).all(|(left, right)| left.eq_structural(right))
+    }
+
+    /// Feeds the structure of the expression into `state`, ignoring location metadata.
+    ///
+    /// Consistent with [`Expression::eq_structural`]: structurally equal expressions hash equally.
+    pub fn hash_structural<H: Hasher>(&self, state: &mut H) {
+        structural_label(self).hash(state);
+        let children = self.children();
+        children.len().hash(state);
+        children.into_iter().for_each(|child| child.hash_structural(state));
+    }
+}
+
+/// Returns a description of the data an expression node holds apart from its subexpressions and location.
+/// Each label is prefixed by the kind of node, so that nodes of different kinds never share a label.
+fn structural_label(expression: &Expression) -> String {
+    match expression {
+        Expression::Access(AccessExpression::Array(_)) => "array_access".to_string(),
+        Expression::Access(AccessExpression::AssociatedConstant(access)) => {
+            format!("associated_constant:{}::{}", access.ty, access.name)
+        }
+        Expression::Access(AccessExpression::AssociatedFunction(access)) => {
+            format!("associated_function:{}::{}", access.ty, access.name)
+        }
+        Expression::Access(AccessExpression::Member(access)) => format!("member_access:{}", access.name),
+        Expression::Access(AccessExpression::Tuple(access)) => format!("tuple_access:{}", access.index),
+        Expression::Array(_) => "array".to_string(),
+        Expression::Binary(binary) => format!("binary:{:?}", binary.op),
+        Expression::Call(call) => match &call.external {
+            Some(external) => format!("call:{external}/{}", call.function),
+            None => format!("call:{}", call.function),
+        },
+        Expression::Cast(cast) => format!("cast:{}", cast.type_),
+        Expression::Struct(struct_) => format!(
+            "struct:{}{{{}}}",
+            struct_.name,
+            struct_
+                .members
+                .iter()
+                .map(|member| match member.expression {
+                    Some(_) => format!("{}:", member.identifier),
+                    None => member.identifier.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        ),
+        Expression::Err(_) => "err".to_string(),
+        Expression::Identifier(identifier) => format!("identifier:{identifier}"),
+        Expression::Literal(literal) => match literal {
+            Literal::Address(value, ..) => format!("address:{value}"),
+            Literal::Boolean(value, ..) => format!("boolean:{value}"),
+            Literal::Field(value, ..) => format!("field:{value}"),
+            Literal::Group(group) => match group.as_ref() {
+                GroupLiteral::Single(value, ..) => format!("group:{value}"),
+                GroupLiteral::Tuple(tuple) => format!("group:({},{})", tuple.x, tuple.y),
+            },
+            Literal::Integer(type_, value, ..) => format!("integer:{value}{type_}"),
+            Literal::Scalar(value, ..) => format!("scalar:{value}"),
+            Literal::String(value, ..) => format!("string:{value:?}"),
+        },
+        Expression::Ternary(_) => "ternary".to_string(),
+        Expression::Tuple(_) => "tuple".to_string(),
+        Expression::Unary(unary) => format!("unary:{:?}", unary.op),
+        Expression::Unit(_) => "unit".to_string(),
+    }
+}
+
+/// A wrapper comparing and hashing an expression by its structure rather than its location.
+///
+/// Two wrapped expressions are equal if the expressions are equal by [`Expression::eq_structural`],
+/// i.e. up to spans and node IDs, and equal wrapped expressions have equal hashes.
+/// This allows expressions to be used as keys of a `HashMap` or `IndexMap`,
+/// e.g. to find repeated subexpressions.
+#[derive(Clone, Debug)]
+pub struct StructuralExpression(pub Expression);
+
+impl PartialEq for StructuralExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_structural(&other.0)
+    }
+}
+
+impl Eq for StructuralExpression {}
+
+impl Hash for StructuralExpression {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_structural(state)
+    }
+}
+
+impl From<Expression> for StructuralExpression {
+    fn from(expression: Expression) -> Self {
+        Self(expression)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{expr, IntegerType, Node};
+    use leo_span::{span::BytePos, symbol::create_session_if_not_set_then, Span};
+
+    use indexmap::IndexSet;
+
+    /// Gives every node of `expression` the node ID `id` and a span starting at `id`.
+    fn relocate(expression: &mut Expression, id: usize) {
+        let mut stack = vec![expression];
+        while let Some(node) = stack.pop() {
+            node.set_id(id);
+            node.set_span(Span::new(BytePos(id as u32), BytePos(id as u32 + 1)));
+            stack.extend(node.children_mut());
+        }
+    }
+
+    #[test]
+    fn test_eq_structural_ignores_location() {
+        create_session_if_not_set_then(|_| {
+            let build = || expr::mul(expr::ident("a"), expr::add(expr::ident("b"), expr::int_u8(1)));
+            let (mut left, mut right) = (build(), build());
+            relocate(&mut left, 1);
+            relocate(&mut right, 2);
+            assert_ne!(left, right);
+            assert!(left.eq_structural(&right));
+            assert_eq!(StructuralExpression(left), StructuralExpression(right));
+        });
+    }
+
+    #[test]
+    fn test_eq_structural_compares_data() {
+        create_session_if_not_set_then(|_| {
+            let base = expr::add(expr::ident("a"), expr::int_u8(1));
+            for other in [
+                expr::sub(expr::ident("a"), expr::int_u8(1)),
+                expr::add(expr::ident("b"), expr::int_u8(1)),
+                expr::add(expr::ident("a"), expr::int_u16(1)),
+                expr::add(expr::ident("a"), expr::integer(IntegerType::U8, "01")),
+                expr::add(expr::int_u8(1), expr::ident("a")),
+                expr::call("add", vec![expr::ident("a"), expr::int_u8(1)]),
+            ] {
+                assert!(!base.eq_structural(&other), "`{base}` is structurally equal to `{other}`");
+            }
+            assert!(!expr::member(expr::ident("p"), "x").eq_structural(&expr::member(expr::ident("p"), "y")));
+            assert!(!expr::tuple_access(expr::ident("t"), 0).eq_structural(&expr::tuple_access(expr::ident("t"), 1)));
+        });
+    }
+
+    #[test]
+    fn test_structural_expressions_as_keys() {
+        create_session_if_not_set_then(|_| {
+            let mut first = expr::add(expr::ident("a"), expr::ident("b"));
+            relocate(&mut first, 1);
+            let mut second = first.clone();
+            relocate(&mut second, 2);
+
+            let set = [first, second, expr::add(expr::ident("b"), expr::ident("a"))]
+                .into_iter()
+                .map(StructuralExpression::from)
+                .collect::<IndexSet<_>>();
+            assert_eq!(set.len(), 2);
+        });
+    }
+}
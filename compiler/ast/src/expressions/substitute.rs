@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Expression, Identifier};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+impl Expression {
+    /// Returns a copy of the expression in which each free identifier with a key in `substitutions`
+    /// is replaced by the corresponding expression.
+    ///
+    /// Keys are matched by name, so a single entry replaces every occurrence of a variable.
+    /// Leo expressions do not bind variables, so the replacement can never be captured.
+    /// Struct initializer shorthands like `Foo { baz }` are expanded to `Foo { baz: <replacement> }`.
+    pub fn substitute(&self, substitutions: &IndexMap<Identifier, Expression>) -> Expression {
+        let substitutions: IndexMap<Symbol, &Expression> =
+            substitutions.iter().map(|(identifier, expression)| (identifier.name, expression)).collect();
+        let mut expression = self.clone();
+        expression.substitute_in_place(&substitutions);
+        expression
+    }
+
+    /// Replaces the free identifiers in the expression, in place.
+    fn substitute_in_place(&mut self, substitutions: &IndexMap<Symbol, &Expression>) {
+        match self {
+            Expression::Identifier(identifier) => {
+                if let Some(replacement) = substitutions.get(&identifier.name).copied() {
+                    *self = replacement.clone();
+                }
+            }
+            Expression::Struct(struct_) => struct_.members.iter_mut().for_each(|member| match &mut member.expression {
+                Some(expression) => expression.substitute_in_place(substitutions),
+                None => member.expression = substitutions.get(&member.identifier.name).map(|e| (*e).clone()),
+            }),
+            _ => self.children_mut().into_iter().for_each(|child| child.substitute_in_place(substitutions)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{expr, StructExpression, StructVariableInitializer};
+    use leo_span::{symbol::create_session_if_not_set_then, Span};
+
+    #[test]
+    fn test_substitute() {
+        create_session_if_not_set_then(|_| {
+            // `a * f(a, b.a)`
+            let expression = expr::mul(
+                expr::ident("a"),
+                expr::call("f", vec![expr::ident("a"), expr::member(expr::ident("b"), "a")]),
+            );
+            let substitutions = IndexMap::from([
+                (expr::name("a"), expr::add(expr::ident("x"), expr::int_u8(1))),
+                (expr::name("f"), expr::ident("g")),
+            ]);
+            let substituted = expression.substitute(&substitutions);
+            assert_eq!(substituted.to_string(), "(x + 1u8) * f(x + 1u8, b.a)");
+            assert_eq!(expression.to_string(), "a * f(a, b.a)");
+        });
+    }
+
+    #[test]
+    fn test_substitute_expands_struct_shorthands() {
+        create_session_if_not_set_then(|_| {
+            // `Foo { a }`
+            let expression = Expression::Struct(StructExpression {
+                name: expr::name("Foo"),
+                members: vec![StructVariableInitializer {
+                    identifier: expr::name("a"),
+                    expression: None,
+                    span: Span::default(),
+                    id: 0,
+                }],
+                span: Span::default(),
+                id: 0,
+            });
+            let substituted = expression.substitute(&IndexMap::from([(expr::name("a"), expr::int_u8(1))]));
+            assert_eq!(substituted.to_string(), "Foo {a: 1u8}");
+        });
+    }
+}
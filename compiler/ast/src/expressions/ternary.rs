@@ -31,9 +31,22 @@ pub struct TernaryExpression {
     pub id: NodeID,
 }
 
+impl TernaryExpression {
+    /// Returns the fragments the ternary is printed as.
+    pub(crate) fn fragments(&self) -> Vec<Fragment<'_>> {
+        vec![
+            Fragment::Operand(&self.condition, Precedence::Or),
+            Fragment::Text(" ? "),
+            Fragment::Operand(&self.if_true, Precedence::Ternary),
+            Fragment::Text(" : "),
+            Fragment::Operand(&self.if_false, Precedence::Ternary),
+        ]
+    }
+}
+
 impl fmt::Display for TernaryExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({} ? {} : {})", self.condition, self.if_true, self.if_false)
+        write_fragments(f, self.fragments())
     }
 }
 
@@ -30,9 +30,19 @@ pub struct TupleExpression {
     pub id: NodeID,
 }
 
+impl TupleExpression {
+    /// Returns the fragments the tuple is printed as.
+    pub(crate) fn fragments(&self) -> Vec<Fragment<'_>> {
+        let mut fragments = vec![Fragment::Text("(")];
+        fragments.extend(separated(&self.elements, ","));
+        fragments.push(Fragment::Text(")"));
+        fragments
+    }
+}
+
 impl fmt::Display for TupleExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({})", self.elements.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","))
+        write_fragments(f, self.fragments())
     }
 }
 
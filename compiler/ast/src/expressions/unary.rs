@@ -90,9 +90,28 @@ pub struct UnaryExpression {
     pub id: NodeID,
 }
 
+impl UnaryExpression {
+    /// Returns the fragments the unary operation is printed as.
+    pub(crate) fn fragments(&self) -> Vec<Fragment<'_>> {
+        match self.op {
+            UnaryOperation::Not => vec![Fragment::Text("!"), Fragment::Operand(&self.receiver, Precedence::Unary)],
+            // The parser reads `-` directly followed by a literal as a negative literal, so use the method form instead.
+            UnaryOperation::Negate if !matches!(*self.receiver, Expression::Literal(_)) => {
+                vec![Fragment::Text("-"), Fragment::Operand(&self.receiver, Precedence::Unary)]
+            }
+            op => vec![
+                Fragment::Operand(&self.receiver, Precedence::Postfix),
+                Fragment::Text("."),
+                Fragment::Text(op.as_str()),
+                Fragment::Text("()"),
+            ],
+        }
+    }
+}
+
 impl fmt::Display for UnaryExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.op.as_str(), self.receiver)
+        write_fragments(f, self.fragments())
     }
 }
 
@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{simple_node_impl, Identifier, Node, NodeID};
+use crate::{simple_node_impl, Identifier, Node, NodeID, NonNegativeNumber};
 
-use leo_span::Span;
+use leo_span::{sym, Span};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -27,6 +27,9 @@ pub struct Annotation {
     // TODO: Consider using a symbol instead of an identifier.
     /// The name of the annotation.
     pub identifier: Identifier,
+    /// The arguments of the annotation, e.g. `8` in `@recursive(8)`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<AnnotationArgument>,
     /// A span locating where the annotation occurred in the source.
     pub span: Span,
     /// The ID of the node.
@@ -35,8 +38,58 @@ pub struct Annotation {
 
 simple_node_impl!(Annotation);
 
+impl Annotation {
+    /// Returns the feature of a `@cfg(feature = "name")` annotation, or `None` if it is not a well-formed one.
+    pub fn cfg_feature(&self) -> Option<&str> {
+        match self.arguments.as_slice() {
+            [AnnotationArgument::KeyValue(key, value)]
+                if self.identifier.name == sym::cfg && key.name == sym::feature =>
+            {
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for Annotation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "@{}", self.identifier)
+        write!(f, "@{}", self.identifier)?;
+        if !self.arguments.is_empty() {
+            write!(
+                f,
+                "({})",
+                self.arguments.iter().map(|argument| argument.to_string()).collect::<Vec<_>>().join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// An argument of an annotation, e.g. `8` in `@recursive(8)`, or `feature = "debug"` in `@cfg(feature = "debug")`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AnnotationArgument {
+    /// A non-negative number, e.g. `8`.
+    Number(NonNegativeNumber),
+    /// A key with a string value, e.g. `feature = "debug"`.
+    KeyValue(Identifier, String),
+}
+
+impl AnnotationArgument {
+    /// Returns the value of the argument if it is a number.
+    pub fn number(&self) -> Option<usize> {
+        match self {
+            Self::Number(number) => Some(number.value()),
+            Self::KeyValue(..) => None,
+        }
+    }
+}
+
+impl fmt::Display for AnnotationArgument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "{number}"),
+            Self::KeyValue(key, value) => write!(f, "{key} = \"{value}\""),
+        }
     }
 }
@@ -288,6 +288,8 @@ pub enum CoreFunction {
     GroupToYCoordinate,
 
     SignatureVerify,
+
+    LookupGet,
 }
 
 impl CoreFunction {
@@ -564,6 +566,8 @@ impl CoreFunction {
             (sym::group, sym::to_y_coordinate) => Self::GroupToYCoordinate,
 
             (sym::signature, sym::verify) => Self::SignatureVerify,
+
+            (sym::Lookup, sym::get) => Self::LookupGet,
             _ => return None,
         })
     }
@@ -841,6 +845,8 @@ impl CoreFunction {
             Self::GroupToYCoordinate => 1,
 
             Self::SignatureVerify => 3,
+
+            Self::LookupGet => 2,
         }
     }
 
@@ -1097,7 +1103,8 @@ impl CoreFunction {
             | CoreFunction::SHA3_512HashToScalar
             | CoreFunction::GroupToXCoordinate
             | CoreFunction::GroupToYCoordinate
-            | CoreFunction::SignatureVerify => false,
+            | CoreFunction::SignatureVerify
+            | CoreFunction::LookupGet => false,
         }
     }
 }
@@ -60,6 +60,15 @@ impl Input {
             External(_) => Mode::None,
         }
     }
+
+    /// Returns `true` if the parameter is passed by reference.
+    pub fn is_reference(&self) -> bool {
+        use Input::*;
+        match self {
+            Internal(input) => input.reference,
+            External(_) => false,
+        }
+    }
 }
 
 impl Node for Input {
@@ -105,6 +114,10 @@ pub struct FunctionInput {
     pub mode: Mode,
     /// What's the parameter's type?
     pub type_: Type,
+    /// Whether the parameter is passed by reference, as in `a: &[u8; 4096]`.
+    /// A reference aliases the argument of the caller, so it cannot be assigned to.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reference: bool,
     /// The parameters span from any annotations to its type.
     pub span: Span,
     /// The ID of the node.
@@ -113,7 +126,8 @@ pub struct FunctionInput {
 
 impl FunctionInput {
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {}: {}", self.mode, self.identifier, self.type_)
+        let reference = if self.reference { "&" } else { "" };
+        write!(f, "{} {}: {reference}{}", self.mode, self.identifier, self.type_)
     }
 }
 
@@ -39,7 +39,7 @@ pub mod mode;
 pub use mode::*;
 
 use crate::{Block, FunctionStub, Identifier, Node, NodeID, TupleType, Type};
-use leo_span::{Span, Symbol};
+use leo_span::{sym, Span, Symbol};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -49,6 +49,9 @@ use std::fmt;
 pub struct Function {
     /// Annotations on the function.
     pub annotations: Vec<Annotation>,
+    /// The lines of the doc comments of the function, without their leading `///`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     /// Is this function a transition, inlined, or a regular function?.
     pub variant: Variant,
     /// The function identifier, e.g., `foo` in `function foo(...) { ... }`.
@@ -103,7 +106,19 @@ impl Function {
             _ => Type::Tuple(TupleType::new(output.iter().map(get_output_type).collect())),
         };
 
-        Function { annotations, variant, identifier, input, output, output_type, block, finalize, span, id }
+        Function {
+            annotations,
+            docs: Vec::new(),
+            variant,
+            identifier,
+            input,
+            output,
+            output_type,
+            block,
+            finalize,
+            span,
+            id,
+        }
     }
 
     /// Returns function name.
@@ -111,6 +126,31 @@ impl Function {
         self.identifier.name
     }
 
+    /// Returns `true` if the function is annotated with `@test`.
+    pub fn is_test(&self) -> bool {
+        self.annotations.iter().any(|annotation| annotation.identifier.name == sym::test)
+    }
+
+    /// Returns `true` if the function is inlined at its call sites, i.e. if it is an `inline` function,
+    /// or if it is annotated with `@inline`.
+    pub fn is_inlined(&self) -> bool {
+        self.variant == Variant::Inline
+            || self.annotations.iter().any(|annotation| annotation.identifier.name == sym::inline)
+    }
+
+    /// Returns `true` if the function is annotated with `@deprecated`.
+    pub fn is_deprecated(&self) -> bool {
+        self.annotations.iter().any(|annotation| annotation.identifier.name == sym::deprecated)
+    }
+
+    /// Returns the maximum depth of recursion declared by a `@recursive(depth)` annotation, if there is one.
+    pub fn recursion_depth(&self) -> Option<usize> {
+        self.annotations
+            .iter()
+            .find(|annotation| annotation.identifier.name == sym::recursive)
+            .map(|annotation| annotation.arguments.first().and_then(AnnotationArgument::number).unwrap_or(0))
+    }
+
     ///
     /// Private formatting method used for optimizing [fmt::Debug] and [fmt::Display] implementations.
     ///
@@ -144,6 +184,7 @@ impl From<FunctionStub> for Function {
         let finalize = function.finalize_stub.map(Finalize::from);
         Self {
             annotations: function.annotations,
+            docs: Vec::new(),
             variant: function.variant,
             identifier: function.identifier,
             input: function.input,
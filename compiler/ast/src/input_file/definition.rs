@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Expression, Identifier, Mode, Type};
+
+use leo_span::Span;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A definition in an input file, e.g. `public a: u32 = 1u32;`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Definition {
+    /// The mode of the input, which is checked against the mode of the function's parameter.
+    pub mode: Mode,
+    /// The name of the input.
+    pub name: Identifier,
+    /// The type of the input.
+    pub type_: Type,
+    /// The value of the input.
+    pub value: Expression,
+    /// The span of the definition.
+    pub span: Span,
+}
+
+impl fmt::Display for Definition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.mode {
+            Mode::None => write!(f, "{}: {} = {};", self.name, self.type_, self.value),
+            mode => write!(f, "{mode} {}: {} = {};", self.name, self.type_, self.value),
+        }
+    }
+}
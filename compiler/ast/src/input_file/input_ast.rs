@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Section;
+
+use leo_span::Symbol;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Stores the Leo input file abstract syntax tree.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct InputAst {
+    /// The sections of the input file.
+    pub sections: Vec<Section>,
+}
+
+impl InputAst {
+    /// Returns the first section with the given name, if any.
+    pub fn section(&self, name: Symbol) -> Option<&Section> {
+        self.sections.iter().find(|section| section.name.name == name)
+    }
+
+    /// Splits the input file into input sets for the function with the given name, one for each of its sections.
+    /// A section of the function starts a new input set, which holds it and the sections that follow it.
+    /// The sections before the first section of the function, e.g. `[constants]`, are shared by every input set.
+    pub fn input_sets(&self, function: Symbol) -> Vec<InputAst> {
+        let shared = self.sections.iter().take_while(|section| section.name.name != function).collect::<Vec<_>>();
+
+        let mut input_sets: Vec<InputAst> = Vec::new();
+        for section in self.sections.iter().skip(shared.len()) {
+            match input_sets.last_mut() {
+                Some(input_set) if section.name.name != function => input_set.sections.push(section.clone()),
+                _ => input_sets.push(InputAst { sections: vec![section.clone()] }),
+            }
+        }
+        if input_sets.is_empty() {
+            return vec![self.clone()];
+        }
+
+        // The sections of an input set take precedence over the shared sections, as they are looked up first.
+        for input_set in input_sets.iter_mut() {
+            input_set.sections.extend(shared.iter().map(|section| (*section).clone()));
+        }
+        input_sets
+    }
+}
+
+impl fmt::Display for InputAst {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for section in self.sections.iter() {
+            writeln!(f, "{section}")?;
+        }
+        Ok(())
+    }
+}
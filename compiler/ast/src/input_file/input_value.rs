@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Expression, Identifier, Literal, Mode, Node, Struct, Type, Value};
+
+use leo_errors::InputError;
+use leo_span::{sym, Symbol};
+use std::fmt;
+
+/// The value of an input, which has been checked against the type of the input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputValue {
+    /// A literal value, e.g. `1u32`.
+    Literal(Literal),
+    /// An array value, e.g. `[1u8, 2u8]`.
+    Array(Vec<InputValue>),
+    /// A struct value, with its members in the order the struct declares them, e.g. `{ x: 1u32, y: 2u32 }`.
+    Struct(Identifier, Vec<(Identifier, InputValue)>),
+    /// A record value, with its members in the order the record declares them and the modes they are declared with,
+    /// e.g. `{ owner: aleo1..., amount: 1u64 }`.
+    Record(Identifier, Vec<(Identifier, Mode, InputValue)>),
+}
+
+impl InputValue {
+    /// Checks that `expression` is a value of type `type_`, and returns it as an input value.
+    /// The members of struct and record values are checked against the declarations in `structs`.
+    pub fn new(type_: &Type, expression: &Expression, structs: &[(Symbol, Struct)]) -> Result<Self, InputError> {
+        match (type_, expression) {
+            (_, Expression::Literal(literal)) => {
+                let found = literal_type(literal);
+                if !type_.eq_flat(&found) {
+                    return Err(InputError::unexpected_type(type_, found, literal.span()));
+                }
+                // Check that integers are within the range of their type.
+                if let Literal::Integer(integer_type, value, span, _) = literal {
+                    Value::try_from(literal)
+                        .map_err(|_| InputError::integer_out_of_range(value, integer_type, *span))?;
+                }
+                Ok(Self::Literal(literal.clone()))
+            }
+            (Type::Array(array_type), Expression::Array(array)) => {
+                if array.elements.len() != array_type.length() {
+                    return Err(InputError::array_length_mismatch(
+                        array_type.length(),
+                        array.elements.len(),
+                        array.span,
+                    ));
+                }
+                let elements = array
+                    .elements
+                    .iter()
+                    .map(|element| Self::new(array_type.element_type(), element, structs))
+                    .collect::<Result<_, _>>()?;
+                Ok(Self::Array(elements))
+            }
+            (Type::Identifier(identifier), Expression::Struct(struct_expression)) => {
+                if identifier.name != struct_expression.name.name {
+                    return Err(InputError::unexpected_type(type_, struct_expression.name, struct_expression.span));
+                }
+                let struct_ = structs
+                    .iter()
+                    .find(|(name, _)| *name == identifier.name)
+                    .map(|(_, struct_)| struct_)
+                    .ok_or_else(|| InputError::unknown_struct(identifier, struct_expression.span))?;
+
+                // Check that each member is given exactly once.
+                for member in struct_expression.members.iter() {
+                    let declared = struct_.members.iter().any(|declared| declared.name() == member.identifier.name);
+                    let given = struct_expression
+                        .members
+                        .iter()
+                        .filter(|other| other.identifier.name == member.identifier.name)
+                        .count();
+                    if !declared || given > 1 {
+                        return Err(InputError::unexpected_struct_member(identifier, member.identifier, member.span));
+                    }
+                }
+                let members: Vec<(Identifier, Mode, InputValue)> = struct_
+                    .members
+                    .iter()
+                    .map(|declared| {
+                        let member = struct_expression
+                            .members
+                            .iter()
+                            .find(|member| member.identifier.name == declared.name())
+                            .ok_or_else(|| {
+                                InputError::missing_struct_member(
+                                    identifier,
+                                    declared.identifier,
+                                    struct_expression.span,
+                                )
+                            })?;
+                        match &member.expression {
+                            Some(expression) => {
+                                Ok((member.identifier, declared.mode, Self::new(&declared.type_, expression, structs)?))
+                            }
+                            None => Err(InputError::invalid_value(member.identifier, member.span)),
+                        }
+                    })
+                    .collect::<Result<_, _>>()?;
+                match struct_.is_record {
+                    true => Ok(Self::Record(*identifier, members)),
+                    false => Ok(Self::Struct(
+                        *identifier,
+                        members.into_iter().map(|(name, _, value)| (name, value)).collect(),
+                    )),
+                }
+            }
+            (_, Expression::Array(array)) => Err(InputError::unexpected_type(type_, "an array", array.span)),
+            (_, Expression::Struct(struct_expression)) => {
+                Err(InputError::unexpected_type(type_, struct_expression.name, struct_expression.span))
+            }
+            _ => Err(InputError::invalid_value(expression, expression.span())),
+        }
+    }
+
+    /// Prints the value as an entry of a record with the given mode, where each literal is suffixed with its
+    /// visibility, e.g. `{ x: 1u32.private, y: 2u32.private }`. Members without a mode are private.
+    fn to_entry(&self, mode: Mode) -> String {
+        match self {
+            Self::Literal(literal) => match mode {
+                Mode::None => format!("{literal}.private"),
+                mode => format!("{literal}.{mode}"),
+            },
+            Self::Array(elements) => {
+                format!("[{}]", elements.iter().map(|element| element.to_entry(mode)).collect::<Vec<_>>().join(", "))
+            }
+            Self::Struct(_, members) => format!(
+                "{{ {} }}",
+                members
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {}", value.to_entry(mode)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::Record(..) => self.to_string(),
+        }
+    }
+}
+
+/// Returns the type of a literal.
+fn literal_type(literal: &Literal) -> Type {
+    match literal {
+        Literal::Address(..) => Type::Address,
+        Literal::Boolean(..) => Type::Boolean,
+        Literal::Field(..) => Type::Field,
+        Literal::Group(..) => Type::Group,
+        Literal::Integer(integer_type, ..) => Type::Integer(*integer_type),
+        Literal::Scalar(..) => Type::Scalar,
+        Literal::String(..) => Type::String,
+    }
+}
+
+/// Prints the value in the syntax of Aleo instructions, e.g. `1u32`, `[1u8, 2u8]` or `{ x: 1u32, y: 2u32 }`.
+/// A record is printed as its plaintext with its `owner` first and the nonce `0group`, e.g.
+/// `{ owner: aleo1....private, amount: 1u64.public, _nonce: 0group.public }`.
+impl fmt::Display for InputValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => write!(f, "{literal}"),
+            Self::Array(elements) => {
+                write!(f, "[{}]", elements.iter().map(|element| element.to_string()).collect::<Vec<_>>().join(", "))
+            }
+            Self::Struct(_, members) => write!(
+                f,
+                "{{ {} }}",
+                members.iter().map(|(name, value)| format!("{name}: {value}")).collect::<Vec<_>>().join(", ")
+            ),
+            Self::Record(_, members) => {
+                let (owner, others): (Vec<_>, Vec<_>) = members.iter().partition(|(name, ..)| name.name == sym::owner);
+                let entries = owner
+                    .into_iter()
+                    .chain(others)
+                    .map(|(name, mode, value)| format!("{name}: {}", value.to_entry(*mode)))
+                    .chain(std::iter::once("_nonce: 0group.public".to_string()))
+                    .collect::<Vec<_>>();
+                write!(f, "{{ {} }}", entries.join(", "))
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The AST of a Leo input file, which gives the values of the inputs to a function in typed sections, e.g.
+//!
+//! ```text
+//! [main]
+//! a: u32 = 1u32;
+//! public c: field = 1field;
+//! d: [u8; 2] = [1u8, 2u8];
+//! p: Point = { x: 1u32, y: 2u32 };
+//!
+//! [constants]
+//! b: bool = true;
+//!
+//! [records]
+//! t: Token = { owner: aleo1..., amount: 1u64 };
+//!
+//! [registers]
+//! r0: u32 = 2u32;
+//! ```
+//!
+//! An input may be annotated `public` or `private`, which must agree with the mode of the function's parameter.
+
+pub mod definition;
+pub use definition::*;
+
+pub mod input_ast;
+pub use input_ast::*;
+
+pub mod input_value;
+pub use input_value::*;
+
+pub mod program_input;
+pub use program_input::*;
+
+pub mod section;
+pub use section::*;
+
+pub mod template;
+pub use template::*;
@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Function, Identifier, Input, InputAst, InputValue, Mode, Struct, Type};
+
+use leo_errors::{emitter::Handler, InputError, Result};
+use leo_span::Symbol;
+
+/// The name of the section that holds the constant inputs of a function.
+pub const CONSTANTS_SECTION: &str = "constants";
+
+/// The name of the section that holds the record inputs of a function.
+pub const RECORDS_SECTION: &str = "records";
+
+/// The name of the section that holds the expected outputs of a function.
+pub const REGISTERS_SECTION: &str = "registers";
+
+/// The values of the inputs to a function, read from an input file and checked against the function's signature.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProgramInput {
+    /// The values of the inputs, in the order of the function's parameters, with the modes they are declared with.
+    pub values: Vec<(Mode, InputValue)>,
+    /// The expected values of the outputs, with the registers they are named by, e.g. `r0`.
+    pub registers: Vec<(Identifier, InputValue)>,
+}
+
+impl ProgramInput {
+    /// Reads the values of the inputs to `function` from `input`.
+    /// The constant inputs are read from the `[constants]` section, the records from the `[records]` section, and the
+    /// other inputs from the section named after the function, e.g. `[main]`. The optional `[registers]` section holds
+    /// the expected values of the outputs. Struct and record values are checked against the declarations in `structs`.
+    ///
+    /// Every missing, unexpected, and mistyped entry is emitted to `handler`, before the last error is returned.
+    pub fn new(input: &InputAst, function: &Function, structs: &[(Symbol, Struct)], handler: &Handler) -> Result<Self> {
+        let function_section = input.section(function.identifier.name);
+        let constants_section = input.section(Symbol::intern(CONSTANTS_SECTION));
+        let records_section = input.section(Symbol::intern(RECORDS_SECTION));
+
+        let mut values = Vec::with_capacity(function.input.len());
+        for parameter in function.input.iter() {
+            let parameter = match parameter {
+                Input::Internal(parameter) => parameter,
+                Input::External(parameter) => {
+                    handler.emit_err(InputError::unsupported_input(parameter.identifier, parameter.span));
+                    continue;
+                }
+            };
+
+            let (section_name, section) = match parameter.mode {
+                Mode::Constant => (Symbol::intern(CONSTANTS_SECTION), constants_section),
+                _ if is_record(&parameter.type_, structs) => (Symbol::intern(RECORDS_SECTION), records_section),
+                _ => (function.identifier.name, function_section),
+            };
+            // Point at the section in the input file if it exists, and otherwise at the parameter.
+            let definition = match section.and_then(|section| section.definition(parameter.identifier.name)) {
+                Some(definition) => definition,
+                None => {
+                    let span = section.map_or(parameter.span, |section| section.span);
+                    handler.emit_err(InputError::missing_input(parameter.identifier, section_name, span));
+                    continue;
+                }
+            };
+
+            if !definition.type_.eq_flat(&parameter.type_) {
+                handler.emit_err(InputError::input_type_mismatch(
+                    parameter.identifier,
+                    &parameter.type_,
+                    &definition.type_,
+                    definition.span,
+                ));
+                continue;
+            }
+            // An unannotated input takes the mode of the parameter, but an annotated one must agree with it.
+            let mode = visibility(parameter.mode);
+            if definition.mode != Mode::None && visibility(definition.mode) != mode {
+                handler.emit_err(InputError::input_mode_mismatch(
+                    parameter.identifier,
+                    mode,
+                    definition.mode,
+                    definition.span,
+                ));
+                continue;
+            }
+            match InputValue::new(&parameter.type_, &definition.value, structs) {
+                Ok(value) => values.push((mode, value)),
+                Err(err) => handler.emit_err(err),
+            }
+        }
+
+        // Report the entries of the function's section that are not its parameters, or that are given twice.
+        // The `[constants]` and `[records]` sections may be shared with other functions, so only duplicates are
+        // reported there.
+        for section in [function_section, constants_section, records_section].into_iter().flatten() {
+            let is_shared = section.name.name != function.identifier.name;
+            for (index, definition) in section.definitions.iter().enumerate() {
+                let is_parameter = function.input.iter().any(|parameter| {
+                    parameter.identifier().name == definition.name.name
+                        && parameter.mode() != Mode::Constant
+                        && !is_record(&parameter.type_(), structs)
+                });
+                if section.definitions[..index].iter().any(|previous| previous.name.name == definition.name.name) {
+                    handler.emit_err(InputError::duplicate_input(definition.name, section.name, definition.span));
+                } else if !is_shared && !is_parameter {
+                    handler.emit_err(InputError::unexpected_input(definition.name, section.name, definition.span));
+                }
+            }
+        }
+
+        let mut registers = Vec::new();
+        if let Some(section) = input.section(Symbol::intern(REGISTERS_SECTION)) {
+            for definition in section.definitions.iter() {
+                let output = match register_index(definition.name.name).and_then(|index| function.output.get(index)) {
+                    Some(output) => output,
+                    None => {
+                        handler.emit_err(InputError::unknown_register(
+                            definition.name,
+                            function.output.len(),
+                            definition.span,
+                        ));
+                        continue;
+                    }
+                };
+
+                if !definition.type_.eq_flat(&output.type_()) {
+                    handler.emit_err(InputError::input_type_mismatch(
+                        definition.name,
+                        output.type_(),
+                        &definition.type_,
+                        definition.span,
+                    ));
+                    continue;
+                }
+                match InputValue::new(&definition.type_, &definition.value, structs) {
+                    Ok(value) => registers.push((definition.name, value)),
+                    Err(err) => handler.emit_err(err),
+                }
+            }
+        }
+
+        handler.last_err().map_err(|err| *err)?;
+        Ok(Self { values, registers })
+    }
+
+    /// Checks the `outputs` of the function, as printed by snarkVM, against the expected values in the `[registers]`
+    /// section. Whitespace and the nonces of records are ignored, since the nonces are random.
+    pub fn check_outputs(&self, outputs: &[String]) -> Result<(), InputError> {
+        for (register, expected) in self.registers.iter() {
+            let found = register_index(register.name).and_then(|index| outputs.get(index)).map_or("", String::as_str);
+            if normalize_output(found) != normalize_output(&expected.to_string()) {
+                return Err(InputError::output_mismatch(register, expected, found, register.span));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the values of the public inputs, which are the statement the verifier checks the proof against.
+    /// The values of the private inputs are only known to the prover.
+    pub fn public_values(&self) -> Vec<&InputValue> {
+        self.values.iter().filter(|(mode, _)| *mode == Mode::Public).map(|(_, value)| value).collect()
+    }
+}
+
+/// Returns the mode an input is passed with, as parameters without an annotation are private.
+fn visibility(mode: Mode) -> Mode {
+    match mode {
+        Mode::None => Mode::Private,
+        mode => mode,
+    }
+}
+
+/// Returns `true` if `type_` is a record declared in `structs`.
+fn is_record(type_: &Type, structs: &[(Symbol, Struct)]) -> bool {
+    match type_ {
+        Type::Identifier(identifier) => {
+            structs.iter().any(|(name, struct_)| *name == identifier.name && struct_.is_record)
+        }
+        _ => false,
+    }
+}
+
+/// Returns the index of the output that a register names, e.g. `0` for `r0`.
+fn register_index(name: Symbol) -> Option<usize> {
+    name.to_string().strip_prefix('r')?.parse().ok()
+}
+
+/// Returns an output without whitespace and without the nonce of a record, which is its last entry.
+fn normalize_output(output: &str) -> String {
+    let output = output.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+    match output.find(",_nonce:") {
+        Some(start) => {
+            let end = output[start..].find('}').map_or(output.len(), |end| start + end);
+            format!("{}{}", &output[..start], &output[end..])
+        }
+        None => output,
+    }
+}
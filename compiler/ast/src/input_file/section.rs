@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Definition, Identifier};
+
+use leo_span::{Span, Symbol};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A section of an input file, e.g. `[main]` and the definitions that follow it.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Section {
+    /// The name of the section.
+    pub name: Identifier,
+    /// The definitions in the section.
+    pub definitions: Vec<Definition>,
+    /// The span of the section header.
+    pub span: Span,
+}
+
+impl Section {
+    /// Returns the definition of the input with the given name, if any.
+    pub fn definition(&self, name: Symbol) -> Option<&Definition> {
+        self.definitions.iter().find(|definition| definition.name.name == name)
+    }
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "[{}]", self.name)?;
+        for definition in self.definitions.iter() {
+            writeln!(f, "{definition}")?;
+        }
+        Ok(())
+    }
+}
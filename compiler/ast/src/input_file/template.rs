@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Function, Input, Mode, Struct, Type, CONSTANTS_SECTION, RECORDS_SECTION};
+
+use leo_span::Symbol;
+
+/// The zero address, which is the default value of an `address` input.
+const ZERO_ADDRESS: &str = "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc";
+
+/// Returns the text of an input file for `function`, giving each of its inputs the zero value of its type.
+/// Each input is commented with its type, and the inputs that cannot be given in an input file are commented out.
+pub fn input_file_template(function: &Function, structs: &[(Symbol, Struct)]) -> String {
+    let mut inputs = String::new();
+    let mut constants = String::new();
+    let mut records = String::new();
+    for parameter in function.input.iter() {
+        let (name, mode, type_) = match parameter {
+            Input::Internal(parameter) => (parameter.identifier, parameter.mode, &parameter.type_),
+            Input::External(parameter) => {
+                inputs.push_str(&format!(
+                    "// `{}` is a record of `{}.aleo`, which must be passed on the command line.\n",
+                    parameter.identifier, parameter.program_name
+                ));
+                continue;
+            }
+        };
+
+        let section = match mode {
+            Mode::Constant => &mut constants,
+            _ if find_composite(type_, structs).map_or(false, |composite| composite.is_record) => &mut records,
+            _ => &mut inputs,
+        };
+        let definition = match mode {
+            Mode::None | Mode::Constant => format!("{name}: {type_}"),
+            mode => format!("{mode} {name}: {type_}"),
+        };
+        section.push_str(&match zero_value(type_, structs) {
+            Some(value) => format!("{definition} = {value}; // {}\n", describe(type_, structs)),
+            None => format!("// `{name}` is {}, which cannot be given in an input file.\n", describe(type_, structs)),
+        });
+    }
+
+    let mut template = format!("// The inputs to `{}`.\n[{}]\n{inputs}", function.identifier, function.identifier);
+    if !constants.is_empty() {
+        template.push_str(&format!("\n[{CONSTANTS_SECTION}]\n{constants}"));
+    }
+    if !records.is_empty() {
+        template.push_str(&format!("\n[{RECORDS_SECTION}]\n{records}"));
+    }
+    template
+}
+
+/// Returns the zero value of `type_` in the syntax of input files, if a value of the type can be given in one.
+fn zero_value(type_: &Type, structs: &[(Symbol, Struct)]) -> Option<String> {
+    match type_ {
+        Type::Address => Some(ZERO_ADDRESS.to_string()),
+        Type::Boolean => Some("false".to_string()),
+        Type::Field => Some("0field".to_string()),
+        Type::Group => Some("0group".to_string()),
+        Type::Integer(integer_type) => Some(format!("0{integer_type}")),
+        Type::Scalar => Some("0scalar".to_string()),
+        Type::Array(array_type) => {
+            let element = zero_value(array_type.element_type(), structs)?;
+            Some(format!("[{}]", vec![element; array_type.length()].join(", ")))
+        }
+        Type::Identifier(_) => {
+            let struct_ = find_composite(type_, structs)?;
+            let members = struct_
+                .members
+                .iter()
+                .map(|member| Some(format!("{}: {}", member.identifier, zero_value(&member.type_, structs)?)))
+                .collect::<Option<Vec<_>>>()?;
+            Some(format!("{} {{ {} }}", struct_.identifier, members.join(", ")))
+        }
+        Type::Mapping(_) | Type::Signature | Type::String | Type::Tuple(_) | Type::Unit | Type::Err => None,
+    }
+}
+
+/// Describes `type_` for the comment of an input, e.g. "an array of 2 `u8`".
+fn describe(type_: &Type, structs: &[(Symbol, Struct)]) -> String {
+    match type_ {
+        Type::Address => "an address".to_string(),
+        Type::Integer(integer_type) => format!("a `{integer_type}` integer"),
+        Type::Array(array_type) => format!("an array of {} `{}`", array_type.length(), array_type.element_type()),
+        Type::Identifier(identifier) => match find_composite(type_, structs) {
+            Some(composite) if !composite.is_record => format!("a `{identifier}` struct"),
+            _ => format!("a `{identifier}` record"),
+        },
+        type_ => format!("a `{type_}`"),
+    }
+}
+
+/// Returns the struct or record that `type_` names, if it is declared.
+fn find_composite<'a>(type_: &Type, structs: &'a [(Symbol, Struct)]) -> Option<&'a Struct> {
+    match type_ {
+        Type::Identifier(identifier) => {
+            structs.iter().find(|(name, _)| *name == identifier.name).map(|(_, struct_)| struct_)
+        }
+        _ => None,
+    }
+}
@@ -31,6 +31,11 @@ pub use self::r#struct::*;
 pub mod common;
 pub use self::common::*;
 
+pub mod diff;
+pub use self::diff::*;
+
+pub mod expr;
+
 pub mod expressions;
 pub use self::expressions::*;
 
@@ -40,12 +45,21 @@ pub use self::functions::*;
 pub mod groups;
 pub use self::groups::*;
 
+pub mod input_file;
+pub use self::input_file::*;
+
+pub mod r#macro;
+pub use self::r#macro::*;
+
 pub mod mapping;
 pub use self::mapping::*;
 
 pub mod passes;
 pub use self::passes::*;
 
+pub mod printer;
+pub use self::printer::*;
+
 pub mod program;
 pub use self::program::*;
 
@@ -112,6 +126,19 @@ impl Ast {
             .map_err(|e| AstError::failed_to_write_ast_to_json_file(&path, &e))?)
     }
 
+    /// Writes the expression trees of the ast into a Graphviz DOT file,
+    /// labelling each node with the text returned by `annotate`, e.g. its type.
+    pub fn to_dot_file(
+        &self,
+        mut path: std::path::PathBuf,
+        file_name: &str,
+        annotate: impl Fn(&Expression) -> Option<String>,
+    ) -> Result<()> {
+        path.push(file_name);
+        Ok(std::fs::write(&path, self.ast.to_dot_with(annotate))
+            .map_err(|e| AstError::failed_to_write_ast_to_dot_file(&path, &e))?)
+    }
+
     /// Serializes the ast into a JSON value and removes keys from object mappings before writing to a file.
     pub fn to_json_file_without_keys(
         &self,
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Expression, Identifier, Node, NodeID};
+
+use itertools::Itertools;
+use leo_span::Span;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A macro definition, e.g. `macro rotl(x, n) { (x << n) | (x >> (32u32 - n)) }`.
+///
+/// Macros are called like functions, e.g. `rotl(a, 3u32)`. Before the program is type checked, each call is replaced
+/// by the body of the macro, in which each parameter is replaced by the corresponding argument.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Macro {
+    /// The name of the macro.
+    pub identifier: Identifier,
+    /// The parameters of the macro, which are untyped.
+    pub parameters: Vec<Identifier>,
+    /// The expression that a call to the macro expands to.
+    pub body: Expression,
+    /// The entire span of the macro definition.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for Macro {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "macro {}({}) {{ {} }}", self.identifier, self.parameters.iter().join(", "), self.body)
+    }
+}
+
+crate::simple_node_impl!(Macro);
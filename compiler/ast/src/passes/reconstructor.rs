@@ -272,6 +272,7 @@ pub trait StatementReconstructor: ExpressionReconstructor {
                         self.reconstruct_expression(left).0,
                         self.reconstruct_expression(right).0,
                     ),
+                    AssertVariant::ConstAssert(expr) => AssertVariant::ConstAssert(self.reconstruct_expression(expr).0),
                 },
                 span: input.span,
                 id: input.id,
@@ -386,6 +387,9 @@ pub trait StatementReconstructor: ExpressionReconstructor {
                 stop_value: input.stop_value,
                 block: self.reconstruct_block(input.block).0,
                 inclusive: input.inclusive,
+                step: input.step.map(|step| self.reconstruct_expression(step).0),
+                step_value: input.step_value,
+                reverse: input.reverse,
                 span: input.span,
                 id: input.id,
             })),
@@ -417,6 +421,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
                 .into_iter()
                 .map(|(id, import)| (id, (self.reconstruct_import(import.0), import.1)))
                 .collect(),
+            module_imports: input.module_imports,
             stubs: input.stubs.into_iter().map(|(id, stub)| (id, self.reconstruct_stub(stub))).collect(),
             program_scopes: input
                 .program_scopes
@@ -452,6 +457,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
                     _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
                 })
                 .collect(),
+            macros: input.macros,
             span: input.span,
         }
     }
@@ -459,6 +465,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
     fn reconstruct_function(&mut self, input: Function) -> Function {
         Function {
             annotations: input.annotations,
+            docs: input.docs,
             variant: input.variant,
             identifier: input.identifier,
             input: input.input,
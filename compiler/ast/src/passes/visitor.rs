@@ -150,7 +150,9 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
 
     fn visit_assert(&mut self, input: &'a AssertStatement) {
         match &input.variant {
-            AssertVariant::Assert(expr) => self.visit_expression(expr, &Default::default()),
+            AssertVariant::Assert(expr) | AssertVariant::ConstAssert(expr) => {
+                self.visit_expression(expr, &Default::default())
+            }
             AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
                 self.visit_expression(left, &Default::default());
                 self.visit_expression(right, &Default::default())
@@ -205,6 +207,9 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
     fn visit_iteration(&mut self, input: &'a IterationStatement) {
         self.visit_expression(&input.start, &Default::default());
         self.visit_expression(&input.stop, &Default::default());
+        if let Some(step) = &input.step {
+            self.visit_expression(step, &Default::default());
+        }
         self.visit_block(&input.block);
     }
 
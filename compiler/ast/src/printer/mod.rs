@@ -0,0 +1,449 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A pretty-printer, which prints programs and modules as source code in a fixed style.
+//!
+//! Items are printed in the order they appear in the source, separated by blank lines.
+//! Blocks are indented by four spaces, and expressions and types are printed by their `Display` implementations.
+//! The AST does not record comments, but the printer can be given the [`Comments`] of the source,
+//! in which case each comment is printed before the item, member, or statement that follows it,
+//! or at the end of the line it trails. Comments within an expression are printed before its statement.
+
+use crate::{
+    AssertStatement,
+    AssertVariant,
+    Block,
+    Comments,
+    ConditionalStatement,
+    ConsoleFunction,
+    ConstDeclaration,
+    DefinitionStatement,
+    Expression,
+    Function,
+    Input,
+    IterationStatement,
+    Macro,
+    Mapping,
+    Mode,
+    Module,
+    Node,
+    Output,
+    Program,
+    ReturnStatement,
+    Statement,
+    Struct,
+    Variant,
+};
+
+use leo_span::{span::BytePos, Span};
+use std::fmt::Write;
+
+/// The string that a block is indented by.
+const INDENT: &str = "    ";
+
+/// Returns the source code of the program, printed in the formatter's style.
+pub fn format_program(program: &Program) -> String {
+    format_program_with_comments(program, Comments::default())
+}
+
+/// Returns the source code of the program, printed in the formatter's style with the comments of its source.
+pub fn format_program_with_comments(program: &Program, comments: Comments) -> String {
+    let mut printer = Printer { comments, ..Default::default() };
+    printer.print_program(program);
+    printer.finish()
+}
+
+/// Returns the source code of the module, printed in the formatter's style.
+pub fn format_module(module: &Module) -> String {
+    format_module_with_comments(module, Comments::default())
+}
+
+/// Returns the source code of the module, printed in the formatter's style with the comments of its source.
+pub fn format_module_with_comments(module: &Module, comments: Comments) -> String {
+    let mut printer = Printer { comments, ..Default::default() };
+    printer.print_module(module);
+    printer.finish()
+}
+
+/// An item of a program scope or module, which are printed in the order of their spans.
+enum Item<'a> {
+    Const(&'a ConstDeclaration),
+    Struct(&'a Struct),
+    Mapping(&'a Mapping),
+    Function(&'a Function),
+    Macro(&'a Macro),
+}
+
+impl Item<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Item::Const(declaration) => declaration.span,
+            Item::Struct(struct_) => struct_.annotations.first().map_or(struct_.span, |annotation| annotation.span),
+            Item::Mapping(mapping) => mapping.span,
+            Item::Function(function) => {
+                function.annotations.first().map_or(function.span, |annotation| annotation.span)
+            }
+            Item::Macro(macro_) => macro_.span,
+        }
+    }
+
+    /// Returns `true` if the item fits on a single line, so that a run of them is not separated by blank lines.
+    fn is_single_line(&self) -> bool {
+        matches!(self, Item::Const(_) | Item::Mapping(_))
+    }
+}
+
+/// Prints source code into a string, line by line.
+#[derive(Default)]
+struct Printer {
+    /// The printed source code.
+    out: String,
+    /// The current level of indentation.
+    indent: usize,
+    /// The comments that are yet to be printed.
+    comments: Comments,
+}
+
+impl Printer {
+    fn finish(mut self) -> String {
+        self.indent = 0;
+        for comment in self.comments.take_all() {
+            self.line(comment.text);
+        }
+
+        // End with exactly one newline.
+        let length = self.out.trim_end().len();
+        self.out.truncate(length);
+        self.out.push('\n');
+        self.out
+    }
+
+    /// Prints `text` on a new line at the current indentation.
+    fn line(&mut self, text: impl AsRef<str>) {
+        for _ in 0..self.indent {
+            self.out.push_str(INDENT);
+        }
+        self.out.push_str(text.as_ref());
+        self.out.push('\n');
+    }
+
+    fn blank_line(&mut self) {
+        self.out.push('\n');
+    }
+
+    /// Prints the comments that start before `pos`, i.e. those that precede the node at `pos`.
+    /// A trailing comment is printed at the end of the last line that was printed.
+    fn comments_before(&mut self, pos: BytePos) {
+        for comment in self.comments.take_before(pos) {
+            match comment.trailing {
+                true => {
+                    let end = self.out.trim_end_matches('\n').len();
+                    self.out.insert_str(end, &format!(" {}", comment.text));
+                }
+                false => self.line(comment.text),
+            }
+        }
+    }
+
+    fn print_program(&mut self, program: &Program) {
+        for (name, (_, span)) in program.imports.iter() {
+            self.comments_before(span.lo);
+            self.line(format!("import {name}.aleo;"));
+        }
+        for import in program.module_imports.iter() {
+            self.comments_before(import.span.lo);
+            self.line(import.to_string());
+        }
+
+        for (_, scope) in program.program_scopes.iter() {
+            if !self.out.is_empty() {
+                self.blank_line();
+            }
+            self.comments_before(scope.span.lo);
+            self.line(format!("program {} {{", scope.program_id));
+            self.indent += 1;
+            let items = scope
+                .consts
+                .iter()
+                .map(|(_, declaration)| Item::Const(declaration))
+                .chain(scope.structs.iter().map(|(_, struct_)| Item::Struct(struct_)))
+                .chain(scope.mappings.iter().map(|(_, mapping)| Item::Mapping(mapping)))
+                .chain(scope.functions.iter().map(|(_, function)| Item::Function(function)))
+                .chain(scope.macros.iter().map(|(_, macro_)| Item::Macro(macro_)))
+                .collect();
+            self.print_items(items);
+            self.comments_before(scope.span.hi);
+            self.indent -= 1;
+            self.line("}");
+        }
+    }
+
+    fn print_module(&mut self, module: &Module) {
+        for import in module.imports.iter() {
+            self.comments_before(import.span.lo);
+            self.line(import.to_string());
+        }
+        if !module.imports.is_empty() {
+            self.blank_line();
+        }
+        let items = module
+            .structs
+            .iter()
+            .map(|(_, struct_)| Item::Struct(struct_))
+            .chain(module.functions.iter().map(|(_, function)| Item::Function(function)))
+            .collect();
+        self.print_items(items);
+    }
+
+    fn print_items(&mut self, mut items: Vec<Item>) {
+        items.sort_by_key(|item| item.span().lo);
+        for (i, item) in items.iter().enumerate() {
+            if i > 0
+                && !(item.is_single_line() && std::mem::discriminant(item) == std::mem::discriminant(&items[i - 1]))
+            {
+                self.blank_line();
+            }
+            self.comments_before(item.span().lo);
+            match item {
+                Item::Const(declaration) => self.print_const(declaration),
+                Item::Struct(struct_) => self.print_struct(struct_),
+                Item::Mapping(mapping) => self.line(format!("{mapping};")),
+                Item::Function(function) => self.print_function(function),
+                Item::Macro(macro_) => self.print_macro(macro_),
+            }
+        }
+    }
+
+    fn print_const(&mut self, declaration: &ConstDeclaration) {
+        self.line(format!("const {}: {} = {};", declaration.place, declaration.type_, declaration.value));
+    }
+
+    fn print_struct(&mut self, struct_: &Struct) {
+        for annotation in struct_.annotations.iter() {
+            self.line(annotation.to_string());
+        }
+        let keyword = if struct_.is_record { "record" } else { "struct" };
+        self.line(format!("{keyword} {} {{", struct_.identifier));
+        self.indent += 1;
+        for member in struct_.members.iter() {
+            self.comments_before(member.span.lo);
+            self.line(format!("{}{}: {},", mode_prefix(member.mode), member.identifier, member.type_));
+        }
+        self.comments_before(struct_.span.hi);
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    fn print_function(&mut self, function: &Function) {
+        for annotation in function.annotations.iter() {
+            self.line(annotation.to_string());
+        }
+        let keyword = match function.variant {
+            Variant::Inline => "inline",
+            Variant::Standard => "function",
+            Variant::Transition => "transition",
+        };
+        let signature = signature(&function.identifier.to_string(), &function.input, &function.output);
+        self.print_block(&format!("{keyword} {signature}"), &function.block);
+        if let Some(finalize) = &function.finalize {
+            let signature = signature(&finalize.identifier.to_string(), &finalize.input, &finalize.output);
+            self.blank_line();
+            self.comments_before(finalize.span.lo);
+            self.print_block(&format!("finalize {signature}"), &finalize.block);
+        }
+    }
+
+    fn print_macro(&mut self, macro_: &Macro) {
+        let parameters = macro_.parameters.iter().map(|parameter| parameter.to_string()).collect::<Vec<_>>();
+        self.line(format!("macro {}({}) {{", macro_.identifier, parameters.join(", ")));
+        self.indent += 1;
+        self.comments_before(macro_.body.span().lo);
+        self.line(macro_.body.to_string());
+        self.comments_before(macro_.span.hi);
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    /// Prints `header {`, followed by the statements of the block and the closing brace.
+    /// A block statement has an empty header.
+    fn print_block(&mut self, header: &str, block: &Block) {
+        match header.is_empty() {
+            true => self.line("{"),
+            false => self.line(format!("{header} {{")),
+        }
+        self.print_statements(block);
+        self.line("}");
+    }
+
+    fn print_statements(&mut self, block: &Block) {
+        self.indent += 1;
+        for statement in block.statements.iter() {
+            self.comments_before(statement.span().lo);
+            self.print_statement(statement);
+        }
+        self.comments_before(block.span.hi);
+        self.indent -= 1;
+    }
+
+    fn print_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Assert(assert) => self.print_assert(assert),
+            Statement::Assign(assign) => self.line(format!("{} = {};", assign.place, assign.value)),
+            Statement::Block(block) => self.print_block("", block),
+            Statement::Conditional(conditional) => self.print_conditional("", conditional),
+            Statement::Console(console) => self.line(format!("console.{};", match &console.function {
+                ConsoleFunction::Assert(expr) => format!("assert({expr})"),
+                ConsoleFunction::AssertEq(left, right) => format!("assert_eq({left}, {right})"),
+                ConsoleFunction::AssertNeq(left, right) => format!("assert_neq({left}, {right})"),
+            })),
+            Statement::Const(declaration) => self.print_const(declaration),
+            Statement::Definition(definition) => self.print_definition(definition),
+            Statement::Expression(statement) => self.line(format!("{};", statement.expression)),
+            Statement::Iteration(iteration) => self.print_iteration(iteration),
+            Statement::Return(return_) => self.print_return(return_),
+        }
+    }
+
+    fn print_assert(&mut self, assert: &AssertStatement) {
+        self.line(match &assert.variant {
+            AssertVariant::Assert(expr) => format!("assert({expr});"),
+            AssertVariant::AssertEq(left, right) => format!("assert_eq({left}, {right});"),
+            AssertVariant::AssertNeq(left, right) => format!("assert_neq({left}, {right});"),
+            AssertVariant::ConstAssert(expr) => format!("const_assert({expr});"),
+        })
+    }
+
+    /// Prints the conditional statement, with `prefix` before `if`, so that an `else if` chain is printed flat.
+    fn print_conditional(&mut self, prefix: &str, conditional: &ConditionalStatement) {
+        self.line(format!("{prefix}if {} {{", restricted(&conditional.condition)));
+        self.print_statements(&conditional.then);
+        match conditional.otherwise.as_deref() {
+            None => self.line("}"),
+            Some(Statement::Conditional(otherwise)) => self.print_conditional("} else ", otherwise),
+            Some(Statement::Block(otherwise)) => {
+                self.line("} else {");
+                self.print_statements(otherwise);
+                self.line("}");
+            }
+            // The parser only produces blocks and conditionals, but print any other statement as a block.
+            Some(otherwise) => {
+                self.line("} else {");
+                self.indent += 1;
+                self.print_statement(otherwise);
+                self.indent -= 1;
+                self.line("}");
+            }
+        }
+    }
+
+    fn print_definition(&mut self, definition: &DefinitionStatement) {
+        self.line(format!(
+            "{} {}: {} = {};",
+            definition.declaration_type, definition.place, definition.type_, definition.value
+        ));
+    }
+
+    fn print_iteration(&mut self, iteration: &IterationStatement) {
+        let mut header = format!("for {}: {} in ", iteration.variable, iteration.type_);
+        let range = format!(
+            "{}..{}{}",
+            iteration.start,
+            if iteration.inclusive { "=" } else { "" },
+            restricted(&iteration.stop)
+        );
+        if iteration.step.is_none() && !iteration.reverse {
+            header.push_str(&range);
+        } else {
+            write!(header, "({range})").unwrap();
+            if let Some(step) = &iteration.step {
+                write!(header, ".step({step})").unwrap();
+            }
+            if iteration.reverse {
+                header.push_str(".rev()");
+            }
+        }
+        self.print_block(&header, &iteration.block);
+    }
+
+    fn print_return(&mut self, return_: &ReturnStatement) {
+        let mut text = match &return_.expression {
+            Expression::Unit(_) => "return".to_string(),
+            expression => format!("return {expression}"),
+        };
+        if let Some(arguments) = &return_.finalize_arguments {
+            text.push_str(" then finalize");
+            if !arguments.is_empty() {
+                write!(text, "({})", join(arguments)).unwrap();
+            }
+        }
+        text.push(';');
+        self.line(text);
+    }
+}
+
+/// Returns the signature of a function or finalize block named `name`, e.g. `main(public a: u8) -> u8`.
+pub fn signature(name: &str, inputs: &[Input], outputs: &[Output]) -> String {
+    let inputs = inputs
+        .iter()
+        .map(|input| match input {
+            Input::Internal(input) => format!(
+                "{}{}: {}{}",
+                mode_prefix(input.mode),
+                input.identifier,
+                if input.reference { "&" } else { "" },
+                input.type_
+            ),
+            Input::External(input) => {
+                format!("{}: {}.aleo/{}.record", input.identifier, input.program_name, input.record)
+            }
+        })
+        .collect::<Vec<_>>();
+    let outputs = outputs
+        .iter()
+        .map(|output| match output {
+            Output::Internal(output) => format!("{}{}", mode_prefix(output.mode), output.type_),
+            Output::External(output) => format!("{}.aleo/{}.record", output.program_name, output.record),
+        })
+        .collect::<Vec<_>>();
+    let mut signature = format!("{name}({})", inputs.join(", "));
+    match outputs.len() {
+        0 => {}
+        1 => write!(signature, " -> {}", outputs[0]).unwrap(),
+        _ => write!(signature, " -> ({})", outputs.join(", ")).unwrap(),
+    }
+    signature
+}
+
+/// Returns the mode followed by a space, or nothing if there is no mode.
+fn mode_prefix(mode: Mode) -> String {
+    match mode {
+        Mode::None => String::new(),
+        mode => format!("{mode} "),
+    }
+}
+
+/// Prints an expression that precedes a block, where struct initializers must be parenthesized,
+/// since the parser would otherwise take their braces for the start of the block.
+fn restricted(expression: &Expression) -> String {
+    match expression.iter().any(|expr| matches!(expr, Expression::Struct(_))) {
+        true => format!("({expression})"),
+        false => expression.to_string(),
+    }
+}
+
+fn join(expressions: &[Expression]) -> String {
+    expressions.iter().map(|expression| expression.to_string()).collect::<Vec<_>>().join(", ")
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A Leo module is a file of struct and function definitions, which programs and other modules import items from.
+
+use crate::{Function, Identifier, Struct};
+
+use leo_span::{Span, Symbol};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An import of items from a module, e.g. `import lib.math.Point as P;` or `import lib.curve.{Point, double};`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ModuleImport {
+    /// The path of the module, e.g. `lib.math`, which refers to the file `lib/math.leo`.
+    pub path: Vec<Identifier>,
+    /// The imported items.
+    pub items: ImportedItems,
+    /// The span of the import.
+    pub span: Span,
+}
+
+impl ModuleImport {
+    /// Returns the name of the module, e.g. `lib.math`.
+    pub fn module_name(&self) -> String {
+        self.path.iter().map(|segment| segment.to_string()).collect::<Vec<_>>().join(".")
+    }
+
+    /// Returns the path of the module as symbols.
+    pub fn module_path(&self) -> Vec<Symbol> {
+        self.path.iter().map(|segment| segment.name).collect()
+    }
+}
+
+impl fmt::Display for ModuleImport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "import {}.{};", self.module_name(), self.items)
+    }
+}
+
+/// The items imported from a module.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ImportedItems {
+    /// The listed items, e.g. `Point` or `{Point, double as dbl}`.
+    List(Vec<ImportedItem>),
+    /// Every item of the module, i.e. `*`.
+    Glob(Span),
+}
+
+impl fmt::Display for ImportedItems {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::List(items) if items.len() == 1 => write!(f, "{}", items[0]),
+            Self::List(items) => {
+                write!(f, "{{{}}}", items.iter().map(|item| item.to_string()).collect::<Vec<_>>().join(", "))
+            }
+            Self::Glob(_) => write!(f, "*"),
+        }
+    }
+}
+
+/// An item imported from a module, e.g. `Point as P`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ImportedItem {
+    /// The imported item, e.g. `Point`.
+    pub item: Identifier,
+    /// The name that the item is imported as, e.g. `P`.
+    pub alias: Option<Identifier>,
+}
+
+impl ImportedItem {
+    /// Returns the name that the item is bound to in the importing file.
+    pub fn name(&self) -> Identifier {
+        self.alias.unwrap_or(self.item)
+    }
+
+    /// Returns the span of the item, including its alias.
+    pub fn span(&self) -> Span {
+        match self.alias {
+            Some(alias) => self.item.span + alias.span,
+            None => self.item.span,
+        }
+    }
+}
+
+impl fmt::Display for ImportedItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.alias {
+            Some(alias) => write!(f, "{} as {alias}", self.item),
+            None => write!(f, "{}", self.item),
+        }
+    }
+}
+
+/// Stores the Leo module abstract syntax tree.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Module {
+    /// The items imported by the module.
+    pub imports: Vec<ModuleImport>,
+    /// A vector of struct definitions.
+    pub structs: Vec<(Symbol, Struct)>,
+    /// A vector of function definitions.
+    pub functions: Vec<(Symbol, Function)>,
+}
+
+impl Module {
+    /// Returns the names of the structs and functions declared in the module.
+    pub fn item_names(&self) -> impl Iterator<Item = Symbol> + '_ {
+        self.structs.iter().map(|(name, _)| *name).chain(self.functions.iter().map(|(name, _)| *name))
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for import in self.imports.iter() {
+            writeln!(f, "{import}")?;
+        }
+        for (_, struct_) in self.structs.iter() {
+            writeln!(f, "{struct_}")?;
+        }
+        for (_, function) in self.functions.iter() {
+            writeln!(f, "{function}")?;
+        }
+        Ok(())
+    }
+}
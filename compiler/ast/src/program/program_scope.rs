@@ -16,7 +16,7 @@
 
 //! A Leo program scope consists of struct, function, and mapping definitions.
 
-use crate::{ConstDeclaration, Function, Mapping, ProgramId, Struct, Stub};
+use crate::{ConstDeclaration, Function, Macro, Mapping, ProgramId, Struct, Stub};
 
 use leo_span::{Span, Symbol};
 use serde::{Deserialize, Serialize};
@@ -35,6 +35,9 @@ pub struct ProgramScope {
     pub mappings: Vec<(Symbol, Mapping)>,
     /// A vector of function definitions.
     pub functions: Vec<(Symbol, Function)>,
+    /// A vector of macro definitions, which are expanded before type checking.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub macros: Vec<(Symbol, Macro)>,
     /// The span associated with the program scope.
     pub span: Span,
 }
@@ -51,6 +54,7 @@ impl From<Stub> for ProgramScope {
                 .into_iter()
                 .map(|(symbol, function)| (symbol, Function::from(function)))
                 .collect(),
+            macros: Vec::new(),
             span: stub.span,
         }
     }
@@ -68,6 +72,9 @@ impl fmt::Display for ProgramScope {
         for (_, function) in self.functions.iter() {
             writeln!(f, "    {function}")?;
         }
+        for (_, macro_) in self.macros.iter() {
+            writeln!(f, "    {macro_}")?;
+        }
         Ok(())
     }
 }
@@ -30,9 +30,11 @@ pub enum AssertVariant {
     AssertEq(Expression, Expression),
     /// A `assert_neq(expr1, expr2)` variant, asserting that the operands are not equal.
     AssertNeq(Expression, Expression),
+    /// A `const_assert(expr)` variant, asserting that the constant expression evaluates to true at compile time.
+    ConstAssert(Expression),
 }
 
-/// An assert statement, `assert(<expr>)`, `assert_eq(<expr>)` or `assert_neq(<expr>)`.
+/// An assert statement, `assert(<expr>)`, `assert_eq(<expr>)`, `assert_neq(<expr>)` or `const_assert(<expr>)`.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub struct AssertStatement {
     /// The variant of the assert statement.
@@ -49,6 +51,7 @@ impl fmt::Display for AssertStatement {
             AssertVariant::Assert(ref expr) => write!(f, "assert({expr});"),
             AssertVariant::AssertEq(ref expr1, ref expr2) => write!(f, "assert_eq({expr1}, {expr2});"),
             AssertVariant::AssertNeq(ref expr1, ref expr2) => write!(f, "assert_neq({expr1}, {expr2});"),
+            AssertVariant::ConstAssert(ref expr) => write!(f, "const_assert({expr});"),
         }
     }
 }
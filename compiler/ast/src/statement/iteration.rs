@@ -21,7 +21,8 @@ use leo_span::Span;
 use serde::{Deserialize, Serialize};
 use std::{cell::RefCell, fmt};
 
-/// A bounded `for` loop statement `for variable in start .. =? stop block`.
+/// A bounded `for` loop statement `for variable in start .. =? stop block`,
+/// or `for variable in (start .. =? stop).step(step).rev() block`, where `.step(..)` and `.rev()` are optional.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub struct IterationStatement {
     /// The binding / variable to introduce in the body `block`.
@@ -41,6 +42,16 @@ pub struct IterationStatement {
     /// Whether `stop` is inclusive or not.
     /// Signified with `=` when parsing.
     pub inclusive: bool,
+    /// The distance between consecutive values of the iteration, if given with `.step(..)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub step: Option<Expression>,
+    /// The concrete value of `step`.
+    #[serde(skip)]
+    pub step_value: RefCell<Option<Value>>,
+    /// Whether the iteration runs from the end of the range to its start.
+    /// Signified with `.rev()` when parsing.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reverse: bool,
     /// The block to run on each iteration.
     pub block: Block,
     /// The span from `for` to `block`.
@@ -52,7 +63,17 @@ pub struct IterationStatement {
 impl fmt::Display for IterationStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let eq = if self.inclusive { "=" } else { "" };
-        write!(f, "for {} in {}..{eq}{} {}", self.variable, self.start, self.stop, self.block)
+        if self.step.is_none() && !self.reverse {
+            return write!(f, "for {} in {}..{eq}{} {}", self.variable, self.start, self.stop, self.block);
+        }
+        write!(f, "for {} in ({}..{eq}{})", self.variable, self.start, self.stop)?;
+        if let Some(step) = &self.step {
+            write!(f, ".step({step})")?;
+        }
+        if self.reverse {
+            write!(f, ".rev()")?;
+        }
+        write!(f, " {}", self.block)
     }
 }
 
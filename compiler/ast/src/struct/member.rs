@@ -24,6 +24,9 @@ use std::fmt;
 /// A member of a structured data type, e.g `foobar: u8` or `private baz: bool` .
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Member {
+    /// The lines of the doc comments of the member, without their leading `///`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     /// The mode of the member.
     pub mode: Mode,
     /// The identifier of the member.
@@ -17,8 +17,8 @@
 pub mod member;
 pub use member::*;
 
-use crate::{Identifier, Mode, Node, NodeID, Type};
-use leo_span::{Span, Symbol};
+use crate::{Annotation, Identifier, Mode, Node, NodeID, Type};
+use leo_span::{sym, Span, Symbol};
 
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -40,6 +40,12 @@ use snarkvm::{
 /// The fields are named so `struct Foo(u8, u16)` is not allowed.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Struct {
+    /// Annotations on the struct, e.g. `@deprecated`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Annotation>,
+    /// The lines of the doc comments of the struct, without their leading `///`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     /// The name of the type in the type system in this module.
     pub identifier: Identifier,
     /// The fields, constant variables, and functions of this structure.
@@ -66,6 +72,11 @@ impl Struct {
     pub fn name(&self) -> Symbol {
         self.identifier.name
     }
+
+    /// Returns `true` if the struct is annotated with `@deprecated`.
+    pub fn is_deprecated(&self) -> bool {
+        self.annotations.iter().any(|annotation| annotation.identifier.name == sym::deprecated)
+    }
 }
 
 impl fmt::Debug for Struct {
@@ -90,11 +101,14 @@ crate::simple_node_impl!(Struct);
 impl<N: Network> From<&StructType<N>> for Struct {
     fn from(input: &StructType<N>) -> Self {
         Self {
+            annotations: Vec::new(),
+            docs: Vec::new(),
             identifier: Identifier::from(input.name()),
             members: input
                 .members()
                 .iter()
                 .map(|(id, type_)| Member {
+                    docs: Vec::new(),
                     mode: Mode::None,
                     identifier: Identifier::from(id),
                     type_: Type::from(type_),
@@ -112,9 +126,12 @@ impl<N: Network> From<&StructType<N>> for Struct {
 impl<N: Network> From<&RecordType<N>> for Struct {
     fn from(input: &RecordType<N>) -> Self {
         Self {
+            annotations: Vec::new(),
+            docs: Vec::new(),
             identifier: Identifier::from(input.name()),
             members: [
                 vec![Member {
+                    docs: Vec::new(),
                     mode: if input.owner().is_private() { Mode::Public } else { Mode::Private },
                     identifier: Identifier::new(Symbol::intern("owner"), Default::default()),
                     type_: Type::Address,
@@ -125,6 +142,7 @@ impl<N: Network> From<&RecordType<N>> for Struct {
                     .entries()
                     .iter()
                     .map(|(id, entry)| Member {
+                        docs: Vec::new(),
                         mode: if input.owner().is_public() { Mode::Public } else { Mode::Private },
                         identifier: Identifier::from(id),
                         type_: match entry {
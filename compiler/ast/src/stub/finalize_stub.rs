@@ -69,6 +69,7 @@ impl<N: Network, Command: CommandTrait<N>> From<&FinalizeCore<N, Command>> for F
                     identifier: arg_name,
                     mode: Mode::None,
                     type_: Type::from(val),
+                    reference: false,
                     span: Default::default(),
                     id: Default::default(),
                 })),
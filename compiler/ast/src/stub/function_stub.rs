@@ -201,6 +201,7 @@ impl<N: Network, Instruction: InstructionTrait<N>> From<&ClosureCore<N, Instruct
                             identifier: arg_name,
                             mode: Mode::None,
                             type_: Type::from(val),
+                            reference: false,
                             span: Default::default(),
                             id: Default::default(),
                         }),
@@ -290,6 +291,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>>
                             identifier: arg_name,
                             mode: Mode::Constant,
                             type_: Type::from(val),
+                            reference: false,
                             span: Default::default(),
                             id: Default::default(),
                         }),
@@ -297,6 +299,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>>
                             identifier: arg_name,
                             mode: Mode::Public,
                             type_: Type::from(val),
+                            reference: false,
                             span: Default::default(),
                             id: Default::default(),
                         }),
@@ -304,6 +307,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>>
                             identifier: arg_name,
                             mode: Mode::Private,
                             type_: Type::from(val),
+                            reference: false,
                             span: Default::default(),
                             id: Default::default(),
                         }),
@@ -311,6 +315,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>>
                             identifier: arg_name,
                             mode: Mode::None,
                             type_: IdentifierType(Identifier::from(id)),
+                            reference: false,
                             span: Default::default(),
                             id: Default::default(),
                         }),
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A constant interpreter, which evaluates expressions over booleans and integers.
+
+use crate::{BinaryOperation, Expression, Node, UnaryOperation, Value};
+
+use leo_errors::{AstError, Result};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// Evaluates expressions given the values of the variables they refer to.
+///
+/// Only literals, variables, ternaries, and the unary and binary operations on booleans and integers are evaluated.
+/// Any other expression is rejected with an error. Expressions must be type checked before they are evaluated.
+#[derive(Clone, Debug, Default)]
+pub struct ConstantInterpreter {
+    /// The values of the variables in scope.
+    values: IndexMap<Symbol, Value>,
+}
+
+impl ConstantInterpreter {
+    /// Binds the variable `name` to `value`, replacing any previous value.
+    pub fn bind(&mut self, name: Symbol, value: Value) {
+        self.values.insert(name, value);
+    }
+
+    /// Unbinds the variable `name`, if it is bound.
+    pub fn unbind(&mut self, name: Symbol) {
+        self.values.shift_remove(&name);
+    }
+
+    /// Returns the value of the variable `name`, if it is bound.
+    pub fn value(&self, name: Symbol) -> Option<&Value> {
+        self.values.get(&name)
+    }
+
+    /// Evaluates `expression` to a value.
+    pub fn evaluate(&self, expression: &Expression) -> Result<Value> {
+        let unsupported = || AstError::cannot_evaluate(expression, expression.span()).into();
+
+        match expression {
+            Expression::Literal(literal) => Value::try_from(literal).map_err(|_| unsupported()),
+            Expression::Identifier(identifier) => self.values.get(&identifier.name).cloned().ok_or_else(unsupported),
+            Expression::Ternary(ternary) => match self.evaluate(&ternary.condition)? {
+                Value::Boolean(true, _) => self.evaluate(&ternary.if_true),
+                Value::Boolean(false, _) => self.evaluate(&ternary.if_false),
+                _ => Err(unsupported()),
+            },
+            Expression::Unary(unary) => {
+                let receiver = self.evaluate(&unary.receiver)?;
+                if !receiver.is_supported_const_fold_type() {
+                    return Err(unsupported());
+                }
+
+                let span = unary.span;
+                match unary.op {
+                    UnaryOperation::Abs => receiver.abs(span),
+                    UnaryOperation::AbsWrapped => receiver.abs_wrapped(span),
+                    UnaryOperation::Negate => receiver.neg(span),
+                    UnaryOperation::Not => receiver.not(span),
+                    _ => Err(unsupported()),
+                }
+            }
+            Expression::Binary(binary) => {
+                let left = self.evaluate(&binary.left)?;
+                let right = self.evaluate(&binary.right)?;
+                if !left.is_supported_const_fold_type() || !right.is_supported_const_fold_type() {
+                    return Err(unsupported());
+                }
+
+                let span = binary.span;
+                match binary.op {
+                    BinaryOperation::Add => left.add(right, span),
+                    BinaryOperation::AddWrapped => left.add_wrapped(right, span),
+                    BinaryOperation::And | BinaryOperation::BitwiseAnd => left.bitand(right, span),
+                    BinaryOperation::Div => left.div(right, span),
+                    BinaryOperation::DivWrapped => left.div_wrapped(right, span),
+                    BinaryOperation::Eq => left.eq(right, span),
+                    BinaryOperation::Gte => left.ge(right, span),
+                    BinaryOperation::Gt => left.gt(right, span),
+                    BinaryOperation::Lte => left.le(right, span),
+                    BinaryOperation::Lt => left.lt(right, span),
+                    BinaryOperation::Mul => left.mul(right, span),
+                    BinaryOperation::MulWrapped => left.mul_wrapped(right, span),
+                    BinaryOperation::Nand => left.bitand(right, span)?.not(span),
+                    BinaryOperation::Neq => left.eq(right, span)?.not(span),
+                    BinaryOperation::Nor => left.bitor(right, span)?.not(span),
+                    BinaryOperation::Or | BinaryOperation::BitwiseOr => left.bitor(right, span),
+                    BinaryOperation::Pow => left.pow(right, span),
+                    BinaryOperation::PowWrapped => left.pow_wrapped(right, span),
+                    BinaryOperation::Shl => left.shl(right, span),
+                    BinaryOperation::ShlWrapped => left.shl_wrapped(right, span),
+                    BinaryOperation::Shr => left.shr(right, span),
+                    BinaryOperation::ShrWrapped => left.shr_wrapped(right, span),
+                    BinaryOperation::Sub => left.sub(right, span),
+                    BinaryOperation::SubWrapped => left.sub_wrapped(right, span),
+                    BinaryOperation::Xor => left.xor(right, span),
+                    BinaryOperation::Mod | BinaryOperation::Rem | BinaryOperation::RemWrapped => Err(unsupported()),
+                }
+            }
+            _ => Err(unsupported()),
+        }
+    }
+}
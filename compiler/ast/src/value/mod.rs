@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod interpreter;
+pub use interpreter::*;
+
 use crate::{GroupLiteral, Identifier, IntegerType, Literal, NodeID, Type};
 
 use leo_errors::{type_name, FlattenError, LeoError, Result};
@@ -899,8 +902,8 @@ impl TryFrom<&Literal> for Value {
 }
 
 impl Literal {
-    #[allow(unused)]
-    fn from_value(v: Value, id: NodeID) -> Self {
+    /// Converts a value to a literal. Structs and inputs have no literals, and must not be converted.
+    pub fn from_value(v: Value, id: NodeID) -> Self {
         use Value::*;
         match v {
             Input(_, _) => todo!("We need to test if this is hittable"),
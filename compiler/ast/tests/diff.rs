@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{diff_programs, NodeBuilder, Program};
+use leo_errors::emitter::Handler;
+use leo_span::{span::BytePos, symbol::create_session_if_not_set_then};
+
+fn parse(source: &str) -> Program {
+    leo_parser::parse(&Handler::default(), &NodeBuilder::default(), source, BytePos(0)).unwrap()
+}
+
+#[test]
+fn diff_programs_reports_changed_expressions() {
+    create_session_if_not_set_then(|_| {
+        let old = r#"program test.aleo {
+    struct Point { x: u8, y: u8 }
+
+    transition main(a: u8, b: u8) -> u8 {
+        let c: u8 = a + b;
+        let d: u8 = c * 2u8;
+        return d;
+    }
+
+    transition unused() {}
+}"#;
+        let new = r#"program test.aleo {
+    struct Point { x: u8, y: u16 }
+
+    transition main(a: u8, b: u8) -> u8 {
+        let c: u8 = a * b;
+
+        let d: u8 = c * 2u8;
+        assert(d > 0u8);
+        return d;
+    }
+}"#;
+        let (old, new) = (parse(old), parse(new));
+        let changes = diff_programs(&old, &new).iter().map(|change| change.to_string()).collect::<Vec<_>>();
+        assert_eq!(changes, [
+            "struct `Point`: changed `{x: u8, y: u8}` to `{x: u8, y: u16}`",
+            "transition `main`: changed `a + b` to `a * b`",
+            "transition `main`: added `d > 0u8`",
+            "program scope `test.aleo`: removed transition `unused`",
+        ]);
+        assert!(diff_programs(&new, &new).is_empty());
+    });
+}
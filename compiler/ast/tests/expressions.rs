@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{expr, ArrayType, IntegerType, TupleType, Type};
+use leo_parser::{expr, parse_test_expression};
+use leo_span::symbol::create_session_if_not_set_then;
+
+#[test]
+fn expr_macro_matches_builders() {
+    create_session_if_not_set_then(|_| {
+        let parsed = expr! { a + b * 3u8 };
+        let built = expr::add(expr::ident("a"), expr::mul(expr::ident("b"), expr::int_u8(3)));
+        assert!(parsed.eq_structural(&built));
+
+        let parsed = expr! { c ? x.0 : !y };
+        let built =
+            expr::ternary(expr::ident("c"), expr::tuple_access(expr::ident("x"), 0), expr::not(expr::ident("y")));
+        assert!(parsed.eq_structural(&built));
+    });
+}
+
+#[test]
+fn sexpr_round_trips_parsed_expressions() {
+    create_session_if_not_set_then(|_| {
+        let parsed = expr! { a * b + 3u8 };
+        assert_eq!(parsed.to_sexpr(), "(add (mul a b) 3u8)");
+
+        for source in [
+            "a * b + 3u8",
+            "c ? x.0 : !y",
+            "foo(1field, [a, b][0u32]) as u64",
+            "Point { x: 1i8, y }.x",
+            "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc == a",
+            "(a as bool, b as address, c as signature, d as string, e as group)",
+            "BHP256::hash_to_field(a) as i128",
+        ] {
+            let parsed = parse_test_expression(source);
+            let round_tripped = expr::parse_sexpr(&parsed.to_sexpr()).unwrap();
+            assert!(parsed.eq_structural(&round_tripped), "{source} did not round trip");
+        }
+
+        // Identifiers that look like literals, and casts to composite types, are not produced by the parser,
+        // but may be produced by passes.
+        let built = [
+            expr::ident("aleo1x"),
+            expr::cast(expr::ident("a"), Type::Array(ArrayType::new(Type::Integer(IntegerType::U8), 2usize.into()))),
+            expr::cast(
+                expr::ident("a"),
+                Type::Tuple(TupleType::new(vec![Type::Boolean, Type::Identifier(expr::name("Point"))])),
+            ),
+            expr::cast(expr::ident("a"), Type::Unit),
+        ];
+        for expression in built {
+            let sexpr = expression.to_sexpr();
+            let round_tripped = expr::parse_sexpr(&sexpr).unwrap();
+            assert!(expression.eq_structural(&round_tripped), "{sexpr} did not round trip");
+        }
+        assert_eq!(expr::ident("aleo1x").to_sexpr(), "(ident aleo1x)");
+    });
+}
+
+#[test]
+fn display_round_trips_parsed_expressions() {
+    create_session_if_not_set_then(|_| {
+        for source in [
+            "a + b * 3u8",
+            "(a + b) * c",
+            "a - (b - c)",
+            "a ** b ** c",
+            "a ** (b ** c)",
+            "(a == b) == c",
+            "a < b == c > d",
+            "a ^ b | c & d",
+            "(a ^ b) & c",
+            "-(a + b)",
+            "!(a && b) || c",
+            "-5u8 + --5u8",
+            "5u8.neg()",
+            "(-5i8).abs()",
+            "x as u64 + (a + b) as u64",
+            "c ? (d ? 1u8 : 2u8) : 3u8",
+            "(c ? a : b) ? x : y",
+            "(c ? a : b).0",
+            "(a + b).add_wrapped(c * d)",
+            "a.nand(b).nor(c)",
+            "Point { x: 1u8, y }.x",
+            "credits.aleo/transfer(a, 1u64)",
+            "BHP256::hash_to_field(a + b)",
+            "Mapping::get_or_use(balances, owner, 0u64)",
+            "[a, b][i + 1u32]",
+            "0b1011 as u8",
+            "(a, b).1",
+            "foo(a, b) | c ^ d",
+        ] {
+            let parsed = parse_test_expression(source);
+            let printed = parsed.to_string();
+            let reparsed = parse_test_expression(&printed);
+            assert!(parsed.eq_structural(&reparsed), "`{source}` was printed as `{printed}`, which parses differently");
+            assert_eq!(printed, reparsed.to_string());
+        }
+    });
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{format_program, format_program_with_comments, NodeBuilder, Program};
+use leo_errors::emitter::Handler;
+use leo_span::{span::BytePos, symbol::create_session_if_not_set_then};
+
+fn parse(source: &str) -> Program {
+    leo_parser::parse(&Handler::default(), &NodeBuilder::default(), source, BytePos(0)).unwrap()
+}
+
+#[test]
+fn format_program_prints_formatted_source_unchanged() {
+    create_session_if_not_set_then(|_| {
+        let source = r#"import credits.aleo;
+
+program test.aleo {
+    const N: u8 = 3u8;
+    const M: u8 = N + 1u8;
+
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    record Token {
+        owner: address,
+        public amount: u64,
+    }
+
+    mapping balances: address => u64;
+
+    macro twice(x) {
+        x + x
+    }
+
+    @program
+    transition main(public a: u8, b: Point, t: credits.aleo/credits.record) -> (u8, public u64) {
+        let c: u8 = a + b.x * 2u8;
+        if (Point {x: c, y: c} == b) {
+            c = 0u8;
+        } else if c > 1u8 {
+            c = c + 1u8;
+        } else {
+            assert_eq(c, 1u8);
+        }
+        for i: u8 in (0u8..N).step(2u8).rev() {
+            c = c.add_wrapped(i);
+        }
+        return (c,0u64) then finalize(self.caller);
+    }
+
+    finalize main(owner: address) {
+        Mapping::set(balances, owner, 0u64);
+    }
+
+    inline helper(bytes: &[u8; 4]) {
+        return;
+    }
+}
+"#;
+        assert_eq!(format_program(&parse(source)), source);
+    });
+}
+
+#[test]
+fn format_program_with_comments_keeps_comments() {
+    create_session_if_not_set_then(|_| {
+        let source = r#"// The program.
+program test.aleo {
+    // A point.
+    struct Point {
+        x: u8, // The abscissa.
+        y: u8,
+    }
+
+    /* The entry point. */
+    transition main(a: u8) -> u8 {
+        // Double it.
+        let b: u8 = a + a; // Trailing.
+        return b;
+        // The end.
+    }
+}
+"#;
+        let comments = leo_parser::parse_comments(source, BytePos(0)).unwrap();
+        assert_eq!(comments.iter().filter(|comment| comment.trailing).count(), 2);
+        assert_eq!(format_program_with_comments(&parse(source), comments), source);
+    });
+}
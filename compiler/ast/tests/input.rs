@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{input_file_template, Function, NodeBuilder, Program, ProgramInput, Struct};
+use leo_errors::emitter::Handler;
+use leo_span::{span::BytePos, symbol::create_session_if_not_set_then, Symbol};
+
+const PROGRAM: &str = r#"program test.aleo {
+    struct Point { x: u8, y: u8 }
+    record Token { owner: address, amount: u64 }
+
+    transition main(public a: address, b: [u8; 2], p: Point, constant c: u32, t: Token) -> u8 {
+        return b[0u8];
+    }
+
+    transition shift(p: Point, public s: u8) -> Point {
+        return Point { x: p.x + s, y: p.y };
+    }
+}"#;
+
+/// Returns the function `name` of `PROGRAM`, with the structs and records it declares.
+fn find_function(program: &Program, name: &str) -> (Function, Vec<(Symbol, Struct)>) {
+    let scope = program.program_scopes.values().next().unwrap();
+    let (_, function) = scope.functions.iter().find(|(function, _)| *function == Symbol::intern(name)).unwrap();
+    (function.clone(), scope.structs.clone())
+}
+
+/// Reads the inputs to the function `name` of `PROGRAM` from `input`, or returns the number of errors.
+fn read_input(name: &str, input: &str) -> Result<ProgramInput, usize> {
+    let (handler, _) = Handler::new_with_buf();
+    let node_builder = NodeBuilder::default();
+    let program = leo_parser::parse(&handler, &node_builder, PROGRAM, BytePos(0)).unwrap();
+    let (function, structs) = find_function(&program, name);
+    let input = leo_parser::parse_input(&handler, &node_builder, input, BytePos(0)).unwrap();
+    ProgramInput::new(&input, &function, &structs, &handler).map_err(|_| handler.err_count())
+}
+
+#[test]
+fn input_file_template_parses_back() {
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+        let program = leo_parser::parse(&handler, &node_builder, PROGRAM, BytePos(0)).unwrap();
+        let (function, structs) = find_function(&program, "main");
+
+        let template = input_file_template(&function, &structs);
+        let input = leo_parser::parse_input(&handler, &node_builder, &template, BytePos(0)).unwrap();
+        let input = ProgramInput::new(&input, &function, &structs, &handler).unwrap();
+
+        let zero = "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc";
+        let values = input.values.iter().map(|(mode, value)| format!("{mode} {value}")).collect::<Vec<_>>();
+        assert_eq!(values, [
+            format!("public {zero}"),
+            "private [0u8, 0u8]".to_string(),
+            "private { x: 0u8, y: 0u8 }".to_string(),
+            "constant 0u32".to_string(),
+            format!("private {{ owner: {zero}.private, amount: 0u64.private, _nonce: 0group.public }}"),
+        ]);
+        assert!(input.registers.is_empty());
+    });
+}
+
+#[test]
+fn program_input_checks_outputs() {
+    create_session_if_not_set_then(|_| {
+        let input = read_input(
+            "shift",
+            "[shift]\np: Point = { x: 1u8, y: 2u8 };\npublic s: u8 = 1u8;\n\n[registers]\nr0: Point = { x: 2u8, y: 2u8 };\n",
+        )
+        .unwrap();
+        let values = input.values.iter().map(|(mode, value)| format!("{mode} {value}")).collect::<Vec<_>>();
+        assert_eq!(values, ["private { x: 1u8, y: 2u8 }", "public 1u8"]);
+        assert_eq!(input.public_values().iter().map(|value| value.to_string()).collect::<Vec<_>>(), ["1u8"]);
+
+        // snarkVM prints structs over several lines.
+        assert!(input.check_outputs(&["{\n  x: 2u8,\n  y: 2u8\n}".to_string()]).is_ok());
+        assert!(input.check_outputs(&["{ x: 1u8, y: 2u8 }".to_string()]).is_err());
+    });
+}
+
+#[test]
+fn program_input_reports_every_mismatched_entry() {
+    create_session_if_not_set_then(|_| {
+        // `p` is missing, `s` is not public, `z` is not a parameter, and there is no second output.
+        let input = "[shift]\nprivate s: u8 = 1u8;\nz: u8 = 2u8;\n\n[registers]\nr1: u8 = 1u8;\n";
+        assert_eq!(read_input("shift", input), Err(4));
+    });
+}
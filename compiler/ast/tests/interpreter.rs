@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{ConstantInterpreter, Literal, NodeID, Value};
+use leo_parser::parse_test_expression;
+use leo_span::{symbol::create_session_if_not_set_then, Span, Symbol};
+
+#[test]
+fn constant_interpreter_evaluates_parsed_expressions() {
+    create_session_if_not_set_then(|_| {
+        let mut interpreter = ConstantInterpreter::default();
+        interpreter.bind(Symbol::intern("x"), Value::U8(3, Span::default()));
+
+        for (source, expected) in [
+            ("x ** 2u8 + 1u8", "10u8"),
+            ("x.add_wrapped(255u8)", "2u8"),
+            ("x != 3u8 ? 0i8 : 5i8 - 7i8", "-2i8"),
+            ("true.nand(false) && !false", "true"),
+        ] {
+            let value = interpreter.evaluate(&parse_test_expression(source)).unwrap();
+            assert_eq!(Literal::from_value(value, NodeID::default()).to_string(), expected, "`{source}`");
+        }
+
+        for source in ["x % 2u8", "x as u16", "1field + 2field", "y", "255u8 + x"] {
+            assert!(interpreter.evaluate(&parse_test_expression(source)).is_err(), "`{source}` was evaluated");
+        }
+    });
+}
@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The ABI of a program, i.e. the interface of its transitions, so that wallets and dapps can construct the inputs of
+//! a transition without parsing the source code.
+//!
+//! The ABI is written as JSON, in the form
+//!
+//! ```text
+//! {
+//!   "program": "token.aleo",
+//!   "structs": [{ "name": "Point", "members": [{ "name": "x", "type": "u32" }, ...] }],
+//!   "records": [{ "name": "Token", "members": [{ "name": "owner", "type": "address", "mode": "private" }, ...] }],
+//!   "functions": [
+//!     {
+//!       "name": "transfer",
+//!       "inputs": [{ "name": "receiver", "type": "address", "mode": "public", "register": "r0" }, ...],
+//!       "outputs": [{ "type": "Token", "mode": "record" }, ...],
+//!       "finalize": true
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! The inputs of a transition are listed in the order of their registers. The mode of an input or an output is
+//! `constant`, `public`, or `private` for a plain value, `record` for a record of the program, and `external_record`
+//! for a record of another program, whose type is written as `program.aleo/Record`. A transition has `finalize` set if
+//! it finalizes on chain.
+
+use leo_ast::{Function, Input, Mode, Output, ProgramScope, Struct, Type, Variant};
+use leo_errors::{CompilerError, Result};
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The mode of an input or output of a transition, or of a member of a record.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AbiMode {
+    /// A value that is part of the circuit.
+    Constant,
+    /// A value that is visible on chain.
+    Public,
+    /// A value that is encrypted on chain.
+    Private,
+    /// A record of the program.
+    Record,
+    /// A record of another program.
+    ExternalRecord,
+}
+
+/// A member of a struct or a record.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AbiMember {
+    /// The name of the member.
+    pub name: String,
+    /// The type of the member.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The mode of the member of a record, which structs do not have.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub mode: Option<AbiMode>,
+}
+
+/// A struct or a record.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AbiStruct {
+    /// The name of the struct or the record.
+    pub name: String,
+    /// The members, in the order they are declared.
+    pub members: Vec<AbiMember>,
+}
+
+/// An input of a transition.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AbiInput {
+    /// The name of the parameter.
+    pub name: String,
+    /// The type of the parameter.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The mode of the parameter.
+    pub mode: AbiMode,
+    /// The register that holds the input, e.g. `r0`.
+    pub register: String,
+}
+
+/// An output of a transition.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AbiOutput {
+    /// The type of the output.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The mode of the output.
+    pub mode: AbiMode,
+}
+
+/// A transition.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AbiFunction {
+    /// The name of the transition.
+    pub name: String,
+    /// The inputs, in the order of their registers.
+    pub inputs: Vec<AbiInput>,
+    /// The outputs, in order.
+    pub outputs: Vec<AbiOutput>,
+    /// Whether the transition finalizes on chain.
+    pub finalize: bool,
+}
+
+/// The ABI of a program.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Abi {
+    /// The ID of the program, e.g. `token.aleo`.
+    pub program: String,
+    /// The structs of the program.
+    pub structs: Vec<AbiStruct>,
+    /// The records of the program.
+    pub records: Vec<AbiStruct>,
+    /// The transitions of the program, which are its entry points.
+    pub functions: Vec<AbiFunction>,
+}
+
+impl Abi {
+    /// Returns the ABI of the program `scope`.
+    pub fn new(scope: &ProgramScope) -> Self {
+        let is_record = |type_: &Type| match type_ {
+            Type::Identifier(identifier) => {
+                scope.structs.iter().any(|(name, struct_)| *name == identifier.name && struct_.is_record)
+            }
+            _ => false,
+        };
+        let value_mode = |mode: Mode, type_: &Type| match mode {
+            _ if is_record(type_) => AbiMode::Record,
+            Mode::Constant => AbiMode::Constant,
+            Mode::Public => AbiMode::Public,
+            Mode::None | Mode::Private => AbiMode::Private,
+        };
+
+        let abi_struct = |struct_: &Struct| AbiStruct {
+            name: struct_.identifier.to_string(),
+            members: struct_
+                .members
+                .iter()
+                .map(|member| AbiMember {
+                    name: member.identifier.to_string(),
+                    type_: member.type_.to_string(),
+                    mode: struct_.is_record.then(|| value_mode(member.mode, &member.type_)),
+                })
+                .collect(),
+        };
+
+        let abi_function = |function: &Function| AbiFunction {
+            name: function.identifier.to_string(),
+            inputs: function
+                .input
+                .iter()
+                .enumerate()
+                .map(|(index, input)| {
+                    let (type_, mode) = match input {
+                        Input::Internal(input) => (input.type_.to_string(), value_mode(input.mode, &input.type_)),
+                        Input::External(input) => {
+                            (format!("{}.aleo/{}", input.program_name, input.record), AbiMode::ExternalRecord)
+                        }
+                    };
+                    AbiInput { name: input.identifier().to_string(), type_, mode, register: format!("r{index}") }
+                })
+                .collect(),
+            outputs: function
+                .output
+                .iter()
+                .map(|output| match output {
+                    Output::Internal(output) => {
+                        AbiOutput { type_: output.type_.to_string(), mode: value_mode(output.mode, &output.type_) }
+                    }
+                    Output::External(output) => AbiOutput {
+                        type_: format!("{}.aleo/{}", output.program_name, output.record),
+                        mode: AbiMode::ExternalRecord,
+                    },
+                })
+                .collect(),
+            finalize: function.finalize.is_some(),
+        };
+
+        Self {
+            program: scope.program_id.to_string(),
+            structs: scope.structs.iter().filter(|(_, s)| !s.is_record).map(|(_, s)| abi_struct(s)).collect(),
+            records: scope.structs.iter().filter(|(_, s)| s.is_record).map(|(_, s)| abi_struct(s)).collect(),
+            functions: scope
+                .functions
+                .iter()
+                .filter(|(_, function)| function.variant == Variant::Transition)
+                .map(|(_, function)| abi_function(function))
+                .collect(),
+        }
+    }
+
+    /// Writes the ABI to the JSON file at `path`.
+    pub fn to_json_file(&self, path: &Path) -> Result<()> {
+        let file = std::fs::File::create(path).map_err(|e| CompilerError::failed_to_write_abi(path.display(), e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer_pretty(writer, self)
+            .map_err(|e| CompilerError::failed_to_write_abi(path.display(), e))?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_ast::NodeBuilder;
+    use leo_errors::emitter::Handler;
+    use leo_span::{
+        source_map::FileName,
+        symbol::{create_session_if_not_set_then, with_session_globals},
+    };
+
+    #[test]
+    fn test_abi() {
+        create_session_if_not_set_then(|_| {
+            let source = "program token.aleo {
+                record Token { owner: address, amount: u64 }
+                transition mint(public receiver: address, amount: u64) -> Token {
+                    return Token { owner: receiver, amount };
+                }
+                function double(a: u64) -> u64 { return a + a; }
+            }";
+            let source_file = with_session_globals(|s| s.source_map.new_source(source, FileName::Custom("abi".into())));
+            let ast = leo_parser::parse_ast(
+                &Handler::default(),
+                &NodeBuilder::default(),
+                &source_file.src,
+                source_file.start_pos,
+            )
+            .unwrap();
+            let abi = Abi::new(ast.as_repr().program_scopes.values().next().unwrap());
+
+            assert_eq!(abi.program, "token.aleo");
+            assert_eq!(abi.records[0].members[1].mode, Some(AbiMode::Private));
+            assert_eq!(abi.functions.len(), 1);
+            let mint = &abi.functions[0];
+            assert_eq!(mint.inputs[0].mode, AbiMode::Public);
+            assert_eq!(mint.inputs[1].register, "r1");
+            assert_eq!(mint.outputs, vec![AbiOutput { type_: "Token".to_string(), mode: AbiMode::Record }]);
+            assert!(!mint.finalize);
+        });
+    }
+}
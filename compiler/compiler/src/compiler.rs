@@ -18,16 +18,16 @@
 //!
 //! The [`Compiler`] type compiles Leo programs into R1CS circuits.
 pub use leo_ast::Ast;
-use leo_ast::{NodeBuilder, Program, Stub};
+use leo_ast::{ImportedItems, Module, ModuleImport, Node, NodeBuilder, Program, Stub};
 use leo_errors::{emitter::Handler, CompilerError, Result};
-pub use leo_passes::SymbolTable;
 use leo_passes::*;
-use leo_span::{source_map::FileName, symbol::with_session_globals, Symbol};
+pub use leo_passes::{LintConfig, LintLevel, LintRule, OverflowBehavior, SymbolTable};
+use leo_span::{source_map::FileName, symbol::with_session_globals, Span, Symbol};
 
 use sha2::{Digest, Sha256};
-use std::{fs, path::PathBuf};
+use std::{fs, path::PathBuf, time::Instant};
 
-use crate::CompilerOptions;
+use crate::{std_module_source, BuildStats, CompilerOptions, IntermediateRepresentation, PhaseStats, STD_LIBRARY_ROOT};
 use indexmap::{IndexMap, IndexSet};
 
 /// The primary entry point of the Leo compiler.
@@ -55,6 +55,10 @@ pub struct Compiler<'a> {
     type_table: TypeTable,
     /// The stubs for imported programs. Produced by `Retriever` module.
     import_stubs: IndexMap<Symbol, Stub>,
+    /// The spans of the expressions that the instructions of each function were generated from, set by code generation.
+    pub instruction_spans: InstructionSpans,
+    /// The statistics of the phases that ran so far, if they are enabled in the output options.
+    pub stats: BuildStats,
 }
 
 impl<'a> Compiler<'a> {
@@ -83,6 +87,8 @@ impl<'a> Compiler<'a> {
             assigner,
             import_stubs,
             type_table,
+            instruction_spans: InstructionSpans::new(),
+            stats: BuildStats::default(),
         }
     }
 
@@ -106,7 +112,13 @@ impl<'a> Compiler<'a> {
         let prg_sf = with_session_globals(|s| s.source_map.new_source(program_string, name));
 
         // Use the parser to construct the abstract syntax tree (ast).
-        self.ast = leo_parser::parse_ast(self.handler, &self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
+        self.ast = leo_parser::parse_ast_with_features(
+            self.handler,
+            &self.node_builder,
+            &prg_sf.src,
+            prg_sf.start_pos,
+            &self.compiler_options.build.features,
+        )?;
 
         // If the program is imported, then check that the name of its program scope matches the file name.
         // Note that parsing enforces that there is exactly one program scope in a file.
@@ -138,6 +150,97 @@ impl<'a> Compiler<'a> {
         self.parse_program_from_string(&program_string, FileName::Real(self.main_file_path.clone()))
     }
 
+    /// Loads the modules that the program imports items from, as well as the modules that those import items from,
+    /// and resolves the imported items into the program scope.
+    pub fn module_resolution_pass(&mut self) -> Result<()> {
+        if self.ast.ast.module_imports.is_empty() {
+            return Ok(());
+        }
+
+        let mut modules: IndexMap<Vec<Symbol>, Module> = IndexMap::new();
+        // The graph of imports between modules, and the first import along each of its edges.
+        let mut import_graph = ImportGraph::new(IndexSet::new());
+        let mut import_spans: IndexMap<(Symbol, Symbol), Span> = IndexMap::new();
+        // Each import to explore, with the name of the module it is in, if any.
+        let mut unexplored: Vec<(Option<Symbol>, ModuleImport)> =
+            self.ast.ast.module_imports.iter().map(|import| (None, import.clone())).collect();
+        while let Some((importer, import)) = unexplored.pop() {
+            let path = import.module_path();
+            let module_name = Symbol::intern(&import.module_name());
+            if let Some(importer) = importer {
+                import_graph.add_edge(importer, module_name);
+                import_spans.entry((importer, module_name)).or_insert(import.span);
+            }
+
+            if !modules.contains_key(&path) {
+                let module = self.parse_module(&import)?;
+                unexplored.extend(module.imports.iter().map(|import| (Some(module_name), import.clone())));
+                modules.insert(path.clone(), module);
+            }
+
+            // Check that the module declares the imported items.
+            if let ImportedItems::List(items) = &import.items {
+                let module = &modules[&path];
+                if let Some(item) = items.iter().find(|item| !module.item_names().any(|name| name == item.item.name)) {
+                    return Err(CompilerError::imported_item_not_found(
+                        item.item,
+                        import.module_name(),
+                        item.item.span,
+                    )
+                    .into());
+                }
+            }
+        }
+
+        // Check that the modules do not import from each other in a cycle, reporting the import that closes the cycle.
+        if let Err(DiGraphError::CycleDetected(path)) = import_graph.post_order() {
+            let span = import_spans[&(path[path.len() - 2], path[path.len() - 1])];
+            return Err(CompilerError::cyclic_module_import(path, span).into());
+        }
+
+        self.ast = ModuleResolver::do_pass((std::mem::take(&mut self.ast), &modules, self.handler))?;
+
+        Ok(())
+    }
+
+    /// Parses the module that `import` imports from, e.g. the module `lib.math` from the file `lib/math.leo`,
+    /// where the path is relative to the directory of the main file.
+    /// The modules of the standard library, e.g. `std.bits`, are shipped with the compiler instead.
+    fn parse_module(&self, import: &ModuleImport) -> Result<Module> {
+        let (module_string, file_name) = if import.path[0].name == Symbol::intern(STD_LIBRARY_ROOT) {
+            let module_name = import.module_name();
+            let module_string = std_module_source(&module_name)
+                .ok_or_else(|| CompilerError::std_module_not_found(&module_name, import.span))?;
+            (module_string.to_string(), FileName::Custom(module_name))
+        } else {
+            let mut path = self.main_file_path.parent().map(PathBuf::from).unwrap_or_default();
+            path.extend(import.path.iter().map(|segment| segment.to_string()));
+            path.set_extension("leo");
+
+            let module_string = fs::read_to_string(&path)
+                .map_err(|_| CompilerError::module_not_found(import.module_name(), &path, import.span))?;
+            (module_string, FileName::Real(path))
+        };
+
+        // Register the source of the module in the source map, so that errors within it can be reported.
+        let module_sf = with_session_globals(|s| s.source_map.new_source(&module_string, file_name));
+
+        leo_parser::parse_module_with_features(
+            self.handler,
+            &self.node_builder,
+            &module_sf.src,
+            module_sf.start_pos,
+            &self.compiler_options.build.features,
+        )
+    }
+
+    /// Runs the macro expansion pass.
+    pub fn macro_expansion_pass(&mut self) -> Result<()> {
+        self.ast = MacroExpander::do_pass((std::mem::take(&mut self.ast), self.handler, &self.node_builder))?;
+
+        Ok(())
+    }
+
     /// Runs the symbol table pass.
     pub fn symbol_table_pass(&self) -> Result<SymbolTable> {
         let symbol_table = SymbolTableCreator::do_pass((&self.ast, self.handler))?;
@@ -149,14 +252,43 @@ impl<'a> Compiler<'a> {
 
     /// Runs the type checker pass.
     pub fn type_checker_pass(&'a self, symbol_table: SymbolTable) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+        // A configured limit on the length of arrays cannot exceed the limit of the network.
+        let max_array_elements = self
+            .compiler_options
+            .build
+            .max_array_elements
+            .map_or(MAX_ARRAY_ELEMENTS, |max| max.min(MAX_ARRAY_ELEMENTS));
         let (symbol_table, struct_graph, call_graph) =
-            TypeChecker::do_pass((&self.ast, self.handler, symbol_table, &self.type_table))?;
+            TypeChecker::do_pass((&self.ast, self.handler, symbol_table, &self.type_table, max_array_elements))?;
         if self.compiler_options.output.type_checked_symbol_table {
             self.write_symbol_table_to_json("type_checked_symbol_table.json", &symbol_table)?;
         }
         Ok((symbol_table, struct_graph, call_graph))
     }
 
+    /// Runs the linter, returning the number of lints emitted for rules that are denied.
+    pub fn lint_pass(&self, config: &LintConfig) -> usize {
+        Linter::do_pass((&self.ast, self.handler, config))
+    }
+
+    /// Removes the transitions annotated with `@test` from the program, unless tests are enabled.
+    pub fn test_removal_pass(&mut self) {
+        if !self.compiler_options.build.tests_enabled {
+            for program_scope in self.ast.ast.program_scopes.values_mut() {
+                program_scope.functions.retain(|(_, function)| !function.is_test());
+            }
+        }
+    }
+
+    /// Runs the canonicalization pass.
+    pub fn canonicalization_pass(&mut self) -> Result<()> {
+        if self.compiler_options.build.canonicalization_enabled {
+            self.ast = Canonicalizer::do_pass(std::mem::take(&mut self.ast))?;
+        }
+
+        Ok(())
+    }
+
     /// Runs the loop unrolling pass.
     pub fn loop_unrolling_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
         let (ast, symbol_table) = Unroller::do_pass((
@@ -172,6 +304,10 @@ impl<'a> Compiler<'a> {
             self.write_ast_to_json("unrolled_ast.json")?;
         }
 
+        if self.compiler_options.output.unrolled_dot_graph {
+            self.write_ast_to_dot("unrolled_ast.dot")?;
+        }
+
         if self.compiler_options.output.unrolled_symbol_table {
             self.write_symbol_table_to_json("unrolled_symbol_table.json", &symbol_table)?;
         }
@@ -179,6 +315,24 @@ impl<'a> Compiler<'a> {
         Ok(symbol_table)
     }
 
+    /// Runs the recursion expansion pass.
+    pub fn recursion_expansion_pass(
+        &mut self,
+        symbol_table: SymbolTable,
+        call_graph: CallGraph,
+    ) -> Result<(SymbolTable, CallGraph)> {
+        let (ast, symbol_table, call_graph) = RecursionExpander::do_pass((
+            std::mem::take(&mut self.ast),
+            &self.node_builder,
+            symbol_table,
+            call_graph,
+            &self.type_table,
+        ))?;
+        self.ast = ast;
+
+        Ok((symbol_table, call_graph))
+    }
+
     /// Runs the static single assignment pass.
     pub fn static_single_assignment_pass(&mut self, symbol_table: &SymbolTable) -> Result<()> {
         self.ast = StaticSingleAssigner::do_pass((
@@ -187,6 +341,7 @@ impl<'a> Compiler<'a> {
             &self.assigner,
             symbol_table,
             &self.type_table,
+            self.compiler_options.build.ssa_options(),
         ))?;
 
         if self.compiler_options.output.ssa_ast {
@@ -204,12 +359,18 @@ impl<'a> Compiler<'a> {
             &self.type_table,
             &self.node_builder,
             &self.assigner,
+            self.compiler_options.build.early_return_guards_enabled,
         ))?;
 
         if self.compiler_options.output.flattened_ast {
             self.write_ast_to_json("flattened_ast.json")?;
         }
 
+        if self.compiler_options.output.ir {
+            let path = self.output_directory.join(format!("{}.ir.json", self.program_name));
+            IntermediateRepresentation::new(&self.program_name, &self.ast, &self.type_table).to_json_file(&path)?;
+        }
+
         Ok(())
     }
 
@@ -247,6 +408,15 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Runs the common subexpression elimination pass.
+    pub fn common_subexpression_elimination_pass(&mut self) -> Result<()> {
+        if self.compiler_options.build.cse_enabled {
+            self.ast = CommonSubexpressionEliminator::do_pass((std::mem::take(&mut self.ast), &self.node_builder))?;
+        }
+
+        Ok(())
+    }
+
     /// Runs the dead code elimination pass.
     pub fn dead_code_elimination_pass(&mut self) -> Result<()> {
         if self.compiler_options.build.dce_enabled {
@@ -267,43 +437,103 @@ impl<'a> Compiler<'a> {
         struct_graph: &StructGraph,
         call_graph: &CallGraph,
     ) -> Result<String> {
-        CodeGenerator::do_pass((&self.ast, symbol_table, &self.type_table, struct_graph, call_graph, &self.ast.ast))
+        let (bytecode, instruction_spans) = CodeGenerator::do_pass((
+            &self.ast,
+            symbol_table,
+            &self.type_table,
+            struct_graph,
+            call_graph,
+            &self.ast.ast,
+        ))?;
+        self.instruction_spans = instruction_spans;
+        Ok(bytecode)
     }
 
     /// Runs the compiler stages.
     pub fn compiler_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
-        let st = self.symbol_table_pass()?;
-        let (st, struct_graph, call_graph) = self.type_checker_pass(st)?;
+        let (st, struct_graph, call_graph) = self.run_phase("check", |compiler| {
+            compiler.macro_expansion_pass()?;
+            let st = compiler.symbol_table_pass()?;
+            compiler.type_checker_pass(st)
+        })?;
+
+        self.run_phase("canonicalize", |compiler| {
+            compiler.test_removal_pass();
+            compiler.canonicalization_pass()
+        })?;
 
         // TODO: Make this pass optional.
-        let st = self.loop_unrolling_pass(st)?;
+        let st = self.run_phase("unroll", |compiler| compiler.loop_unrolling_pass(st))?;
+
+        let (st, call_graph) =
+            self.run_phase("expand", |compiler| compiler.recursion_expansion_pass(st, call_graph))?;
 
-        self.static_single_assignment_pass(&st)?;
+        self.run_phase("ssa", |compiler| compiler.static_single_assignment_pass(&st))?;
 
-        self.flattening_pass(&st)?;
+        self.run_phase("flatten", |compiler| compiler.flattening_pass(&st))?;
 
-        self.destructuring_pass()?;
+        self.run_phase("destructure", |compiler| compiler.destructuring_pass())?;
 
-        self.function_inlining_pass(&call_graph)?;
+        self.run_phase("inline", |compiler| compiler.function_inlining_pass(&call_graph))?;
 
-        self.dead_code_elimination_pass()?;
+        self.run_phase("cse", |compiler| compiler.common_subexpression_elimination_pass())?;
+
+        self.run_phase("dce", |compiler| compiler.dead_code_elimination_pass())?;
 
         Ok((st, struct_graph, call_graph))
     }
 
+    /// Parses and type checks the program, without running any later stages.
+    /// All errors found by the type checker are emitted to the handler before an error is returned.
+    pub fn check(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+        // Parse the program.
+        self.run_phase("parse", |compiler| compiler.parse_program())?;
+        // Resolve the items imported from modules, and copy the dependencies specified in `program.json` into the AST.
+        self.run_phase("resolve", |compiler| {
+            compiler.module_resolution_pass()?;
+            compiler.add_import_stubs()
+        })?;
+        // Check the program.
+        self.run_phase("check", |compiler| {
+            compiler.macro_expansion_pass()?;
+            let st = compiler.symbol_table_pass()?;
+            compiler.type_checker_pass(st)
+        })
+    }
+
     /// Returns a compiled Leo program.
     pub fn compile(&mut self) -> Result<String> {
         // Parse the program.
-        self.parse_program()?;
-        // Copy the dependencies specified in `program.json` into the AST.
-        self.add_import_stubs()?;
+        self.run_phase("parse", |compiler| compiler.parse_program())?;
+        self.compile_parsed_program()
+    }
+
+    /// Compiles the program that was parsed by [`Compiler::parse_program`] into Aleo instructions.
+    pub fn compile_parsed_program(&mut self) -> Result<String> {
+        // Resolve the items imported from modules, and copy the dependencies specified in `program.json` into the AST.
+        self.run_phase("resolve", |compiler| {
+            compiler.module_resolution_pass()?;
+            compiler.add_import_stubs()
+        })?;
         // Run the intermediate compiler stages.
         let (symbol_table, struct_graph, call_graph) = self.compiler_stages()?;
         // Run code generation.
-        let bytecode = self.code_generation_pass(&symbol_table, &struct_graph, &call_graph)?;
+        let bytecode = self.run_phase("codegen", |compiler| {
+            compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph)
+        })?;
         Ok(bytecode)
     }
 
+    /// Runs the phase `phase` of the build, recording its statistics if they are enabled.
+    pub fn run_phase<T>(&mut self, phase: &str, run: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let start = Instant::now();
+        let output = run(self)?;
+        if self.compiler_options.output.stats {
+            self.stats.phases.push(PhaseStats::new(phase, start.elapsed(), &self.ast, &self.node_builder));
+        }
+        Ok(output)
+    }
+
     /// Writes the AST to a JSON file.
     fn write_ast_to_json(&self, file_suffix: &str) -> Result<()> {
         // Remove `Span`s if they are not enabled.
@@ -319,6 +549,15 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Writes the expression trees of the AST, annotated with their types, to a DOT file.
+    fn write_ast_to_dot(&self, file_suffix: &str) -> Result<()> {
+        self.ast.to_dot_file(
+            self.output_directory.clone(),
+            &format!("{}.{file_suffix}", self.program_name),
+            |expression| self.type_table.get(&expression.id()).map(|type_| type_.to_string()),
+        )
+    }
+
     /// Writes the Symbol Table to a JSON file.
     fn write_symbol_table_to_json(&self, file_suffix: &str, symbol_table: &SymbolTable) -> Result<()> {
         // Remove `Span`s if they are not enabled.
@@ -372,3 +611,436 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{BuildOptions, OutputOptions};
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Compiles the program `test.aleo` from `source` with the build options `build`, and returns its bytecode.
+    fn compile(source: &str, build: BuildOptions) -> String {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let mut compiler = Compiler::new(
+                "test".to_string(),
+                "aleo".to_string(),
+                &handler,
+                PathBuf::from("test.leo"),
+                std::env::temp_dir(),
+                Some(CompilerOptions { build, ..Default::default() }),
+                IndexMap::new(),
+            );
+            compiler.parse_program_from_string(source, FileName::Custom("test.leo".into())).unwrap();
+            compiler.compile_parsed_program().unwrap()
+        })
+    }
+
+    /// Compiles the program `test.aleo` from `source` with the build options `build`, and returns its bytecode,
+    /// or the number of errors if it fails to compile.
+    fn try_compile(source: &str, build: BuildOptions) -> Result<String, usize> {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let mut compiler = Compiler::new(
+                "test".to_string(),
+                "aleo".to_string(),
+                &handler,
+                PathBuf::from("test.leo"),
+                std::env::temp_dir(),
+                Some(CompilerOptions { build, ..Default::default() }),
+                IndexMap::new(),
+            );
+            compiler
+                .parse_program_from_string(source, FileName::Custom("test.leo".into()))
+                .and_then(|_| compiler.compile_parsed_program())
+                .map_err(|_| handler.err_count())
+        })
+    }
+
+    /// Type checks the program `test.aleo` from `source`, and checks it against the lint rules with the levels `lints`.
+    /// Returns the warnings that are emitted, and the number of them that are for denied rules.
+    fn lint(source: &str, lints: &[(LintRule, LintLevel)]) -> (Vec<String>, usize) {
+        create_session_if_not_set_then(|_| {
+            let (handler, buffer) = Handler::new_with_buf();
+            let mut compiler = Compiler::new(
+                "test".to_string(),
+                "aleo".to_string(),
+                &handler,
+                PathBuf::from("test.leo"),
+                std::env::temp_dir(),
+                None,
+                IndexMap::new(),
+            );
+            compiler.parse_program_from_string(source, FileName::Custom("test.leo".into())).unwrap();
+            let symbol_table = compiler.symbol_table_pass().unwrap();
+            compiler.type_checker_pass(symbol_table).unwrap();
+
+            let mut config = LintConfig::default();
+            for (rule, level) in lints {
+                config.set(*rule, *level);
+            }
+            let denied = compiler.lint_pass(&config);
+            let warnings = buffer.extract_warnings().into_inner().iter().map(|warning| warning.to_string()).collect();
+            (warnings, denied)
+        })
+    }
+
+    #[test]
+    fn test_overflow_behavior() {
+        let source = "program test.aleo {
+            transition main(a: u8, b: u8) -> (u8, u8) {
+                return (a * b, a.sub_wrapped(b));
+            }
+        }";
+        let compile = |overflow_behavior| {
+            compile(source, BuildOptions { dce_enabled: true, overflow_behavior, ..Default::default() })
+        };
+
+        let bytecode = compile(OverflowBehavior::Checked);
+        assert!(bytecode.contains("mul r0 r1 into"), "{bytecode}");
+        assert!(bytecode.contains("sub.w r0 r1 into"), "{bytecode}");
+
+        let bytecode = compile(OverflowBehavior::Wrapping);
+        assert!(bytecode.contains("mul.w r0 r1 into"), "{bytecode}");
+        assert!(bytecode.contains("sub.w r0 r1 into"), "{bytecode}");
+
+        // Trapping keeps the check of the checked operation, and adds one to the wrapping operation.
+        let bytecode = compile(OverflowBehavior::Trap);
+        assert!(bytecode.contains("mul r0 r1 into"), "{bytecode}");
+        assert!(bytecode.contains("sub r0 r1 into"), "{bytecode}");
+    }
+
+    #[test]
+    fn test_canonicalization() {
+        let source = "program test.aleo {
+            transition main(a: u8, b: u8) -> bool {
+                let c: u8 = 1u8 + a;
+                return !!(!(c == b));
+            }
+        }";
+        let compile =
+            |canonicalization_enabled| compile(source, BuildOptions { canonicalization_enabled, ..Default::default() });
+
+        let bytecode = compile(true);
+        assert!(bytecode.contains("add r0 1u8 into"), "{bytecode}");
+        assert!(bytecode.contains("is.neq "), "{bytecode}");
+        assert!(!bytecode.contains("not "), "{bytecode}");
+
+        let bytecode = compile(false);
+        assert!(bytecode.contains("add 1u8 r0 into"), "{bytecode}");
+        assert!(bytecode.contains("not "), "{bytecode}");
+    }
+
+    #[test]
+    fn test_lint_levels() {
+        let source = "program test.aleo {
+            transition main(a: u8) -> u8 {
+                let b: u64 = 5u64;
+                let c: u8 = a + 1u8;
+                return 1u8 < 2u8 ? a : b as u8;
+            }
+        }";
+
+        // `wide-integers` is allowed by default.
+        let (warnings, denied) = lint(source, &[]);
+        assert_eq!(warnings.len(), 2, "{warnings:?}");
+        assert!(warnings.iter().any(|warning| warning.contains("The value of `c` is never read.")), "{warnings:?}");
+        assert!(
+            warnings.iter().any(|warning| warning.contains("The condition `1u8 < 2u8` is constant.")),
+            "{warnings:?}"
+        );
+        assert_eq!(denied, 0);
+
+        let (warnings, denied) =
+            lint(source, &[(LintRule::UnusedValues, LintLevel::Deny), (LintRule::WideIntegers, LintLevel::Warn)]);
+        assert_eq!(warnings.len(), 3, "{warnings:?}");
+        assert!(warnings.iter().any(|warning| warning.contains("but its value fits in `u8`")), "{warnings:?}");
+        assert_eq!(denied, 1);
+
+        let (warnings, denied) = lint(source, &[
+            (LintRule::UnusedValues, LintLevel::Allow),
+            (LintRule::ConstantConditions, LintLevel::Allow),
+        ]);
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(denied, 0);
+    }
+
+    #[test]
+    fn test_boolean_folding() {
+        let source = "program test.aleo {
+            transition main(a: bool, b: u8) -> (bool, bool, bool, bool, bool) {
+                return (a && true, a == false, !true || a, b == 1u8 && false, b + 1u8 == 2u8 || true);
+            }
+        }";
+        let compile = |canonicalization_enabled| {
+            compile(source, BuildOptions { dce_enabled: true, canonicalization_enabled, ..Default::default() })
+        };
+
+        let bytecode = compile(true);
+        assert!(!bytecode.contains("and ") && !bytecode.contains("is.eq r0 false"), "{bytecode}");
+        assert!(bytecode.contains("not r0 into") && !bytecode.contains("is.eq r1 1u8"), "{bytecode}");
+        // The addition may overflow, so the disjunction with `true` keeps it.
+        assert!(bytecode.contains("add r1 1u8 into"), "{bytecode}");
+        assert_eq!(bytecode.matches("or ").count(), 1, "{bytecode}");
+
+        let bytecode = compile(false);
+        assert!(bytecode.contains("and r0 true into") && bytecode.contains("is.eq r0 false into"), "{bytecode}");
+        assert!(bytecode.contains("is.eq r1 1u8 into"), "{bytecode}");
+        assert_eq!(bytecode.matches("or ").count(), 2, "{bytecode}");
+    }
+
+    #[test]
+    fn test_common_subexpression_elimination() {
+        let source = "program test.aleo {
+            transition main(a: u8, b: u8) -> u8 {
+                return a * b + a * b + a * b;
+            }
+        }";
+        let compile =
+            |cse_enabled| compile(source, BuildOptions { dce_enabled: true, cse_enabled, ..Default::default() });
+        assert_eq!(compile(true).matches("mul r0 r1 into").count(), 1);
+        assert_eq!(compile(false).matches("mul r0 r1 into").count(), 3);
+    }
+
+    #[test]
+    fn test_comparison_sharing() {
+        let source = "program test.aleo {
+            transition main(a: u8, b: u8) -> (bool, bool, bool, bool) {
+                return (a < b, b > a, a >= b, b <= a);
+            }
+        }";
+        let compile =
+            |cse_enabled| compile(source, BuildOptions { dce_enabled: true, cse_enabled, ..Default::default() });
+
+        // Every comparison is `a < b` or its negation.
+        let bytecode = compile(true);
+        assert_eq!(bytecode.matches("lt r0 r1 into").count(), 1, "{bytecode}");
+        assert!(!bytecode.contains("gt ") && !bytecode.contains("gte ") && !bytecode.contains("lte "), "{bytecode}");
+        assert_eq!(bytecode.matches("not ").count(), 1, "{bytecode}");
+
+        let bytecode = compile(false);
+        assert!(bytecode.contains("lt r0 r1 into") && bytecode.contains("gt r1 r0 into"), "{bytecode}");
+        assert!(bytecode.contains("gte r0 r1 into") && bytecode.contains("lte r1 r0 into"), "{bytecode}");
+    }
+
+    #[test]
+    fn test_branch_pruning() {
+        let source = "program test.aleo {
+            transition main(a: u8) -> u8 {
+                if false {
+                    a = a + 1u8;
+                }
+                return a;
+            }
+        }";
+        let compile = |branch_pruning_enabled| {
+            compile(source, BuildOptions { dce_enabled: true, branch_pruning_enabled, ..Default::default() })
+        };
+        let bytecode = compile(true);
+        assert!(!bytecode.contains("add ") && !bytecode.contains("ternary "), "{bytecode}");
+        let bytecode = compile(false);
+        assert!(bytecode.contains("add ") && bytecode.contains("ternary "), "{bytecode}");
+    }
+
+    #[test]
+    fn test_early_return_guards() {
+        let source = "program test.aleo {
+            transition main(a: u8) -> u8 {
+                if a == 0u8 {
+                    return 0u8;
+                }
+                assert_neq(a, 0u8);
+                return a;
+            }
+        }";
+        let compile = |early_return_guards_enabled| {
+            compile(source, BuildOptions { dce_enabled: true, early_return_guards_enabled, ..Default::default() })
+        };
+        let bytecode = compile(true);
+        assert!(!bytecode.contains("assert.neq r0 0u8;"), "{bytecode}");
+        let bytecode = compile(false);
+        assert!(bytecode.contains("assert.neq r0 0u8;"), "{bytecode}");
+    }
+
+    #[test]
+    fn test_mux_lowering() {
+        let source = "program test.aleo {
+            transition main(x: u8) -> field {
+                return x == 0u8 ? 1field : x == 1u8 ? 5field : 7field;
+            }
+        }";
+        let compile = |mux_lowering_enabled| {
+            compile(source, BuildOptions { dce_enabled: true, mux_lowering_enabled, ..Default::default() })
+        };
+        let bytecode = compile(true);
+        assert!(!bytecode.contains("ternary ") && bytecode.contains("as field;"), "{bytecode}");
+        let bytecode = compile(false);
+        assert_eq!(bytecode.matches("ternary ").count(), 2, "{bytecode}");
+    }
+
+    #[test]
+    fn test_pow_lowering() {
+        let source = "program test.aleo {
+            transition main(x: u32) -> (u32, u32) {
+                return (x ** 5u8, x.pow_wrapped(15u8));
+            }
+        }";
+        let compile = |pow_lowering_enabled| {
+            compile(source, BuildOptions { dce_enabled: true, pow_lowering_enabled, ..Default::default() })
+        };
+
+        // The shortest addition chains are `1, 2, 4, 5` and `1, 2, 3, 6, 12, 15`.
+        let bytecode = compile(true);
+        assert!(!bytecode.contains("pow"), "{bytecode}");
+        assert_eq!(bytecode.matches("mul ").count(), 3, "{bytecode}");
+        assert_eq!(bytecode.matches("mul.w ").count(), 5, "{bytecode}");
+
+        let bytecode = compile(false);
+        assert!(bytecode.contains("pow r0 5u8 into") && bytecode.contains("pow.w r0 15u8 into"), "{bytecode}");
+    }
+
+    #[test]
+    fn test_div_lowering() {
+        let source = "program test.aleo {
+            transition main(x: u8, y: i8) -> (u8, u8, i8) {
+                return (x / 8u8, x % 8u8, y / 8i8);
+            }
+        }";
+        let compile = |div_lowering_enabled| {
+            compile(source, BuildOptions { dce_enabled: true, div_lowering_enabled, ..Default::default() })
+        };
+
+        let bytecode = compile(true);
+        assert!(bytecode.contains("shr r0 3u32 into") && bytecode.contains("and r0 7u8 into"), "{bytecode}");
+        // Signed divisions round towards zero, so they are not shifts.
+        assert!(bytecode.contains("div r1 8i8 into"), "{bytecode}");
+
+        let bytecode = compile(false);
+        assert!(bytecode.contains("div r0 8u8 into") && bytecode.contains("rem r0 8u8 into"), "{bytecode}");
+    }
+
+    #[test]
+    fn test_test_removal() {
+        let source = "program test.aleo {
+            transition double(a: u8) -> u8 {
+                return a + a;
+            }
+
+            @test
+            transition test_double(a: u8) {
+                assert_eq(a + a, a * 2u8);
+            }
+        }";
+        let compile = |tests_enabled| compile(source, BuildOptions { tests_enabled, ..Default::default() });
+        let bytecode = compile(true);
+        assert!(bytecode.contains("function double:") && bytecode.contains("function test_double:"), "{bytecode}");
+        let bytecode = compile(false);
+        assert!(bytecode.contains("function double:") && !bytecode.contains("function test_double:"), "{bytecode}");
+    }
+
+    #[test]
+    fn test_features() {
+        let source = r#"program test.aleo {
+            @cfg(feature = "debug")
+            transition trace(a: u8) -> u8 {
+                return a;
+            }
+
+            transition main(a: u8) -> u8 {
+                @cfg(feature = "debug")
+                assert_neq(a, 0u8);
+                return a + 1u8;
+            }
+        }"#;
+        let compile = |features: &[&str]| {
+            let features = features.iter().map(|feature| feature.to_string()).collect();
+            compile(source, BuildOptions { features, ..Default::default() })
+        };
+        let bytecode = compile(&["debug"]);
+        assert!(bytecode.contains("function trace:") && bytecode.contains("assert.neq r0 0u8;"), "{bytecode}");
+        let bytecode = compile(&[]);
+        assert!(!bytecode.contains("function trace:") && !bytecode.contains("assert.neq"), "{bytecode}");
+    }
+
+    #[test]
+    fn test_field_pow_lowering() {
+        let source = "program test.aleo {
+            transition main(x: field) -> (field, field) {
+                return (x ** 5field, x * (2field ** 3field));
+            }
+        }";
+        let compile = |pow_lowering_enabled| {
+            compile(source, BuildOptions { dce_enabled: true, pow_lowering_enabled, ..Default::default() })
+        };
+
+        let bytecode = compile(true);
+        assert!(!bytecode.contains("pow"), "{bytecode}");
+        assert_eq!(bytecode.matches("mul ").count(), 4, "{bytecode}");
+        assert!(bytecode.contains("mul r0 8field into"), "{bytecode}");
+
+        let bytecode = compile(false);
+        assert!(bytecode.contains("pow r0 5field into") && bytecode.contains("pow 2field 3field into"), "{bytecode}");
+    }
+
+    #[test]
+    fn test_max_array_elements() {
+        let source = "program test.aleo {
+            transition main(a: [u8; 4]) -> u8 {
+                let b: [u8; 2] = [a[0u32], a[1u32]];
+                return b[1u32];
+            }
+        }";
+        let compile =
+            |max_array_elements| try_compile(source, BuildOptions { max_array_elements, ..Default::default() });
+        assert!(compile(None).is_ok());
+        assert!(compile(Some(4)).is_ok());
+        assert!(compile(Some(3)).is_err());
+        assert!(compile(Some(1)).is_err());
+
+        // A limit above the one of the network is capped at it.
+        let source = "program test.aleo {
+            transition main(a: [u8; 33]) -> u8 {
+                return a[0u32];
+            }
+        }";
+        assert!(try_compile(source, BuildOptions { max_array_elements: Some(64), ..Default::default() }).is_err());
+    }
+
+    #[test]
+    fn test_emit_ir() {
+        let source = "program test.aleo {
+            transition main(a: u8, b: bool) -> u8 {
+                if b {
+                    return a + 1u8;
+                } else {
+                    return a;
+                }
+            }
+        }";
+        let directory = tempfile::tempdir().unwrap();
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let output = OutputOptions { ir: true, ..Default::default() };
+            let mut compiler = Compiler::new(
+                "test".to_string(),
+                "aleo".to_string(),
+                &handler,
+                PathBuf::from("test.leo"),
+                directory.path().to_path_buf(),
+                Some(CompilerOptions { output, ..Default::default() }),
+                IndexMap::new(),
+            );
+            compiler.parse_program_from_string(source, FileName::Custom("test.leo".into())).unwrap();
+            compiler.compile_parsed_program().unwrap();
+
+            let ir = IntermediateRepresentation::from_json_file(&directory.path().join("test.ir.json")).unwrap();
+            assert_eq!(ir.version, crate::IR_VERSION);
+            assert_eq!(ir.program_name, "test");
+            assert!(!ir.types.is_empty());
+            // The program is flattened, so the conditional statement is replaced by a ternary expression.
+            let program = serde_json::to_string(&ir.program).unwrap();
+            assert!(!program.contains("\"Conditional\"") && program.contains("\"Ternary\""), "{program}");
+        });
+    }
+}
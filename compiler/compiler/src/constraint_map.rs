@@ -0,0 +1,381 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The source map of the constraints of a function, which relates each constraint to the Leo expression it originates
+//! from, for debuggers and profilers.
+//!
+//! snarkVM synthesizes the circuit of a function from its Aleo instructions in order, so the constraints of each
+//! instruction form a range of indices, which follows the range of the instruction before it. The range of an
+//! instruction is measured by synthesizing the function truncated before and after it, so mapping a function with `n`
+//! instructions synthesizes `n + 1` circuits, which are synthesized in parallel. Each instruction is then related to the expression that code generation
+//! produced it from.
+//!
+//! A source map is written as JSON, in the form
+//!
+//! ```text
+//! {
+//!   "program": "hello.aleo",
+//!   "function": "main",
+//!   "input_constraints": 130,
+//!   "ranges": [
+//!     {
+//!       "start": 130,
+//!       "end": 1187,
+//!       "instruction": "mul r0 r1 into r2;",
+//!       "location": {
+//!         "file": "main.leo",
+//!         "line_start": 5,
+//!         "col_start": 23,
+//!         "line_stop": 5,
+//!         "col_stop": 28,
+//!         "source": "a * b"
+//!       }
+//!     },
+//!     ...
+//!   ]
+//! }
+//! ```
+//!
+//! The constraints `0..input_constraints` check the inputs of the function, and each range holds the constraints
+//! `start..end`. A range without a location is of an instruction that does not originate from an expression in a
+//! source file.
+
+use crate::{
+    circuit_hashes,
+    parallel::par_map,
+    proving::{load_process, parse_function_name},
+    Compiler,
+    SynthesisCache,
+};
+
+use leo_errors::{CompilerError, Result};
+use leo_span::{source_map::FileName, symbol::with_session_globals, Span};
+
+use serde::{Deserialize, Serialize};
+use snarkvm::{
+    circuit::Aleo,
+    prelude::{CryptoRng, Error as SnarkVMError, Rng},
+};
+use std::{fmt::Write as _, path::Path};
+
+/// The location of an expression in a source file.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SourceLocation {
+    /// The name of the source file, e.g. `main.leo`.
+    pub file: String,
+    /// The line that the expression starts on.
+    pub line_start: usize,
+    /// The column that the expression starts at.
+    pub col_start: usize,
+    /// The line that the expression ends on.
+    pub line_stop: usize,
+    /// The column that the expression ends at.
+    pub col_stop: usize,
+    /// The first line of the source of the expression.
+    pub source: String,
+}
+
+impl SourceLocation {
+    /// Returns the location of `span` in the source files of the session, if it is in one.
+    pub fn from_span(span: Span) -> Option<Self> {
+        with_session_globals(|s| {
+            let location = s.source_map.span_to_location(span)?;
+            let contents = s.source_map.contents_of_span(span)?;
+            let file = match &location.source_file.name {
+                FileName::Real(path) => path
+                    .file_name()
+                    .map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().to_string()),
+                FileName::Custom(name) => name.clone(),
+            };
+            Some(Self {
+                file,
+                line_start: location.line_start,
+                col_start: location.col_start,
+                line_stop: location.line_stop,
+                col_stop: location.col_stop,
+                source: contents.lines().next().unwrap_or_default().trim().to_string(),
+            })
+        })
+    }
+}
+
+/// The constraints of an instruction of a function body.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ConstraintRange {
+    /// The index of the first constraint of the instruction.
+    pub start: usize,
+    /// The index after the last constraint of the instruction.
+    pub end: usize,
+    /// The instruction, e.g. `mul r0 r1 into r2;`.
+    pub instruction: String,
+    /// The location of the expression that the instruction was generated from.
+    pub location: Option<SourceLocation>,
+}
+
+/// The source map of the constraints of the circuit of a function.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ConstraintMap {
+    /// The ID of the program, e.g. `hello.aleo`.
+    pub program: String,
+    /// The name of the function.
+    pub function: String,
+    /// The number of constraints of the inputs of the function, which come before those of any instruction.
+    pub input_constraints: usize,
+    /// The constraints of each instruction of the function body, in order.
+    pub ranges: Vec<ConstraintRange>,
+}
+
+impl ConstraintMap {
+    /// Returns the number of constraints of the circuit of the function.
+    pub fn num_constraints(&self) -> usize {
+        self.ranges.last().map_or(self.input_constraints, |range| range.end)
+    }
+
+    /// Returns the range of the instruction that the constraint at `index` belongs to, or `None` if the constraint
+    /// checks the inputs of the function or does not exist.
+    pub fn range_of(&self, index: usize) -> Option<&ConstraintRange> {
+        let position = self.ranges.partition_point(|range| range.end <= index);
+        self.ranges.get(position).filter(|range| range.start <= index)
+    }
+
+    /// Writes the source map to the JSON file at `path`.
+    pub fn to_json_file(&self, path: &Path) -> Result<()> {
+        let file = std::fs::File::create(path)
+            .map_err(|e| CompilerError::failed_to_write_constraint_map(path.display(), e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer_pretty(writer, self)
+            .map_err(|e| CompilerError::failed_to_write_constraint_map(path.display(), e))?)
+    }
+
+    /// Reads a source map from the JSON file at `path`.
+    pub fn from_json_file(path: &Path) -> Result<Self> {
+        let file =
+            std::fs::File::open(path).map_err(|e| CompilerError::failed_to_read_constraint_map(path.display(), e))?;
+        let reader = std::io::BufReader::new(file);
+        Ok(serde_json::from_reader(reader)
+            .map_err(|e| CompilerError::failed_to_read_constraint_map(path.display(), e))?)
+    }
+}
+
+impl Compiler<'_> {
+    /// Maps the constraints of the function `function_name` of the compiled `bytecode` to their source.
+    /// `spans` holds the span of the expression that each instruction of the function body was generated from, as
+    /// recorded in [`Compiler::instruction_spans`]. The circuits are only synthesized if they are not in `cache`.
+    pub fn constraint_map<A: Aleo, R: Rng + CryptoRng>(
+        bytecode: &str,
+        function_name: &str,
+        spans: &[Span],
+        cache: &mut SynthesisCache,
+        rng: &mut R,
+    ) -> Result<ConstraintMap> {
+        let (_, program_id) = load_process::<A::Network>(bytecode, &[])?;
+        let instructions = function_instructions(bytecode, function_name);
+
+        let hash = circuit_hashes(bytecode).get(function_name).cloned().unwrap_or_default();
+        let counts = match cache.prefix_counts.get(&hash) {
+            Some(counts) if counts.len() == instructions.len() + 1 => counts.clone(),
+            _ => {
+                let counts = constraint_counts::<A, R>(bytecode, function_name, instructions.len(), rng)?;
+                cache.num_constraints.insert(hash.clone(), counts[instructions.len()]);
+                cache.prefix_counts.insert(hash, counts.clone());
+                counts
+            }
+        };
+
+        let ranges = instructions
+            .into_iter()
+            .enumerate()
+            .map(|(index, instruction)| ConstraintRange {
+                start: counts[index],
+                end: counts[index + 1],
+                instruction,
+                location: spans.get(index).and_then(|span| SourceLocation::from_span(*span)),
+            })
+            .collect();
+
+        Ok(ConstraintMap {
+            program: program_id.to_string(),
+            function: function_name.to_string(),
+            input_constraints: counts[0],
+            ranges,
+        })
+    }
+}
+
+/// Returns the number of constraints of the function `function_name` of the compiled `bytecode`, truncated after each
+/// of its `num_instructions` instructions, starting with none of them. The truncated circuits are synthesized in
+/// parallel.
+fn constraint_counts<A: Aleo, R: Rng + CryptoRng>(
+    bytecode: &str,
+    function_name: &str,
+    num_instructions: usize,
+    rng: &mut R,
+) -> Result<Vec<usize>> {
+    let lengths = (0..=num_instructions).collect::<Vec<_>>();
+    let synthesized = par_map(&lengths, rng, |length, rng| -> Result<usize> {
+        let (process, program_id) =
+            load_process::<A::Network>(&truncate_function(bytecode, function_name, *length), &[])?;
+        let function = parse_function_name::<A::Network>(function_name)?;
+        let mut synthesize = || -> Result<_, SnarkVMError> {
+            process.synthesize_key::<A, _>(&program_id, &function, rng)?;
+            Ok(process.get_proving_key(program_id, function)?.circuit.index_info.num_constraints)
+        };
+        Ok(synthesize().map_err(|err| CompilerError::failed_to_setup(function_name, err))?)
+    });
+
+    let mut counts = Vec::with_capacity(synthesized.len());
+    for count in synthesized {
+        let count = count?;
+        // Each count is at least the previous one, since synthesizing an instruction only adds constraints.
+        counts.push(counts.last().map_or(count, |previous: &usize| count.max(*previous)));
+    }
+    Ok(counts)
+}
+
+/// Returns the instructions of the body of the function `function_name`, without its inputs and outputs.
+fn function_instructions(bytecode: &str, function_name: &str) -> Vec<String> {
+    let header = format!("function {function_name}:");
+    bytecode
+        .lines()
+        .skip_while(|line| *line != header)
+        .skip(1)
+        .take_while(|line| line.is_empty() || line.starts_with(char::is_whitespace))
+        .map(str::trim)
+        .filter(|line| is_instruction(line))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Returns the `bytecode`, with only the first `length` instructions of the body of the function `function_name`.
+/// The outputs of the function and its `finalize` block are removed, along with any `async` instruction, so that the
+/// truncated program is well-formed. Neither of them adds constraints to the circuit of the function.
+fn truncate_function(bytecode: &str, function_name: &str, length: usize) -> String {
+    let function_header = format!("function {function_name}:");
+    let finalize_header = format!("finalize {function_name}:");
+
+    let (mut in_function, mut in_finalize, mut index) = (false, false, 0);
+    let mut truncated = String::new();
+    for line in bytecode.lines() {
+        if !line.is_empty() && !line.starts_with(char::is_whitespace) {
+            in_function = line == function_header;
+            in_finalize = line == finalize_header;
+        }
+        let keep = match (in_function, in_finalize) {
+            (_, true) => false,
+            (true, _) if is_instruction(line.trim()) => {
+                index += 1;
+                index <= length && !line.trim().starts_with("async ")
+            }
+            (true, _) => !line.trim().starts_with("output "),
+            (false, false) => true,
+        };
+        if keep {
+            writeln!(truncated, "{line}").expect("failed to write to string");
+        }
+    }
+    truncated
+}
+
+/// Returns whether a line of a function body is an instruction, i.e. not empty, an input, or an output.
+fn is_instruction(line: &str) -> bool {
+    !line.is_empty() && !line.ends_with(':') && !line.starts_with("input ") && !line.starts_with("output ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use snarkvm::{circuit::AleoV0, prelude::TestRng};
+
+    const BYTECODE: &str = "program hello.aleo;
+
+function main:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    mul r0 r1 into r2;
+    add r2 r0 into r3;
+    async main r3 into r4;
+    output r3 as u32.private;
+    output r4 as hello.aleo/main.future;
+
+finalize main:
+    input r0 as u32.public;
+    assert.eq r0 r0;
+";
+
+    #[test]
+    fn test_truncate_function() {
+        assert_eq!(function_instructions(BYTECODE, "main"), [
+            "mul r0 r1 into r2;",
+            "add r2 r0 into r3;",
+            "async main r3 into r4;"
+        ]);
+
+        let truncated = truncate_function(BYTECODE, "main", 1);
+        assert_eq!(
+            truncated,
+            "program hello.aleo;\n\nfunction main:\n    input r0 as u32.public;\n    input r1 as u32.private;\n    mul r0 r1 \
+             into r2;\n\n"
+        );
+        assert!(!truncate_function(BYTECODE, "main", 3).contains("async"));
+    }
+
+    #[test]
+    fn test_range_of() {
+        let range = |start, end| ConstraintRange { start, end, instruction: String::new(), location: None };
+        let map = ConstraintMap {
+            program: "hello.aleo".to_string(),
+            function: "main".to_string(),
+            input_constraints: 2,
+            ranges: vec![range(2, 5), range(5, 5), range(5, 9)],
+        };
+
+        assert_eq!(map.num_constraints(), 9);
+        assert_eq!(map.range_of(1), None);
+        assert_eq!(map.range_of(4), Some(&map.ranges[0]));
+        assert_eq!(map.range_of(5), Some(&map.ranges[2]));
+        assert_eq!(map.range_of(9), None);
+    }
+
+    #[test]
+    fn test_constraint_map() {
+        let rng = &mut TestRng::default();
+        let mut cache = SynthesisCache::default();
+        let map = Compiler::constraint_map::<AleoV0, _>(BYTECODE, "main", &[], &mut cache, rng).unwrap();
+        assert_eq!(map.program, "hello.aleo");
+        assert_eq!(map.function, "main");
+
+        // The ranges of the instructions follow each other, starting after the constraints of the inputs.
+        let instructions = map.ranges.iter().map(|range| range.instruction.as_str()).collect::<Vec<_>>();
+        assert_eq!(instructions, function_instructions(BYTECODE, "main"));
+        assert_eq!(map.ranges[0].start, map.input_constraints);
+        assert!(map.ranges.windows(2).all(|ranges| ranges[0].end == ranges[1].start));
+        // A checked multiplication adds constraints, and an `async` instruction adds none.
+        assert!(map.ranges[0].end > map.ranges[0].start);
+        assert_eq!(map.ranges[2].start, map.ranges[2].end);
+        // Without the spans of the instructions, they have no locations.
+        assert!(map.ranges.iter().all(|range| range.location.is_none()));
+
+        // The counts are cached, so mapping the function again gives the same map.
+        assert_eq!(cache.prefix_counts.len(), 1);
+        assert_eq!(Compiler::constraint_map::<AleoV0, _>(BYTECODE, "main", &[], &mut cache, rng).unwrap(), map);
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("main.map.json");
+        map.to_json_file(&path).unwrap();
+        assert_eq!(ConstraintMap::from_json_file(&path).unwrap(), map);
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Encodings of proofs and keys, to write them for other tools, e.g. verifiers outside of the Rust toolchain.
+
+use leo_errors::{CompilerError, Result};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use snarkvm::prelude::{FromBytes, Network, ToBytes};
+
+/// The name of the proving system of snarkVM, which proves and verifies the executions of programs.
+pub const PROVING_SYSTEM: &str = "varuna";
+
+/// An encoding of a proof or a key.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Encoding {
+    /// The raw bytes of the value, in little-endian order.
+    Bytes,
+    /// The raw bytes of the value, as a lowercase hexadecimal string.
+    Hex,
+    /// The value as JSON, along with the [`Metadata`] of the program it belongs to.
+    Json,
+}
+
+/// The metadata that the JSON encoding embeds, to identify what a proof or key belongs to.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// The SHA256 checksum of the Aleo instructions of the program.
+    pub program_checksum: String,
+    /// The pairing-friendly curve of the proving system, e.g. `Bls12_377`.
+    pub curve: String,
+    /// The name of the proving system, e.g. `varuna`.
+    pub backend: String,
+}
+
+impl Metadata {
+    /// Returns the metadata of the proofs and keys of the compiled `bytecode`, proven on the network `N`.
+    pub fn new<N: Network>(bytecode: &str) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(bytecode.as_bytes());
+        let hash = hasher.finalize();
+
+        let curve = std::any::type_name::<N::PairingCurve>();
+        Self {
+            program_checksum: format!("{hash:x}"),
+            curve: curve.rsplit("::").next().unwrap_or(curve).to_string(),
+            backend: PROVING_SYSTEM.to_string(),
+        }
+    }
+}
+
+/// A value in the JSON encoding.
+#[derive(Serialize, Deserialize)]
+struct Envelope<T> {
+    metadata: Metadata,
+    value: T,
+}
+
+/// Encodes `value` with `encoding`. Only the JSON encoding embeds `metadata`.
+pub fn encode<T: ToBytes + Serialize>(value: &T, encoding: Encoding, metadata: &Metadata) -> Result<Vec<u8>> {
+    match encoding {
+        Encoding::Bytes => value.to_bytes_le().map_err(|err| CompilerError::failed_to_encode(err).into()),
+        Encoding::Hex => {
+            let bytes = value.to_bytes_le().map_err(CompilerError::failed_to_encode)?;
+            Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect::<String>().into_bytes())
+        }
+        Encoding::Json => serde_json::to_vec_pretty(&Envelope { metadata: metadata.clone(), value })
+            .map_err(|err| CompilerError::failed_to_encode(err).into()),
+    }
+}
+
+/// Decodes a value that was encoded with `encoding`, along with its metadata if the encoding embeds it.
+pub fn decode<T: FromBytes + DeserializeOwned>(encoded: &[u8], encoding: Encoding) -> Result<(T, Option<Metadata>)> {
+    let from_bytes = |bytes: &[u8]| T::from_bytes_le(bytes).map_err(CompilerError::failed_to_decode);
+    match encoding {
+        Encoding::Bytes => Ok((from_bytes(encoded)?, None)),
+        Encoding::Hex => {
+            let hex = std::str::from_utf8(encoded).map_err(CompilerError::failed_to_decode)?.trim();
+            if hex.len() % 2 != 0 {
+                return Err(CompilerError::failed_to_decode("the hexadecimal string has an odd length").into());
+            }
+            let bytes = (0..hex.len())
+                .step_by(2)
+                .map(|index| u8::from_str_radix(&hex[index..index + 2], 16))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(CompilerError::failed_to_decode)?;
+            Ok((from_bytes(&bytes)?, None))
+        }
+        Encoding::Json => {
+            let envelope: Envelope<T> = serde_json::from_slice(encoded).map_err(CompilerError::failed_to_decode)?;
+            Ok((envelope.value, Some(envelope.metadata)))
+        }
+    }
+}
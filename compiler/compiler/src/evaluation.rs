@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Evaluating expressions and definitions of variables one at a time, as in the REPL of the CLI.
+//!
+//! Each input is parsed, type checked against the variables defined so far, and evaluated by the
+//! [`ConstantInterpreter`]. The circuit of an expression may also be synthesized, to count its constraints: the
+//! expression is compiled into a transition that takes the variables as private inputs, and its constraints are those
+//! of the transition minus those of the same transition returning the value of the expression as a constant.
+
+use crate::Compiler;
+
+use leo_ast::{
+    ConstantInterpreter,
+    Expression,
+    ExpressionVisitor,
+    Literal,
+    Node,
+    NodeBuilder,
+    NodeID,
+    Statement,
+    Type,
+    Value,
+};
+use leo_errors::{emitter::Handler, CompilerError, Result};
+use leo_passes::{SymbolTable, TypeChecker, TypeTable, VariableSymbol, VariableType, MAX_ARRAY_ELEMENTS};
+use leo_span::{source_map::FileName, symbol::with_session_globals, Symbol};
+
+use indexmap::IndexMap;
+use snarkvm::{
+    circuit::Aleo,
+    prelude::{CryptoRng, Rng},
+};
+use std::{fmt::Display, path::PathBuf};
+
+/// The result of evaluating an input.
+pub struct Evaluation {
+    /// The variable that the input defines, if it is a definition.
+    pub name: Option<Symbol>,
+    /// The value of the expression.
+    pub value: Value,
+    /// The type of the expression.
+    pub type_: Type,
+    /// The number of constraints of the expression, if its circuit was synthesized.
+    pub num_constraints: Option<usize>,
+}
+
+/// Evaluates inputs in the scope of the variables defined by the previous ones.
+#[derive(Default)]
+pub struct Evaluator {
+    /// The types of the variables defined so far.
+    types: IndexMap<Symbol, Type>,
+    /// The interpreter, which holds the values of the variables defined so far.
+    interpreter: ConstantInterpreter,
+    /// The builder of the node IDs of the parsed inputs.
+    node_builder: NodeBuilder,
+}
+
+impl Evaluator {
+    /// Evaluates `input`, which is an expression, or a `let` or `const` definition of a single variable.
+    /// The circuit of the expression is synthesized if an `rng` is given.
+    pub fn evaluate<A: Aleo, R: Rng + CryptoRng>(
+        &mut self,
+        handler: &Handler,
+        input: &str,
+        rng: Option<&mut R>,
+    ) -> Result<Evaluation> {
+        let (name, expected, expression) = self.parse(handler, input)?;
+
+        // Type check the expression against the types of the variables defined so far.
+        let type_ = {
+            let mut symbol_table = SymbolTable::default();
+            for (name, type_) in self.types.iter() {
+                let variable =
+                    VariableSymbol { type_: type_.clone(), span: Default::default(), declaration: VariableType::Mut };
+                symbol_table.insert_variable(*name, variable)?;
+            }
+            let type_table = TypeTable::default();
+            let mut type_checker = TypeChecker::new(symbol_table, &type_table, handler, MAX_ARRAY_ELEMENTS);
+            let type_ = type_checker.visit_expression(&expression, &expected);
+            handler.last_err().map_err(|e| *e)?;
+            type_.unwrap_or(Type::Err)
+        };
+
+        let value = self.interpreter.evaluate(&expression)?;
+        let num_constraints = match rng {
+            Some(rng) => {
+                let constant = Literal::from_value(value.clone(), NodeID::default());
+                let num_constraints = self.synthesize::<A, R>(handler, &type_, &expression, rng)?;
+                let num_constant_constraints = self.synthesize::<A, R>(handler, &type_, &constant, rng)?;
+                Some(num_constraints.saturating_sub(num_constant_constraints))
+            }
+            None => None,
+        };
+
+        if let Some(name) = name {
+            self.types.insert(name, type_.clone());
+            self.interpreter.bind(name, value.clone());
+        }
+
+        Ok(Evaluation { name, value, type_, num_constraints })
+    }
+
+    /// Parses `input`, returning the variable it defines and its declared type, if it is a definition, and the
+    /// expression to evaluate.
+    fn parse(&self, handler: &Handler, input: &str) -> Result<(Option<Symbol>, Option<Type>, Expression)> {
+        let source_file = with_session_globals(|s| s.source_map.new_source(input, FileName::Custom("repl".into())));
+
+        let is_definition = ["let ", "const "].iter().any(|keyword| input.trim_start().starts_with(keyword));
+        if !is_definition {
+            let expression =
+                leo_parser::parse_expression(handler, &self.node_builder, &source_file.src, source_file.start_pos)?;
+            return Ok((None, None, expression));
+        }
+
+        match leo_parser::parse_statement(handler, &self.node_builder, &source_file.src, source_file.start_pos)? {
+            Statement::Const(definition) => Ok((Some(definition.place.name), Some(definition.type_), definition.value)),
+            Statement::Definition(definition) => match definition.place {
+                Expression::Identifier(identifier) => {
+                    Ok((Some(identifier.name), Some(definition.type_), definition.value))
+                }
+                place => Err(CompilerError::expected_expression_or_definition(place.span()).into()),
+            },
+            statement => Err(CompilerError::expected_expression_or_definition(statement.span()).into()),
+        }
+    }
+
+    /// Returns the number of constraints of a transition that takes the variables defined so far as private inputs
+    /// and returns `output`, of type `type_`.
+    fn synthesize<A: Aleo, R: Rng + CryptoRng>(
+        &self,
+        handler: &Handler,
+        type_: &Type,
+        output: &impl Display,
+        rng: &mut R,
+    ) -> Result<usize> {
+        let inputs = self.types.iter().map(|(name, type_)| format!("{name}: {type_}")).collect::<Vec<_>>().join(", ");
+        let program = format!(
+            "program repl.aleo {{\n    transition main({inputs}) -> {type_} {{\n        return {output};\n    }}\n}}\n"
+        );
+
+        let mut compiler = Compiler::new(
+            "repl".to_string(),
+            "aleo".to_string(),
+            handler,
+            PathBuf::from("repl.leo"),
+            std::env::temp_dir(),
+            None,
+            IndexMap::new(),
+        );
+        compiler.parse_program_from_string(&program, FileName::Custom("repl".into()))?;
+        let (symbol_table, struct_graph, call_graph) = compiler.compiler_stages()?;
+        let bytecode = compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph)?;
+
+        let keys = Compiler::setup::<A, R>(&bytecode, &[], "main", rng)?;
+        Ok(keys.proving_key.circuit.index_info.num_constraints)
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Reusing the synthesized circuits of the functions that did not change since the last build.
+//!
+//! The circuit of a function is synthesized from its instructions, along with the closures it may call and the
+//! structs and records it may use. Each function is hashed together with those, but without the other functions and
+//! the `finalize` blocks, so that editing one function only changes the hash of that function, while editing a
+//! closure, a struct, or a record changes the hash of every function. The numbers of constraints that were synthesized
+//! for a hash are cached, and reused while the hash does not change.
+//!
+//! The instructions are generated from the typed AST of the function, so that they change whenever its typed AST does.
+//! The program is still parsed and type checked as a whole, since a function may be affected by the items it uses.
+
+use crate::{parallel::par_map, Compiler};
+
+use leo_errors::{CompilerError, Result};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use snarkvm::{
+    circuit::Aleo,
+    prelude::{CryptoRng, Rng},
+};
+use std::path::Path;
+
+/// The numbers of constraints of synthesized circuits, by the hashes of the functions they were synthesized from.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SynthesisCache {
+    /// The number of constraints of the circuit of each function.
+    pub num_constraints: IndexMap<String, usize>,
+    /// The numbers of constraints of the circuit of each function, truncated after each of its instructions.
+    pub prefix_counts: IndexMap<String, Vec<usize>>,
+}
+
+impl SynthesisCache {
+    /// Removes the circuits of the functions whose hashes are not in `hashes`, e.g. those that changed since.
+    pub fn retain(&mut self, hashes: &IndexMap<String, String>) {
+        let current = |hash: &String| hashes.values().any(|current| current == hash);
+        self.num_constraints.retain(|hash, _| current(hash));
+        self.prefix_counts.retain(|hash, _| current(hash));
+    }
+
+    /// Writes the cache to the JSON file at `path`.
+    pub fn to_json_file(&self, path: &Path) -> Result<()> {
+        let file = std::fs::File::create(path)
+            .map_err(|e| CompilerError::failed_to_write_synthesis_cache(path.display(), e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer(writer, self)
+            .map_err(|e| CompilerError::failed_to_write_synthesis_cache(path.display(), e))?)
+    }
+
+    /// Reads a cache from the JSON file at `path`.
+    pub fn from_json_file(path: &Path) -> Result<Self> {
+        let file =
+            std::fs::File::open(path).map_err(|e| CompilerError::failed_to_read_synthesis_cache(path.display(), e))?;
+        let reader = std::io::BufReader::new(file);
+        Ok(serde_json::from_reader(reader)
+            .map_err(|e| CompilerError::failed_to_read_synthesis_cache(path.display(), e))?)
+    }
+}
+
+impl Compiler<'_> {
+    /// Returns the number of constraints of the circuit of each of the functions `function_names` of the compiled
+    /// `bytecode`. Only the circuits of the functions that are not in `cache` are synthesized, in parallel.
+    pub fn num_constraints_all<A: Aleo, R: Rng + CryptoRng>(
+        bytecode: &str,
+        function_names: &[String],
+        cache: &mut SynthesisCache,
+        rng: &mut R,
+    ) -> Result<Vec<usize>> {
+        let hashes = circuit_hashes(bytecode);
+        let hash = |function_name: &String| hashes.get(function_name).cloned().unwrap_or_default();
+
+        let missing = function_names
+            .iter()
+            .filter(|function_name| !cache.num_constraints.contains_key(&hash(function_name)))
+            .cloned()
+            .collect::<Vec<_>>();
+        let synthesized = par_map(&missing, rng, |function_name, rng| {
+            Self::setup::<A, _>(bytecode, &[], function_name, rng)
+                .map(|keys| keys.proving_key.circuit.index_info.num_constraints)
+        });
+        for (function_name, num_constraints) in missing.iter().zip(synthesized) {
+            cache.num_constraints.insert(hash(function_name), num_constraints?);
+        }
+
+        Ok(function_names.iter().map(|function_name| cache.num_constraints[&hash(function_name)]).collect())
+    }
+}
+
+/// Returns the hash of everything that the circuit of each function of the compiled `bytecode` is synthesized from,
+/// by the name of the function.
+pub fn circuit_hashes(bytecode: &str) -> IndexMap<String, String> {
+    // Split the program into its items, each of which starts with a line that is not indented.
+    let mut items: Vec<Vec<&str>> = Vec::new();
+    for line in bytecode.lines() {
+        match items.last_mut() {
+            Some(item) if line.is_empty() || line.starts_with(char::is_whitespace) => item.push(line),
+            _ => items.push(vec![line]),
+        }
+    }
+
+    // The items that every function may depend on, i.e. all but the functions and the finalize blocks.
+    let is_function = |item: &Vec<&str>| item[0].starts_with("function ") || item[0].starts_with("finalize ");
+    let shared = items.iter().filter(|item| !is_function(item)).flatten().copied().collect::<Vec<_>>().join("\n");
+
+    items
+        .iter()
+        .filter(|item| item[0].starts_with("function "))
+        .map(|item| {
+            let name = item[0].trim_start_matches("function ").trim_end_matches(':').to_string();
+            let mut hasher = Sha256::new();
+            hasher.update(shared.as_bytes());
+            hasher.update(item.join("\n").as_bytes());
+            (name, format!("{:x}", hasher.finalize()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use snarkvm::{circuit::AleoV0, prelude::TestRng};
+
+    #[test]
+    fn test_circuit_hashes() {
+        let program = |main: &str, closure: &str| {
+            format!(
+                "program hello.aleo;\n\nclosure double:\n    input r0 as u32;\n    {closure}\n    output r1 as u32;\n\n\
+                 function main:\n    input r0 as u32.private;\n    {main}\n\nfunction other:\n    input r0 as u32.private;\n"
+            )
+        };
+        let hashes = circuit_hashes(&program("add r0 r0 into r1;", "add r0 r0 into r1;"));
+        assert_eq!(hashes.keys().collect::<Vec<_>>(), ["main", "other"]);
+
+        // Editing a function only changes its own hash.
+        let edited = circuit_hashes(&program("mul r0 r0 into r1;", "add r0 r0 into r1;"));
+        assert_ne!(hashes["main"], edited["main"]);
+        assert_eq!(hashes["other"], edited["other"]);
+
+        // Editing a closure changes the hash of every function.
+        let edited = circuit_hashes(&program("add r0 r0 into r1;", "mul r0 2u32 into r1;"));
+        assert_ne!(hashes["main"], edited["main"]);
+        assert_ne!(hashes["other"], edited["other"]);
+    }
+
+    #[test]
+    fn test_num_constraints_all() {
+        let bytecode = "program hello.aleo;
+
+function square:
+    input r0 as u32.private;
+    mul r0 r0 into r1;
+    output r1 as u32.private;
+
+function double:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+
+function cube:
+    input r0 as u32.private;
+    mul r0 r0 into r1;
+    mul r1 r0 into r2;
+    output r2 as u32.private;
+";
+        let functions = ["square".to_string(), "double".to_string(), "cube".to_string()];
+        let rng = &mut TestRng::default();
+
+        // The circuits synthesized in parallel have the numbers of constraints of those synthesized one at a time, in
+        // the order of the functions.
+        let mut cache = SynthesisCache::default();
+        let counts = Compiler::num_constraints_all::<AleoV0, _>(bytecode, &functions, &mut cache, rng).unwrap();
+        for (function, count) in functions.iter().zip(&counts) {
+            let single = Compiler::num_constraints_all::<AleoV0, _>(
+                bytecode,
+                &[function.clone()],
+                &mut SynthesisCache::default(),
+                rng,
+            )
+            .unwrap();
+            assert_eq!(single, [*count]);
+        }
+        assert!(counts[0] < counts[2], "{counts:?}");
+
+        // The counts of the circuits in the cache are not synthesized again.
+        assert_eq!(cache.num_constraints.len(), 3);
+        let hashes = circuit_hashes(bytecode);
+        cache.num_constraints.insert(hashes["double"].clone(), 1);
+        let cached = Compiler::num_constraints_all::<AleoV0, _>(bytecode, &functions, &mut cache, rng).unwrap();
+        assert_eq!(cached, [counts[0], 1, counts[2]]);
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The intermediate representation that the compiler exports for external optimizers and analyzers.
+//!
+//! The intermediate representation is the program in its flat SSA form, i.e. the AST after the static single
+//! assignment and flattening passes, and before destructuring, inlining, and code generation. In this form:
+//!
+//! - every variable is assigned exactly once,
+//! - every operand of an expression is a variable or a literal, and
+//! - every function consists of a sequence of assignments, assertions, and calls, followed by a single return, with
+//!   the conditional statements replaced by ternary expressions.
+//!
+//! The intermediate representation is written as a JSON object, with the fields of [`IntermediateRepresentation`]:
+//! the `version` of the format, the `program_name`, the `program` in the JSON form of [`Program`], and the `types` of
+//! its nodes, by the IDs of the nodes. The table of types may also contain the IDs of nodes that earlier passes
+//! removed. The version is increased whenever the JSON form of the AST changes, so that a tool can refuse the files
+//! that it does not understand.
+
+use leo_ast::{Ast, NodeID, Program, Type};
+use leo_errors::{CompilerError, Result};
+use leo_passes::TypeTable;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The version of the intermediate representation that this compiler writes and reads.
+pub const IR_VERSION: u32 = 1;
+
+/// A program in its flat SSA form, along with the types of its nodes.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IntermediateRepresentation {
+    /// The version of the format, i.e. [`IR_VERSION`].
+    pub version: u32,
+    /// The name of the program, without the network.
+    pub program_name: String,
+    /// The program.
+    pub program: Program,
+    /// The types of the nodes of the program, by their IDs.
+    pub types: IndexMap<NodeID, Type>,
+}
+
+impl IntermediateRepresentation {
+    /// Returns the intermediate representation of `ast`, whose node types are in `type_table`.
+    pub fn new(program_name: &str, ast: &Ast, type_table: &TypeTable) -> Self {
+        Self {
+            version: IR_VERSION,
+            program_name: program_name.to_string(),
+            program: ast.as_repr().clone(),
+            types: type_table.entries(),
+        }
+    }
+
+    /// Writes the intermediate representation to the JSON file at `path`.
+    pub fn to_json_file(&self, path: &Path) -> Result<()> {
+        let file = std::fs::File::create(path).map_err(|e| CompilerError::failed_to_write_ir(path.display(), e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer_pretty(writer, self)
+            .map_err(|e| CompilerError::failed_to_write_ir(path.display(), e))?)
+    }
+
+    /// Reads an intermediate representation from the JSON file at `path`, which must have the version [`IR_VERSION`].
+    pub fn from_json_file(path: &Path) -> Result<Self> {
+        let data = std::fs::read_to_string(path).map_err(|e| CompilerError::failed_to_read_ir(path.display(), e))?;
+        // The version is checked first, since a file of another version may not parse.
+        #[derive(Deserialize)]
+        struct Version {
+            version: u32,
+        }
+        let Version { version } =
+            serde_json::from_str(&data).map_err(|e| CompilerError::failed_to_read_ir(path.display(), e))?;
+        if version != IR_VERSION {
+            return Err(CompilerError::unsupported_ir_version(path.display(), version, IR_VERSION).into());
+        }
+        Ok(serde_json::from_str(&data).map_err(|e| CompilerError::failed_to_read_ir(path.display(), e))?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_ir_round_trip() {
+        create_session_if_not_set_then(|_| {
+            let directory = tempfile::tempdir().unwrap();
+            let path = directory.path().join("main.ir.json");
+
+            let type_table = TypeTable::default();
+            type_table.insert(1, Type::Boolean);
+            let ir = IntermediateRepresentation::new("main", &Ast::new(Program::default()), &type_table);
+            ir.to_json_file(&path).unwrap();
+            assert_eq!(IntermediateRepresentation::from_json_file(&path).unwrap(), ir);
+
+            std::fs::write(&path, r#"{ "version": 0 }"#).unwrap();
+            assert!(IntermediateRepresentation::from_json_file(&path).is_err());
+        });
+    }
+}
@@ -19,8 +19,49 @@
 #![allow(clippy::upper_case_acronyms)]
 #![doc = include_str!("../README.md")]
 
+pub mod abi;
+pub use abi::*;
+
 mod compiler;
 pub use compiler::*;
 
+pub mod constraint_map;
+pub use constraint_map::*;
+
+pub mod encoding;
+pub use encoding::*;
+
+pub mod evaluation;
+pub use evaluation::*;
+
+pub mod incremental;
+pub use incremental::*;
+
+pub mod ir;
+pub use ir::*;
+
+pub mod library;
+pub use library::*;
+
 mod options;
 pub use options::*;
+
+mod parallel;
+
+pub mod profiling;
+pub use profiling::*;
+
+pub mod proving;
+pub use proving::*;
+
+pub mod r1cs;
+pub use r1cs::*;
+
+pub mod stats;
+pub use stats::*;
+
+pub mod testing;
+pub use testing::*;
+
+pub mod verifier;
+pub use verifier::*;
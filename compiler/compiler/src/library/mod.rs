@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Leo standard library.
+//!
+//! The modules of the standard library are shipped with the compiler, and their items are imported like those of
+//! any other module, e.g. `import std.bits.is_set_u32;`.
+
+/// The first segment of the path of every module in the standard library.
+pub const STD_LIBRARY_ROOT: &str = "std";
+
+/// Returns the source of the standard library module with the given name, e.g. `std.merkle`.
+pub fn std_module_source(module_name: &str) -> Option<&'static str> {
+    match module_name {
+        "std.bits" => Some(include_str!("bits.leo")),
+        "std.compare" => Some(include_str!("compare.leo")),
+        "std.hash" => Some(include_str!("hash.leo")),
+        "std.merkle" => Some(include_str!("merkle.leo")),
+        _ => None,
+    }
+}
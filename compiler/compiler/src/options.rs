@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use leo_passes::{OverflowBehavior, SsaOptions};
+
 // NOTE: If compiler passes are made optional, pass preconditions and invariants may not necessarily hold true.
 
 #[derive(Clone, Default)]
@@ -24,10 +26,66 @@ pub struct CompilerOptions {
     pub output: OutputOptions,
 }
 
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct BuildOptions {
     /// Whether to enable dead code elimination.
     pub dce_enabled: bool,
+    /// Whether to canonicalize equivalent expression forms.
+    pub canonicalization_enabled: bool,
+    /// Whether to enable common subexpression elimination.
+    pub cse_enabled: bool,
+    /// Whether to prune the branches of conditional statements that a constant condition rules out.
+    pub branch_pruning_enabled: bool,
+    /// Whether to skip the assertions that follow an early return when the return is taken, which is the default.
+    pub early_return_guards_enabled: bool,
+    /// Whether to lower chains of ternary expressions that look up a `field` constant into weighted sums.
+    pub mux_lowering_enabled: bool,
+    /// Whether to unroll powers with a constant exponent into multiplications.
+    pub pow_lowering_enabled: bool,
+    /// Whether to lower unsigned divisions and remainders by a constant power of two into shifts and masks.
+    pub div_lowering_enabled: bool,
+    /// How integer operations behave when they overflow.
+    pub overflow_behavior: OverflowBehavior,
+    /// The maximum number of elements of an array.
+    /// Defaults to, and is capped at, the maximum number that the network supports.
+    pub max_array_elements: Option<usize>,
+    /// Whether to compile the transitions annotated with `@test`, which are otherwise removed after type checking.
+    pub tests_enabled: bool,
+    /// The features that `@cfg(feature = "...")` annotations are resolved against.
+    /// Items and statements annotated with other features are removed when the program is parsed.
+    pub features: Vec<String>,
+}
+
+impl Default for BuildOptions {
+    fn default() -> Self {
+        Self {
+            dce_enabled: false,
+            canonicalization_enabled: false,
+            cse_enabled: false,
+            branch_pruning_enabled: false,
+            early_return_guards_enabled: true,
+            mux_lowering_enabled: false,
+            pow_lowering_enabled: false,
+            div_lowering_enabled: false,
+            overflow_behavior: OverflowBehavior::default(),
+            tests_enabled: false,
+            features: Vec::new(),
+            max_array_elements: None,
+        }
+    }
+}
+
+impl BuildOptions {
+    /// Returns the options of the static single assignment pass.
+    pub fn ssa_options(&self) -> SsaOptions {
+        SsaOptions {
+            prune_dead_branches: self.branch_pruning_enabled,
+            lower_multiplexers: self.mux_lowering_enabled,
+            lower_powers: self.pow_lowering_enabled,
+            lower_divisions: self.div_lowering_enabled,
+            overflow_behavior: self.overflow_behavior,
+        }
+    }
 }
 
 #[derive(Clone, Default)]
@@ -56,4 +114,10 @@ pub struct OutputOptions {
     pub inlined_ast: bool,
     /// If enabled writes the AST after dead code elimination.
     pub dce_ast: bool,
+    /// If enabled writes the expression trees of the unrolled AST as a Graphviz DOT graph.
+    pub unrolled_dot_graph: bool,
+    /// If enabled writes the intermediate representation, i.e. the flat SSA form, after flattening.
+    pub ir: bool,
+    /// If enabled records the time, node counts, and peak memory of each phase of the build.
+    pub stats: bool,
 }
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Synthesizing independent circuits on a pool of threads.
+//!
+//! The circuits of different functions, or of the same function truncated after different instructions, share no
+//! state, and snarkVM synthesizes each circuit in an environment that is local to its thread. They are synthesized on
+//! scoped threads, and their results are collected in the order of the jobs, so that they do not depend on how the
+//! jobs were scheduled. Each job samples from its own generator, which is seeded from the generator of the caller, so
+//! that the results are reproducible from the seed of the caller.
+//!
+//! The instructions of a function, including those unrolled from a loop, are synthesized into a single circuit by
+//! snarkVM, so they are not split across threads.
+
+use rand_chacha::{rand_core::SeedableRng, ChaChaRng};
+use snarkvm::prelude::{CryptoRng, Rng};
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+/// Runs `job` on each of `inputs` on a pool of threads, with a generator for each input seeded from `rng`, and returns
+/// the results in the order of the inputs. There is a thread for each available core, up to the number of inputs.
+pub(crate) fn par_map<T, U, R>(inputs: &[T], rng: &mut R, job: impl Fn(&T, &mut ChaChaRng) -> U + Sync) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    R: Rng + CryptoRng,
+{
+    let seeds = inputs.iter().map(|_| rng.gen()).collect::<Vec<u64>>();
+    let num_threads = thread::available_parallelism().map_or(1, NonZeroUsize::get).min(inputs.len()).max(1);
+    let next = AtomicUsize::new(0);
+
+    let mut results = inputs.iter().map(|_| None).collect::<Vec<_>>();
+    thread::scope(|scope| {
+        let workers = (0..num_threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        if index >= inputs.len() {
+                            return done;
+                        }
+                        done.push((index, job(&inputs[index], &mut ChaChaRng::seed_from_u64(seeds[index]))));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for worker in workers {
+            let done = worker.join().unwrap_or_else(|payload| std::panic::resume_unwind(payload));
+            for (index, result) in done {
+                results[index] = Some(result);
+            }
+        }
+    });
+
+    // Note that this unwrap is safe, since the workers run every job before they return.
+    results.into_iter().map(|result| result.unwrap()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_par_map_keeps_order() {
+        let inputs = (0..100).collect::<Vec<u64>>();
+        let rng = &mut ChaChaRng::seed_from_u64(0);
+        let squares = par_map(&inputs, rng, |input, _| input * input);
+        assert_eq!(squares, inputs.iter().map(|input| input * input).collect::<Vec<_>>());
+
+        // The generators of the jobs only depend on the seed of the caller.
+        let sample = |seed| par_map(&inputs, &mut ChaChaRng::seed_from_u64(seed), |_, rng| rng.gen::<u64>());
+        assert_eq!(sample(1), sample(1));
+        assert_ne!(sample(1), sample(2));
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Profiling the constraints of the circuit of a function, by the Leo expressions they originate from.
+//!
+//! A profile is derived from the [`ConstraintMap`] of the function, and is written in the folded stack format read by
+//! flamegraph tools, e.g. `inferno-flamegraph`:
+//!
+//! ```text
+//! hello.aleo/main;inputs 130
+//! hello.aleo/main;main.leo:5:23 a * b;mul 1057
+//! hello.aleo/main;main.leo:6:16 c + a;add 33
+//! ```
+
+use crate::{Compiler, ConstraintMap, SynthesisCache};
+
+use leo_errors::Result;
+use leo_span::Span;
+
+use indexmap::IndexMap;
+use snarkvm::{
+    circuit::Aleo,
+    prelude::{CryptoRng, Rng},
+};
+use std::fmt::Write as _;
+
+/// The label of the instructions that do not originate from an expression in a source file.
+const GENERATED_LABEL: &str = "<generated>";
+
+/// The constraints of an instruction of a function body.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstructionProfile {
+    /// The location and the source of the expression that the instruction was generated from.
+    pub statement: String,
+    /// The opcode of the instruction, along with the callee of a `call`.
+    pub opcode: String,
+    /// The number of constraints that the instruction adds to the circuit.
+    pub num_constraints: usize,
+}
+
+/// The constraints of the circuit of a function, attributed to the expressions they originate from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Profile {
+    /// The ID of the program, e.g. `hello.aleo`.
+    pub program: String,
+    /// The name of the function.
+    pub function: String,
+    /// The number of constraints of the inputs of the function, before any instruction.
+    pub input_constraints: usize,
+    /// The constraints of each instruction of the function body, in order.
+    pub instructions: Vec<InstructionProfile>,
+}
+
+impl Profile {
+    /// Returns the number of constraints of the circuit of the function.
+    pub fn num_constraints(&self) -> usize {
+        self.input_constraints + self.instructions.iter().map(|instruction| instruction.num_constraints).sum::<usize>()
+    }
+
+    /// Returns the profile in the folded stack format, with a line for each expression and opcode.
+    /// The instructions of an expression that was unrolled from a loop are summed, and those without constraints are
+    /// left out.
+    pub fn to_folded(&self) -> String {
+        let root = format!("{}/{}", self.program, self.function);
+
+        let mut stacks = IndexMap::new();
+        stacks.insert(format!("{root};inputs"), self.input_constraints);
+        for instruction in &self.instructions {
+            *stacks.entry(format!("{root};{};{}", instruction.statement, instruction.opcode)).or_default() +=
+                instruction.num_constraints;
+        }
+
+        stacks.into_iter().filter(|(_, count)| *count > 0).fold(String::new(), |mut folded, (stack, count)| {
+            writeln!(folded, "{stack} {count}").expect("failed to write to string");
+            folded
+        })
+    }
+}
+
+impl From<&ConstraintMap> for Profile {
+    fn from(map: &ConstraintMap) -> Self {
+        let instructions = map
+            .ranges
+            .iter()
+            .map(|range| InstructionProfile {
+                // Frames are separated by semicolons, so the semicolons of the source are left out.
+                statement: range.location.as_ref().map_or_else(
+                    || GENERATED_LABEL.to_string(),
+                    |location| {
+                        format!(
+                            "{}:{}:{} {}",
+                            location.file,
+                            location.line_start,
+                            location.col_start,
+                            location.source.replace(';', "")
+                        )
+                    },
+                ),
+                opcode: opcode(&range.instruction),
+                num_constraints: range.end - range.start,
+            })
+            .collect();
+
+        Self {
+            program: map.program.clone(),
+            function: map.function.clone(),
+            input_constraints: map.input_constraints,
+            instructions,
+        }
+    }
+}
+
+impl Compiler<'_> {
+    /// Profiles the constraints of the function `function_name` of the compiled `bytecode`.
+    /// `spans` holds the span of the expression that each instruction of the function body was generated from, as
+    /// recorded in [`Compiler::instruction_spans`]. The circuits are only synthesized if they are not in `cache`.
+    pub fn profile<A: Aleo, R: Rng + CryptoRng>(
+        bytecode: &str,
+        function_name: &str,
+        spans: &[Span],
+        cache: &mut SynthesisCache,
+        rng: &mut R,
+    ) -> Result<Profile> {
+        Ok(Profile::from(&Self::constraint_map::<A, R>(bytecode, function_name, spans, cache, rng)?))
+    }
+}
+
+/// Returns the opcode of the `instruction`, along with the callee if it is a `call`.
+fn opcode(instruction: &str) -> String {
+    let mut tokens = instruction.trim_end_matches(';').split_whitespace();
+    match (tokens.next(), tokens.next()) {
+        (Some("call"), Some(callee)) => format!("call {callee}"),
+        (opcode, _) => opcode.unwrap_or_default().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ConstraintRange, SourceLocation};
+
+    #[test]
+    fn test_to_folded() {
+        let location = |line_start, source: &str| SourceLocation {
+            file: "main.leo".to_string(),
+            line_start,
+            col_start: 16,
+            line_stop: line_start,
+            col_stop: 16 + source.len(),
+            source: source.to_string(),
+        };
+        let range = |start, end, instruction: &str, location| ConstraintRange {
+            start,
+            end,
+            instruction: instruction.to_string(),
+            location,
+        };
+        let map = ConstraintMap {
+            program: "hello.aleo".to_string(),
+            function: "main".to_string(),
+            input_constraints: 2,
+            ranges: vec![
+                range(2, 7, "mul r0 r1 into r2;", Some(location(5, "a * b;"))),
+                // The instructions of an expression unrolled from a loop are summed.
+                range(7, 10, "add r2 r0 into r3;", Some(location(6, "c + a"))),
+                range(10, 13, "add r3 r0 into r4;", Some(location(6, "c + a"))),
+                // An instruction without constraints is left out.
+                range(13, 13, "cast r4 into r5 as u32;", None),
+                range(13, 14, "call foo.aleo/bar r5 into r6;", None),
+            ],
+        };
+
+        let profile = Profile::from(&map);
+        assert_eq!(profile.num_constraints(), 14);
+        assert_eq!(profile.instructions[0].statement, "main.leo:5:16 a * b");
+        assert_eq!(
+            profile.to_folded(),
+            "hello.aleo/main;inputs 2\n\
+             hello.aleo/main;main.leo:5:16 a * b;mul 5\n\
+             hello.aleo/main;main.leo:6:16 c + a;add 6\n\
+             hello.aleo/main;<generated>;call foo.aleo/bar 1\n"
+        );
+    }
+
+    #[test]
+    fn test_opcode() {
+        assert_eq!(opcode("call foo r0 into r1;"), "call foo");
+        assert_eq!(opcode("assert.eq r0 r0;"), "assert.eq");
+    }
+}
@@ -0,0 +1,410 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Proving and verifying executions of compiled programs, for services that embed the Leo pipeline.
+//!
+//! The keys, the witnesses, and the executions are serializable, so that they can be stored, or sent between the
+//! processes that generate witnesses, prove them, and verify the proofs.
+
+use crate::{parallel::par_map, Compiler};
+
+use leo_errors::{CompilerError, Result};
+
+use serde::{Deserialize, Serialize};
+use snarkvm::{
+    circuit::Aleo,
+    ledger::{
+        block::{Execution, Input, Transition},
+        store::helpers::memory::BlockMemory,
+    },
+    prelude::{
+        Authorization,
+        CryptoRng,
+        Error as SnarkVMError,
+        Identifier,
+        Locator,
+        Network,
+        Plaintext,
+        PrivateKey,
+        Process,
+        Program,
+        ProgramID,
+        ProvingKey,
+        Query,
+        Rng,
+        Value,
+        VerifyingKey,
+    },
+};
+use std::{path::Path, str::FromStr};
+
+/// The keys to prove and to verify the executions of a function.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct FunctionKeys<N: Network> {
+    /// The key to prove executions of the function.
+    pub proving_key: ProvingKey<N>,
+    /// The key to verify executions of the function.
+    pub verifying_key: VerifyingKey<N>,
+    /// The keys of the functions of imported programs that the function calls, by their locators, e.g.
+    /// `adder.aleo/add`, since an execution holds a transition for each of them.
+    #[serde(default)]
+    pub calls: Vec<(String, ProvingKey<N>, VerifyingKey<N>)>,
+}
+
+impl<N: Network> FunctionKeys<N> {
+    /// Returns the keys to verify the executions of the function, without the keys to prove them.
+    pub fn verifying_keys(&self) -> VerifyingKeys<N> {
+        VerifyingKeys {
+            verifying_key: self.verifying_key.clone(),
+            calls: self
+                .calls
+                .iter()
+                .map(|(locator, _, verifying_key)| (locator.clone(), verifying_key.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// The keys to verify the executions of a function.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct VerifyingKeys<N: Network> {
+    /// The key to verify executions of the function.
+    pub verifying_key: VerifyingKey<N>,
+    /// The keys to verify the transitions of the functions of imported programs that the function calls, by their
+    /// locators, e.g. `adder.aleo/add`.
+    pub calls: Vec<(String, VerifyingKey<N>)>,
+}
+
+/// The witness of an execution of a function.
+///
+/// It holds the signed requests to execute the function and the functions it calls, from which snarkVM synthesizes
+/// the assignment of each circuit while proving. Witnesses are generated and proven separately so that they can be
+/// written to a file and proven in another process, e.g. on a dedicated prover.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Witness<N: Network> {
+    /// The name of the function.
+    pub function: String,
+    /// The signed requests of the execution.
+    pub authorization: Authorization<N>,
+}
+
+impl<N: Network> Witness<N> {
+    /// Writes the witness to the JSON file at `path`.
+    pub fn to_json_file(&self, path: &Path) -> Result<()> {
+        let file =
+            std::fs::File::create(path).map_err(|e| CompilerError::failed_to_write_witness(path.display(), e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer(writer, self)
+            .map_err(|e| CompilerError::failed_to_write_witness(path.display(), e))?)
+    }
+
+    /// Reads a witness from the JSON file at `path`.
+    pub fn from_json_file(path: &Path) -> Result<Self> {
+        let file = std::fs::File::open(path).map_err(|e| CompilerError::failed_to_read_witness(path.display(), e))?;
+        let reader = std::io::BufReader::new(file);
+        Ok(serde_json::from_reader(reader).map_err(|e| CompilerError::failed_to_read_witness(path.display(), e))?)
+    }
+}
+
+impl Compiler<'_> {
+    /// Synthesizes the keys of the function `function_name` of the compiled `bytecode`, which imports the compiled
+    /// programs `imports`.
+    pub fn setup<A: Aleo, R: Rng + CryptoRng>(
+        bytecode: &str,
+        imports: &[String],
+        function_name: &str,
+        rng: &mut R,
+    ) -> Result<FunctionKeys<A::Network>> {
+        let (process, program_id) = load_process::<A::Network>(bytecode, imports)?;
+        let function = parse_function_name::<A::Network>(function_name)?;
+
+        let mut setup = || -> Result<_, SnarkVMError> {
+            // The keys of the functions that the function calls are synthesized along with its own.
+            process.synthesize_key::<A, R>(&program_id, &function, rng)?;
+            Ok(FunctionKeys {
+                proving_key: process.get_proving_key(program_id, function)?,
+                verifying_key: process.get_verifying_key(program_id, function)?,
+                calls: call_keys(&process, imports)?,
+            })
+        };
+        setup().map_err(|err| CompilerError::failed_to_setup(function_name, err).into())
+    }
+
+    /// Synthesizes the keys of each of the functions `function_names` of the compiled `bytecode`, which imports the
+    /// compiled programs `imports`, in parallel. The keys of each function are synthesized from a generator that is
+    /// seeded from `rng`, in the order of the functions, so they are the same as long as `rng` is seeded the same.
+    pub fn setup_all<A: Aleo, R: Rng + CryptoRng>(
+        bytecode: &str,
+        imports: &[String],
+        function_names: &[String],
+        rng: &mut R,
+    ) -> Result<Vec<FunctionKeys<A::Network>>> {
+        par_map(function_names, rng, |function_name, rng| Self::setup::<A, _>(bytecode, imports, function_name, rng))
+            .into_iter()
+            .collect()
+    }
+
+    /// Executes the function `function_name` of the compiled `bytecode`, which imports the compiled programs
+    /// `imports`, on `inputs`, and proves the execution with `keys`. The state of the network, e.g. the records that
+    /// are spent, is queried from `query`, which is either the URL of a node's REST API or a block store.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove<A: Aleo, R: Rng + CryptoRng>(
+        bytecode: &str,
+        imports: &[String],
+        keys: &FunctionKeys<A::Network>,
+        private_key: &PrivateKey<A::Network>,
+        function_name: &str,
+        inputs: &[String],
+        query: impl Into<Query<A::Network, BlockMemory<A::Network>>>,
+        rng: &mut R,
+    ) -> Result<Execution<A::Network>> {
+        let witness = Self::witness::<A, R>(bytecode, imports, private_key, function_name, inputs, rng)?;
+        Self::prove_witness::<A, R>(bytecode, imports, keys, &witness, query, rng)
+    }
+
+    /// Executes the function `function_name` of the compiled `bytecode`, which imports the compiled programs
+    /// `imports`, on each of the `input_sets`, and proves each execution with the same `keys`, so that they are
+    /// synthesized once for all of the sets. The results are in the order of the sets, and a set that fails does not
+    /// stop the others from being proven.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_all<A: Aleo, R: Rng + CryptoRng>(
+        bytecode: &str,
+        imports: &[String],
+        keys: &FunctionKeys<A::Network>,
+        private_key: &PrivateKey<A::Network>,
+        function_name: &str,
+        input_sets: &[Vec<String>],
+        query: impl Into<Query<A::Network, BlockMemory<A::Network>>>,
+        rng: &mut R,
+    ) -> Vec<Result<Execution<A::Network>>> {
+        let query = query.into();
+        input_sets
+            .iter()
+            .map(|inputs| {
+                Self::prove::<A, R>(bytecode, imports, keys, private_key, function_name, inputs, query.clone(), rng)
+            })
+            .collect()
+    }
+
+    /// Generates the witness of an execution of the function `function_name` of the compiled `bytecode`, which
+    /// imports the compiled programs `imports`, on `inputs`. It may be proven by another process with
+    /// [`Compiler::prove_witness`].
+    pub fn witness<A: Aleo, R: Rng + CryptoRng>(
+        bytecode: &str,
+        imports: &[String],
+        private_key: &PrivateKey<A::Network>,
+        function_name: &str,
+        inputs: &[String],
+        rng: &mut R,
+    ) -> Result<Witness<A::Network>> {
+        let (process, program_id) = load_process::<A::Network>(bytecode, imports)?;
+        let function = parse_function_name::<A::Network>(function_name)?;
+
+        let mut authorize = || -> Result<_, SnarkVMError> {
+            let inputs = inputs.iter().map(|input| Value::from_str(input)).collect::<Result<Vec<_>, _>>()?;
+            process.authorize::<A, R>(private_key, program_id, function, inputs.iter(), rng)
+        };
+        let authorization = authorize().map_err(|err| CompilerError::failed_to_prove(function_name, err))?;
+        Ok(Witness { function: function_name.to_string(), authorization })
+    }
+
+    /// Synthesizes the assignment of the circuit of the execution that `witness` is the witness of, without proving
+    /// it, and returns the outputs of the function. The `keys` are loaded so that snarkVM does not synthesize them.
+    pub fn synthesize<A: Aleo, R: Rng + CryptoRng>(
+        bytecode: &str,
+        imports: &[String],
+        keys: &FunctionKeys<A::Network>,
+        witness: &Witness<A::Network>,
+        rng: &mut R,
+    ) -> Result<Vec<String>> {
+        let (process, program_id) = load_process::<A::Network>(bytecode, imports)?;
+        let function = parse_function_name::<A::Network>(&witness.function)?;
+
+        let mut synthesize = || -> Result<_, SnarkVMError> {
+            insert_keys(&process, &program_id, &function, keys)?;
+
+            let (response, _) = process.execute::<A, R>(witness.authorization.clone(), rng)?;
+            Ok(response.outputs().iter().map(|output| output.to_string()).collect())
+        };
+        synthesize().map_err(|err| CompilerError::failed_to_synthesize(&witness.function, err).into())
+    }
+
+    /// Proves the execution that `witness` is the witness of with `keys`, where `bytecode` is the compiled program
+    /// and `imports` are the compiled programs it imports. The state of the network, e.g. the records that are spent,
+    /// is queried from `query`, which is either the URL of a node's REST API or a block store.
+    pub fn prove_witness<A: Aleo, R: Rng + CryptoRng>(
+        bytecode: &str,
+        imports: &[String],
+        keys: &FunctionKeys<A::Network>,
+        witness: &Witness<A::Network>,
+        query: impl Into<Query<A::Network, BlockMemory<A::Network>>>,
+        rng: &mut R,
+    ) -> Result<Execution<A::Network>> {
+        let query = query.into();
+        let (process, program_id) = load_process::<A::Network>(bytecode, imports)?;
+        let function = parse_function_name::<A::Network>(&witness.function)?;
+
+        let mut prove = || -> Result<_, SnarkVMError> {
+            insert_keys(&process, &program_id, &function, keys)?;
+
+            let (_, mut trace) = process.execute::<A, R>(witness.authorization.clone(), rng)?;
+            trace.prepare(query.clone())?;
+            trace.prove_execution::<A, R>(&format!("{program_id}/{function}"), rng)
+        };
+        prove().map_err(|err| CompilerError::failed_to_prove(&witness.function, err).into())
+    }
+
+    /// Verifies the `execution` of the function `function_name` of the compiled `bytecode`, which imports the
+    /// compiled programs `imports`, with `keys`, and checks that the public inputs of the execution are
+    /// `public_inputs`. Returns the public inputs of the execution, which the proof was verified against.
+    pub fn verify<N: Network>(
+        bytecode: &str,
+        imports: &[String],
+        keys: &VerifyingKeys<N>,
+        function_name: &str,
+        execution: &Execution<N>,
+        public_inputs: &[String],
+    ) -> Result<Vec<String>> {
+        let (process, program_id) = load_process::<N>(bytecode, imports)?;
+        let function = parse_function_name::<N>(function_name)?;
+
+        let verify = || -> Result<_, SnarkVMError> {
+            process.insert_verifying_key(&program_id, &function, keys.verifying_key.clone())?;
+            for (locator, verifying_key) in &keys.calls {
+                let locator = Locator::<N>::from_str(locator)?;
+                process.insert_verifying_key(locator.program_id(), locator.resource(), verifying_key.clone())?;
+            }
+            process.verify_execution(execution)
+        };
+        verify().map_err(|err| CompilerError::failed_to_verify(function_name, err))?;
+
+        // The transition of the function is the last one, after the transitions of the functions it calls.
+        let found = execution
+            .transitions()
+            .filter(|transition| *transition.program_id() == program_id && *transition.function_name() == function)
+            .last()
+            .map(public_plaintexts)
+            .unwrap_or_default();
+        let expected = public_inputs.iter().map(|input| Plaintext::<N>::from_str(input)).collect::<Vec<_>>();
+
+        let matches = found.len() == expected.len()
+            && found
+                .iter()
+                .zip(expected.iter())
+                .all(|(found, expected)| expected.as_ref().map_or(false, |e| e == found));
+        if !matches {
+            return Err(CompilerError::public_inputs_mismatch(
+                function_name,
+                public_inputs.join(", "),
+                found.iter().map(|plaintext| plaintext.to_string()).collect::<Vec<_>>().join(", "),
+            )
+            .into());
+        }
+        Ok(found.iter().map(|plaintext| plaintext.to_string()).collect())
+    }
+
+    /// Returns the public inputs of the `execution`, which its proof is verified against. They are the public inputs
+    /// of the function that was executed, whose transition is the last one, after the transitions of the functions it
+    /// calls.
+    pub fn public_inputs<N: Network>(execution: &Execution<N>) -> Vec<String> {
+        execution
+            .transitions()
+            .last()
+            .map(|transition| public_plaintexts(transition).iter().map(|plaintext| plaintext.to_string()).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the values of the public inputs of `transition`.
+fn public_plaintexts<N: Network>(transition: &Transition<N>) -> Vec<Plaintext<N>> {
+    transition
+        .inputs()
+        .iter()
+        .filter_map(|input| match input {
+            Input::Public(_, Some(plaintext)) => Some(plaintext.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns a process that has loaded the compiled `bytecode`, after the compiled programs `imports` that it imports,
+/// along with the ID of the program. The imports may be in any order, as each is loaded after the programs it imports.
+pub(crate) fn load_process<N: Network>(bytecode: &str, imports: &[String]) -> Result<(Process<N>, ProgramID<N>)> {
+    let load = || -> Result<_, SnarkVMError> {
+        let mut process = Process::<N>::load()?;
+        let mut imports = imports.iter().map(|import| Program::<N>::from_str(import)).collect::<Result<Vec<_>, _>>()?;
+        // An import whose own imports are never loaded is left out, and snarkVM reports it when it is needed.
+        while let Some(index) = imports
+            .iter()
+            .position(|import| import.imports().keys().all(|program_id| process.contains_program(program_id)))
+        {
+            let import = imports.remove(index);
+            // `credits.aleo` is loaded along with the process.
+            if !process.contains_program(import.id()) {
+                process.add_program(&import)?;
+            }
+        }
+
+        let program = Program::<N>::from_str(bytecode)?;
+        process.add_program(&program)?;
+        Ok((process, *program.id()))
+    };
+    load().map_err(|err| CompilerError::failed_to_load_program(err).into())
+}
+
+/// Returns the keys that `process` has synthesized for the functions of the compiled programs `imports`, by their
+/// locators.
+fn call_keys<N: Network>(
+    process: &Process<N>,
+    imports: &[String],
+) -> Result<Vec<(String, ProvingKey<N>, VerifyingKey<N>)>, SnarkVMError> {
+    let mut keys = Vec::new();
+    for import in imports {
+        let program = Program::<N>::from_str(import)?;
+        let stack = process.get_stack(program.id())?;
+        for function in program.functions().keys().filter(|function| stack.contains_proving_key(function)) {
+            let locator = Locator::new(*program.id(), *function);
+            keys.push((locator.to_string(), stack.get_proving_key(function)?, stack.get_verifying_key(function)?));
+        }
+    }
+    Ok(keys)
+}
+
+/// Inserts the `keys` of the function `function` of the program `program_id`, and of the functions it calls, into
+/// `process`, so that snarkVM does not synthesize them.
+fn insert_keys<N: Network>(
+    process: &Process<N>,
+    program_id: &ProgramID<N>,
+    function: &Identifier<N>,
+    keys: &FunctionKeys<N>,
+) -> Result<(), SnarkVMError> {
+    process.insert_proving_key(program_id, function, keys.proving_key.clone())?;
+    process.insert_verifying_key(program_id, function, keys.verifying_key.clone())?;
+    for (locator, proving_key, verifying_key) in &keys.calls {
+        let locator = Locator::<N>::from_str(locator)?;
+        process.insert_proving_key(locator.program_id(), locator.resource(), proving_key.clone())?;
+        process.insert_verifying_key(locator.program_id(), locator.resource(), verifying_key.clone())?;
+    }
+    Ok(())
+}
+
+/// Parses the name of a function as a snarkVM identifier.
+pub(crate) fn parse_function_name<N: Network>(function_name: &str) -> Result<Identifier<N>> {
+    Identifier::<N>::from_str(function_name).map_err(|err| CompilerError::failed_to_load_program(err).into())
+}
@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The rank-1 constraint system of a function, exported for external provers and auditors.
+//!
+//! The constraint system is written as JSON, in the form
+//!
+//! ```text
+//! {
+//!   "program": "hello.aleo",
+//!   "function": "main",
+//!   "num_public_variables": 2,
+//!   "num_variables": 5,
+//!   "num_constraints": 3,
+//!   "variables": ["public", "public", "private", "private", "private"],
+//!   "a": [[["1field", 0], ["2field", 3]], ...],
+//!   "b": [...],
+//!   "c": [...]
+//! }
+//! ```
+//!
+//! `variables` maps the index of each variable to its visibility. The public variables come first, and the variable
+//! at index 0 is the constant `1`. Each of `a`, `b`, and `c` holds a row for each constraint, and each row holds the
+//! nonzero entries of the row as pairs of a coefficient and the index of a variable. The constraint `i` holds when
+//! `<a[i], z> * <b[i], z> = <c[i], z>`, where `z` is the vector of the values of the variables.
+//!
+//! The constraint system can also be written in the binary `.r1cs` format of circom, which other proving stacks such
+//! as snarkjs and ark-circom read. Its header records the modulus of the scalar field, so that a reader can check that
+//! it supports the field. The public variables other than the constant `1` are written as public inputs, and the
+//! private variables as internal wires.
+
+use crate::FunctionKeys;
+
+use leo_errors::{CompilerError, Result};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use snarkvm::prelude::{Error, Field, Network, PrimeField, ToBytes};
+use std::{path::Path, str::FromStr};
+
+/// The visibility of a variable of a constraint system.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    /// A public variable, whose value is part of the statement that is proven.
+    Public,
+    /// A private variable, whose value is part of the witness.
+    Private,
+}
+
+/// The rank-1 constraint system of a function, as matrices over its variables.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct R1CS {
+    /// The ID of the program, e.g. `hello.aleo`.
+    pub program: String,
+    /// The name of the function.
+    pub function: String,
+    /// The number of public variables, including the constant `1`.
+    pub num_public_variables: usize,
+    /// The number of public and private variables.
+    pub num_variables: usize,
+    /// The number of constraints.
+    pub num_constraints: usize,
+    /// The visibility of each variable, by index.
+    pub variables: Vec<Visibility>,
+    /// The rows of the `A` matrix, as the coefficients of the variables.
+    pub a: Vec<Vec<(String, usize)>>,
+    /// The rows of the `B` matrix, as the coefficients of the variables.
+    pub b: Vec<Vec<(String, usize)>>,
+    /// The rows of the `C` matrix, as the coefficients of the variables.
+    pub c: Vec<Vec<(String, usize)>>,
+}
+
+impl R1CS {
+    /// Returns the constraint system of the function `function` of `program`, as it was synthesized into `keys`.
+    pub fn new<N: Network>(program: &str, function: &str, keys: &FunctionKeys<N>) -> Self {
+        let circuit = &keys.proving_key.circuit;
+        let info = &circuit.index_info;
+
+        let num_public_variables = info.num_public_inputs;
+        let num_variables = info.num_public_and_private_variables;
+        let variables = (0..num_variables)
+            .map(|index| if index < num_public_variables { Visibility::Public } else { Visibility::Private })
+            .collect();
+
+        let rows = |matrix: &[Vec<(_, usize)>]| {
+            matrix
+                .iter()
+                .map(|row| row.iter().map(|(coefficient, index)| (format!("{coefficient}field"), *index)).collect())
+                .collect()
+        };
+
+        Self {
+            program: program.to_string(),
+            function: function.to_string(),
+            num_public_variables,
+            num_variables,
+            num_constraints: info.num_constraints,
+            variables,
+            a: rows(&circuit.a),
+            b: rows(&circuit.b),
+            c: rows(&circuit.c),
+        }
+    }
+
+    /// Returns a SHA256 checksum of the constraint system, which is identical for identical constraint systems.
+    pub fn checksum(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(serde_json::to_string(self).expect("a constraint system serializes to JSON").as_bytes());
+        let hash = hasher.finalize();
+
+        format!("{hash:x}")
+    }
+
+    /// Writes the constraint system to the JSON file at `path`.
+    pub fn to_json_file(&self, path: &Path) -> Result<()> {
+        let file = std::fs::File::create(path).map_err(|e| CompilerError::failed_to_write_r1cs(path.display(), e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer_pretty(writer, self)
+            .map_err(|e| CompilerError::failed_to_write_r1cs(path.display(), e))?)
+    }
+
+    /// Writes the constraint system to the file at `path`, in the binary `.r1cs` format of circom over the scalar
+    /// field of `N`.
+    pub fn to_circom_file<N: Network>(&self, path: &Path) -> Result<()> {
+        let error = |e: Error| CompilerError::failed_to_write_r1cs(path.display(), e);
+
+        let modulus = N::Field::modulus().to_bytes_le().map_err(error)?;
+        let mut header = Vec::new();
+        header.extend((modulus.len() as u32).to_le_bytes());
+        header.extend(&modulus);
+        header.extend((self.num_variables as u32).to_le_bytes());
+        // The public outputs, public inputs, and private inputs, where the constant `1` is not an input.
+        header.extend(0u32.to_le_bytes());
+        header.extend((self.num_public_variables.saturating_sub(1) as u32).to_le_bytes());
+        header.extend(0u32.to_le_bytes());
+        header.extend((self.num_variables as u64).to_le_bytes());
+        header.extend((self.num_constraints as u32).to_le_bytes());
+
+        let mut constraints = Vec::new();
+        for ((a, b), c) in self.a.iter().zip(&self.b).zip(&self.c) {
+            for row in [a, b, c] {
+                constraints.extend((row.len() as u32).to_le_bytes());
+                for (coefficient, index) in row {
+                    constraints.extend((*index as u32).to_le_bytes());
+                    constraints.extend(Field::<N>::from_str(coefficient).and_then(|f| f.to_bytes_le()).map_err(error)?);
+                }
+            }
+        }
+
+        // Each variable is labelled with its own index.
+        let labels = (0..self.num_variables as u64).flat_map(u64::to_le_bytes).collect::<Vec<_>>();
+
+        let mut bytes = b"r1cs".to_vec();
+        bytes.extend(1u32.to_le_bytes());
+        bytes.extend(3u32.to_le_bytes());
+        for (section, data) in [(1u32, header), (2, constraints), (3, labels)] {
+            bytes.extend(section.to_le_bytes());
+            bytes.extend((data.len() as u64).to_le_bytes());
+            bytes.extend(data);
+        }
+        Ok(std::fs::write(path, bytes).map_err(|e| CompilerError::failed_to_write_r1cs(path.display(), e))?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use snarkvm::prelude::Testnet3;
+
+    #[test]
+    fn test_circom_file() {
+        // The constraint `x * x = y`, where `y` is public.
+        let r1cs = R1CS {
+            program: "square.aleo".to_string(),
+            function: "main".to_string(),
+            num_public_variables: 2,
+            num_variables: 3,
+            num_constraints: 1,
+            variables: vec![Visibility::Public, Visibility::Public, Visibility::Private],
+            a: vec![vec![("1field".to_string(), 2)]],
+            b: vec![vec![("1field".to_string(), 2)]],
+            c: vec![vec![("1field".to_string(), 1)]],
+        };
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("main.r1cs");
+        r1cs.to_circom_file::<Testnet3>(&path).unwrap();
+
+        let bytes = std::fs::read(path).unwrap();
+        assert_eq!(&bytes[..12], b"r1cs\x01\0\0\0\x03\0\0\0");
+        // The magic, the version, and the number of sections, followed by three sections with their types and sizes,
+        // a header with a 32 byte modulus, one constraint with three entries, and three labels.
+        let header = 4 + 32 + 4 * 4 + 8 + 4;
+        let constraints = 3 * (4 + 4 + 32);
+        assert_eq!(bytes.len(), 12 + 3 * 12 + header + constraints + 3 * 8);
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Statistics on the time and memory that each phase of a build takes.
+//!
+//! After each phase, the number of expression nodes in the AST and the number of node IDs handed out so far are
+//! recorded, along with the peak memory of the process. The node IDs are never reused, so their number is the size of
+//! every AST that was built so far, including the nodes that later phases replaced. The peak memory is read from
+//! `/proc/self/status`, so it is only available on Linux, and it never decreases: a phase whose peak exceeds that of
+//! the previous phases raised it.
+
+use leo_ast::{Ast, NodeBuilder};
+
+use std::{fmt, time::Duration};
+
+/// The statistics of one phase of a build.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PhaseStats {
+    /// The name of the phase, e.g. `parse`.
+    pub phase: String,
+    /// The time the phase took.
+    pub duration: Duration,
+    /// The number of expression nodes in the AST after the phase.
+    pub expression_nodes: usize,
+    /// The number of node IDs handed out by the end of the phase.
+    pub node_ids: usize,
+    /// The peak memory of the process by the end of the phase, in bytes, if it is known.
+    pub peak_memory: Option<u64>,
+}
+
+impl PhaseStats {
+    /// Records the statistics of the phase `phase`, which took `duration` and produced `ast`.
+    pub fn new(phase: &str, duration: Duration, ast: &Ast, node_builder: &NodeBuilder) -> Self {
+        Self {
+            phase: phase.to_string(),
+            duration,
+            expression_nodes: ast.ast.expression_metrics().node_count,
+            node_ids: node_builder.num_ids(),
+            peak_memory: peak_memory(),
+        }
+    }
+}
+
+/// The statistics of the phases of a build, in the order they ran.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BuildStats {
+    pub phases: Vec<PhaseStats>,
+}
+
+impl fmt::Display for BuildStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:<16} {:>10} {:>12} {:>10} {:>12}",
+            "phase", "time (ms)", "expressions", "node IDs", "peak memory"
+        )?;
+        for stats in &self.phases {
+            let peak_memory = match stats.peak_memory {
+                Some(bytes) => format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0)),
+                None => "-".to_string(),
+            };
+            write!(
+                f,
+                "\n{:<16} {:>10.2} {:>12} {:>10} {:>12}",
+                stats.phase,
+                stats.duration.as_secs_f64() * 1000.0,
+                stats.expression_nodes,
+                stats.node_ids,
+                peak_memory
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the peak resident memory of the process, in bytes, if the platform reports it.
+pub fn peak_memory() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    // The line has the form `VmHWM:    1234 kB`.
+    let kilobytes = line.trim_start_matches("VmHWM:").trim().trim_end_matches("kB").trim().parse::<u64>().ok()?;
+    Some(kilobytes * 1024)
+}
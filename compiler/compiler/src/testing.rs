@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Running the transitions of a program that are annotated with `@test`.
+//!
+//! A test passes on a set of inputs if the assignment of its circuit, i.e. the witness, can be synthesized from them.
+//! Only the transition is run, and not its `finalize` block, since that needs the state of a network.
+
+use crate::{
+    proving::{load_process, parse_function_name},
+    Compiler,
+};
+
+use leo_errors::{CompilerError, Result};
+
+use snarkvm::{
+    circuit::Aleo,
+    prelude::{CryptoRng, Error as SnarkVMError, PrivateKey, Rng, Value},
+};
+use std::str::FromStr;
+
+/// The results of running a test on each of its sets of inputs.
+pub struct TestReport {
+    /// The number of constraints of the circuit of the test.
+    pub num_constraints: usize,
+    /// The outputs of the test on each set of inputs, or the error it failed with.
+    pub results: Vec<Result<Vec<String>>>,
+}
+
+impl Compiler<'_> {
+    /// Runs the test `function_name` of the compiled `bytecode`, which imports the compiled programs `imports`, on each
+    /// of `input_sets`. The requests to run it are signed by a fresh account, so tests should not depend on the caller.
+    pub fn run_test<A: Aleo, R: Rng + CryptoRng>(
+        bytecode: &str,
+        imports: &[String],
+        function_name: &str,
+        input_sets: &[Vec<String>],
+        rng: &mut R,
+    ) -> Result<TestReport> {
+        let (process, program_id) = load_process::<A::Network>(bytecode, imports)?;
+        let function = parse_function_name::<A::Network>(function_name)?;
+
+        let mut setup = || -> Result<_, SnarkVMError> {
+            process.synthesize_key::<A, R>(&program_id, &function, rng)?;
+            let proving_key = process.get_proving_key(program_id, function)?;
+            Ok((proving_key.circuit.index_info.num_constraints, PrivateKey::<A::Network>::new(rng)?))
+        };
+        let (num_constraints, private_key) =
+            setup().map_err(|err| CompilerError::failed_to_setup(function_name, err))?;
+
+        let results = input_sets
+            .iter()
+            .map(|inputs| {
+                let mut execute = || -> Result<_, SnarkVMError> {
+                    let inputs = inputs.iter().map(|input| Value::from_str(input)).collect::<Result<Vec<_>, _>>()?;
+                    let authorization =
+                        process.authorize::<A, R>(&private_key, program_id, function, inputs.iter(), rng)?;
+                    let (response, _) = process.execute::<A, R>(authorization, rng)?;
+                    Ok(response.outputs().iter().map(|output| output.to_string()).collect())
+                };
+                execute().map_err(|err| CompilerError::test_failed(function_name, err).into())
+            })
+            .collect();
+
+        Ok(TestReport { num_constraints, results })
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Verifier artifacts, which hold everything that is needed to verify the proofs of a function elsewhere.
+
+use crate::{Compiler, Metadata, VerifyingKeys};
+
+use leo_errors::{CompilerError, Result};
+
+use serde::{Deserialize, Serialize};
+use snarkvm::{
+    ledger::block::Execution,
+    prelude::{Identifier, Network, Program, ValueType},
+};
+use std::{path::Path, str::FromStr};
+
+/// A self-contained artifact to verify the proofs of the executions of a function.
+///
+/// It holds the verifying keys, the Aleo instructions of the program, and the types of the public inputs that a proof
+/// is checked against. Proofs are verified by the Varuna verifier of snarkVM, e.g. with [`VerifierArtifact::verify`],
+/// or by the Aleo network, which accepts the proofs of the programs that are deployed to it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct VerifierArtifact<N: Network> {
+    /// The metadata of the program and the proving system.
+    pub metadata: Metadata,
+    /// The Aleo instructions of the program.
+    pub program: String,
+    /// The Aleo instructions of the programs it imports.
+    pub imports: Vec<String>,
+    /// The name of the function.
+    pub function: String,
+    /// The types of the public inputs of the function, in order, e.g. `u32.public`.
+    pub public_inputs: Vec<String>,
+    /// The keys to verify executions of the function.
+    pub verifying_keys: VerifyingKeys<N>,
+}
+
+impl<N: Network> VerifierArtifact<N> {
+    /// Returns the verifier artifact of the function `function_name` of the compiled `bytecode`, which imports the
+    /// compiled programs `imports`.
+    pub fn new(
+        bytecode: &str,
+        imports: &[String],
+        function_name: &str,
+        verifying_keys: VerifyingKeys<N>,
+    ) -> Result<Self> {
+        let program = Program::<N>::from_str(bytecode).map_err(CompilerError::failed_to_load_program)?;
+        let function = Identifier::<N>::from_str(function_name)
+            .and_then(|function| program.get_function(&function))
+            .map_err(CompilerError::failed_to_load_program)?;
+        let public_inputs = function
+            .inputs()
+            .iter()
+            .filter(|input| matches!(input.value_type(), ValueType::Public(_)))
+            .map(|input| input.value_type().to_string())
+            .collect();
+
+        Ok(Self {
+            metadata: Metadata::new::<N>(bytecode),
+            program: bytecode.to_string(),
+            imports: imports.to_vec(),
+            function: function_name.to_string(),
+            public_inputs,
+            verifying_keys,
+        })
+    }
+
+    /// Verifies the `execution` of the function, and checks that its public inputs are `public_inputs`.
+    /// Returns the public inputs of the execution.
+    pub fn verify(&self, execution: &Execution<N>, public_inputs: &[String]) -> Result<Vec<String>> {
+        Compiler::verify(&self.program, &self.imports, &self.verifying_keys, &self.function, execution, public_inputs)
+    }
+
+    /// Writes the verifier artifact to the JSON file at `path`.
+    pub fn to_json_file(&self, path: &Path) -> Result<()> {
+        let file =
+            std::fs::File::create(path).map_err(|e| CompilerError::failed_to_write_verifier(path.display(), e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer_pretty(writer, self)
+            .map_err(|e| CompilerError::failed_to_write_verifier(path.display(), e))?)
+    }
+}
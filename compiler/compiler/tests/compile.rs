@@ -98,6 +98,9 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
                 destructured_ast: true,
                 inlined_ast: true,
                 dce_ast: true,
+                unrolled_dot_graph: false,
+                ir: false,
+                stats: false,
             },
         };
 
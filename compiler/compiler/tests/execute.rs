@@ -112,6 +112,9 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
                 destructured_ast: true,
                 inlined_ast: true,
                 dce_ast: true,
+                unrolled_dot_graph: false,
+                ir: false,
+                stats: false,
             },
         };
 
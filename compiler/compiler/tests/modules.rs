@@ -0,0 +1,276 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::Compiler;
+use leo_errors::emitter::Handler;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use indexmap::IndexMap;
+use std::fs;
+
+/// The outcome of compiling a package.
+struct Compiled {
+    /// The bytecode of the program, if it compiled.
+    bytecode: Option<String>,
+    /// The errors, both emitted and returned.
+    errors: String,
+    /// The warnings.
+    warnings: String,
+}
+
+/// Writes the files of a package, given by their paths and sources, to a temporary directory,
+/// and compiles the program `test.aleo` in its file `main.leo`.
+fn compile_package(files: &[(&str, &str)]) -> Compiled {
+    let directory = tempfile::tempdir().unwrap();
+    for (path, source) in files {
+        let path = directory.path().join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, source).unwrap();
+    }
+
+    create_session_if_not_set_then(|_| {
+        let (handler, buffer) = Handler::new_with_buf();
+        let mut compiler = Compiler::new(
+            "test".to_string(),
+            "aleo".to_string(),
+            &handler,
+            directory.path().join("main.leo"),
+            directory.path().join("build"),
+            None,
+            IndexMap::new(),
+        );
+        let result = compiler.compile();
+        let mut errors = buffer.extract_errs().to_string();
+        if let Err(err) = &result {
+            errors.push_str(&err.to_string());
+        }
+        Compiled { bytecode: result.ok(), errors, warnings: buffer.extract_warnings().to_string() }
+    })
+}
+
+const MATH: &str = "
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+inline double(a: u32) -> u32 {
+    return a + a;
+}
+
+inline triple(a: u32) -> u32 {
+    return a + double(a);
+}
+";
+
+const GEOMETRY: &str = "
+import lib.math.{Point, double};
+
+inline scale(p: Point) -> Point {
+    return Point { x: double(p.x), y: double(p.y) };
+}
+";
+
+#[test]
+fn resolves_modules_in_several_files() {
+    let main = "
+import lib.math.{Point, triple as thrice};
+import lib.geometry.*;
+
+program test.aleo {
+    transition main(a: u32) -> Point {
+        return scale(Point { x: thrice(a), y: a });
+    }
+}
+";
+    let compiled = compile_package(&[("main.leo", main), ("lib/math.leo", MATH), ("lib/geometry.leo", GEOMETRY)]);
+    let bytecode = compiled.bytecode.unwrap_or_else(|| panic!("{}", compiled.errors));
+    assert!(bytecode.contains("struct lib__math__Point:"), "{bytecode}");
+    assert!(compiled.warnings.is_empty(), "{}", compiled.warnings);
+}
+
+#[test]
+fn reports_a_module_that_is_not_found() {
+    let main = "
+import lib.missing.*;
+
+program test.aleo {
+    transition main(a: u32) -> u32 {
+        return a;
+    }
+}
+";
+    let compiled = compile_package(&[("main.leo", main)]);
+    assert!(compiled.bytecode.is_none());
+    assert!(compiled.errors.contains("Cannot find the module `lib.missing`"), "{}", compiled.errors);
+}
+
+#[test]
+fn reports_cyclic_imports() {
+    let main = "
+import lib.a.f;
+
+program test.aleo {
+    transition main(x: u32) -> u32 {
+        return f(x);
+    }
+}
+";
+    let a = "
+import lib.b.g;
+
+inline f(x: u32) -> u32 {
+    return g(x);
+}
+
+inline h(x: u32) -> u32 {
+    return x;
+}
+";
+    let b = "
+import lib.a.h;
+
+inline g(x: u32) -> u32 {
+    return h(x);
+}
+";
+    let compiled = compile_package(&[("main.leo", main), ("lib/a.leo", a), ("lib/b.leo", b)]);
+    assert!(compiled.bytecode.is_none());
+    assert!(compiled.errors.contains("Cyclic import between modules"), "{}", compiled.errors);
+}
+
+#[test]
+fn warns_about_unused_imports() {
+    let main = "
+import lib.math.{double, triple};
+import lib.geometry.*;
+
+program test.aleo {
+    transition main(a: u32) -> u32 {
+        return double(a);
+    }
+}
+";
+    let compiled = compile_package(&[("main.leo", main), ("lib/math.leo", MATH), ("lib/geometry.leo", GEOMETRY)]);
+    assert!(compiled.bytecode.is_some(), "{}", compiled.errors);
+    assert!(compiled.warnings.contains("The import `triple` is never used."), "{}", compiled.warnings);
+    assert!(compiled.warnings.contains("The import `lib.geometry.*` is never used."), "{}", compiled.warnings);
+    assert!(!compiled.warnings.contains("`double`"), "{}", compiled.warnings);
+}
+
+#[test]
+fn reports_names_that_two_glob_imports_make_ambiguous() {
+    let a = "
+inline f(x: u32) -> u32 {
+    return x;
+}
+";
+    let b = "
+inline f(x: u32) -> u32 {
+    return x + 1u32;
+}
+";
+    let main = "
+import lib.a.*;
+import lib.b.*;
+
+program test.aleo {
+    transition main(x: u32) -> u32 {
+        return f(x);
+    }
+}
+";
+    let compiled = compile_package(&[("main.leo", main), ("lib/a.leo", a), ("lib/b.leo", b)]);
+    assert!(compiled.bytecode.is_none());
+    assert!(
+        compiled.errors.contains("`f` is imported by the glob imports of both `lib.a` and `lib.b`."),
+        "{}",
+        compiled.errors
+    );
+
+    // An item that is imported by name shadows the items imported by glob imports.
+    let main = "
+import lib.a.*;
+import lib.b.*;
+import lib.b.f;
+
+program test.aleo {
+    transition main(x: u32) -> u32 {
+        return f(x);
+    }
+}
+";
+    let compiled = compile_package(&[("main.leo", main), ("lib/a.leo", a), ("lib/b.leo", b)]);
+    assert!(compiled.bytecode.is_some(), "{}", compiled.errors);
+}
+
+#[test]
+fn compiles_every_item_of_the_standard_library() {
+    let main = "
+import std.bits.*;
+import std.compare.*;
+import std.hash.*;
+import std.merkle.*;
+
+program test.aleo {
+    transition bits(a: u32, b: u64, i: u32) -> (bool, u32, u32, u32, bool, u64, u64, u64) {
+        return (
+            is_set_u32(a, i),
+            set_bit_u32(a, i),
+            clear_bit_u32(a, i),
+            count_ones_u32(a),
+            is_set_u64(b, i),
+            set_bit_u64(b, i),
+            clear_bit_u64(b, i),
+            count_ones_u64(b),
+        );
+    }
+
+    transition compare(a: u32, b: u32, c: u64, d: u64, e: field, f: field) -> (u32, u32, u32, u32, u64, u64, u64, u64, field, field) {
+        return (
+            min_u32(a, b),
+            max_u32(a, b),
+            clamp_u32(a, 1u32, 10u32),
+            abs_diff_u32(a, b),
+            min_u64(c, d),
+            max_u64(c, d),
+            clamp_u64(c, 1u64, 10u64),
+            abs_diff_u64(c, d),
+            min_field(e, f),
+            max_field(e, f),
+        );
+    }
+
+    transition hash(a: field, b: field, owner: address, r: scalar) -> (field, field, field) {
+        return (hash_pair(a, b), hash_address(owner), commit_field(a, r));
+    }
+
+    transition merkle_8(root: field, leaf: field, siblings: [field; 8], is_right: [bool; 8]) -> (field, bool) {
+        return (root_from_path_8(leaf, siblings, is_right), verify_path_8(root, leaf, siblings, is_right));
+    }
+
+    transition merkle_16(root: field, leaf: field, siblings: [field; 16], is_right: [bool; 16]) -> (field, bool) {
+        return (root_from_path_16(leaf, siblings, is_right), verify_path_16(root, leaf, siblings, is_right));
+    }
+}
+";
+    let compiled = compile_package(&[("main.leo", main)]);
+    let bytecode = compiled.bytecode.unwrap_or_else(|| panic!("{}", compiled.errors));
+    for function in ["bits", "compare", "hash", "merkle_8", "merkle_16"] {
+        assert!(bytecode.contains(&format!("function {function}:")), "{bytecode}");
+    }
+    assert!(compiled.warnings.is_empty(), "{}", compiled.warnings);
+}
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{Compiler, FunctionKeys, Witness};
+
+use snarkvm::{
+    circuit::AleoV0,
+    ledger::store::{helpers::memory::BlockMemory, BlockStore},
+    prelude::{PrivateKey, TestRng, Testnet3},
+};
+
+type Network = Testnet3;
+
+const ADDER: &str = "program adder.aleo;
+
+function add:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+";
+
+const CALLER: &str = "import adder.aleo;
+
+program caller.aleo;
+
+function double:
+    input r0 as u32.public;
+    call adder.aleo/add r0 r0 into r1;
+    output r1 as u32.private;
+";
+
+/// Returns a block store that holds no blocks, to query the state root from instead of a node.
+fn block_store() -> BlockStore<Network, BlockMemory<Network>> {
+    BlockStore::open(None).unwrap()
+}
+
+#[test]
+fn proves_and_verifies_an_execution() {
+    let rng = &mut TestRng::default();
+    let private_key = PrivateKey::<Network>::new(rng).unwrap();
+
+    let keys = Compiler::setup::<AleoV0, _>(ADDER, &[], "add", rng).unwrap();
+    let inputs = ["1u32".to_string(), "2u32".to_string()];
+    let execution =
+        Compiler::prove::<AleoV0, _>(ADDER, &[], &keys, &private_key, "add", &inputs, block_store(), rng).unwrap();
+
+    // The public inputs that the proof is verified against are the public inputs of the execution.
+    assert_eq!(Compiler::public_inputs(&execution), ["1u32"]);
+    let public_inputs = Compiler::verify(ADDER, &[], &keys.verifying_keys(), "add", &execution, &["1u32".to_string()]);
+    assert_eq!(public_inputs.unwrap(), ["1u32"]);
+    // The proof does not verify against other public inputs.
+    assert!(Compiler::verify(ADDER, &[], &keys.verifying_keys(), "add", &execution, &["2u32".to_string()]).is_err());
+    assert!(Compiler::verify(ADDER, &[], &keys.verifying_keys(), "add", &execution, &[]).is_err());
+}
+
+#[test]
+fn proves_and_verifies_with_serialized_keys_and_witnesses() {
+    let rng = &mut TestRng::default();
+    let private_key = PrivateKey::<Network>::new(rng).unwrap();
+
+    // The keys and the witness are written by the setup and by the prover, and read back by the verifier.
+    let keys = Compiler::setup::<AleoV0, _>(ADDER, &[], "add", rng).unwrap();
+    let keys: FunctionKeys<Network> = serde_json::from_str(&serde_json::to_string(&keys).unwrap()).unwrap();
+
+    let inputs = ["3u32".to_string(), "4u32".to_string()];
+    let witness = Compiler::witness::<AleoV0, _>(ADDER, &[], &private_key, "add", &inputs, rng).unwrap();
+    let directory = tempfile::tempdir().unwrap();
+    let witness_path = directory.path().join("witness.json");
+    witness.to_json_file(&witness_path).unwrap();
+    let witness = Witness::<Network>::from_json_file(&witness_path).unwrap();
+
+    let outputs = Compiler::synthesize::<AleoV0, _>(ADDER, &[], &keys, &witness, rng).unwrap();
+    assert_eq!(outputs, ["7u32"]);
+
+    let execution = Compiler::prove_witness::<AleoV0, _>(ADDER, &[], &keys, &witness, block_store(), rng).unwrap();
+    let execution = serde_json::from_str(&serde_json::to_string(&execution).unwrap()).unwrap();
+    Compiler::verify(ADDER, &[], &keys.verifying_keys(), "add", &execution, &["3u32".to_string()]).unwrap();
+}
+
+#[test]
+fn proves_and_verifies_a_program_with_imports() {
+    let rng = &mut TestRng::default();
+    let private_key = PrivateKey::<Network>::new(rng).unwrap();
+    let imports = [ADDER.to_string()];
+
+    // The imported program must be loaded before the program that calls it.
+    assert!(Compiler::setup::<AleoV0, _>(CALLER, &[], "double", rng).is_err());
+
+    let keys = Compiler::setup::<AleoV0, _>(CALLER, &imports, "double", rng).unwrap();
+    // The keys of the function it calls are needed to prove and to verify the transition of that call.
+    let calls = keys.calls.iter().map(|(locator, ..)| locator.as_str()).collect::<Vec<_>>();
+    assert_eq!(calls, ["adder.aleo/add"]);
+    let inputs = ["5u32".to_string()];
+    let execution =
+        Compiler::prove::<AleoV0, _>(CALLER, &imports, &keys, &private_key, "double", &inputs, block_store(), rng)
+            .unwrap();
+
+    // The execution holds the transition of the imported function, before the one of the function that calls it.
+    assert_eq!(execution.transitions().count(), 2);
+    let public_inputs =
+        Compiler::verify(CALLER, &imports, &keys.verifying_keys(), "double", &execution, &["5u32".to_string()]);
+    assert_eq!(public_inputs.unwrap(), ["5u32"]);
+}
+
+#[test]
+fn proves_each_input_set_with_the_same_keys() {
+    let rng = &mut TestRng::default();
+    let private_key = PrivateKey::<Network>::new(rng).unwrap();
+
+    let keys = Compiler::setup::<AleoV0, _>(ADDER, &[], "add", rng).unwrap();
+    let input_sets = [vec!["1u32".to_string(), "2u32".to_string()], vec!["1u32".to_string()], vec![
+        "3u32".to_string(),
+        "4u32".to_string(),
+    ]];
+    let executions =
+        Compiler::prove_all::<AleoV0, _>(ADDER, &[], &keys, &private_key, "add", &input_sets, block_store(), rng);
+
+    // The set with too few inputs fails without stopping the sets after it.
+    assert_eq!(executions.len(), 3);
+    assert!(executions[1].is_err());
+    for (index, public_input) in [(0, "1u32"), (2, "3u32")] {
+        let execution = executions[index].as_ref().unwrap();
+        let public_inputs = &[public_input.to_string()];
+        Compiler::verify(ADDER, &[], &keys.verifying_keys(), "add", execution, public_inputs).unwrap();
+    }
+}
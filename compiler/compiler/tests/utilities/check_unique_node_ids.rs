@@ -173,7 +173,9 @@ impl<'a> ExpressionVisitor<'a> for CheckUniqueNodeIds<'a> {
 impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
     fn visit_assert(&mut self, input: &'a AssertStatement) {
         match &input.variant {
-            AssertVariant::Assert(expr) => self.visit_expression(expr, &Default::default()),
+            AssertVariant::Assert(expr) | AssertVariant::ConstAssert(expr) => {
+                self.visit_expression(expr, &Default::default())
+            }
             AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
                 self.visit_expression(left, &Default::default());
                 self.visit_expression(right, &Default::default())
@@ -236,6 +238,9 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
         self.check_ty(&input.type_);
         self.visit_expression(&input.start, &Default::default());
         self.visit_expression(&input.stop, &Default::default());
+        if let Some(step) = &input.step {
+            self.visit_expression(step, &Default::default());
+        }
         self.visit_block(&input.block);
         self.check(input.id)
     }
@@ -86,25 +86,60 @@ pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
                 .iter()
                 .map(|config| {
                     let config = config.as_mapping().expect("Expected the compiler configuration to be a mapping.");
-                    assert_eq!(
-                        config.len(),
-                        1,
-                        "A compiler configuration must have exactly one key-value pair. e.g. `dce_enabled`: true"
-                    );
-                    BuildOptions {
-                        dce_enabled: config
-                            .get(&serde_yaml::Value::String("dce_enabled".to_string()))
-                            .expect("Expected key `dce_enabled`")
-                            .as_bool()
-                            .expect("Expected value to be a boolean."),
-                    }
+                    config.iter().fold(default_build_options(), |options, (key, value)| {
+                        let key = key.as_str().expect("Expected the keys of a compiler configuration to be strings.");
+                        set_build_option(options, key, value)
+                    })
                 })
                 .collect()
         }
-        None => vec![BuildOptions { dce_enabled: true }],
+        None => vec![default_build_options()],
     }
 }
 
+/// Returns the build options of a test that does not configure them.
+fn default_build_options() -> BuildOptions {
+    BuildOptions { dce_enabled: true, ..Default::default() }
+}
+
+/// Returns `options` with the option named `key` in a compiler configuration set to `value`.
+fn set_build_option(mut options: BuildOptions, key: &str, value: &serde_yaml::Value) -> BuildOptions {
+    let flag = || value.as_bool().unwrap_or_else(|| panic!("Expected the value of `{key}` to be a boolean."));
+    match key {
+        "dce_enabled" => options.dce_enabled = flag(),
+        "canonicalization_enabled" => options.canonicalization_enabled = flag(),
+        "cse_enabled" => options.cse_enabled = flag(),
+        "branch_pruning_enabled" => options.branch_pruning_enabled = flag(),
+        "early_return_guards_enabled" => options.early_return_guards_enabled = flag(),
+        "mux_lowering_enabled" => options.mux_lowering_enabled = flag(),
+        "pow_lowering_enabled" => options.pow_lowering_enabled = flag(),
+        "div_lowering_enabled" => options.div_lowering_enabled = flag(),
+        "tests_enabled" => options.tests_enabled = flag(),
+        "overflow_behavior" => {
+            options.overflow_behavior = value
+                .as_str()
+                .and_then(|behavior| behavior.parse().ok())
+                .expect("Expected the value of `overflow_behavior` to be `checked`, `wrapping`, or `trap`.")
+        }
+        "max_array_elements" => {
+            options.max_array_elements = Some(
+                value
+                    .as_u64()
+                    .and_then(|max| usize::try_from(max).ok())
+                    .expect("Expected the value of `max_array_elements` to be a non-negative integer."),
+            )
+        }
+        "features" => {
+            options.features = value
+                .as_sequence()
+                .and_then(|features| features.iter().map(|feature| feature.as_str().map(String::from)).collect())
+                .expect("Expected the value of `features` to be a sequence of strings.")
+        }
+        _ => panic!("Unknown compiler configuration key `{key}`."),
+    }
+    options
+}
+
 #[allow(unused)]
 pub fn setup_build_directory(program_name: &str, bytecode: &String, handler: &Handler) -> Result<Package<Network>, ()> {
     // Initialize a temporary directory.
@@ -231,6 +266,10 @@ pub fn temp_dir() -> PathBuf {
 }
 
 pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, LeoError> {
+    parsed.module_resolution_pass()?;
+
+    parsed.macro_expansion_pass()?;
+
     let st = parsed.symbol_table_pass()?;
 
     CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
@@ -239,8 +278,14 @@ pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, L
 
     CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
 
+    parsed.test_removal_pass();
+
+    parsed.canonicalization_pass()?;
+
     let st = parsed.loop_unrolling_pass(st)?;
 
+    let (st, call_graph) = parsed.recursion_expansion_pass(st, call_graph)?;
+
     parsed.static_single_assignment_pass(&st)?;
 
     parsed.flattening_pass(&st)?;
@@ -249,6 +294,8 @@ pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, L
 
     parsed.function_inlining_pass(&call_graph)?;
 
+    parsed.common_subexpression_elimination_pass()?;
+
     parsed.dead_code_elimination_pass()?;
 
     // Compile Leo program to bytecode.
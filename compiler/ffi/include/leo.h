@@ -0,0 +1,86 @@
+/*
+ * Copyright (C) 2019-2023 Aleo Systems Inc.
+ * This file is part of the Leo library.
+ *
+ * The Leo library is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * The Leo library is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+#ifndef LEO_H
+#define LEO_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The function succeeded. */
+#define LEO_OK 0
+/* An input is a null pointer, is not UTF-8, or is not in the expected format. */
+#define LEO_INVALID_ARGUMENT -1
+/* The function failed with an error that has no exit code, e.g. a panic. */
+#define LEO_ERROR -2
+
+/* A buffer of bytes owned by the caller, which is only read. */
+typedef struct LeoSlice {
+    const uint8_t *data;
+    size_t len;
+} LeoSlice;
+
+/* A buffer of bytes owned by the library, which the caller frees with `leo_buffer_free`. */
+typedef struct LeoBuffer {
+    uint8_t *data;
+    size_t len;
+} LeoBuffer;
+
+/* Compiles the Leo program `source`, whose program scope is `program_name`, to Aleo instructions. */
+int32_t leo_compile(LeoSlice program_name, LeoSlice source, LeoBuffer *out);
+
+/* Synthesizes the keys of the function `function` of the Aleo instructions `bytecode`, as JSON.
+ * The Aleo instructions of the programs it imports are given in `imports`, a JSON array. */
+int32_t leo_setup(LeoSlice bytecode, LeoSlice imports, LeoSlice function, LeoBuffer *out);
+
+/* Executes `function` on `inputs`, a JSON array of Aleo values, and proves the execution with `keys`, as JSON. */
+int32_t leo_prove(
+    LeoSlice bytecode,
+    LeoSlice imports,
+    LeoSlice keys,
+    LeoSlice private_key,
+    LeoSlice function,
+    LeoSlice inputs,
+    LeoSlice endpoint,
+    LeoBuffer *out
+);
+
+/* Verifies the `execution` of `function` with `keys`, given its public inputs as a JSON array.
+ * The public inputs that the execution was verified against are written to `out`, as a JSON array. */
+int32_t leo_verify(
+    LeoSlice bytecode,
+    LeoSlice imports,
+    LeoSlice keys,
+    LeoSlice function,
+    LeoSlice execution,
+    LeoSlice public_inputs,
+    LeoBuffer *out
+);
+
+/* Frees a buffer that the library wrote. */
+void leo_buffer_free(LeoBuffer buffer);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* LEO_H */
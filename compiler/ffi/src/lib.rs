@@ -0,0 +1,335 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A C interface to the Leo compiler, so that services written in other languages can compile programs, and prove and
+//! verify their executions, without running the CLI.
+//!
+//! Each function reads its inputs from [`LeoSlice`]s and writes its output to a [`LeoBuffer`], which the caller frees
+//! with [`leo_buffer_free`]. Text is UTF-8, and keys, executions, and lists of inputs are JSON. A function returns
+//! [`LEO_OK`] if it succeeds. Otherwise it writes the message of its error to the output buffer, and returns either the
+//! exit code of the Leo error, which is positive, or one of the negative codes of this crate. The declarations for C
+//! are in `include/leo.h`.
+
+use leo_compiler::{Compiler, FunctionKeys};
+use leo_errors::{emitter::Handler, CompilerError, LeoError};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use indexmap::IndexMap;
+use snarkvm::{
+    circuit::AleoV0,
+    ledger::block::Execution,
+    prelude::{PrivateKey, Testnet3},
+};
+use std::{fmt::Display, panic::AssertUnwindSafe, path::PathBuf, str::FromStr};
+
+type CurrentNetwork = Testnet3;
+
+/// The function succeeded.
+pub const LEO_OK: i32 = 0;
+/// An input is a null pointer, is not UTF-8, or is not in the expected format.
+pub const LEO_INVALID_ARGUMENT: i32 = -1;
+/// The function failed with an error that has no exit code, e.g. a panic.
+pub const LEO_ERROR: i32 = -2;
+
+/// A buffer of bytes owned by the caller, which is only read.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LeoSlice {
+    /// The first byte of the buffer, which may be null if the buffer is empty.
+    pub data: *const u8,
+    /// The number of bytes in the buffer.
+    pub len: usize,
+}
+
+impl LeoSlice {
+    /// Returns the buffer as a string, where `name` names the input in errors.
+    ///
+    /// # Safety
+    ///
+    /// `data` must point to `len` readable bytes, which must not change while the string is alive.
+    unsafe fn to_str<'a>(self, name: &str) -> Result<&'a str, Failure> {
+        if self.data.is_null() {
+            return match self.len {
+                0 => Ok(""),
+                _ => Err(Failure::invalid(format!("`{name}` is a null pointer"))),
+            };
+        }
+        std::str::from_utf8(std::slice::from_raw_parts(self.data, self.len))
+            .map_err(|_| Failure::invalid(format!("`{name}` is not UTF-8")))
+    }
+}
+
+/// A buffer of bytes owned by this library, which the caller frees with [`leo_buffer_free`].
+#[repr(C)]
+pub struct LeoBuffer {
+    /// The first byte of the buffer.
+    pub data: *mut u8,
+    /// The number of bytes in the buffer.
+    pub len: usize,
+}
+
+impl LeoBuffer {
+    /// Hands `bytes` over to the caller.
+    fn new(bytes: Vec<u8>) -> Self {
+        let len = bytes.len();
+        Self { data: Box::into_raw(bytes.into_boxed_slice()) as *mut u8, len }
+    }
+}
+
+/// Why a function failed.
+struct Failure {
+    /// The code that the function returns.
+    code: i32,
+    /// The message that the function writes to its output buffer.
+    message: String,
+}
+
+impl Failure {
+    /// Returns the failure of a function whose input is invalid.
+    fn invalid(message: impl Display) -> Self {
+        Self { code: LEO_INVALID_ARGUMENT, message: message.to_string() }
+    }
+}
+
+impl From<LeoError> for Failure {
+    fn from(err: LeoError) -> Self {
+        let code = match err.exit_code() {
+            0 => LEO_ERROR,
+            code => code,
+        };
+        Self { code, message: err.to_string() }
+    }
+}
+
+/// Runs `function`, writes its output or the message of its error to `out`, and returns the code of the outcome.
+///
+/// # Safety
+///
+/// `out` must be null or writable.
+unsafe fn run(out: *mut LeoBuffer, function: impl FnOnce() -> Result<Vec<u8>, Failure>) -> i32 {
+    if out.is_null() {
+        return LEO_INVALID_ARGUMENT;
+    }
+    // Unwinding into the caller is undefined behavior, so panics are caught.
+    let (code, bytes) = match std::panic::catch_unwind(AssertUnwindSafe(function)) {
+        Ok(Ok(bytes)) => (LEO_OK, bytes),
+        Ok(Err(failure)) => (failure.code, failure.message.into_bytes()),
+        Err(_) => (LEO_ERROR, b"the compiler panicked".to_vec()),
+    };
+    out.write(LeoBuffer::new(bytes));
+    code
+}
+
+/// Parses the JSON input `slice`, where `name` names the input in errors.
+///
+/// # Safety
+///
+/// See [`LeoSlice::to_str`].
+unsafe fn from_json<T: serde::de::DeserializeOwned>(slice: LeoSlice, name: &str) -> Result<T, Failure> {
+    serde_json::from_str(slice.to_str(name)?).map_err(|err| Failure::invalid(format!("`{name}` is invalid: {err}")))
+}
+
+/// Returns the JSON of `value`.
+fn to_json<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, Failure> {
+    serde_json::to_vec(value).map_err(|err| LeoError::from(CompilerError::failed_to_encode(err)).into())
+}
+
+/// Compiles the Leo program `source`, whose program scope is `program_name`, and writes its Aleo instructions to `out`.
+/// Programs that import other programs cannot be compiled, since the imported programs are not available.
+///
+/// # Safety
+///
+/// The inputs must be readable and `out` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn leo_compile(program_name: LeoSlice, source: LeoSlice, out: *mut LeoBuffer) -> i32 {
+    run(out, || {
+        let program_name = program_name.to_str("program_name")?;
+        let source = source.to_str("source")?;
+        create_session_if_not_set_then(|_| {
+            let (handler, buffer) = Handler::new_with_buf();
+            let file_name = format!("{program_name}.leo");
+            let mut compiler = Compiler::new(
+                program_name.to_string(),
+                "aleo".to_string(),
+                &handler,
+                PathBuf::from(&file_name),
+                std::env::temp_dir(),
+                None,
+                IndexMap::new(),
+            );
+            let result = compiler
+                .parse_program_from_string(source, FileName::Custom(file_name))
+                .and_then(|_| compiler.compile_parsed_program());
+            match result {
+                Ok(bytecode) => Ok(bytecode.into_bytes()),
+                // The errors were emitted to the handler rather than returned.
+                Err(LeoError::LastErrorCode(code)) => Err(Failure { code, message: buffer.extract_errs().to_string() }),
+                Err(err) => Err(err.into()),
+            }
+        })
+    })
+}
+
+/// Synthesizes the keys of the function `function` of the Aleo instructions `bytecode`, and writes them to `out`.
+/// The Aleo instructions of the programs it imports are given in `imports`, a JSON array.
+///
+/// # Safety
+///
+/// The inputs must be readable and `out` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn leo_setup(
+    bytecode: LeoSlice,
+    imports: LeoSlice,
+    function: LeoSlice,
+    out: *mut LeoBuffer,
+) -> i32 {
+    run(out, || {
+        let bytecode = bytecode.to_str("bytecode")?;
+        let imports: Vec<String> = from_json(imports, "imports")?;
+        let function = function.to_str("function")?;
+        to_json(&Compiler::setup::<AleoV0, _>(bytecode, &imports, function, &mut rand::thread_rng())?)
+    })
+}
+
+/// Executes the function `function` of the Aleo instructions `bytecode` on `inputs`, a JSON array of Aleo values,
+/// signed by `private_key`, proves the execution with `keys`, and writes the execution to `out`. The state of the
+/// network, e.g. the records that are spent, is queried from `endpoint`. The Aleo instructions of the programs that
+/// `bytecode` imports are given in `imports`, a JSON array.
+///
+/// # Safety
+///
+/// The inputs must be readable and `out` must be writable.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn leo_prove(
+    bytecode: LeoSlice,
+    imports: LeoSlice,
+    keys: LeoSlice,
+    private_key: LeoSlice,
+    function: LeoSlice,
+    inputs: LeoSlice,
+    endpoint: LeoSlice,
+    out: *mut LeoBuffer,
+) -> i32 {
+    run(out, || {
+        let bytecode = bytecode.to_str("bytecode")?;
+        let imports: Vec<String> = from_json(imports, "imports")?;
+        let keys: FunctionKeys<CurrentNetwork> = from_json(keys, "keys")?;
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(private_key.to_str("private_key")?)
+            .map_err(|err| Failure::invalid(format!("`private_key` is invalid: {err}")))?;
+        let function = function.to_str("function")?;
+        let inputs: Vec<String> = from_json(inputs, "inputs")?;
+        let endpoint = endpoint.to_str("endpoint")?;
+        let execution = Compiler::prove::<AleoV0, _>(
+            bytecode,
+            &imports,
+            &keys,
+            &private_key,
+            function,
+            &inputs,
+            endpoint,
+            &mut rand::thread_rng(),
+        )?;
+        to_json(&execution)
+    })
+}
+
+/// Verifies the `execution` of the function `function` of the Aleo instructions `bytecode` with `keys`, and checks
+/// that its public inputs are `public_inputs`, a JSON array of Aleo values. The Aleo instructions of the programs
+/// that `bytecode` imports are given in `imports`, a JSON array. If the execution is valid, the public inputs it was
+/// verified against are written to `out`, as a JSON array of Aleo values.
+///
+/// # Safety
+///
+/// The inputs must be readable and `out` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn leo_verify(
+    bytecode: LeoSlice,
+    imports: LeoSlice,
+    keys: LeoSlice,
+    function: LeoSlice,
+    execution: LeoSlice,
+    public_inputs: LeoSlice,
+    out: *mut LeoBuffer,
+) -> i32 {
+    run(out, || {
+        let bytecode = bytecode.to_str("bytecode")?;
+        let imports: Vec<String> = from_json(imports, "imports")?;
+        let keys: FunctionKeys<CurrentNetwork> = from_json(keys, "keys")?;
+        let function = function.to_str("function")?;
+        let execution: Execution<CurrentNetwork> = from_json(execution, "execution")?;
+        let public_inputs: Vec<String> = from_json(public_inputs, "public_inputs")?;
+        to_json(&Compiler::verify(bytecode, &imports, &keys.verifying_keys(), function, &execution, &public_inputs)?)
+    })
+}
+
+/// Frees a buffer that a function of this library wrote.
+///
+/// # Safety
+///
+/// `buffer` must have been written by this library, and must not be used or freed again.
+#[no_mangle]
+pub unsafe extern "C" fn leo_buffer_free(buffer: LeoBuffer) {
+    if !buffer.data.is_null() {
+        drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(buffer.data, buffer.len)));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns a slice of `text`.
+    fn slice(text: &str) -> LeoSlice {
+        LeoSlice { data: text.as_ptr(), len: text.len() }
+    }
+
+    /// Compiles `source` through the C interface, and returns the code and the output.
+    fn compile(program_name: LeoSlice, source: LeoSlice) -> (i32, String) {
+        let mut out = LeoBuffer { data: std::ptr::null_mut(), len: 0 };
+        let code = unsafe { leo_compile(program_name, source, &mut out) };
+        let output = String::from_utf8_lossy(unsafe { std::slice::from_raw_parts(out.data, out.len) }).into_owned();
+        unsafe { leo_buffer_free(out) };
+        (code, output)
+    }
+
+    const SOURCE: &str = "program hello.aleo {
+    transition main(a: u32) -> u32 {
+        return a + 1u32;
+    }
+}
+";
+
+    #[test]
+    fn test_compile() {
+        let (code, bytecode) = compile(slice("hello"), slice(SOURCE));
+        assert_eq!(code, LEO_OK, "{bytecode}");
+        assert!(bytecode.starts_with("program hello.aleo;"), "{bytecode}");
+        assert!(bytecode.contains("function main:"), "{bytecode}");
+        assert!(bytecode.contains("add r0 1u32 into r1;"), "{bytecode}");
+    }
+
+    #[test]
+    fn test_compile_reports_errors() {
+        let (code, message) = compile(slice("hello"), slice("program hello.aleo {"));
+        assert!(code > 0, "{message}");
+        assert!(!message.is_empty());
+
+        let (code, message) = compile(LeoSlice { data: std::ptr::null(), len: 1 }, slice(SOURCE));
+        assert_eq!(code, LEO_INVALID_ARGUMENT);
+        assert_eq!(message, "`program_name` is a null pointer");
+    }
+}
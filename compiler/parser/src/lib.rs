@@ -24,15 +24,16 @@
 #![doc = include_str!("../README.md")]
 
 pub(crate) mod tokenizer;
-use leo_span::span::BytePos;
+use leo_span::span::{BytePos, Pos, Span};
 pub use tokenizer::KEYWORD_TOKENS;
 pub(crate) use tokenizer::*;
 
 pub mod parser;
 pub use parser::*;
 
-use leo_ast::{Ast, NodeBuilder};
+use leo_ast::{Ast, Comment, Comments, Expression, NodeBuilder};
 use leo_errors::{emitter::Handler, Result};
+use leo_span::{source_map::FileName, sym, symbol::with_session_globals};
 
 #[cfg(test)]
 mod test;
@@ -41,3 +42,121 @@ mod test;
 pub fn parse_ast(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<Ast> {
     Ok(Ast::new(parser::parse(handler, node_builder, source, start_pos)?))
 }
+
+/// Creates a new AST from a given file path and source code text, resolving its `@cfg` annotations against `features`.
+pub fn parse_ast_with_features(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+    features: &[String],
+) -> Result<Ast> {
+    Ok(Ast::new(parser::parse_with_features(handler, node_builder, source, start_pos, features)?))
+}
+
+/// Returns `true` if the source code contains a comment.
+pub fn has_comments(source: &str) -> Result<bool> {
+    for token in tokenize_iter(source, BytePos(0)) {
+        if matches!(token?.token, Token::CommentLine(_) | Token::CommentBlock(_)) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Returns the comments of the source code, whose spans start at `start_pos` like those of [`parse()`].
+///
+/// Comments are dropped by the parser, so tools that print source code from the AST use these to put them back.
+/// The `@cfg(...)` annotations of statements are not kept in the AST either, so they are returned here as well.
+pub fn parse_comments(source: &str, start_pos: BytePos) -> Result<Comments> {
+    let tokens = tokenize_iter(source, start_pos).collect::<Result<Vec<_>>>()?;
+    let text_of = |span: Span| &source[(span.lo - start_pos).to_usize()..(span.hi - start_pos).to_usize()];
+    let mut comments = Vec::new();
+    // The end of the previous token, if any.
+    let mut previous: Option<BytePos> = None;
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = &tokens[index];
+        // The span of the token, and whether it is kept as trivia.
+        let (span, is_trivia) = match &token.token {
+            Token::CommentLine(text) | Token::CommentBlock(text) => {
+                // A line comment ends before its line feed.
+                let text = text.trim_end();
+                (Span::new(token.span.lo, token.span.lo + BytePos::from_usize(text.len())), true)
+            }
+            Token::At => match statement_cfg_end(&tokens, index) {
+                Some(end) => {
+                    index = end;
+                    (Span::new(token.span.lo, tokens[end].span.hi), true)
+                }
+                None => (token.span, false),
+            },
+            _ => (token.span, false),
+        };
+        if is_trivia {
+            // The trivia trails code if no line feed separates it from the previous token.
+            let trailing = previous.map_or(false, |hi| {
+                !source[(hi - start_pos).to_usize()..(span.lo - start_pos).to_usize()].contains('\n')
+            });
+            comments.push(Comment { text: text_of(span).to_string(), span, trailing });
+        }
+        previous = Some(span.hi);
+        index += 1;
+    }
+    Ok(Comments::new(comments))
+}
+
+/// If the `@` at `index` starts a `@cfg(...)` annotation of a statement, returns the index of its closing parenthesis.
+///
+/// Annotations of functions and structs are kept in the AST, so only those followed by a statement are matched.
+fn statement_cfg_end(tokens: &[SpannedToken], index: usize) -> Option<usize> {
+    if !matches!(tokens.get(index + 1)?.token, Token::Identifier(name) if name == sym::cfg)
+        || tokens.get(index + 2)?.token != Token::LeftParen
+    {
+        return None;
+    }
+    let end = index + 3 + tokens[index + 3..].iter().position(|token| token.token == Token::RightParen)?;
+    let next = tokens[end + 1..]
+        .iter()
+        .find(|token| !matches!(token.token, Token::CommentLine(_) | Token::CommentBlock(_)))?;
+    let is_item = matches!(
+        next.token,
+        Token::At | Token::Function | Token::Transition | Token::Inline | Token::Struct | Token::Record
+    );
+    (!is_item).then_some(end)
+}
+
+/// Parses the text of a single expression, such as the `Display` output of an [`Expression`].
+///
+/// Parsing the printed form of an expression yields an expression equal to it up to spans and node IDs,
+/// see [`Expression::eq_structural`].
+/// It must be called within a session, e.g. in `leo_span::symbol::create_session_if_not_set_then`.
+pub fn parse_expression_text(handler: &Handler, node_builder: &NodeBuilder, source: &str) -> Result<Expression> {
+    let source_file = with_session_globals(|s| s.source_map.new_source(source, FileName::Custom("expr".into())));
+    parser::parse_expression(handler, node_builder, &source_file.src, source_file.start_pos)
+}
+
+/// Parses `source` as a single expression, panicking if it is not one.
+///
+/// This is a helper for compiler unit tests, available to other crates with the `test-utils` feature;
+/// see the [`expr!`] macro.
+/// It must be called within a session, e.g. in `leo_span::symbol::create_session_if_not_set_then`.
+#[cfg(any(test, feature = "test-utils"))]
+pub fn parse_test_expression(source: &str) -> Expression {
+    parse_expression_text(&Handler::default(), &NodeBuilder::default(), source)
+        .unwrap_or_else(|err| panic!("failed to parse expression `{source}`: {err}"))
+}
+
+/// Parses Leo expression syntax into an [`Expression`], for use in compiler unit tests.
+/// It is available to other crates with the `test-utils` feature.
+///
+/// ```ignore
+/// let e = leo_parser::expr!{ a + b * 3u8 };
+/// ```
+#[cfg(any(test, feature = "test-utils"))]
+#[macro_export]
+macro_rules! expr {
+    ($($tokens:tt)*) => {
+        $crate::parse_test_expression(stringify!($($tokens)*))
+    };
+}
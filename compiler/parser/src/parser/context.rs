@@ -18,7 +18,7 @@ use crate::{tokenizer::*, Token};
 
 use leo_ast::*;
 use leo_errors::{emitter::Handler, ParserError, ParserWarning, Result};
-use leo_span::{Span, Symbol};
+use leo_span::{span::BytePos, Span, Symbol};
 
 use std::{fmt::Display, mem};
 
@@ -39,6 +39,16 @@ pub(crate) struct ParserContext<'a> {
     pub(crate) prev_token: SpannedToken,
     /// true if parsing an expression for if and loop statements -- means struct inits are not legal
     pub(crate) disallow_struct_construction: bool,
+    /// The number of expressions that are being parsed, each nested in the previous one.
+    pub(crate) expression_depth: usize,
+    /// The number of curly braces before the current token that are not closed, e.g. `1` in a program scope.
+    pub(crate) curly_depth: usize,
+    /// The lines of the doc comments, i.e. `///` comments, that have not been attached to a node yet,
+    /// each paired with the start of the token that follows the comment.
+    doc_comments: Vec<(BytePos, String)>,
+    /// The features that `@cfg(feature = "...")` annotations are resolved against,
+    /// or `None` if the annotated items and statements are kept along with their annotations.
+    pub(crate) features: Option<Vec<String>>,
 }
 
 /// Dummy span used to appease borrow checker.
@@ -47,7 +57,19 @@ const DUMMY_EOF: SpannedToken = SpannedToken { token: Token::Eof, span: Span::du
 impl<'a> ParserContext<'a> {
     /// Returns a new [`ParserContext`] type given a vector of tokens.
     pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, mut tokens: Vec<SpannedToken>) -> Self {
-        // Strip out comments.
+        // Collect the doc comments, each with the token that it documents, and then strip out comments.
+        let mut doc_comments = Vec::new();
+        let mut docs = Vec::new();
+        for token in tokens.iter() {
+            match &token.token {
+                Token::CommentLine(text) if text.starts_with("///") && !text.starts_with("////") => {
+                    let doc = text["///".len()..].trim_end();
+                    docs.push(doc.strip_prefix(' ').unwrap_or(doc).to_string());
+                }
+                Token::CommentLine(_) | Token::CommentBlock(_) => {}
+                _ => doc_comments.extend(docs.drain(..).map(|doc| (token.span.lo, doc))),
+            }
+        }
         tokens.retain(|x| !matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)));
         // For performance we reverse so that we get cheap `.pop()`s.
         tokens.reverse();
@@ -57,6 +79,10 @@ impl<'a> ParserContext<'a> {
             handler,
             node_builder,
             disallow_struct_construction: false,
+            expression_depth: 0,
+            curly_depth: 0,
+            doc_comments,
+            features: None,
             prev_token: token.clone(),
             token,
             tokens,
@@ -75,6 +101,13 @@ impl<'a> ParserContext<'a> {
             panic!("attempted to bump the parser past EOF (may be stuck in a loop)");
         }
 
+        // Track the curly braces that are bumped past.
+        match self.token.token {
+            Token::LeftCurly => self.curly_depth += 1,
+            Token::RightCurly => self.curly_depth = self.curly_depth.saturating_sub(1),
+            _ => {}
+        }
+
         // Extract next token, or `Eof` if there was none.
         let next_token = self.tokens.pop().unwrap_or(SpannedToken { token: Token::Eof, span: self.token.span });
 
@@ -82,6 +115,13 @@ impl<'a> ParserContext<'a> {
         self.prev_token = mem::replace(&mut self.token, next_token);
     }
 
+    /// Returns the lines of the doc comments that precede the token starting at `lo`,
+    /// and discards those of the tokens before it, which do not document a node.
+    pub(super) fn take_docs(&mut self, lo: BytePos) -> Vec<String> {
+        let count = self.doc_comments.partition_point(|(next, _)| *next <= lo);
+        self.doc_comments.drain(..count).filter(|(next, _)| *next == lo).map(|(_, doc)| doc).collect()
+    }
+
     /// Checks whether the current token is `tok`.
     pub(super) fn check(&self, tok: &Token) -> bool {
         &self.token.token == tok
@@ -162,6 +202,11 @@ impl<'a> ParserContext<'a> {
                 return Err(ParserError::tuple_index_must_be_whole_number(&self.token.token, self.token.span).into());
             }
 
+            // Reject value if it does not fit in a `usize`, rather than panicking when it is converted.
+            if value.parse::<usize>().is_err() {
+                return Err(ParserError::whole_number_too_large(&value, self.prev_token.span).into());
+            }
+
             Ok((NonNegativeNumber::from(value), self.prev_token.span))
         } else {
             Err(ParserError::unexpected(&self.token.token, "integer literal", self.token.span).into())
@@ -180,7 +225,11 @@ impl<'a> ParserContext<'a> {
 
     /// Eats the expected `token`, or errors.
     pub(super) fn expect(&mut self, token: &Token) -> Result<Span> {
-        if self.eat(token) { Ok(self.prev_token.span) } else { self.unexpected(token) }
+        if self.eat(token) {
+            Ok(self.prev_token.span)
+        } else {
+            self.unexpected(token)
+        }
     }
 
     /// Eats one of the expected `tokens`, or errors.
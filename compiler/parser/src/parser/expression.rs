@@ -36,21 +36,31 @@ const INT_TYPES: &[Token] = &[
     Token::Scalar,
 ];
 
+/// The maximum number of expressions that may be nested in one another, e.g. in parentheses or as arguments.
+/// Nested expressions are parsed recursively, so deeper inputs are rejected instead of overflowing the stack.
+pub(crate) const MAX_EXPRESSION_DEPTH: usize = 64;
+
 impl ParserContext<'_> {
     /// Returns an [`Expression`] AST node if the next token is an expression.
     /// Includes struct init expressions.
     pub(crate) fn parse_expression(&mut self) -> Result<Expression> {
+        if self.expression_depth == MAX_EXPRESSION_DEPTH {
+            return Err(ParserError::expression_too_deep(MAX_EXPRESSION_DEPTH, self.token.span).into());
+        }
+
         // Store current parser state.
         let prior_fuzzy_state = self.disallow_struct_construction;
 
         // Allow struct init expressions.
         self.disallow_struct_construction = false;
+        self.expression_depth += 1;
 
         // Parse expression.
         let result = self.parse_conditional_expression();
 
         // Restore prior parser state.
         self.disallow_struct_construction = prior_fuzzy_state;
+        self.expression_depth -= 1;
 
         result
     }
@@ -558,7 +568,7 @@ impl ParserContext<'_> {
             // If the array expression is empty, return an error.
             true => Err(ParserError::array_must_have_at_least_one_element("expression", span).into()),
             // Otherwise, return an array expression.
-            // Note: This is the only place where `ArrayExpression` is constructed in the parser.
+            // Note: Besides bit strings, this is the only place where `ArrayExpression` is constructed in the parser.
             false => Ok(Expression::Array(ArrayExpression { elements, span, id: self.node_builder.next_id() })),
         }
     }
@@ -653,7 +663,7 @@ impl ParserContext<'_> {
     }
 
     /// Returns an [`Expression`] AST node if the next token is a primary expression:
-    /// - Literals: field, group, unsigned integer, signed integer, boolean, address
+    /// - Literals: field, group, unsigned integer, signed integer, boolean, address, bit string
     /// - Aggregate types: array, tuple
     /// - Identifiers: variables, keywords
     /// - self
@@ -703,6 +713,15 @@ impl ParserContext<'_> {
                     None => return Err(ParserError::implicit_values_not_allowed(value, span).into()),
                 }
             }
+            // A bit string, e.g. `0b1011`, is an array of booleans, with the most significant bit first.
+            Token::BitString(bits) => Expression::Array(ArrayExpression {
+                elements: bits
+                    .chars()
+                    .map(|bit| Expression::Literal(Literal::Boolean(bit == '1', span, self.node_builder.next_id())))
+                    .collect(),
+                span,
+                id: self.node_builder.next_id(),
+            }),
             Token::True => Expression::Literal(Literal::Boolean(true, span, self.node_builder.next_id())),
             Token::False => Expression::Literal(Literal::Boolean(false, span, self.node_builder.next_id())),
             Token::AddressLit(address_string) => {
@@ -750,3 +769,70 @@ fn assert_no_whitespace(left_span: Span, right_span: Span, left: &str, right: &s
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use leo_ast::{ConstantInterpreter, Expression, Literal, NodeBuilder, NodeID};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn bit_strings_are_parsed_as_boolean_arrays() {
+        create_session_if_not_set_then(|_| {
+            let bits = match crate::parse_test_expression("0b10_11") {
+                Expression::Array(array) => array
+                    .elements
+                    .iter()
+                    .map(|element| match element {
+                        Expression::Literal(Literal::Boolean(bit, ..)) => *bit,
+                        element => panic!("`{element}` is not a boolean literal"),
+                    })
+                    .collect::<Vec<_>>(),
+                expression => panic!("`{expression}` is not an array"),
+            };
+            assert_eq!(bits, [true, false, true, true]);
+
+            let (handler, node_builder) = (Handler::default(), NodeBuilder::default());
+            for source in ["0b", "0b_", "0b102", "0b11u8"] {
+                assert!(
+                    crate::parse_expression_text(&handler, &node_builder, source).is_err(),
+                    "`{source}` was accepted"
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn negations_of_literals_are_folded_into_the_literal() {
+        create_session_if_not_set_then(|_| {
+            for (source, expected) in
+                [("-5i32", "-5i32"), ("-1field", "-1field"), ("-2group", "-2group"), ("-128i8", "-128i8")]
+            {
+                match crate::parse_test_expression(source) {
+                    Expression::Literal(literal) => assert_eq!(literal.to_string(), expected),
+                    expression => panic!("`{source}` was parsed as `{expression}`, which is not a literal"),
+                }
+            }
+
+            // Only the innermost negation is folded, and the others are kept as operations.
+            let parsed = crate::parse_test_expression("--5i32");
+            assert_eq!(parsed.to_sexpr(), "(neg -5i32)");
+            let value = ConstantInterpreter::default().evaluate(&parsed).unwrap();
+            assert_eq!(Literal::from_value(value, NodeID::default()).to_string(), "5i32");
+        });
+    }
+
+    #[test]
+    fn deeply_nested_expressions_are_rejected() {
+        create_session_if_not_set_then(|_| {
+            let nested = |depth: usize| format!("{}a{}", "(".repeat(depth), ")".repeat(depth));
+            let (handler, node_builder) = (Handler::default(), NodeBuilder::default());
+
+            // The outermost expression is not in parentheses, so it adds a level.
+            let limit = super::MAX_EXPRESSION_DEPTH;
+            assert!(crate::parse_expression_text(&handler, &node_builder, &nested(limit - 1)).is_ok());
+            let err = crate::parse_expression_text(&handler, &node_builder, &nested(10_000)).unwrap_err();
+            assert!(err.to_string().contains("nested more than"), "{err}");
+        });
+    }
+}
@@ -16,12 +16,29 @@
 
 use super::*;
 
-use leo_errors::{ParserError, Result};
+use leo_errors::{LeoError, ParserError, Result};
+
+/// The tokens that can start an item of a program scope.
+const ITEM_START_TOKENS: &[Token] = &[
+    Token::Const,
+    Token::Struct,
+    Token::Record,
+    Token::Mapping,
+    Token::Macro,
+    Token::At,
+    Token::Function,
+    Token::Transition,
+    Token::Inline,
+];
+
+/// The tokens that can start an item of a module.
+const MODULE_ITEM_START_TOKENS: &[Token] = &[Token::Import, Token::Struct, Token::Function, Token::Inline];
 
 impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
     pub fn parse_program(&mut self) -> Result<Program> {
         let mut imports = IndexMap::new();
+        let mut module_imports = Vec::new();
         let mut program_scopes = IndexMap::new();
 
         // TODO: Remove restrictions on multiple program scopes
@@ -29,6 +46,15 @@ impl ParserContext<'_> {
 
         while self.has_next() {
             match &self.token.token {
+                // An import of items from a module, e.g. `import lib.math.Point;`, as opposed to `import foo.aleo;`.
+                Token::Import
+                    if matches!(
+                        self.look_ahead(3, |t| &t.token),
+                        Token::Identifier(_) | Token::LeftCurly | Token::Mul
+                    ) =>
+                {
+                    module_imports.push(self.parse_module_import()?);
+                }
                 Token::Import => {
                     let (id, import) = self.parse_import()?;
                     imports.insert(id, import);
@@ -53,7 +79,23 @@ impl ParserContext<'_> {
             return Err(ParserError::missing_program_scope(self.token.span).into());
         }
 
-        Ok(Program { imports, stubs: IndexMap::new(), program_scopes })
+        Ok(Program { imports, module_imports, stubs: IndexMap::new(), program_scopes })
+    }
+
+    /// Returns a [`Module`] AST if all tokens can be consumed and represent a valid Leo module.
+    pub fn parse_module(&mut self) -> Result<Module> {
+        let mut module = Module::default();
+
+        while self.has_next() {
+            match &self.token.token {
+                Token::Import => module.imports.push(self.parse_module_import()?),
+                Token::Struct => module.structs.push(self.parse_struct(Vec::new())?),
+                Token::Function | Token::Inline => module.functions.push(self.parse_function(Vec::new())?),
+                _ => return Err(Self::unexpected_item(&self.token, MODULE_ITEM_START_TOKENS).into()),
+            }
+        }
+
+        Ok(module)
     }
 
     fn unexpected_item(token: &SpannedToken, expected: &[Token]) -> ParserError {
@@ -87,6 +129,57 @@ impl ParserContext<'_> {
         Ok((import_name.name, (Program::default(), start + end)))
     }
 
+    /// Parses an import of an item from a module `import lib.math.Point as P;`.
+    fn parse_module_import(&mut self) -> Result<ModuleImport> {
+        // Parse `import`.
+        let start = self.expect(&Token::Import)?;
+
+        // Parse the path of the module, e.g. `lib.math`.
+        let mut path = vec![self.expect_identifier()?];
+        self.expect(&Token::Dot)?;
+
+        // Parse the items, e.g. `Point as P`, `{Point, double}`, or `*`, extending the path until they are found.
+        let items = loop {
+            match &self.token.token {
+                Token::Mul => break ImportedItems::Glob(self.expect(&Token::Mul)?),
+                Token::LeftCurly => {
+                    let (items, _, _) =
+                        self.parse_list(Delimiter::Brace, Some(Token::Comma), |p| p.parse_imported_item().map(Some))?;
+                    break ImportedItems::List(items);
+                }
+                _ => {
+                    let identifier = self.expect_identifier()?;
+                    match self.eat(&Token::Dot) {
+                        true => path.push(identifier),
+                        false => {
+                            let alias = self.parse_import_alias()?;
+                            break ImportedItems::List(vec![ImportedItem { item: identifier, alias }]);
+                        }
+                    }
+                }
+            }
+        };
+
+        let end = self.expect(&Token::Semicolon)?;
+
+        Ok(ModuleImport { path, items, span: start + end })
+    }
+
+    /// Parses an item in a list of imported items, e.g. `double as dbl`.
+    fn parse_imported_item(&mut self) -> Result<ImportedItem> {
+        let item = self.expect_identifier()?;
+        let alias = self.parse_import_alias()?;
+        Ok(ImportedItem { item, alias })
+    }
+
+    /// Parses `as P`, if it exists.
+    fn parse_import_alias(&mut self) -> Result<Option<Identifier>> {
+        match self.eat(&Token::As) {
+            true => Ok(Some(self.expect_identifier()?)),
+            false => Ok(None),
+        }
+    }
+
     /// Parses a program scope `program foo.aleo { ... }`.
     fn parse_program_scope(&mut self) -> Result<ProgramScope> {
         // Parse `program` keyword.
@@ -113,45 +206,89 @@ impl ParserContext<'_> {
         let mut functions: Vec<(Symbol, Function)> = Vec::new();
         let mut structs: Vec<(Symbol, Struct)> = Vec::new();
         let mut mappings: Vec<(Symbol, Mapping)> = Vec::new();
+        let mut macros: Vec<(Symbol, Macro)> = Vec::new();
+
+        // Whether an item failed to parse and the parser skipped ahead to the next one.
+        let mut recovered = false;
+        // The number of curly braces that the items are nested in.
+        let scope_depth = self.curly_depth;
 
         while self.has_next() {
-            match &self.token.token {
-                Token::Const => {
-                    let declaration = self.parse_const_declaration_statement()?;
+            let item_start = self.token.span;
+            let item = match &self.token.token {
+                Token::Const => self.parse_const_declaration_statement().map(|declaration| {
                     consts.push((Symbol::intern(&declaration.place.to_string()), declaration));
-                }
-                Token::Struct | Token::Record => {
-                    let (id, struct_) = self.parse_struct()?;
-                    structs.push((id, struct_));
-                }
-                Token::Mapping => {
-                    let (id, mapping) = self.parse_mapping()?;
-                    mappings.push((id, mapping));
-                }
-                Token::At | Token::Function | Token::Transition | Token::Inline => {
-                    let (id, function) = self.parse_function()?;
-                    functions.push((id, function));
+                }),
+                Token::At => self.parse_annotations().and_then(|mut annotations| {
+                    // Items annotated with `@cfg(feature = "...")` are dropped if the feature is not enabled.
+                    let enabled = self.resolve_cfg(&mut annotations)?;
+                    match self.token.token {
+                        Token::Struct | Token::Record => {
+                            let struct_ = self.parse_struct(annotations)?;
+                            if enabled {
+                                structs.push(struct_);
+                            }
+                        }
+                        _ => {
+                            let function = self.parse_function(annotations)?;
+                            if enabled {
+                                functions.push(function);
+                            }
+                        }
+                    }
+                    Ok(())
+                }),
+                Token::Struct | Token::Record => self.parse_struct(Vec::new()).map(|struct_| structs.push(struct_)),
+                Token::Mapping => self.parse_mapping().map(|mapping| mappings.push(mapping)),
+                Token::Macro => self.parse_macro().map(|macro_| macros.push(macro_)),
+                Token::Function | Token::Transition | Token::Inline => {
+                    self.parse_function(Vec::new()).map(|function| functions.push(function))
                 }
                 Token::RightCurly => break,
-                _ => {
-                    return Err(Self::unexpected_item(&self.token, &[
-                        Token::Struct,
-                        Token::Record,
-                        Token::Mapping,
-                        Token::At,
-                        Token::Function,
-                        Token::Transition,
-                        Token::Inline,
-                    ])
-                    .into());
-                }
+                _ => Err(Self::unexpected_item(&self.token, ITEM_START_TOKENS).into()),
+            };
+
+            if let Err(err) = item {
+                self.recover_item(item_start, scope_depth, err)?;
+                recovered = true;
             }
         }
 
         // Parse `}`.
         let end = self.expect(&Token::RightCurly)?;
 
-        Ok(ProgramScope { program_id, consts, functions, structs, mappings, span: start + end })
+        // The errors of the skipped items have already been emitted.
+        if recovered {
+            self.handler.last_err().map_err(|e| *e)?;
+        }
+
+        Ok(ProgramScope { program_id, consts, functions, structs, mappings, macros, span: start + end })
+    }
+
+    /// Recovers from `err`, raised while parsing the program scope item starting at `item_start`,
+    /// by skipping to the start of the next item so that it can be checked as well.
+    /// The items of the program scope are nested in `scope_depth` curly braces.
+    /// Returns `err` if no item follows, and emits it otherwise.
+    fn recover_item(&mut self, item_start: Span, scope_depth: usize, err: LeoError) -> Result<()> {
+        // Always make progress, even if the item was rejected at its first token.
+        if self.token.span == item_start && self.has_next() {
+            self.bump();
+        }
+
+        // Skip ahead, ignoring keywords which are part of a path, e.g. `token.aleo/token.record`,
+        // and constants which are declared in the body of a function rather than in the program scope.
+        while !ITEM_START_TOKENS.contains(&self.token.token)
+            || self.prev_token.token == Token::Dot
+            || (self.token.token == Token::Const && self.curly_depth > scope_depth)
+        {
+            if !self.has_next() {
+                return Err(err);
+            }
+            self.bump();
+        }
+
+        self.handler.emit_err(err);
+        Ok(())
     }
 
     /// Returns a [`Vec<Member>`] AST node if the next tokens represent a struct member.
@@ -195,15 +332,18 @@ impl ParserContext<'_> {
 
     /// Returns a [`Member`] AST node if the next tokens represent a struct member variable.
     fn parse_member_variable_declaration(&mut self) -> Result<Member> {
+        let docs = self.take_docs(self.token.span.lo);
         let mode = self.parse_mode()?;
 
         let (identifier, type_, span) = self.parse_typed_ident()?;
 
-        Ok(Member { mode, identifier, type_, span, id: self.node_builder.next_id() })
+        Ok(Member { docs, mode, identifier, type_, span, id: self.node_builder.next_id() })
     }
 
-    /// Parses a struct or record definition, e.g., `struct Foo { ... }` or `record Foo { ... }`.
-    pub(super) fn parse_struct(&mut self) -> Result<(Symbol, Struct)> {
+    /// Parses a struct or record definition, e.g., `struct Foo { ... }` or `record Foo { ... }`,
+    /// given the annotations that precede it.
+    pub(super) fn parse_struct(&mut self, annotations: Vec<Annotation>) -> Result<(Symbol, Struct)> {
+        let docs = self.take_docs(annotations.first().map_or(self.token.span, |annotation| annotation.span).lo);
         let is_record = matches!(&self.token.token, Token::Record);
         let start = self.expect_any(&[Token::Struct, Token::Record])?;
 
@@ -219,6 +359,8 @@ impl ParserContext<'_> {
         let (members, end) = self.parse_struct_members()?;
 
         Ok((struct_name.name, Struct {
+            annotations,
+            docs,
             identifier: struct_name,
             members,
             is_record,
@@ -245,6 +387,24 @@ impl ParserContext<'_> {
         }))
     }
 
+    /// Returns an [`(Symbol, Macro)`] AST node if the next tokens represent a macro definition,
+    /// e.g. `macro rotl(x, n) { (x << n) | (x >> (32u32 - n)) }`.
+    pub(super) fn parse_macro(&mut self) -> Result<(Symbol, Macro)> {
+        let start = self.expect(&Token::Macro)?;
+        let identifier = self.expect_identifier()?;
+        let (parameters, ..) = self.parse_paren_comma_list(|p| p.expect_identifier().map(Some))?;
+        self.expect(&Token::LeftCurly)?;
+        let body = self.parse_expression()?;
+        let end = self.expect(&Token::RightCurly)?;
+        Ok((identifier.name, Macro {
+            identifier,
+            parameters,
+            body,
+            span: start + end,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
     // TODO: Return a span associated with the mode.
     /// Returns a [`ParamMode`] AST node if the next tokens represent a function parameter mode.
     pub(super) fn parse_mode(&mut self) -> Result<Mode> {
@@ -301,12 +461,15 @@ impl ParserContext<'_> {
                 id: self.node_builder.next_id(),
             }))
         } else {
+            // Parse the `&` of a parameter that is passed by reference.
+            let reference = self.eat(&Token::BitAnd);
             let type_ = self.parse_type()?.0;
 
             Ok(functions::Input::Internal(FunctionInput {
                 identifier: name,
                 mode,
                 type_,
+                reference,
                 span: name.span,
                 id: self.node_builder.next_id(),
             }))
@@ -356,6 +519,16 @@ impl ParserContext<'_> {
         matches!((&self.token.token, self.look_ahead(1, |t| &t.token)), (Token::Identifier(_), Token::Dot))
     }
 
+    /// Returns the [`Annotation`] AST nodes that precede an item or a statement, if any.
+    pub(super) fn parse_annotations(&mut self) -> Result<Vec<Annotation>> {
+        // TODO: Handle dangling annotations.
+        let mut annotations = Vec::new();
+        while self.check(&Token::At) {
+            annotations.push(self.parse_annotation()?)
+        }
+        Ok(annotations)
+    }
+
     /// Returns an [`Annotation`] AST node if the next tokens represent an annotation.
     fn parse_annotation(&mut self) -> Result<Annotation> {
         // Parse the `@` symbol and identifier.
@@ -364,27 +537,70 @@ impl ParserContext<'_> {
             Token::Program => {
                 Identifier { name: sym::program, span: self.expect(&Token::Program)?, id: self.node_builder.next_id() }
             }
+            Token::Inline => {
+                Identifier { name: sym::inline, span: self.expect(&Token::Inline)?, id: self.node_builder.next_id() }
+            }
             _ => self.expect_identifier()?,
         };
         let span = start + identifier.span;
 
         // TODO: Verify that this check is sound.
         // Check that there is no whitespace in between the `@` symbol and identifier.
-        match identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
-            true => Err(ParserError::space_in_annotation(span).into()),
-            false => Ok(Annotation { identifier, span, id: self.node_builder.next_id() }),
+        if identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
+            return Err(ParserError::space_in_annotation(span).into());
         }
+
+        // Parse the arguments, if any, e.g. `(8)` in `@recursive(8)`.
+        let (arguments, span) = match self.check(&Token::LeftParen) {
+            true => {
+                let (arguments, _, arguments_span) =
+                    self.parse_paren_comma_list(|p| p.parse_annotation_argument().map(Some))?;
+                (arguments, span + arguments_span)
+            }
+            false => (Vec::new(), span),
+        };
+
+        Ok(Annotation { identifier, arguments, span, id: self.node_builder.next_id() })
     }
 
-    /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent a function name
-    /// and function definition.
-    fn parse_function(&mut self) -> Result<(Symbol, Function)> {
-        // TODO: Handle dangling annotations.
-        // Parse annotations, if they exist.
-        let mut annotations = Vec::new();
-        while self.look_ahead(0, |t| &t.token) == &Token::At {
-            annotations.push(self.parse_annotation()?)
+    /// Returns an [`AnnotationArgument`] if the next tokens represent one, e.g. `8` or `feature = "debug"`.
+    fn parse_annotation_argument(&mut self) -> Result<AnnotationArgument> {
+        if !matches!(self.token.token, Token::Identifier(_)) {
+            return Ok(AnnotationArgument::Number(self.eat_whole_number()?.0));
+        }
+        let key = self.expect_identifier()?;
+        self.expect(&Token::Assign)?;
+        match &self.token.token {
+            Token::StaticString(value) => {
+                let value = value.clone();
+                self.bump();
+                Ok(AnnotationArgument::KeyValue(key, value))
+            }
+            _ => self.unexpected("a string"),
+        }
+    }
+
+    /// Checks the `@cfg(feature = "...")` annotations among `annotations`, and returns whether all of their features
+    /// are enabled. If the features to compile with are given, the `@cfg` annotations are resolved and removed.
+    pub(super) fn resolve_cfg(&self, annotations: &mut Vec<Annotation>) -> Result<bool> {
+        let mut enabled = true;
+        for annotation in annotations.iter().filter(|annotation| annotation.identifier.name == sym::cfg) {
+            let feature =
+                annotation.cfg_feature().ok_or_else(|| ParserError::invalid_cfg_annotation(annotation.span))?;
+            if let Some(features) = &self.features {
+                enabled &= features.iter().any(|enabled| enabled == feature);
+            }
         }
+        if self.features.is_some() {
+            annotations.retain(|annotation| annotation.identifier.name != sym::cfg);
+        }
+        Ok(enabled)
+    }
+
+    /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent a function name
+    /// and function definition, given the annotations that precede it.
+    fn parse_function(&mut self, annotations: Vec<Annotation>) -> Result<(Symbol, Function)> {
+        let docs = self.take_docs(annotations.first().map_or(self.token.span, |annotation| annotation.span).lo);
         // Parse `<variant> IDENT`, where `<variant>` is `function`, `transition`, or `inline`.
         let (variant, start) = match self.token.token {
             Token::Inline => (Variant::Inline, self.expect(&Token::Inline)?),
@@ -457,21 +673,131 @@ impl ParserContext<'_> {
         };
 
         let span = start + block.span;
-        Ok((
-            name.name,
-            Function::new(
-                annotations,
-                variant,
-                name,
-                inputs,
-                output,
-                block,
-                finalize,
-                span,
-                self.node_builder.next_id(),
-            ),
-        ))
+        let function = Function::new(
+            annotations,
+            variant,
+            name,
+            inputs,
+            output,
+            block,
+            finalize,
+            span,
+            self.node_builder.next_id(),
+        );
+        Ok((name.name, Function { docs, ..function }))
     }
 }
 
 use leo_span::{sym, Symbol};
+
+#[cfg(test)]
+mod test {
+    use leo_ast::NodeBuilder;
+    use leo_errors::emitter::Handler;
+    use leo_span::{span::BytePos, symbol::create_session_if_not_set_then};
+
+    #[test]
+    fn doc_comments_are_attached_to_items() {
+        create_session_if_not_set_then(|_| {
+            let source = r#"program test.aleo {
+    /// A point.
+    struct Point {
+        /// The abscissa.
+        x: u8,
+        // Not a doc comment.
+        y: u8,
+    }
+
+    /// Returns the sum.
+    ///
+    /// Wraps on overflow.
+    @inline
+    function sum(a: u8, b: u8) -> u8 {
+        /// Not attached to anything.
+        return a.add_wrapped(b);
+    }
+
+    transition main(a: u8) -> u8 {
+        return sum(a, a);
+    }
+}
+"#;
+            let handler = Handler::default();
+            let program = crate::parse(&handler, &NodeBuilder::default(), source, BytePos(0)).unwrap();
+            let scope = program.program_scopes.values().next().unwrap();
+            let (_, point) = &scope.structs[0];
+            assert_eq!(point.docs, ["A point."]);
+            assert_eq!(point.members[0].docs, ["The abscissa."]);
+            assert!(point.members[1].docs.is_empty());
+            let (_, sum) = &scope.functions[0];
+            assert_eq!(sum.docs, ["Returns the sum.", "", "Wraps on overflow."]);
+            let (_, main) = &scope.functions[1];
+            assert!(main.docs.is_empty());
+        });
+    }
+
+    #[test]
+    fn cfg_annotations_are_resolved_against_features() {
+        create_session_if_not_set_then(|_| {
+            let source = r#"program test.aleo {
+    @cfg(feature = "debug")
+    function trace(a: u8) -> u8 {
+        return a;
+    }
+
+    transition main(a: u8) -> u8 {
+        @cfg(feature = "debug")
+        assert_eq(a, a);
+        return a;
+    }
+}
+"#;
+            let handler = Handler::default();
+            let parse = |features: &[String]| {
+                let program =
+                    crate::parse_with_features(&handler, &NodeBuilder::default(), source, BytePos(0), features)
+                        .unwrap();
+                let scope = program.program_scopes.into_values().next().unwrap();
+                let statements = scope.functions.last().unwrap().1.block.statements.len();
+                (scope.functions.len(), statements)
+            };
+            assert_eq!(parse(&[]), (1, 1));
+            assert_eq!(parse(&["debug".to_string()]), (2, 2));
+
+            // Without features, everything is kept, and the formatter puts the annotations back.
+            let program = crate::parse(&handler, &NodeBuilder::default(), source, BytePos(0)).unwrap();
+            let comments = crate::parse_comments(source, BytePos(0)).unwrap();
+            assert_eq!(comments.iter().count(), 1);
+            assert_eq!(leo_ast::format_program_with_comments(&program, comments), source);
+        });
+    }
+
+    #[test]
+    fn recovery_resumes_at_constants_of_the_program_scope() {
+        create_session_if_not_set_then(|_| {
+            let count_errors = |source: &str| {
+                let (handler, _) = Handler::new_with_buf();
+                assert!(crate::parse(&handler, &NodeBuilder::default(), source, BytePos(0)).is_err());
+                handler.err_count()
+            };
+
+            // The constant is checked after the struct fails to parse.
+            let source =
+                "program test.aleo {\n    struct Foo { x: }\n    const X: u8 = ;\n    transition main() {}\n}\n";
+            assert_eq!(count_errors(source), 2);
+
+            // The constants of a function are skipped along with the rest of its body.
+            let source = r#"program test.aleo {
+    transition main() -> u8 {
+        let a: u8 = ;
+        const B: u8 = 1u8;
+        return B;
+    }
+
+    const X: u8 = 1u8;
+}
+"#;
+            assert_eq!(count_errors(source), 1);
+        });
+    }
+}
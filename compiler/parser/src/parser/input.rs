@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_errors::Result;
+
+impl ParserContext<'_> {
+    /// Returns an [`InputAst`] if all tokens can be consumed and represent a valid input file.
+    pub(crate) fn parse_input_file(&mut self) -> Result<InputAst> {
+        let mut sections = Vec::new();
+
+        while self.has_next() {
+            match &self.token.token {
+                Token::LeftSquare => sections.push(self.parse_section()?),
+                _ => return self.unexpected("'['"),
+            }
+        }
+
+        Ok(InputAst { sections })
+    }
+
+    /// Parses a section of an input file, e.g. `[main]` and the definitions that follow it.
+    fn parse_section(&mut self) -> Result<Section> {
+        let start = self.expect(&Token::LeftSquare)?;
+        let name = self.expect_identifier()?;
+        let end = self.expect(&Token::RightSquare)?;
+
+        let mut definitions = Vec::new();
+        while self.has_next() && !self.check(&Token::LeftSquare) {
+            definitions.push(self.parse_input_definition()?);
+        }
+
+        Ok(Section { name, definitions, span: start + end })
+    }
+
+    /// Parses a definition in an input file, e.g. `public a: u32 = 1u32;` or `p: Point = { x: 1u32, y: 2u32 };`.
+    fn parse_input_definition(&mut self) -> Result<Definition> {
+        let start = self.token.span;
+        let mode = self.parse_mode()?;
+        let name = self.expect_identifier()?;
+        self.expect(&Token::Colon)?;
+        let (type_, _) = self.parse_type()?;
+        self.expect(&Token::Assign)?;
+        let value = match &type_ {
+            // A struct value may omit the name of the struct, e.g. `p: Point = { x: 1u32, y: 2u32 };`.
+            Type::Identifier(identifier) if self.check(&Token::LeftCurly) => {
+                self.parse_struct_init_expression(Identifier { span: self.token.span, ..*identifier })?
+            }
+            _ => self.parse_expression()?,
+        };
+        let end = self.expect(&Token::Semicolon)?;
+
+        Ok(Definition { mode, name, type_, value, span: start + end })
+    }
+}
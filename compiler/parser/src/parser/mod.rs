@@ -33,7 +33,9 @@ mod context;
 pub(super) use context::ParserContext;
 
 mod expression;
+pub(crate) use expression::MAX_EXPRESSION_DEPTH;
 mod file;
+mod input;
 mod statement;
 pub(super) mod type_;
 
@@ -43,3 +45,84 @@ pub fn parse(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_
 
     tokens.parse_program()
 }
+
+/// Creates a new program from a given file path and source code text, keeping only the items and statements
+/// whose `@cfg(feature = "...")` annotations name one of the given features.
+pub fn parse_with_features(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+    features: &[String],
+) -> Result<Program> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+    tokens.features = Some(features.to_vec());
+
+    tokens.parse_program()
+}
+
+/// Creates a new input file AST from a given file path and source code text.
+pub fn parse_input(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<InputAst> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+
+    tokens.parse_input_file()
+}
+
+/// Creates a new module from a given file path and source code text.
+pub fn parse_module(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<Module> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+
+    tokens.parse_module()
+}
+
+/// Creates a new module from a given file path and source code text, keeping only the items and statements
+/// whose `@cfg(feature = "...")` annotations name one of the given features.
+pub fn parse_module_with_features(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+    features: &[String],
+) -> Result<Module> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+    tokens.features = Some(features.to_vec());
+
+    tokens.parse_module()
+}
+
+/// Creates a new expression from the given source code text, which must consist of exactly one expression.
+pub fn parse_expression(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<Expression> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+
+    let expression = tokens.parse_expression()?;
+    if tokens.has_next() {
+        return tokens.unexpected("end of expression");
+    }
+    Ok(expression)
+}
+
+/// Creates a new statement from the given source code text, which must consist of exactly one statement.
+pub fn parse_statement(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<Statement> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+
+    let statement = tokens.parse_statement()?;
+    if tokens.has_next() {
+        return tokens.unexpected("end of statement");
+    }
+    Ok(statement)
+}
@@ -43,7 +43,9 @@ impl ParserContext<'_> {
             Token::Return => Ok(Statement::Return(self.parse_return_statement()?)),
             Token::If => Ok(Statement::Conditional(self.parse_conditional_statement()?)),
             Token::For => Ok(Statement::Iteration(Box::new(self.parse_loop_statement()?))),
-            Token::Assert | Token::AssertEq | Token::AssertNeq => Ok(self.parse_assert_statement()?),
+            Token::Assert | Token::AssertEq | Token::AssertNeq | Token::ConstAssert => {
+                Ok(self.parse_assert_statement()?)
+            }
             Token::Let => Ok(Statement::Definition(self.parse_definition_statement()?)),
             Token::Const => Ok(Statement::Const(self.parse_const_declaration_statement()?)),
             Token::LeftCurly => Ok(Statement::Block(self.parse_block()?)),
@@ -53,6 +55,18 @@ impl ParserContext<'_> {
         }
     }
 
+    /// Returns a [`Statement`] AST node if the next tokens represent a statement, which may be annotated with
+    /// `@cfg(feature = "...")`, or `None` if the feature of the annotation is not enabled.
+    fn parse_annotated_statement(&mut self) -> Result<Option<Statement>> {
+        let mut annotations = self.parse_annotations()?;
+        if let Some(annotation) = annotations.iter().find(|annotation| annotation.identifier.name != sym::cfg) {
+            return Err(ParserError::invalid_statement_annotation(annotation, annotation.span).into());
+        }
+        let enabled = self.resolve_cfg(&mut annotations)?;
+        let statement = self.parse_statement()?;
+        Ok(enabled.then_some(statement))
+    }
+
     /// Returns a [`AssertStatement`] AST node if the next tokens represent an assertion statement.
     fn parse_assert_statement(&mut self) -> Result<Statement> {
         // Check which variant of the assert statement is being used.
@@ -60,22 +74,24 @@ impl ParserContext<'_> {
         let is_assert = self.check(&Token::Assert);
         let is_assert_eq = self.check(&Token::AssertEq);
         let is_assert_neq = self.check(&Token::AssertNeq);
+        let is_const_assert = self.check(&Token::ConstAssert);
         // Parse the span of the assertion statement.
-        let span = self.expect_any(&[Token::Assert, Token::AssertEq, Token::AssertNeq])?;
+        let span = self.expect_any(&[Token::Assert, Token::AssertEq, Token::AssertNeq, Token::ConstAssert])?;
         // Parse the left parenthesis token.
         self.expect(&Token::LeftParen)?;
         // Parse the variant.
-        let variant = match (is_assert, is_assert_eq, is_assert_neq) {
-            (true, false, false) => AssertVariant::Assert(self.parse_expression()?),
-            (false, true, false) => AssertVariant::AssertEq(self.parse_expression()?, {
+        let variant = match (is_assert, is_assert_eq, is_assert_neq, is_const_assert) {
+            (true, false, false, false) => AssertVariant::Assert(self.parse_expression()?),
+            (false, true, false, false) => AssertVariant::AssertEq(self.parse_expression()?, {
                 self.expect(&Token::Comma)?;
                 self.parse_expression()?
             }),
-            (false, false, true) => AssertVariant::AssertNeq(self.parse_expression()?, {
+            (false, false, true, false) => AssertVariant::AssertNeq(self.parse_expression()?, {
                 self.expect(&Token::Comma)?;
                 self.parse_expression()?
             }),
-            _ => unreachable!("The call the `expect_any` ensures that only one of the three tokens is true."),
+            (false, false, false, true) => AssertVariant::ConstAssert(self.parse_expression()?),
+            _ => unreachable!("The call the `expect_any` ensures that only one of the four tokens is true."),
         };
         // Parse the right parenthesis token.
         self.expect(&Token::RightParen)?;
@@ -168,7 +184,7 @@ impl ParserContext<'_> {
 
     /// Returns a [`Block`] AST node if the next tokens represent a block of statements.
     pub(super) fn parse_block(&mut self) -> Result<Block> {
-        self.parse_list(Delimiter::Brace, None, |p| p.parse_statement().map(Some)).map(|(statements, _, span)| Block {
+        self.parse_list(Delimiter::Brace, None, |p| p.parse_annotated_statement()).map(|(statements, _, span)| Block {
             statements,
             span,
             id: self.node_builder.next_id(),
@@ -242,13 +258,30 @@ impl ParserContext<'_> {
         let type_ = self.parse_type()?;
         self.expect(&Token::In)?;
 
-        // Parse iteration range.
+        // Parse iteration range, which is parenthesized when followed by `.step(..)` or `.rev()`.
+        let parenthesized = self.check(&Token::LeftParen) && self.peek_parenthesized_range();
+        if parenthesized {
+            self.expect(&Token::LeftParen)?;
+        }
         let start = self.parse_expression()?;
         self.expect(&Token::DotDot)?;
         self.disallow_struct_construction = true;
         let stop = self.parse_conditional_expression()?;
         self.disallow_struct_construction = false;
 
+        // Parse the optional `.step(..)`, followed by the optional `.rev()`.
+        let (mut step, mut reverse) = (None, false);
+        if parenthesized {
+            self.expect(&Token::RightParen)?;
+            while let Some((name, argument)) = self.parse_loop_adapter()? {
+                match (name.name, argument) {
+                    (sym::step, Some(argument)) if step.is_none() && !reverse => step = Some(argument),
+                    (sym::rev, None) if !reverse => reverse = true,
+                    _ => return Err(ParserError::invalid_loop_adapter(name, name.span).into()),
+                }
+            }
+        }
+
         let block = self.parse_block()?;
 
         Ok(IterationStatement {
@@ -260,11 +293,43 @@ impl ParserContext<'_> {
             stop,
             stop_value: Default::default(),
             inclusive: false,
+            step,
+            step_value: Default::default(),
+            reverse,
             block,
             id: self.node_builder.next_id(),
         })
     }
 
+    /// Returns `true` if the next tokens are a parenthesized range, e.g. `(0u8..8u8)`.
+    fn peek_parenthesized_range(&self) -> bool {
+        let mut depth = 0usize;
+        let mut dist = 0;
+        loop {
+            match self.look_ahead(dist, |t| t.token.clone()) {
+                Token::LeftParen => depth += 1,
+                Token::RightParen if depth <= 1 => return false,
+                Token::RightParen => depth -= 1,
+                Token::DotDot if depth == 1 => return true,
+                Token::Eof => return false,
+                _ => {}
+            }
+            dist += 1;
+        }
+    }
+
+    /// Returns the name and the argument of a loop adapter `.name(argument?)`, if there is one.
+    fn parse_loop_adapter(&mut self) -> Result<Option<(Identifier, Option<Expression>)>> {
+        if !self.eat(&Token::Dot) {
+            return Ok(None);
+        }
+        let name = self.expect_identifier()?;
+        self.expect(&Token::LeftParen)?;
+        let argument = if self.check(&Token::RightParen) { None } else { Some(self.parse_expression()?) };
+        self.expect(&Token::RightParen)?;
+        Ok(Some((name, argument)))
+    }
+
     /// Returns a [`ConsoleStatement`] AST node if the next tokens represent a console statement.
     #[allow(dead_code)]
     fn parse_console_statement(&mut self) -> Result<ConsoleStatement> {
@@ -137,6 +137,18 @@ impl Namespace for ParseNamespace {
     }
 }
 
+struct InputNamespace;
+
+impl Namespace for InputNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        create_session_if_not_set_then(|s| with_handler(tokenize(test, s)?, |p| p.parse_input_file()).map(yaml_or_fail))
+    }
+}
+
 struct SerializeNamespace;
 
 // Helper functions to recursively filter keys from AST JSON.
@@ -207,6 +219,7 @@ struct TestRunner;
 impl Runner for TestRunner {
     fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
         Some(match name {
+            "Input" => Box::new(InputNamespace),
             "Parse" => Box::new(ParseNamespace),
             "ParseExpression" => Box::new(ParseExpressionNamespace),
             "ParseStatement" => Box::new(ParseStatementNamespace),
@@ -165,6 +165,20 @@ impl Token {
 
         // Note that it is still impossible to have a number that starts with an `_` because eat_integer is only called when the first character is a digit.
         while let Some(c) = input.next_if(|c| c.is_ascii_digit() || *c == '_') {
+            // A bit string, e.g. `0b1011`, is a literal of an array of booleans.
+            if c == '0' && int.is_empty() && matches!(input.peek(), Some('b')) {
+                input.next();
+                let mut bits = String::new();
+                while let Some(c) = input.next_if(|c| c.is_ascii_alphanumeric() || *c == '_') {
+                    bits.push(c);
+                }
+                let digits = bits.chars().filter(|c| *c != '_').collect::<String>();
+                if digits.is_empty() || !digits.chars().all(|c| c == '0' || c == '1') {
+                    return Err(ParserError::lexer_invalid_bit_string(format!("0b{bits}")).into());
+                }
+                return Ok((bits.len() + 2, Token::BitString(digits)));
+            }
+
             if c == '0' && matches!(input.peek(), Some('x')) {
                 int.push(c);
                 int.push(input.next().unwrap());
@@ -388,6 +402,7 @@ impl Token {
                     "bool" => Token::Bool,
                     "console" => Token::Console,
                     "const" => Token::Const,
+                    "const_assert" => Token::ConstAssert,
                     "constant" => Token::Constant,
                     "else" => Token::Else,
                     "false" => Token::False,
@@ -407,6 +422,7 @@ impl Token {
                     "inline" => Token::Inline,
                     "let" => Token::Let,
                     "leo" => Token::Leo,
+                    "macro" => Token::Macro,
                     "mapping" => Token::Mapping,
                     "private" => Token::Private,
                     "program" => Token::Program,
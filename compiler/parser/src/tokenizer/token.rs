@@ -29,6 +29,7 @@ pub enum Token {
     StaticString(String),
     Identifier(Symbol),
     Integer(String),
+    BitString(String),
     True,
     False,
     AddressLit(String),
@@ -115,6 +116,7 @@ pub enum Token {
     AssertNeq,
     Console,
     Const,
+    ConstAssert,
     Constant,
     Else,
     Finalize,
@@ -125,6 +127,7 @@ pub enum Token {
     In,
     Inline,
     Let,
+    Macro,
     Mapping,
     Private,
     Program,
@@ -158,6 +161,7 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::Bool,
     Token::Console,
     Token::Const,
+    Token::ConstAssert,
     Token::Constant,
     Token::Else,
     Token::False,
@@ -176,6 +180,7 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::In,
     Token::Inline,
     Token::Let,
+    Token::Macro,
     Token::Mapping,
     Token::Private,
     Token::Program,
@@ -216,6 +221,7 @@ impl Token {
             Token::Bool => sym::bool,
             Token::Console => sym::console,
             Token::Const => sym::Const,
+            Token::ConstAssert => sym::const_assert,
             Token::Constant => sym::constant,
             Token::Else => sym::Else,
             Token::False => sym::False,
@@ -235,6 +241,7 @@ impl Token {
             Token::Inline => sym::inline,
             Token::Let => sym::Let,
             Token::Leo => sym::leo,
+            Token::Macro => sym::Macro,
             Token::Mapping => sym::mapping,
             Token::Private => sym::private,
             Token::Program => sym::program,
@@ -268,6 +275,7 @@ impl fmt::Display for Token {
             StaticString(s) => write!(f, "\"{s}\""),
             Identifier(s) => write!(f, "{s}"),
             Integer(s) => write!(f, "{s}"),
+            BitString(s) => write!(f, "0b{s}"),
             True => write!(f, "true"),
             False => write!(f, "false"),
             AddressLit(s) => write!(f, "{s}"),
@@ -351,6 +359,7 @@ impl fmt::Display for Token {
             AssertNeq => write!(f, "assert_neq"),
             Console => write!(f, "console"),
             Const => write!(f, "const"),
+            ConstAssert => write!(f, "const_assert"),
             Constant => write!(f, "constant"),
             Else => write!(f, "else"),
             Finalize => write!(f, "finalize"),
@@ -361,6 +370,7 @@ impl fmt::Display for Token {
             In => write!(f, "in"),
             Inline => write!(f, "inline"),
             Let => write!(f, "let"),
+            Macro => write!(f, "macro"),
             Mapping => write!(f, "mapping"),
             Private => write!(f, "private"),
             Program => write!(f, "program"),
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{diff_programs, expr::parse_sexpr, format_program, Expression, NodeBuilder, Program};
+use leo_errors::emitter::Handler;
+use leo_parser::{parse, parse_expression_text};
+use leo_span::{span::BytePos, symbol::create_session_if_not_set_then};
+
+use proptest::{prelude::*, test_runner::TestRunner};
+
+#[test]
+fn arbitrary_expressions_round_trip() {
+    create_session_if_not_set_then(|_| {
+        let (handler, node_builder) = (Handler::default(), NodeBuilder::default());
+        TestRunner::default()
+            .run(&any::<Expression>(), |expression| {
+                let printed = expression.to_string();
+                let reparsed = parse_expression_text(&handler, &node_builder, &printed);
+                prop_assert!(reparsed.is_ok(), "`{printed}` does not parse");
+                let reparsed = reparsed.unwrap();
+                prop_assert!(expression.eq_structural(&reparsed), "`{printed}` parses as `{reparsed}`");
+
+                let sexpr = expression.to_sexpr();
+                let round_tripped = parse_sexpr(&sexpr);
+                prop_assert!(round_tripped.is_ok(), "`{sexpr}` does not parse");
+                prop_assert!(expression.eq_structural(&round_tripped.unwrap()), "`{sexpr}` did not round trip");
+                Ok(())
+            })
+            .unwrap();
+    });
+}
+
+#[test]
+fn arbitrary_programs_round_trip() {
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+        TestRunner::default()
+            .run(&any::<Program>(), |program| {
+                let printed = format_program(&program);
+                let reparsed = parse(&handler, &NodeBuilder::default(), &printed, BytePos(0));
+                prop_assert!(reparsed.is_ok(), "the program does not parse:\n{printed}");
+                let reparsed = reparsed.unwrap();
+                prop_assert_eq!(&format_program(&reparsed), &printed);
+                prop_assert!(diff_programs(&program, &reparsed).is_empty());
+                Ok(())
+            })
+            .unwrap();
+    });
+}
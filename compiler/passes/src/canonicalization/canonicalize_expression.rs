@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Canonicalizer;
+
+use leo_ast::{
+    BinaryExpression,
+    BinaryOperation,
+    Expression,
+    ExpressionReconstructor,
+    Literal,
+    UnaryExpression,
+    UnaryOperation,
+};
+
+impl ExpressionReconstructor for Canonicalizer {
+    type AdditionalOutput = ();
+
+    /// Reconstructs the operands, moving a literal operand of a commutative operation to the right.
+    /// Boolean operations with a literal operand are folded.
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let left = self.reconstruct_expression(*input.left).0;
+        let right = self.reconstruct_expression(*input.right).0;
+
+        let swap = input.op.is_commutative()
+            && matches!(left, Expression::Literal(_))
+            && !matches!(right, Expression::Literal(_));
+        let (left, right) = if swap { (right, left) } else { (left, right) };
+
+        let expression = match (input.op, left, right) {
+            // `a && true`, `a || false`, `a == true` and `a != false` become `a`.
+            (BinaryOperation::And | BinaryOperation::Eq, left, Expression::Literal(Literal::Boolean(true, ..)))
+            | (BinaryOperation::Or | BinaryOperation::Neq, left, Expression::Literal(Literal::Boolean(false, ..))) => {
+                left
+            }
+            // `a && false` becomes `false` and `a || true` becomes `true`, unless evaluating `a` can fail.
+            (BinaryOperation::And, left, right @ Expression::Literal(Literal::Boolean(false, ..)))
+            | (BinaryOperation::Or, left, right @ Expression::Literal(Literal::Boolean(true, ..)))
+                if is_infallible(&left) =>
+            {
+                right
+            }
+            // `a == false` and `a != true` become `!a`.
+            (BinaryOperation::Eq, left, Expression::Literal(Literal::Boolean(false, ..)))
+            | (BinaryOperation::Neq, left, Expression::Literal(Literal::Boolean(true, ..))) => {
+                self.reconstruct_unary(UnaryExpression {
+                    receiver: Box::new(left),
+                    op: UnaryOperation::Not,
+                    span: input.span,
+                    id: input.id,
+                })
+                .0
+            }
+            (op, left, right) => Expression::Binary(BinaryExpression {
+                left: Box::new(left),
+                right: Box::new(right),
+                op,
+                span: input.span,
+                id: input.id,
+            }),
+        };
+
+        (expression, Default::default())
+    }
+
+    /// Reconstructs the receiver, folding negations of negations, of (in)equalities, and of boolean literals.
+    fn reconstruct_unary(&mut self, input: UnaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let receiver = self.reconstruct_expression(*input.receiver).0;
+
+        let expression = match (input.op, receiver) {
+            // `!!a` becomes `a`.
+            (UnaryOperation::Not, Expression::Unary(UnaryExpression { op: UnaryOperation::Not, receiver, .. })) => {
+                *receiver
+            }
+            // `!(a == b)` becomes `a != b`, and `!(a != b)` becomes `a == b`.
+            (
+                UnaryOperation::Not,
+                Expression::Binary(BinaryExpression {
+                    left,
+                    right,
+                    op: op @ (BinaryOperation::Eq | BinaryOperation::Neq),
+                    ..
+                }),
+            ) => Expression::Binary(BinaryExpression {
+                left,
+                right,
+                op: if op == BinaryOperation::Eq { BinaryOperation::Neq } else { BinaryOperation::Eq },
+                span: input.span,
+                id: input.id,
+            }),
+            // `!true` becomes `false`, and `!false` becomes `true`.
+            (UnaryOperation::Not, Expression::Literal(Literal::Boolean(value, ..))) => {
+                Expression::Literal(Literal::Boolean(!value, input.span, input.id))
+            }
+            (op, receiver) => {
+                Expression::Unary(UnaryExpression { receiver: Box::new(receiver), op, span: input.span, id: input.id })
+            }
+        };
+
+        (expression, Default::default())
+    }
+}
+
+/// Returns whether evaluating the expression cannot fail, i.e. whether it can be dropped without removing a check.
+/// This holds for literals, variables, and boolean operations and comparisons of them.
+fn is_infallible(expression: &Expression) -> bool {
+    match expression {
+        Expression::Literal(_) | Expression::Identifier(_) => true,
+        Expression::Unary(UnaryExpression { op: UnaryOperation::Not, receiver, .. }) => is_infallible(receiver),
+        Expression::Binary(BinaryExpression { left, right, op, .. }) => {
+            matches!(
+                op,
+                BinaryOperation::And
+                    | BinaryOperation::Or
+                    | BinaryOperation::Nand
+                    | BinaryOperation::Nor
+                    | BinaryOperation::Xor
+                    | BinaryOperation::Eq
+                    | BinaryOperation::Neq
+            ) && is_infallible(left)
+                && is_infallible(right)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The canonicalization pass rewrites equivalent expression forms into a single canonical representation.
+//! The pass is run after the type checking pass, before loop unrolling.
+//!
+//! The following rewrites are applied, innermost expressions first:
+//! - Operands of commutative operations are ordered so that a literal operand appears on the right, e.g. `1u8 + x` becomes `x + 1u8`.
+//! - Negated comparisons are folded into their complement, e.g. `!(a == b)` becomes `a != b` and `!(a != b)` becomes `a == b`.
+//! - Double negations are eliminated, e.g. `!!a` becomes `a`.
+//! - Boolean operations with a literal operand are folded, e.g. `a && true` becomes `a`, `a == false` becomes `!a`,
+//!   and `!true` becomes `false`. An operand is only dropped, as in `a || true`, if evaluating it cannot fail.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(a: u8, b: u8) -> bool {
+//!     let c: u8 = 1u8 + a;
+//!     return !!(!(c == b));
+//! }
+//! ```
+//!
+//! The canonicalization pass produces the following code.
+//! ```leo
+//! function main(a: u8, b: u8) -> bool {
+//!     let c: u8 = a + 1u8;
+//!     return c != b;
+//! }
+//! ```
+//!
+//! Rewritten expressions reuse the IDs of the expressions they replace, so the type table remains valid.
+
+mod canonicalize_expression;
+
+mod canonicalize_program;
+
+mod canonicalize_statement;
+
+pub mod canonicalizer;
+pub use canonicalizer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for Canonicalizer {
+    type Input = Ast;
+    type Output = Result<Ast>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut reconstructor = Canonicalizer::new();
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
@@ -17,10 +17,14 @@
 use crate::{CallGraph, StructGraph, SymbolTable, TypeTable};
 
 use leo_ast::{Function, Program, ProgramId};
-use leo_span::Symbol;
+use leo_span::{Span, Symbol};
 
 use indexmap::IndexMap;
 
+/// The spans of the expressions that the instructions of each function and closure body were generated from, in the
+/// order of the instructions. The instructions of `finalize` blocks are not included.
+pub type InstructionSpans = IndexMap<String, Vec<Span>>;
+
 pub struct CodeGenerator<'a> {
     /// The symbol table for the program.
     pub(crate) symbol_table: &'a SymbolTable,
@@ -53,6 +57,8 @@ pub struct CodeGenerator<'a> {
     pub(crate) program: &'a Program,
     // The program ID of the current program.
     pub(crate) program_id: Option<ProgramId>,
+    /// The spans of the expressions that the instructions of each function body were generated from.
+    pub(crate) instruction_spans: InstructionSpans,
 }
 
 impl<'a> CodeGenerator<'a> {
@@ -80,6 +86,7 @@ impl<'a> CodeGenerator<'a> {
             futures: Vec::new(),
             program,
             program_id: None,
+            instruction_spans: IndexMap::new(),
         }
     }
 }
@@ -32,12 +32,12 @@ use leo_errors::Result;
 
 impl<'a> Pass for CodeGenerator<'a> {
     type Input = (&'a Ast, &'a SymbolTable, &'a TypeTable, &'a StructGraph, &'a CallGraph, &'a Program);
-    type Output = Result<String>;
+    type Output = Result<(String, InstructionSpans)>;
 
     fn do_pass((ast, symbol_table, type_table, struct_graph, call_graph, program): Self::Input) -> Self::Output {
         let mut generator = Self::new(symbol_table, type_table, struct_graph, call_graph, program);
         let bytecode = generator.visit_program(ast.as_repr());
 
-        Ok(bytecode)
+        Ok((bytecode, generator.instruction_spans))
     }
 }
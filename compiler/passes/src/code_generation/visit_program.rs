@@ -163,6 +163,7 @@ impl<'a> CodeGenerator<'a> {
             Variant::Standard => format!("\nclosure {}:\n", function.identifier),
             Variant::Inline => return String::from("\n"),
         };
+        self.instruction_spans.insert(function.identifier.to_string(), Vec::new());
 
         // Construct and append the input declarations of the function.
         for input in function.input.iter() {
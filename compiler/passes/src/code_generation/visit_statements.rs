@@ -28,6 +28,7 @@ use leo_ast::{
     ExpressionStatement,
     IterationStatement,
     Mode,
+    Node,
     Output,
     ReturnStatement,
     Statement,
@@ -38,7 +39,7 @@ use std::fmt::Write as _;
 
 impl<'a> CodeGenerator<'a> {
     fn visit_statement(&mut self, input: &'a Statement) -> String {
-        match input {
+        let instructions = match input {
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
@@ -51,7 +52,31 @@ impl<'a> CodeGenerator<'a> {
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
+        };
+
+        // Record the span of the expression that the statement computes for each instruction it generated in a
+        // function body. The statements that earlier passes introduce have no span of their own, but their values
+        // keep the spans of the expressions they were split from. The statements of a block are recorded as they are
+        // visited, so a block itself is not.
+        if !self.in_finalize && !matches!(input, Statement::Block(_) | Statement::Conditional(_)) {
+            // Note that this unwrap is safe, since `current_function` is set in `visit_function`.
+            let function = self.current_function.unwrap().identifier.to_string();
+            let num_instructions = instructions
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with("output "))
+                .count();
+            if let Some(spans) = self.instruction_spans.get_mut(&function) {
+                let span = match input {
+                    Statement::Assign(stmt) if !stmt.value.span().is_dummy() => stmt.value.span(),
+                    Statement::Definition(stmt) if !stmt.value.span().is_dummy() => stmt.value.span(),
+                    _ => input.span(),
+                };
+                spans.extend(std::iter::repeat(span).take(num_instructions));
+            }
         }
+
+        instructions
     }
 
     fn visit_assert(&mut self, input: &'a AssertStatement) -> String {
@@ -77,6 +102,9 @@ impl<'a> CodeGenerator<'a> {
             }
             AssertVariant::AssertEq(left, right) => generate_assert_instruction("assert.eq", left, right),
             AssertVariant::AssertNeq(left, right) => generate_assert_instruction("assert.neq", left, right),
+            AssertVariant::ConstAssert(_) => {
+                unreachable!("Loop unrolling pass removes all constant assertions in the program.")
+            }
         }
     }
 
@@ -29,6 +29,9 @@ pub use replacer::*;
 pub mod constant_propagation_table;
 pub use constant_propagation_table::*;
 
+pub mod scoped_table;
+pub use scoped_table::*;
+
 pub mod symbol_table;
 pub use symbol_table::*;
 
@@ -30,12 +30,15 @@ pub struct RenameTable {
     /// These are used to ensure that newly introduced symbols reference the appropriate information
     /// that has been previously indexed by node ID. e,g. `TypeTable`.
     ids: IndexMap<Symbol, NodeID>,
+    /// The mapping from a renamed variable and the name of one of its members to the variable that holds the member.
+    /// These are used to access each member of a variable at most once in a scope and the scopes nested in it.
+    members: IndexMap<(Symbol, Symbol), Symbol>,
 }
 
 impl RenameTable {
     /// Create a new `RenameTable` with the given parent.
     pub(crate) fn new(parent: Option<Box<RenameTable>>) -> Self {
-        Self { parent, names: IndexMap::new(), ids: IndexMap::new() }
+        Self { parent, names: IndexMap::new(), ids: IndexMap::new(), members: IndexMap::new() }
     }
 
     /// Returns the symbols that were renamed in the current scope.
@@ -71,4 +74,21 @@ impl RenameTable {
             None
         }
     }
+
+    /// Records that `place` holds the member `member` of the renamed variable `variable`.
+    pub(crate) fn update_member(&mut self, variable: Symbol, member: Symbol, place: Symbol) {
+        self.members.insert((variable, member), place);
+    }
+
+    /// Looks up the variable that holds the member `member` of the renamed variable `variable`,
+    /// recursively checking the parent if it is not found.
+    pub(crate) fn lookup_member(&self, variable: Symbol, member: Symbol) -> Option<Symbol> {
+        if let Some(place) = self.members.get(&(variable, member)) {
+            Some(*place)
+        } else if let Some(parent) = &self.parent {
+            parent.lookup_member(variable, member)
+        } else {
+            None
+        }
+    }
 }
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// An existing binding that a new binding of the same name would conflict with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Conflict<'a, V> {
+    /// The name is already bound in the innermost scope.
+    Redefinition(&'a V),
+    /// The name is bound in an enclosing scope.
+    Shadowing(&'a V),
+}
+
+/// A table of bindings organized as a stack of nested scopes.
+///
+/// Passes that track names through blocks, such as the linter, can use a `ScopedTable` instead of
+/// maintaining their own stacks of maps. Whether shadowing and redefinition are allowed is up to the caller,
+/// who can query [`ScopedTable::conflict`] before calling [`ScopedTable::insert`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScopedTable<V> {
+    /// The bindings of each scope, from the outermost to the innermost.
+    /// There is always at least one scope.
+    scopes: Vec<IndexMap<Symbol, V>>,
+}
+
+impl<V> Default for ScopedTable<V> {
+    fn default() -> Self {
+        Self { scopes: vec![IndexMap::new()] }
+    }
+}
+
+impl<V> ScopedTable<V> {
+    /// Returns a new table containing only the outermost scope.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of scopes, including the outermost one.
+    pub fn depth(&self) -> usize {
+        self.scopes.len()
+    }
+
+    /// Enters a new, empty innermost scope.
+    pub fn enter_scope(&mut self) {
+        self.scopes.push(IndexMap::new());
+    }
+
+    /// Exits the innermost scope, returning its bindings in insertion order.
+    /// The outermost scope is never exited.
+    pub fn exit_scope(&mut self) -> IndexMap<Symbol, V> {
+        match self.scopes.len() {
+            1 => std::mem::take(&mut self.scopes[0]),
+            _ => self.scopes.pop().unwrap_or_default(),
+        }
+    }
+
+    /// Returns the existing binding that a new binding of `symbol` would conflict with, if any.
+    pub fn conflict(&self, symbol: Symbol) -> Option<Conflict<'_, V>> {
+        let (innermost, enclosing) = self.scopes.split_last()?;
+        match innermost.get(&symbol) {
+            Some(existing) => Some(Conflict::Redefinition(existing)),
+            None => enclosing.iter().rev().find_map(|scope| scope.get(&symbol)).map(Conflict::Shadowing),
+        }
+    }
+
+    /// Binds `symbol` to `value` in the innermost scope, returning the previous binding of that scope, if any.
+    pub fn insert(&mut self, symbol: Symbol, value: V) -> Option<V> {
+        self.innermost_mut().insert(symbol, value)
+    }
+
+    /// Looks up the binding of `symbol`, starting from the innermost scope.
+    pub fn lookup(&self, symbol: Symbol) -> Option<&V> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(&symbol))
+    }
+
+    /// Looks up the binding of `symbol` mutably, starting from the innermost scope.
+    pub fn lookup_mut(&mut self, symbol: Symbol) -> Option<&mut V> {
+        self.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(&symbol))
+    }
+
+    /// Looks up the binding of `symbol` in the innermost scope only.
+    pub fn lookup_local(&self, symbol: Symbol) -> Option<&V> {
+        self.scopes.last().and_then(|scope| scope.get(&symbol))
+    }
+
+    /// Returns the bindings of the innermost scope.
+    fn innermost_mut(&mut self) -> &mut IndexMap<Symbol, V> {
+        // There is always at least one scope.
+        self.scopes.last_mut().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_scoping() {
+        create_session_if_not_set_then(|_| {
+            let (x, y) = (Symbol::intern("x"), Symbol::intern("y"));
+            let mut table = ScopedTable::new();
+
+            assert_eq!(table.conflict(x), None);
+            table.insert(x, 1);
+            assert_eq!(table.conflict(x), Some(Conflict::Redefinition(&1)));
+
+            table.enter_scope();
+            assert_eq!(table.depth(), 2);
+            assert_eq!(table.conflict(x), Some(Conflict::Shadowing(&1)));
+            table.insert(x, 2);
+            table.insert(y, 3);
+            assert_eq!(table.lookup(x), Some(&2));
+            assert_eq!(table.lookup_local(y), Some(&3));
+
+            let exited = table.exit_scope();
+            assert_eq!(exited.len(), 2);
+            assert_eq!(table.lookup(x), Some(&1));
+            assert_eq!(table.lookup(y), None);
+        });
+    }
+}
@@ -40,11 +40,14 @@ pub struct FunctionSymbol {
     /// Is this function a transition, inlined, or a regular function?.
     pub variant: Variant,
     /// The `Span` associated with the function.
-    pub(crate) _span: Span,
+    pub(crate) span: Span,
     /// The inputs to the function.
     pub(crate) input: Vec<Input>,
     /// Metadata associated with the finalize block.
     pub(crate) finalize: Option<FinalizeData>,
+    /// Whether the function is annotated with `@deprecated`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) is_deprecated: bool,
 }
 
 impl SymbolTable {
@@ -53,12 +56,13 @@ impl SymbolTable {
             id,
             output_type: func.output_type.clone(),
             variant: func.variant,
-            _span: func.span,
+            span: func.span,
             input: func.input.clone(),
             finalize: func.finalize.as_ref().map(|finalize| FinalizeData {
                 input: finalize.input.clone(),
                 output_type: finalize.output_type.clone(),
             }),
+            is_deprecated: func.is_deprecated(),
         }
     }
 }
@@ -56,14 +56,14 @@ impl SymbolTable {
     /// Recursively checks if the symbol table contains an entry for the given symbol.
     /// Leo does not allow any variable shadowing or overlap between different symbols.
     pub fn check_shadowing(&self, symbol: Symbol, span: Span) -> Result<()> {
-        if self.variables.contains_key(&symbol) {
-            Err(AstError::shadowed_variable(symbol, span).into())
-        } else if self.functions.contains_key(&symbol) {
-            Err(AstError::shadowed_function(symbol, span).into())
+        if let Some(existing) = self.variables.get(&symbol) {
+            Err(AstError::shadowed_variable(symbol, existing.span, span).into())
+        } else if let Some(existing) = self.functions.get(&symbol) {
+            Err(AstError::shadowed_function(symbol, existing.span, span).into())
         } else if let Some(existing) = self.structs.get(&symbol) {
             match existing.is_record {
-                true => Err(AstError::shadowed_record(symbol, span).into()),
-                false => Err(AstError::shadowed_struct(symbol, span).into()),
+                true => Err(AstError::shadowed_record(symbol, existing.span, span).into()),
+                false => Err(AstError::shadowed_struct(symbol, existing.span, span).into()),
             }
         } else if let Some(parent) = self.parent.as_ref() {
             parent.check_shadowing(symbol, span)
@@ -115,7 +115,7 @@ impl SymbolTable {
                 Ok(())
             }
             Err(e) => {
-                if e.error_code() == AstError::shadowed_struct(symbol, insert.span).error_code() {
+                if e.error_code() == AstError::shadowed_struct(symbol, insert.span, insert.span).error_code() {
                     if self.check_duplicate_struct(
                         self.structs.get(&symbol).expect("Must be in symbol table since struct already referenced"),
                         insert,
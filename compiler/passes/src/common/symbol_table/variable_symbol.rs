@@ -27,6 +27,7 @@ pub enum VariableType {
     Const,
     Input(Mode),
     Mut,
+    Reference,
 }
 
 impl Display for VariableType {
@@ -37,6 +38,7 @@ impl Display for VariableType {
             Const => write!(f, "const var"),
             Input(m) => write!(f, "{m} input"),
             Mut => write!(f, "mut var"),
+            Reference => write!(f, "reference input"),
         }
     }
 }
@@ -37,4 +37,9 @@ impl TypeTable {
     pub fn insert(&self, index: NodeID, value: Type) {
         self.inner.borrow_mut().insert(index, value);
     }
+
+    /// Returns the entries of the table, in the order they were inserted.
+    pub fn entries(&self) -> IndexMap<NodeID, Type> {
+        self.inner.borrow().clone()
+    }
 }
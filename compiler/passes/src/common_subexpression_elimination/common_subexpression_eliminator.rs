@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Identifier, NodeBuilder, StructuralExpression};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+pub struct CommonSubexpressionEliminator<'a> {
+    /// A counter to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// The variable holding the value of each expression computed so far in the current block.
+    pub(crate) expressions: IndexMap<StructuralExpression, Identifier>,
+    /// For each comparison `a < b` computed so far in the current block, the variable holding either it or its negation,
+    /// and whether the variable holds the negation.
+    pub(crate) comparisons: IndexMap<StructuralExpression, (Identifier, bool)>,
+    /// A mapping from the variables of eliminated assignments to the variables replacing them.
+    pub(crate) replacements: IndexMap<Symbol, Identifier>,
+}
+
+impl<'a> CommonSubexpressionEliminator<'a> {
+    /// Initializes a new `CommonSubexpressionEliminator`.
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self {
+            node_builder,
+            expressions: Default::default(),
+            comparisons: Default::default(),
+            replacements: Default::default(),
+        }
+    }
+}
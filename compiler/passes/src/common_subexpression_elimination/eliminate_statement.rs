@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CommonSubexpressionEliminator;
+
+use leo_ast::{
+    AccessExpression,
+    AssignStatement,
+    BinaryExpression,
+    BinaryOperation,
+    Expression,
+    ExpressionReconstructor,
+    Statement,
+    StatementReconstructor,
+    StructuralExpression,
+    UnaryExpression,
+    UnaryOperation,
+};
+
+impl StatementReconstructor for CommonSubexpressionEliminator<'_> {
+    /// Eliminates the assignment if its value has already been computed, and otherwise records it.
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        let mut value = self.reconstruct_expression(input.value).0;
+
+        // A comparison shares its result with earlier comparisons of the same operands, whatever their order and strictness.
+        if let (Expression::Identifier(place), Some((key, negated))) = (&input.place, strict_comparison(&value)) {
+            match self.comparisons.get(&key) {
+                Some((existing, existing_negated)) if *existing_negated == negated => {
+                    self.replacements.insert(place.name, *existing);
+                    return (Statement::dummy(Default::default(), self.node_builder.next_id()), Default::default());
+                }
+                // The comparison is the negation of an earlier one, so its value is the negation of the earlier result.
+                Some((existing, _)) => {
+                    value = Expression::Unary(UnaryExpression {
+                        receiver: Box::new(Expression::Identifier(*existing)),
+                        op: UnaryOperation::Not,
+                        span: Default::default(),
+                        id: self.node_builder.next_id(),
+                    });
+                }
+                None => {
+                    self.comparisons.insert(key, (*place, negated));
+                }
+            }
+        }
+
+        if let (Expression::Identifier(place), true) = (&input.place, is_shareable(&value)) {
+            let key = StructuralExpression::from(value.clone());
+            match self.expressions.get(&key) {
+                Some(existing) => {
+                    self.replacements.insert(place.name, *existing);
+                    return (Statement::dummy(Default::default(), self.node_builder.next_id()), Default::default());
+                }
+                None => {
+                    self.expressions.insert(key, *place);
+                }
+            }
+        }
+
+        (
+            Statement::Assign(Box::new(AssignStatement { place: input.place, value, span: input.span, id: input.id })),
+            Default::default(),
+        )
+    }
+}
+
+/// Returns the strict comparison `a < b` that a comparison is equivalent to, and whether the comparison is its negation.
+/// That is, `a < b` is itself, `a > b` is `b < a`, `a <= b` is `!(b < a)`, and `a >= b` is `!(a < b)`.
+fn strict_comparison(expression: &Expression) -> Option<(StructuralExpression, bool)> {
+    let (left, right, negated) = match expression {
+        Expression::Binary(BinaryExpression { left, right, op, .. }) => match op {
+            BinaryOperation::Lt => (left, right, false),
+            BinaryOperation::Gt => (right, left, false),
+            BinaryOperation::Lte => (right, left, true),
+            BinaryOperation::Gte => (left, right, true),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let comparison = Expression::Binary(BinaryExpression {
+        left: left.clone(),
+        right: right.clone(),
+        op: BinaryOperation::Lt,
+        span: Default::default(),
+        id: Default::default(),
+    });
+
+    Some((StructuralExpression::from(comparison), negated))
+}
+
+/// Returns `true` if the expression is an operation without side effects, whose result can be reused.
+fn is_shareable(expression: &Expression) -> bool {
+    matches!(
+        expression,
+        Expression::Binary(_)
+            | Expression::Unary(_)
+            | Expression::Ternary(_)
+            | Expression::Cast(_)
+            | Expression::Access(AccessExpression::Array(_) | AccessExpression::Member(_) | AccessExpression::Tuple(_))
+    )
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Common Subexpression Elimination pass traverses the AST and removes assignments whose value
+//! is an expression already computed earlier in the same `transition`, `function`, or `finalize` block.
+//! Later uses of the removed variable are replaced with the variable holding the earlier result.
+//! The pass is run after the Function Inlining pass, and before the Dead Code Elimination pass.
+//!
+//! See https://en.wikipedia.org/wiki/Common_subexpression_elimination for more information.
+//!
+//! Consider the following flattened Leo code.
+//! ```leo
+//! function main(a: u8, b: u8) -> u8 {
+//!     $var$0 = a * b;
+//!     $var$1 = a * b;
+//!     $var$2 = $var$0 + $var$1;
+//!     $var$3 = a * b;
+//!     $var$4 = $var$2 + $var$3;
+//!     return $var$4;
+//! }
+//! ```
+//!
+//! The common subexpression elimination pass produces the following code.
+//! ```leo
+//! function main(a: u8, b: u8) -> u8 {
+//!     $var$0 = a * b;
+//!     $var$2 = $var$0 + $var$0;
+//!     $var$4 = $var$2 + $var$0;
+//!     return $var$4;
+//! }
+//! ```
+//!
+//! Comparisons of the same operands are also shared when they differ in order or strictness. Since `a > b` is `b < a`,
+//! `a <= b` is `!(b < a)`, and `a >= b` is `!(a < b)`, each of them reuses an earlier one, negating its result if needed.
+//! The negation is free, whereas each comparison decomposes its operands into bits.
+//!
+//! Only operations without side effects are shared, i.e. operators, casts, ternaries, and accesses of
+//! members, tuples, and arrays. Calls and associated functions, such as mapping operations, are left in place.
+//!
+//! Note this pass relies on the following invariants:
+//! - Unique variable names (provided by SSA)
+//! - Flattened code (provided by the flattening pass)
+
+mod eliminate_expression;
+
+mod eliminate_program;
+
+mod eliminate_statement;
+
+pub mod common_subexpression_eliminator;
+pub use common_subexpression_eliminator::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for CommonSubexpressionEliminator<'a> {
+    type Input = (Ast, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = CommonSubexpressionEliminator::new(node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
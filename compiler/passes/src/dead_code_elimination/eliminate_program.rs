@@ -49,6 +49,7 @@ impl ProgramReconstructor for DeadCodeEliminator<'_> {
 
         Function {
             annotations: input.annotations,
+            docs: input.docs,
             variant: input.variant,
             identifier: input.identifier,
             input: input.input,
@@ -49,6 +49,9 @@ impl StatementReconstructor for DeadCodeEliminator<'_> {
                 AssertVariant::AssertNeq(left, right) => {
                     AssertVariant::AssertNeq(self.reconstruct_expression(left).0, self.reconstruct_expression(right).0)
                 }
+                AssertVariant::ConstAssert(_) => {
+                    unreachable!("Loop unrolling pass removes all constant assertions in the program.")
+                }
             },
             span: input.span,
             id: input.id,
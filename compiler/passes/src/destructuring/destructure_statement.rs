@@ -44,7 +44,7 @@ impl StatementReconstructor for Destructurer<'_> {
     fn reconstruct_assign(&mut self, assign: AssignStatement) -> (Statement, Self::AdditionalOutput) {
         // Flatten the rhs of the assignment.
         let value = self.reconstruct_expression(assign.value).0;
-        match (assign.place, value.clone()) {
+        match (assign.place, value) {
             // If the lhs is an identifier and the rhs is a tuple, then add the tuple to `self.tuples`.
             // Return a dummy statement in its place.
             (Expression::Identifier(identifier), Expression::Tuple(tuple)) => {
@@ -68,8 +68,21 @@ impl ExpressionReconstructor for Flattener<'_> {
     /// let var$2 = Foo { bar: var$0, baz: var$1 };
     /// var$2
     /// ```
+    /// A condition that is not a variable or a literal, such as the guard `cond1 && !cond2` of a conditional return, is
+    /// first assigned to a variable, so that it is evaluated once and every select shares it.
     fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
         let mut statements = Vec::new();
+
+        // Evaluate the condition once.
+        let condition = match *input.condition {
+            condition @ (Expression::Identifier(_) | Expression::Literal(_)) => condition,
+            condition => {
+                let (identifier, statement) = self.unique_simple_assign_statement(condition);
+                statements.push(statement);
+                Expression::Identifier(identifier)
+            }
+        };
+
         match (*input.if_true, *input.if_false) {
             // If both expressions are identifiers which are arrays, construct ternary expressions for each of the members and an array expression for the result.
             (Expression::Identifier(first), Expression::Identifier(second)) => {
@@ -85,14 +98,14 @@ impl ExpressionReconstructor for Flattener<'_> {
                 // Note that type checking guarantees that both expressions have the same same type. This is a sanity check.
                 assert!(first_type.eq_flat(&second_type));
 
-                match &first_type {
-                    Type::Array(first_type) => self.ternary_array(first_type, &input.condition, &first, &second),
+                let (expression, stmts) = match &first_type {
+                    Type::Array(first_type) => self.ternary_array(first_type, &condition, &first, &second),
                     Type::Identifier(first_type) => {
                         // Get the struct definitions.
                         let first_type = self.symbol_table.lookup_struct(first_type.name).unwrap();
-                        self.ternary_struct(first_type, &input.condition, &first, &second)
+                        self.ternary_struct(first_type, &condition, &first, &second)
                     }
-                    Type::Tuple(first_type) => self.ternary_tuple(first_type, &input.condition, &first, &second),
+                    Type::Tuple(first_type) => self.ternary_tuple(first_type, &condition, &first, &second),
                     _ => {
                         // Reconstruct the true case.
                         let (if_true, stmts) = self.reconstruct_expression(Expression::Identifier(first));
@@ -104,19 +117,21 @@ impl ExpressionReconstructor for Flattener<'_> {
 
                         let (identifier, statement) =
                             self.unique_simple_assign_statement(Expression::Ternary(TernaryExpression {
-                                condition: input.condition,
+                                condition: Box::new(condition),
                                 if_true: Box::new(if_true),
                                 if_false: Box::new(if_false),
                                 span: input.span,
                                 id: input.id,
                             }));
 
-                        // Accumulate the new assignment statement.
-                        statements.push(statement);
-
-                        (Expression::Identifier(identifier), statements)
+                        (Expression::Identifier(identifier), vec![statement])
                     }
-                }
+                };
+
+                // Accumulate the statements that compute the result.
+                statements.extend(stmts);
+
+                (expression, statements)
             }
             _ => {
                 unreachable!("SSA guarantees that the subexpressions of a ternary expression are identifiers.")
@@ -55,6 +55,7 @@ impl ProgramReconstructor for Flattener<'_> {
 
         Function {
             annotations: function.annotations,
+            docs: function.docs,
             variant: function.variant,
             identifier: function.identifier,
             input: function.input,
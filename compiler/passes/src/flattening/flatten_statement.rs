@@ -85,11 +85,14 @@ impl StatementReconstructor for Flattener<'_> {
                     statements.extend(additional_statements);
                     AssertVariant::AssertNeq(left, right)
                 }
+                AssertVariant::ConstAssert(_) => {
+                    unreachable!("Loop unrolling pass removes all constant assertions in the program.")
+                }
             },
         };
 
         // Add the appropriate guards.
-        match self.construct_guard() {
+        match self.construct_assert_guard() {
             // If the condition stack is empty, we can return the flattened assert statement.
             None => (Statement::Assert(assert), statements),
             // Otherwise, we need to join the guard with the expression in the flattened assert statement.
@@ -153,6 +156,9 @@ impl StatementReconstructor for Flattener<'_> {
                                     id
                                 },
                             }),
+                            AssertVariant::ConstAssert(_) => {
+                                unreachable!("Loop unrolling pass removes all constant assertions in the program.")
+                            }
                         }),
                     })),
                 }),
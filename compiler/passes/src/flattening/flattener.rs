@@ -44,6 +44,8 @@ use leo_ast::{
     TupleExpression,
     TupleType,
     Type,
+    UnaryExpression,
+    UnaryOperation,
     UnitExpression,
 };
 
@@ -63,6 +65,8 @@ pub struct Flattener<'a> {
     /// Note that returns are inserted in the order they are encountered during a pre-order traversal of the AST.
     /// Note that type checking guarantees that there is at most one return in a basic block.
     pub(crate) returns: Vec<(Option<Expression>, ReturnStatement)>,
+    /// Whether the assertions that follow an early return are only checked if the return is not taken.
+    pub(crate) guard_after_early_returns: bool,
 }
 
 impl<'a> Flattener<'a> {
@@ -71,8 +75,17 @@ impl<'a> Flattener<'a> {
         type_table: &'a TypeTable,
         node_builder: &'a NodeBuilder,
         assigner: &'a Assigner,
+        guard_after_early_returns: bool,
     ) -> Self {
-        Self { symbol_table, type_table, node_builder, assigner, condition_stack: Vec::new(), returns: Vec::new() }
+        Self {
+            symbol_table,
+            type_table,
+            node_builder,
+            assigner,
+            condition_stack: Vec::new(),
+            returns: Vec::new(),
+            guard_after_early_returns,
+        }
     }
 
     /// Clears the state associated with `ReturnStatements`, returning the ones that were previously stored.
@@ -106,6 +119,50 @@ impl<'a> Flattener<'a> {
         }
     }
 
+    /// Constructs the guard of an assertion from the current state of the condition stack.
+    /// If `guard_after_early_returns` is set, the guard also requires that none of the earlier returns were taken.
+    pub(crate) fn construct_assert_guard(&mut self) -> Option<Expression> {
+        let guard = self.construct_guard();
+        if !self.guard_after_early_returns {
+            return guard;
+        }
+
+        // Note that type checking guarantees that an unguarded return is not followed by any statements.
+        let return_guards: Vec<Expression> = self.returns.iter().filter_map(|(guard, _)| guard.clone()).collect();
+        return_guards.into_iter().fold(guard, |acc, return_guard| {
+            // Construct the negation of the return guard, i.e. the condition that the return was not taken.
+            let not_returned = Expression::Unary(UnaryExpression {
+                op: UnaryOperation::Not,
+                receiver: Box::new(return_guard),
+                span: Default::default(),
+                id: {
+                    // Create a new node ID for the unary expression.
+                    let id = self.node_builder.next_id();
+                    // Set the type of the node ID.
+                    self.type_table.insert(id, Type::Boolean);
+                    id
+                },
+            });
+            Some(match acc {
+                None => not_returned,
+                // Construct the binary expression.
+                Some(acc) => Expression::Binary(BinaryExpression {
+                    op: BinaryOperation::And,
+                    left: Box::new(acc),
+                    right: Box::new(not_returned),
+                    span: Default::default(),
+                    id: {
+                        // Create a new node ID for the binary expression.
+                        let id = self.node_builder.next_id();
+                        // Set the type of the node ID.
+                        self.type_table.insert(id, Type::Boolean);
+                        id
+                    },
+                }),
+            })
+        })
+    }
+
     /// Fold guards and expressions into a single expression.
     /// Note that this function assumes that at least one guard is present.
     pub(crate) fn fold_guards(
@@ -561,7 +618,7 @@ impl<'a> Flattener<'a> {
                 id
             },
         };
-        let (expr, stmts) = self.reconstruct_tuple(tuple.clone());
+        let (expr, stmts) = self.reconstruct_tuple(tuple);
 
         // Accumulate any statements generated.
         statements.extend(stmts);
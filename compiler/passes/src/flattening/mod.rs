@@ -18,6 +18,7 @@
 //! The pass flattens `ConditionalStatement`s into a sequence of `AssignStatement`s.
 //! The pass rewrites `ReturnStatement`s into `AssignStatement`s and consolidates the returned values as a single `ReturnStatement` at the end of the function.
 //! The pass rewrites ternary expressions over composite data types, into ternary expressions over the individual fields of the composite data type, followed by an expression constructing the composite data type.
+//! If enabled, an `assert` that follows an early return is also guarded by the negation of the return's guard, so that it only fails when the return is not taken.
 //!
 //! Consider the following Leo code, output by the SSA pass.
 //! ```leo
@@ -65,11 +66,11 @@ use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
 use leo_errors::Result;
 
 impl<'a> Pass for Flattener<'a> {
-    type Input = (Ast, &'a SymbolTable, &'a TypeTable, &'a NodeBuilder, &'a Assigner);
+    type Input = (Ast, &'a SymbolTable, &'a TypeTable, &'a NodeBuilder, &'a Assigner, bool);
     type Output = Result<Ast>;
 
-    fn do_pass((ast, st, tt, node_builder, assigner): Self::Input) -> Self::Output {
-        let mut reconstructor = Flattener::new(st, tt, node_builder, assigner);
+    fn do_pass((ast, st, tt, node_builder, assigner, guard_after_early_returns): Self::Input) -> Self::Output {
+        let mut reconstructor = Flattener::new(st, tt, node_builder, assigner, guard_after_early_returns);
         let program = reconstructor.reconstruct_program(ast.into_repr());
 
         Ok(Ast::new(program))
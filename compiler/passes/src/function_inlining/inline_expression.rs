@@ -26,7 +26,6 @@ use leo_ast::{
     StatementReconstructor,
     Type,
     UnitExpression,
-    Variant,
 };
 
 use indexmap::IndexMap;
@@ -52,9 +51,9 @@ impl ExpressionReconstructor for FunctionInliner<'_> {
         let (_, callee) = self.reconstructed_functions.iter().find(|(symbol, _)| *symbol == function_name).unwrap();
 
         // Inline the callee function, if required, otherwise, return the call expression.
-        match callee.variant {
-            Variant::Transition | Variant::Standard => (Expression::Call(input), Default::default()),
-            Variant::Inline => {
+        match callee.is_inlined() {
+            false => (Expression::Call(input), Default::default()),
+            true => {
                 // Construct a mapping from input variables of the callee function to arguments passed to the callee.
                 let parameter_to_argument = callee
                     .input
@@ -54,6 +54,7 @@ impl ProgramReconstructor for FunctionInliner<'_> {
             mappings: input.mappings,
             functions,
             consts: input.consts,
+            macros: input.macros,
             span: input.span,
         }
     }
@@ -34,7 +34,7 @@ impl StatementReconstructor for FunctionInliner<'_> {
     /// Reconstruct an assignment statement by inlining any function calls.
     /// This function also segments tuple assignment statements into multiple assignment statements.
     fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
-        let (value, mut statements) = self.reconstruct_expression(input.value.clone());
+        let (value, mut statements) = self.reconstruct_expression(input.value);
         match (input.place, value) {
             // If the function call produces a tuple, we need to segment the tuple into multiple assignment statements.
             (Expression::Tuple(left), Expression::Tuple(right)) if left.elements.len() == right.elements.len() => {
@@ -17,12 +17,18 @@
 #![forbid(unsafe_code)]
 #![doc = include_str!("../README.md")]
 
+pub mod canonicalization;
+pub use canonicalization::*;
+
 pub mod code_generation;
 pub use code_generation::*;
 
 pub mod common;
 pub use common::*;
 
+pub mod common_subexpression_elimination;
+pub use common_subexpression_elimination::*;
+
 pub mod dead_code_elimination;
 pub use dead_code_elimination::*;
 
@@ -35,12 +41,24 @@ pub use flattening::*;
 pub mod function_inlining;
 pub use function_inlining::*;
 
+pub mod linting;
+pub use linting::*;
+
 pub mod loop_unrolling;
 pub use self::loop_unrolling::*;
 
+pub mod macro_expansion;
+pub use macro_expansion::*;
+
+pub mod module_resolution;
+pub use module_resolution::*;
+
 pub mod pass;
 pub use self::pass::*;
 
+pub mod recursion_expansion;
+pub use recursion_expansion::*;
+
 pub mod static_single_assignment;
 pub use static_single_assignment::*;
 
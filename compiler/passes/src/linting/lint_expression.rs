@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Linter;
+
+use leo_ast::{ExpressionVisitor, Identifier, StructExpression, TernaryExpression};
+
+impl<'a> ExpressionVisitor<'a> for Linter<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        if let Some(variable) = self.variable_mut(input.name) {
+            variable.read = true;
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for member in input.members.iter() {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                // A member without an expression is initialized by the variable of the same name, e.g. `Foo { x }`.
+                None => self.visit_identifier(&member.identifier, additional),
+            }
+        }
+    }
+
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.check_condition(&input.condition, false);
+        self.visit_expression(&input.condition, additional);
+        self.visit_expression(&input.if_true, additional);
+        self.visit_expression(&input.if_false, additional);
+    }
+}
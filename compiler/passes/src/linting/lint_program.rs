@@ -0,0 +1,28 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Linter;
+
+use leo_ast::{ExpressionVisitor, ProgramScope, ProgramVisitor};
+
+impl<'a> ProgramVisitor<'a> for Linter<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        // The constants of the program scope are in scope in every function, wherever they are declared.
+        self.constants = input.consts.iter().map(|(name, _)| *name).collect();
+        input.consts.iter().for_each(|(_, declaration)| self.visit_expression(&declaration.value, &()));
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Linter, Variable};
+
+use leo_ast::*;
+
+impl<'a> StatementVisitor<'a> for Linter<'a> {
+    fn visit_assert(&mut self, input: &'a AssertStatement) {
+        if let AssertVariant::Assert(condition) = &input.variant {
+            self.check_condition(condition, false);
+        }
+        match &input.variant {
+            AssertVariant::Assert(expr) | AssertVariant::ConstAssert(expr) => self.visit_expression(expr, &()),
+            AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
+                self.visit_expression(left, &());
+                self.visit_expression(right, &());
+            }
+        }
+    }
+
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.value, &());
+        match &input.place {
+            Expression::Identifier(identifier) => {
+                if let Some(variable) = self.variable_mut(identifier.name) {
+                    variable.assigned = true;
+                }
+            }
+            // Assigning to a part of a variable, e.g. `x.a = 1u8`, reads the rest of it.
+            place => self.visit_expression(place, &()),
+        }
+    }
+
+    fn visit_block(&mut self, input: &'a Block) {
+        self.variables.enter_scope();
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.pop_scope();
+    }
+
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        self.check_condition(&input.condition, true);
+        self.visit_expression(&input.condition, &());
+        self.visit_block(&input.then);
+        if let Some(otherwise) = &input.otherwise {
+            self.visit_statement(otherwise);
+        }
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.visit_expression(&input.value, &());
+        self.constants.insert(input.place.name);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_expression(&input.value, &());
+
+        let literal = match &input.value {
+            Expression::Literal(literal) => Some(literal.clone()),
+            _ => None,
+        };
+        let places = match (&input.place, &input.type_) {
+            (Expression::Identifier(identifier), type_) => vec![(identifier, type_.clone(), literal)],
+            (Expression::Tuple(tuple), Type::Tuple(types)) => tuple
+                .elements
+                .iter()
+                .zip(types.elements().iter())
+                .filter_map(|(element, type_)| match element {
+                    Expression::Identifier(identifier) => Some((identifier, type_.clone(), None)),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        for (identifier, type_, literal) in places {
+            self.variables.insert(identifier.name, Variable {
+                span: identifier.span,
+                type_,
+                literal,
+                read: false,
+                assigned: false,
+            });
+        }
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{LintConfig, LintLevel, LintRule, ScopedTable};
+
+use leo_ast::{Expression, IntegerType, Literal, Node, Type};
+use leo_errors::{emitter::Handler, LinterWarning};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexSet;
+
+/// A variable defined by a `let` statement, tracked until the end of its scope.
+pub(crate) struct Variable {
+    /// The span of the name of the variable in its definition.
+    pub(crate) span: Span,
+    /// The type of the variable.
+    pub(crate) type_: Type,
+    /// The literal the variable is defined as, if any.
+    pub(crate) literal: Option<Literal>,
+    /// Whether the variable is read.
+    pub(crate) read: bool,
+    /// Whether the variable is assigned after its definition.
+    pub(crate) assigned: bool,
+}
+
+pub struct Linter<'a> {
+    /// The handler that the lints are emitted to.
+    handler: &'a Handler,
+    /// The levels of the rules.
+    config: &'a LintConfig,
+    /// The number of lints emitted for rules that are denied.
+    pub(crate) denied: usize,
+    /// The variables in scope.
+    pub(crate) variables: ScopedTable<Variable>,
+    /// The names of the constants in scope.
+    pub(crate) constants: IndexSet<Symbol>,
+}
+
+impl<'a> Linter<'a> {
+    /// Returns a new linter.
+    pub(crate) fn new(handler: &'a Handler, config: &'a LintConfig) -> Self {
+        Self { handler, config, denied: 0, variables: ScopedTable::new(), constants: IndexSet::new() }
+    }
+
+    /// Emits `warning` for a violation of `rule`, according to the level of the rule.
+    pub(crate) fn emit(&mut self, rule: LintRule, warning: LinterWarning) {
+        match self.config.level(rule) {
+            LintLevel::Allow => {}
+            LintLevel::Warn => self.handler.emit_warning(warning.into()),
+            LintLevel::Deny => {
+                self.handler.emit_warning(warning.into());
+                self.denied += 1;
+            }
+        }
+    }
+
+    /// Returns the innermost variable named `name`, if it is in scope.
+    pub(crate) fn variable_mut(&mut self, name: Symbol) -> Option<&mut Variable> {
+        self.variables.lookup_mut(name)
+    }
+
+    /// Ends the innermost scope, linting the variables defined in it.
+    pub(crate) fn pop_scope(&mut self) {
+        let scope = self.variables.exit_scope();
+        for (name, variable) in scope {
+            if !variable.read {
+                self.emit(LintRule::UnusedValues, LinterWarning::unused_value(name, variable.span));
+                continue;
+            }
+            if let (Type::Integer(type_), Some(Literal::Integer(_, value, ..)), false) =
+                (&variable.type_, &variable.literal, variable.assigned)
+            {
+                if let Some(narrower) = narrowest_integer_type(*type_, value) {
+                    self.emit(
+                        LintRule::WideIntegers,
+                        LinterWarning::wide_integer(name, type_, narrower, variable.span),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Checks that `condition` depends on a variable.
+    /// Literal conditions of `if` statements are already reported by the type checker, so they are skipped.
+    pub(crate) fn check_condition(&mut self, condition: &Expression, skip_literals: bool) {
+        if skip_literals && matches!(condition, Expression::Literal(_)) {
+            return;
+        }
+        let is_constant = condition.iter().all(|expression| match expression {
+            Expression::Literal(_) | Expression::Binary(_) | Expression::Unary(_) | Expression::Ternary(_) => true,
+            Expression::Cast(_) => true,
+            Expression::Identifier(identifier) => self.constants.contains(&identifier.name),
+            _ => false,
+        });
+        if is_constant {
+            self.emit(LintRule::ConstantConditions, LinterWarning::constant_condition(condition, condition.span()));
+        }
+    }
+}
+
+/// Returns the narrowest integer type with the same signedness as `type_` that `value` fits in,
+/// if it is narrower than `type_`.
+fn narrowest_integer_type(type_: IntegerType, value: &str) -> Option<IntegerType> {
+    use IntegerType::*;
+
+    let value: u128 = value.replace('_', "").parse().ok()?;
+    let candidates = match type_.is_signed() {
+        true => [(I8, i8::MAX as u128), (I16, i16::MAX as u128), (I32, i32::MAX as u128), (I64, i64::MAX as u128)],
+        false => [(U8, u8::MAX as u128), (U16, u16::MAX as u128), (U32, u32::MAX as u128), (U64, u64::MAX as u128)],
+    };
+    let (narrowest, _) = candidates.into_iter().find(|(_, max)| value <= *max)?;
+    (narrowest.bits() < type_.bits()).then_some(narrowest)
+}
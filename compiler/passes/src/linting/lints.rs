@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use indexmap::IndexMap;
+use std::{fmt, str::FromStr};
+
+/// A rule checked by the linter.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LintRule {
+    /// A variable that is defined but never read.
+    UnusedValues,
+    /// A condition of an `if` statement, ternary, or assertion that does not depend on any variable.
+    ConstantConditions,
+    /// A variable that is only ever given a literal which fits in a narrower integer type.
+    WideIntegers,
+}
+
+impl LintRule {
+    /// All of the rules, in the order they are listed in.
+    pub const ALL: [LintRule; 3] = [LintRule::UnusedValues, LintRule::ConstantConditions, LintRule::WideIntegers];
+
+    /// Returns the name of the rule, as used on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintRule::UnusedValues => "unused-values",
+            LintRule::ConstantConditions => "constant-conditions",
+            LintRule::WideIntegers => "wide-integers",
+        }
+    }
+
+    /// Returns the level of the rule when it is not configured.
+    /// Narrower types are not always better, e.g. when the variable is combined with wider values, so `wide-integers` is opt-in.
+    pub fn default_level(&self) -> LintLevel {
+        match self {
+            LintRule::UnusedValues | LintRule::ConstantConditions => LintLevel::Warn,
+            LintRule::WideIntegers => LintLevel::Allow,
+        }
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for LintRule {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|rule| rule.name() == name).ok_or(())
+    }
+}
+
+/// What the linter does when a rule is broken.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LintLevel {
+    /// The rule is not checked.
+    Allow,
+    /// A warning is emitted.
+    Warn,
+    /// A warning is emitted, and the lint fails.
+    Deny,
+}
+
+/// The levels of the rules checked by the linter.
+#[derive(Clone, Debug, Default)]
+pub struct LintConfig {
+    /// The levels of the rules that are configured, which override their default levels.
+    levels: IndexMap<LintRule, LintLevel>,
+}
+
+impl LintConfig {
+    /// Sets the level of `rule`, overriding any level it was previously set to.
+    pub fn set(&mut self, rule: LintRule, level: LintLevel) {
+        self.levels.insert(rule, level);
+    }
+
+    /// Returns the level of `rule`.
+    pub fn level(&self, rule: LintRule) -> LintLevel {
+        self.levels.get(&rule).copied().unwrap_or_else(|| rule.default_level())
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The linter checks a type checked program for code that is valid, but likely to be a mistake or to be improved.
+//! It is run by `leo lint`, and is not part of compilation.
+//!
+//! Each rule has a level, see [`LintLevel`], which is its default level unless it is configured.
+//! - `unused-values` reports variables defined by `let` statements which are never read.
+//! - `constant-conditions` reports conditions of `if` statements, ternaries, and assertions which only depend on
+//!   literals and constants.
+//! - `wide-integers` reports variables that are defined as an integer literal, and never assigned afterwards,
+//!   whose value fits in a narrower integer type. It is allowed by default.
+//!
+//! Leo already rejects shadowed variables and literals without a type, so there are no rules for those.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8) -> u8 {
+//!     let b: u64 = 5u64;
+//!     let c: u8 = a + 1u8;
+//!     return 1u8 < 2u8 ? a : b as u8;
+//! }
+//! ```
+//!
+//! The linter warns that `c` is never read and that the condition `1u8 < 2u8` is constant.
+//! If `wide-integers` is enabled, it also warns that the value of `b` fits in a `u8`.
+
+mod lint_expression;
+
+mod lint_program;
+
+mod lint_statement;
+
+pub mod linter;
+pub use linter::*;
+
+pub mod lints;
+pub use lints::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::emitter::Handler;
+
+impl<'a> Pass for Linter<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a LintConfig);
+    /// The number of lints emitted for rules that are denied.
+    type Output = usize;
+
+    fn do_pass((ast, handler, config): Self::Input) -> Self::Output {
+        let mut visitor = Linter::new(handler, config);
+        visitor.visit_program(ast.as_repr());
+        visitor.denied
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use num_traits::One;
+use num_traits::{CheckedAdd, One, Zero};
 use std::{fmt::Display, ops::Add};
 
 use leo_ast::Value;
@@ -24,7 +24,7 @@ use leo_errors::LeoError;
 // TODO: Better name.
 /// A trait for whose implementors are concrete values for loop bounds.
 pub(crate) trait LoopBound:
-    Add<Output = Self> + Copy + Display + One + PartialOrd + TryFrom<Value, Error = LeoError>
+    Add<Output = Self> + CheckedAdd + Copy + Display + One + PartialOrd + TryFrom<Value, Error = LeoError> + Zero
 {
 }
 
@@ -37,16 +37,17 @@ pub(crate) enum Clusivity {
     Exclusive,
 }
 
-/// An iterator over a range of values.
+/// An iterator over a range of values, advancing by `step` each time.
 pub(crate) struct RangeIterator<I: LoopBound> {
     end: I,
+    step: I,
     current: Option<I>,
     clusivity: Clusivity,
 }
 
 impl<I: LoopBound> RangeIterator<I> {
-    pub(crate) fn new(start: I, end: I, clusivity: Clusivity) -> Self {
-        Self { end, current: Some(start), clusivity }
+    pub(crate) fn new(start: I, end: I, step: I, clusivity: Clusivity) -> Self {
+        Self { end, step, current: Some(start), clusivity }
     }
 }
 
@@ -57,14 +58,16 @@ impl<I: LoopBound> Iterator for RangeIterator<I> {
         match self.current {
             None => None,
             Some(value) if value < self.end => {
-                self.current = Some(value.add(I::one()));
+                // Stop after this value if advancing overflows, since the next value would be past the end anyway.
+                self.current = value.checked_add(&self.step);
                 Some(value)
             }
             Some(value) => {
                 self.current = None;
+                // A step larger than one may skip past the end, in which case the end is not part of the range.
                 match self.clusivity {
-                    Clusivity::Exclusive => None,
-                    Clusivity::Inclusive => Some(value),
+                    Clusivity::Inclusive if value == self.end => Some(value),
+                    _ => None,
                 }
             }
         }
@@ -24,11 +24,8 @@ impl ExpressionReconstructor for Unroller<'_> {
 
     fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
         // Reconstruct the index.
+        // Note that an index that is not a literal after unrolling is lowered into a select during static single assignment.
         let index = self.reconstruct_expression(*input.index).0;
-        // If the index is not a literal, then emit an error.
-        if !matches!(index, Expression::Literal(_)) {
-            self.emit_err(LoopUnrollerError::variable_array_access(input.span));
-        }
 
         (
             Expression::Access(AccessExpression::Array(ArrayAccess {
@@ -41,6 +38,33 @@ impl ExpressionReconstructor for Unroller<'_> {
         )
     }
 
+    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+        let arguments: Vec<Expression> =
+            input.arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0).collect();
+
+        // The table of a lookup must be an array of literals once constants are propagated.
+        if let Type::Identifier(module) = &input.ty {
+            if CoreFunction::from_symbols(module.name, input.name.name) == Some(CoreFunction::LookupGet) {
+                match arguments.first() {
+                    Some(Expression::Array(table))
+                        if table.elements.iter().all(|element| matches!(element, Expression::Literal(_))) => {}
+                    _ => self.emit_err(LoopUnrollerError::non_constant_lookup_table(input.span)),
+                }
+            }
+        }
+
+        (
+            Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                ty: input.ty,
+                name: input.name,
+                arguments,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
         // Substitute the identifier with the constant value if it is a constant.
         if let Some(expr) = self.constant_propagation_table.borrow().lookup_constant(input.name) {
@@ -30,6 +30,7 @@ impl ProgramReconstructor for Unroller<'_> {
             mappings: input.mappings,
             functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
             consts: Vec::new(),
+            macros: input.macros,
             span: input.span,
         }
     }
@@ -84,6 +85,7 @@ impl ProgramReconstructor for Unroller<'_> {
         // Reconstruct the function block.
         let reconstructed_function = Function {
             annotations: function.annotations,
+            docs: function.docs,
             variant: function.variant,
             identifier: function.identifier,
             input: function.input,
@@ -22,6 +22,46 @@ use leo_span::{Span, Symbol};
 use crate::{unroller::Unroller, VariableSymbol, VariableType};
 
 impl StatementReconstructor for Unroller<'_> {
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        let place = match input.place {
+            // The index of an assigned array element is propagated into, but it need not be constant.
+            Expression::Access(AccessExpression::Array(access)) => {
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: access.array,
+                    index: Box::new(self.reconstruct_expression(*access.index).0),
+                    span: access.span,
+                    id: access.id,
+                }))
+            }
+            place => place,
+        };
+
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                place,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_assert(&mut self, input: AssertStatement) -> (Statement, Self::AdditionalOutput) {
+        let variant = match input.variant {
+            // Constant assertions are checked by the type checker, so they are removed like constant declarations.
+            AssertVariant::ConstAssert(_) => return (Statement::Assert(input), true),
+            AssertVariant::Assert(expr) => AssertVariant::Assert(self.reconstruct_expression(expr).0),
+            AssertVariant::AssertEq(left, right) => {
+                AssertVariant::AssertEq(self.reconstruct_expression(left).0, self.reconstruct_expression(right).0)
+            }
+            AssertVariant::AssertNeq(left, right) => {
+                AssertVariant::AssertNeq(self.reconstruct_expression(left).0, self.reconstruct_expression(right).0)
+            }
+        };
+        (Statement::Assert(AssertStatement { variant, ..input }), Default::default())
+    }
+
     fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
         let scope_index = self.current_scope_index();
 
@@ -131,14 +171,30 @@ impl StatementReconstructor for Unroller<'_> {
         // Reconstruct the bound expressions
         let (new_start, _) = self.reconstruct_expression(input.start);
         let (new_stop, _) = self.reconstruct_expression(input.stop);
+        let new_step = input.step.map(|step| self.reconstruct_expression(step).0);
 
-        // Convert into values
-        match (new_start.clone(), new_stop.clone()) {
+        // Convert into values.
+        // Type checking only accepts literals and constants, but a constant whose value is not known here is reported
+        // rather than unrolled.
+        match (&new_start, &new_stop) {
             (Literal(start_lit), Literal(stop_lit)) => {
-                input.start_value.replace(Some(Value::try_from(&start_lit).unwrap()));
-                input.stop_value.replace(Some(Value::try_from(&stop_lit).unwrap()));
+                input.start_value.replace(Some(Value::try_from(start_lit).unwrap()));
+                input.stop_value.replace(Some(Value::try_from(stop_lit).unwrap()));
+            }
+            (Literal(_), bound) | (bound, _) => {
+                self.emit_err(LoopUnrollerError::non_constant_loop_bound(bound.span()));
+                return (Statement::dummy(input.span, self.node_builder.next_id()), Default::default());
+            }
+        };
+        match &new_step {
+            Some(Literal(step_lit)) => {
+                input.step_value.replace(Some(Value::try_from(step_lit).unwrap()));
+            }
+            Some(step) => {
+                self.emit_err(LoopUnrollerError::non_constant_loop_bound(step.span()));
+                return (Statement::dummy(input.span, self.node_builder.next_id()), Default::default());
             }
-            _ => unreachable!("Type checking guarantees that the loop bounds are literals."),
+            None => {}
         };
 
         // Ensure loop bounds are increasing. This cannot be done in the type checker because constant propagation occurs in this pass.
@@ -187,6 +243,9 @@ impl StatementReconstructor for Unroller<'_> {
                 start_value: input.start_value.clone(),
                 stop_value: input.stop_value.clone(),
                 inclusive: false,
+                step: new_step,
+                step_value: input.step_value.clone(),
+                reverse: input.reverse,
                 block: input.block,
                 span: input.span,
                 id: input.id,
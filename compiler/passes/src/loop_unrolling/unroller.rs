@@ -20,6 +20,7 @@ use leo_ast::{
     IntegerType,
     IterationStatement,
     Literal,
+    Node,
     NodeBuilder,
     Statement,
     StatementReconstructor,
@@ -141,6 +142,21 @@ impl<'a> Unroller<'a> {
             Ok(v) => v,
             Err(s) => return s,
         };
+        // Cast `step` to `I`, defaulting to one if the loop has no step.
+        let step = match input.step_value.borrow().clone() {
+            None => I::one(),
+            Some(step) => match cast_to_number(step) {
+                Ok(v) => v,
+                Err(s) => return s,
+            },
+        };
+
+        // Ensure the step is positive, as otherwise the iteration would never advance.
+        if step <= I::zero() {
+            let span = input.step.as_ref().map_or(input.span, |step| step.span());
+            self.emit_err(LoopUnrollerError::loop_step_must_be_positive(span));
+            return Statement::dummy(input.span, self.node_builder.next_id());
+        }
 
         // Get the index of the current scope.
         let scope_index = self.current_scope_index();
@@ -158,15 +174,17 @@ impl<'a> Unroller<'a> {
         // Creates a new block per iteration inside the outer block statement.
         let iter_blocks = Statement::Block(Block {
             span: input.span,
-            statements: match input.inclusive {
-                true => {
-                    let iter = RangeIterator::new(start, stop, Clusivity::Inclusive);
-                    iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
-                }
-                false => {
-                    let iter = RangeIterator::new(start, stop, Clusivity::Exclusive);
-                    iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
+            statements: {
+                let clusivity = if input.inclusive { Clusivity::Inclusive } else { Clusivity::Exclusive };
+                let mut iterations: Vec<I> = RangeIterator::new(start, stop, step, clusivity).collect();
+                // A reversed loop visits the same values as the forward loop, in the opposite order.
+                if input.reverse {
+                    iterations.reverse();
                 }
+                iterations
+                    .into_iter()
+                    .map(|iteration_count| self.unroll_single_iteration(&input, iteration_count))
+                    .collect()
             },
             id: input.id,
         });
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::MacroExpander;
+
+use leo_ast::{CallExpression, ErrExpression, Expression, ExpressionReconstructor, Node};
+use leo_errors::TypeCheckerError;
+
+use indexmap::IndexMap;
+
+impl ExpressionReconstructor for MacroExpander<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
+        let arguments: Vec<Expression> =
+            input.arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0).collect();
+
+        // Calls to external functions are never calls to macros.
+        let macro_ = match (&*input.function, &input.external) {
+            (Expression::Identifier(identifier), None) => self.macros.get(&identifier.name).cloned(),
+            _ => None,
+        };
+        let macro_ = match macro_ {
+            Some(macro_) => macro_,
+            None => {
+                return (
+                    Expression::Call(CallExpression {
+                        function: input.function,
+                        arguments,
+                        external: input.external,
+                        span: input.span,
+                        id: input.id,
+                    }),
+                    Default::default(),
+                );
+            }
+        };
+
+        let name = macro_.identifier.name;
+        if arguments.len() != macro_.parameters.len() {
+            self.emit_err(TypeCheckerError::macro_arity_mismatch(
+                name,
+                macro_.parameters.len(),
+                arguments.len(),
+                input.span,
+            ));
+            return (Expression::Err(ErrExpression { span: input.span, id: input.id }), Default::default());
+        }
+        if self.expanding.contains(&name) {
+            self.emit_err(TypeCheckerError::recursive_macro(name, input.span));
+            return (Expression::Err(ErrExpression { span: input.span, id: input.id }), Default::default());
+        }
+
+        // Substitute the arguments into the body, and give the copies fresh IDs.
+        let substitutions: IndexMap<_, _> = macro_.parameters.iter().copied().zip(arguments).collect();
+        let mut expansion = macro_.body.substitute(&substitutions);
+        self.refresh_ids(&mut expansion);
+        expansion.set_span(input.span);
+
+        // Expand the calls to other macros in the body.
+        self.expanding.push(name);
+        let expansion = self.reconstruct_expression(expansion).0;
+        self.expanding.pop();
+
+        (expansion, Default::default())
+    }
+}
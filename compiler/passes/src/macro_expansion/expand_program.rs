@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::MacroExpander;
+
+use leo_ast::{Macro, ProgramReconstructor, ProgramScope, Statement, StatementReconstructor};
+use leo_errors::TypeCheckerError;
+use leo_span::{sym, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+impl ProgramReconstructor for MacroExpander<'_> {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        // Check the macros of the program scope before expanding their calls.
+        let consts: IndexSet<Symbol> = input.consts.iter().map(|(name, _)| *name).collect();
+        self.macros = IndexMap::new();
+        for (name, macro_) in input.macros {
+            if input.functions.iter().any(|(function, _)| *function == name) {
+                self.emit_err(TypeCheckerError::macro_conflicts_with_function(name, macro_.identifier.span));
+            }
+            self.check_macro(&macro_, &consts);
+            if self.macros.contains_key(&name) {
+                self.emit_err(TypeCheckerError::duplicate_macro(name, macro_.identifier.span));
+            } else {
+                self.macros.insert(name, macro_);
+            }
+        }
+
+        ProgramScope {
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(name, declaration)| match self.reconstruct_const(declaration) {
+                    (Statement::Const(declaration), _) => (name, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            functions: input.functions.into_iter().map(|(name, f)| (name, self.reconstruct_function(f))).collect(),
+            // The macros are fully expanded, so they are no longer needed.
+            macros: Vec::new(),
+            ..input
+        }
+    }
+}
+
+impl MacroExpander<'_> {
+    /// Checks that the parameters of the macro are distinct, and that its body only refers to its parameters
+    /// and to the constants of the program, so that it cannot capture the variables at its call sites.
+    fn check_macro(&self, macro_: &Macro, consts: &IndexSet<Symbol>) {
+        let mut parameters = IndexSet::new();
+        for parameter in macro_.parameters.iter() {
+            if !parameters.insert(parameter.name) {
+                self.emit_err(TypeCheckerError::duplicate_macro_parameter(
+                    macro_.identifier,
+                    parameter,
+                    parameter.span,
+                ));
+            }
+        }
+        for identifier in macro_.body.free_identifiers() {
+            let name = identifier.name;
+            if !parameters.contains(&name) && !consts.contains(&name) && name != sym::SelfLower && name != sym::block {
+                self.emit_err(TypeCheckerError::unbound_macro_identifier(
+                    macro_.identifier,
+                    identifier,
+                    identifier.span,
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{AccessExpression, Expression, Macro, Node, NodeBuilder, Type};
+use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// Expands the calls to the macros of a program.
+pub struct MacroExpander<'a> {
+    /// An error handler used for any errors found during expansion.
+    pub(crate) handler: &'a Handler,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// The macros of the program scope that is being expanded.
+    pub(crate) macros: IndexMap<Symbol, Macro>,
+    /// The macros whose calls are being expanded, innermost last.
+    pub(crate) expanding: Vec<Symbol>,
+}
+
+impl<'a> MacroExpander<'a> {
+    /// Initializes a new `MacroExpander`.
+    pub(crate) fn new(handler: &'a Handler, node_builder: &'a NodeBuilder) -> Self {
+        Self { handler, node_builder, macros: IndexMap::new(), expanding: Vec::new() }
+    }
+
+    /// Emits an error to the handler.
+    pub(crate) fn emit_err(&self, err: TypeCheckerError) {
+        self.handler.emit_err(err);
+    }
+
+    /// Gives each node of the expression a fresh ID, so that each expansion of a macro is typed on its own.
+    pub(crate) fn refresh_ids(&self, expression: &mut Expression) {
+        expression.set_id(self.node_builder.next_id());
+        // Refresh the IDs of the names and types in the expression, which are not subexpressions.
+        match expression {
+            Expression::Access(AccessExpression::AssociatedConstant(access)) => {
+                self.refresh_type_ids(&mut access.ty);
+                access.name.id = self.node_builder.next_id();
+            }
+            Expression::Access(AccessExpression::AssociatedFunction(access)) => {
+                self.refresh_type_ids(&mut access.ty);
+                access.name.id = self.node_builder.next_id();
+            }
+            Expression::Access(AccessExpression::Member(access)) => access.name.id = self.node_builder.next_id(),
+            Expression::Call(call) => {
+                self.refresh_ids(&mut call.function);
+                if let Some(external) = &mut call.external {
+                    self.refresh_ids(external);
+                }
+            }
+            Expression::Cast(cast) => self.refresh_type_ids(&mut cast.type_),
+            Expression::Struct(struct_) => {
+                struct_.name.id = self.node_builder.next_id();
+                for member in struct_.members.iter_mut() {
+                    member.identifier.id = self.node_builder.next_id();
+                    member.id = self.node_builder.next_id();
+                }
+            }
+            _ => {}
+        }
+        expression.children_mut().into_iter().for_each(|child| self.refresh_ids(child));
+    }
+
+    /// Gives the name of the type a fresh ID, if it is a named type such as `BHP256`.
+    fn refresh_type_ids(&self, type_: &mut Type) {
+        if let Type::Identifier(identifier) = type_ {
+            identifier.id = self.node_builder.next_id();
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The macro expansion pass replaces each call to a macro by the body of the macro, in which each parameter is
+//! replaced by the corresponding argument. The pass is run before the symbol table is created, so the expansions are
+//! type checked like any other code, and the macro definitions are removed from the program.
+//!
+//! Macros are hygienic: the body of a macro can only refer to its parameters and to the constants of the program,
+//! which cannot be shadowed, so it never captures a variable at its call site. Since the arguments are substituted as
+//! syntax trees rather than text, an expansion is never parsed differently than its macro body, e.g. `rotl(a + b, 3u32)`
+//! rotates `a + b`. Note that an argument is evaluated as many times as its parameter occurs in the body.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! macro rotl(x, n) {
+//!     (x << n) | (x >> (32u32 - n))
+//! }
+//!
+//! function main(a: u32, b: u32) -> u32 {
+//!     return rotl(a ^ b, 7u32);
+//! }
+//! ```
+//!
+//! The macro expansion pass produces the following code.
+//! ```leo
+//! function main(a: u32, b: u32) -> u32 {
+//!     return ((a ^ b) << 7u32) | ((a ^ b) >> (32u32 - 7u32));
+//! }
+//! ```
+
+mod expand_expression;
+
+mod expand_program;
+
+mod expand_statement;
+
+pub mod macro_expander;
+pub use macro_expander::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for MacroExpander<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = MacroExpander::new(handler, node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The module resolution pass resolves the items that a program imports from modules, e.g. `import lib.math.Point as P;`.
+//! Each module item that the program uses, directly or through other module items, is copied into the program scope.
+//! Since module items are declared in their own namespaces, they are renamed after the path of their module, e.g. `lib__math__Point`,
+//! and each reference to an item is renamed according to the imports and items of the file that it appears in.
+
+mod resolve_expression;
+
+mod resolve_program;
+
+mod resolve_statement;
+
+pub mod module_resolver;
+pub use module_resolver::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, Module, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+impl<'a> Pass for ModuleResolver<'a> {
+    type Input = (Ast, &'a IndexMap<Vec<Symbol>, Module>, &'a Handler);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, modules, handler): Self::Input) -> Self::Output {
+        let mut reconstructor = ModuleResolver::new(modules, handler);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{
+    ArrayType,
+    Function,
+    FunctionInput,
+    FunctionOutput,
+    Identifier,
+    ImportedItems,
+    Input,
+    MappingType,
+    Module,
+    ModuleImport,
+    NonNegativeNumber,
+    Output,
+    Struct,
+    TupleType,
+    Type,
+};
+use leo_errors::{emitter::Handler, CompilerError, CompilerWarning};
+use leo_span::{Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// An item declared in a module.
+pub enum ModuleItem<'a> {
+    Struct(&'a Struct),
+    Function(&'a Function),
+}
+
+pub struct ModuleResolver<'a> {
+    /// The modules that are imported from, by their paths.
+    pub(crate) modules: &'a IndexMap<Vec<Symbol>, Module>,
+    /// An error handler used for any errors found during resolution.
+    pub(crate) handler: &'a Handler,
+    /// The names in scope in each module, mapped to the names they resolve to.
+    pub(crate) module_scopes: IndexMap<Vec<Symbol>, IndexMap<Symbol, Symbol>>,
+    /// The names in scope in the file being resolved, mapped to the names they resolve to.
+    pub(crate) scope: IndexMap<Symbol, Symbol>,
+    /// The resolved names of the items that have been referenced, in the order they were first referenced.
+    pub(crate) references: IndexSet<Symbol>,
+}
+
+impl<'a> ModuleResolver<'a> {
+    /// Initializes a new `ModuleResolver`, resolving the names in scope in each module.
+    pub(crate) fn new(modules: &'a IndexMap<Vec<Symbol>, Module>, handler: &'a Handler) -> Self {
+        let mut resolver = Self {
+            modules,
+            handler,
+            module_scopes: IndexMap::new(),
+            scope: IndexMap::new(),
+            references: IndexSet::new(),
+        };
+
+        for (path, module) in modules.iter() {
+            let items = module
+                .structs
+                .iter()
+                .map(|(_, struct_)| struct_.identifier)
+                .chain(module.functions.iter().map(|(_, function)| function.identifier))
+                .map(|identifier| (identifier, mangle(path, identifier.name)))
+                .collect();
+            let scope = resolver.create_scope(items, &module.imports);
+            resolver.module_scopes.insert(path.clone(), scope);
+        }
+
+        resolver
+    }
+
+    /// Returns the names in scope in a file with the given items and imports, mapped to the names they resolve to.
+    /// Emits an error for each imported item whose name is already in scope,
+    /// and for each name that is imported by the glob imports of two different modules.
+    /// Note that the items and imported items of a file shadow the items imported by its glob imports.
+    pub(crate) fn create_scope(
+        &self,
+        items: Vec<(Identifier, Symbol)>,
+        imports: &[ModuleImport],
+    ) -> IndexMap<Symbol, Symbol> {
+        // Note that items which are declared more than once are reported by the symbol table creation pass.
+        let mut spans: IndexMap<Symbol, Span> = IndexMap::new();
+        let mut scope = IndexMap::new();
+        for (identifier, name) in items {
+            spans.entry(identifier.name).or_insert(identifier.span);
+            scope.insert(identifier.name, name);
+        }
+
+        for import in imports {
+            if let ImportedItems::List(items) = &import.items {
+                for item in items {
+                    let name = item.name();
+                    match spans.get(&name.name) {
+                        Some(previous) => self
+                            .handler
+                            .emit_err(CompilerError::name_imported_more_than_once(name, previous, name.span)),
+                        None => {
+                            spans.insert(name.name, name.span);
+                            scope.insert(name.name, mangle(&import.module_path(), item.item.name));
+                        }
+                    }
+                }
+            }
+        }
+
+        // The names imported by glob imports, mapped to the imports they are imported by.
+        let mut globbed: IndexMap<Symbol, &ModuleImport> = IndexMap::new();
+        for import in imports.iter().filter(|import| matches!(import.items, ImportedItems::Glob(_))) {
+            let path = import.module_path();
+            for name in self.modules[&path].item_names().filter(|name| !spans.contains_key(name)) {
+                match globbed.get(&name) {
+                    // A module that is glob imported twice imports the same items.
+                    Some(previous) if previous.module_path() == path => {}
+                    Some(previous) => self.handler.emit_err(CompilerError::ambiguous_glob_import(
+                        name,
+                        previous.module_name(),
+                        import.module_name(),
+                        import.span,
+                    )),
+                    None => {
+                        globbed.insert(name, import);
+                        scope.insert(name, mangle(&path, name));
+                    }
+                }
+            }
+        }
+
+        scope
+    }
+
+    /// Emits a warning for each item imported by `imports` that is not among the first `count` references.
+    pub(crate) fn check_unused_imports(&self, imports: &[ModuleImport], count: usize) {
+        let is_used = |name: Symbol| self.references.get_index_of(&name).map_or(false, |index| index < count);
+        for import in imports {
+            let path = import.module_path();
+            match &import.items {
+                ImportedItems::List(items) => {
+                    for item in items.iter().filter(|item| !is_used(mangle(&path, item.item.name))) {
+                        self.handler.emit_warning(CompilerWarning::unused_import(item.name(), item.span()).into());
+                    }
+                }
+                ImportedItems::Glob(_) => {
+                    if !self.modules[&path].item_names().any(|name| is_used(mangle(&path, name))) {
+                        self.handler.emit_warning(
+                            CompilerWarning::unused_import(format!("{}.*", import.module_name()), import.span).into(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the module item with the given resolved name, and the path of its module.
+    pub(crate) fn lookup_item(&self, name: Symbol) -> Option<(&'a Vec<Symbol>, ModuleItem<'a>)> {
+        let modules: &'a IndexMap<Vec<Symbol>, Module> = self.modules;
+        modules.iter().find_map(|(path, module)| {
+            let item = module
+                .structs
+                .iter()
+                .find(|(item, _)| mangle(path, *item) == name)
+                .map(|(_, struct_)| ModuleItem::Struct(struct_))
+                .or_else(|| {
+                    module
+                        .functions
+                        .iter()
+                        .find(|(item, _)| mangle(path, *item) == name)
+                        .map(|(_, function)| ModuleItem::Function(function))
+                })?;
+            Some((path, item))
+        })
+    }
+
+    /// Resolves a reference to an item, recording that the item is referenced.
+    pub(crate) fn resolve(&mut self, identifier: Identifier) -> Identifier {
+        match self.scope.get(&identifier.name) {
+            Some(name) => {
+                self.references.insert(*name);
+                Identifier { name: *name, ..identifier }
+            }
+            None => identifier,
+        }
+    }
+
+    /// Resolves the references to structs in a type.
+    pub(crate) fn resolve_type(&mut self, type_: Type) -> Type {
+        match type_ {
+            Type::Identifier(identifier) => Type::Identifier(self.resolve(identifier)),
+            Type::Array(array_type) => Type::Array(ArrayType::new(
+                self.resolve_type(array_type.element_type().clone()),
+                NonNegativeNumber::from(array_type.length()),
+            )),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|element| self.resolve_type(element.clone())).collect(),
+            )),
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.resolve_type(*mapping_type.key)),
+                value: Box::new(self.resolve_type(*mapping_type.value)),
+            }),
+            type_ => type_,
+        }
+    }
+
+    /// Resolves the type of a function input.
+    pub(crate) fn resolve_input(&mut self, input: Input) -> Input {
+        match input {
+            Input::Internal(input) => Input::Internal(FunctionInput { type_: self.resolve_type(input.type_), ..input }),
+            input => input,
+        }
+    }
+
+    /// Resolves the type of a function output.
+    pub(crate) fn resolve_output(&mut self, output: Output) -> Output {
+        match output {
+            Output::Internal(output) => {
+                Output::Internal(FunctionOutput { type_: self.resolve_type(output.type_), ..output })
+            }
+            output => output,
+        }
+    }
+}
+
+/// Returns the name of the item `item` of the module at `path` in the program, e.g. `lib__math__Point`.
+pub fn mangle(path: &[Symbol], item: Symbol) -> Symbol {
+    Symbol::intern(&path.iter().chain([item].iter()).map(|segment| segment.to_string()).collect::<Vec<_>>().join("__"))
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ModuleResolver;
+
+use leo_ast::{CallExpression, Expression, ExpressionReconstructor, StructExpression, StructVariableInitializer};
+
+impl ExpressionReconstructor for ModuleResolver<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
+        // Note that calls to functions of other programs are not resolved.
+        let function = match (*input.function, &input.external) {
+            (Expression::Identifier(identifier), None) => Expression::Identifier(self.resolve(identifier)),
+            (function, _) => function,
+        };
+
+        (
+            Expression::Call(CallExpression {
+                function: Box::new(function),
+                arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                external: input.external,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: self.resolve(input.name),
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: member.identifier,
+                        expression: member.expression.map(|expression| self.reconstruct_expression(expression).0),
+                        span: member.span,
+                        id: member.id,
+                    })
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ModuleItem, ModuleResolver};
+
+use leo_ast::{Finalize, Function, Mapping, Member, Program, ProgramReconstructor, StatementReconstructor, Struct};
+
+impl ProgramReconstructor for ModuleResolver<'_> {
+    fn reconstruct_program(&mut self, input: Program) -> Program {
+        let program_scopes = input
+            .program_scopes
+            .into_iter()
+            .map(|(name, scope)| {
+                // Resolve the program scope, in which its own items and the items it imports are in scope.
+                let items = scope
+                    .structs
+                    .iter()
+                    .map(|(_, struct_)| struct_.identifier)
+                    .chain(scope.functions.iter().map(|(_, function)| function.identifier))
+                    .map(|identifier| (identifier, identifier.name))
+                    .collect();
+                self.scope = self.create_scope(items, &input.module_imports);
+                let mut scope = self.reconstruct_program_scope(scope);
+                let program_references = self.references.len();
+
+                // Resolve the module items that are referenced, including those referenced by other module items.
+                let (mut structs, mut functions) = (Vec::new(), Vec::new());
+                let mut index = 0;
+                while let Some(name) = self.references.get_index(index).copied() {
+                    index += 1;
+                    if let Some((path, item)) = self.lookup_item(name) {
+                        self.scope = self.module_scopes[path].clone();
+                        match item {
+                            ModuleItem::Struct(struct_) => {
+                                structs.push((name, self.reconstruct_struct(struct_.clone())))
+                            }
+                            ModuleItem::Function(function) => {
+                                functions.push((name, self.reconstruct_function(function.clone())))
+                            }
+                        }
+                    }
+                }
+
+                // Only the references made by the program scope itself count as uses of its imports.
+                self.check_unused_imports(&input.module_imports, program_references);
+
+                // The module items are declared before the items of the program scope.
+                structs.append(&mut scope.structs);
+                functions.append(&mut scope.functions);
+                scope.structs = structs;
+                scope.functions = functions;
+
+                (name, scope)
+            })
+            .collect();
+
+        // The imports of the program have been resolved.
+        Program { imports: input.imports, module_imports: Vec::new(), stubs: input.stubs, program_scopes }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            annotations: input.annotations,
+            docs: input.docs,
+            variant: input.variant,
+            identifier: self.resolve(input.identifier),
+            input: input.input.into_iter().map(|input| self.resolve_input(input)).collect(),
+            output: input.output.into_iter().map(|output| self.resolve_output(output)).collect(),
+            output_type: self.resolve_type(input.output_type),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input.into_iter().map(|input| self.resolve_input(input)).collect(),
+                output: finalize.output.into_iter().map(|output| self.resolve_output(output)).collect(),
+                output_type: self.resolve_type(finalize.output_type),
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: finalize.id,
+            }),
+            span: input.span,
+            id: input.id,
+        }
+    }
+
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            annotations: input.annotations,
+            docs: input.docs,
+            identifier: self.resolve(input.identifier),
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member { type_: self.resolve_type(member.type_), ..member })
+                .collect(),
+            is_record: input.is_record,
+            span: input.span,
+            id: input.id,
+        }
+    }
+
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping {
+            key_type: self.resolve_type(input.key_type),
+            value_type: self.resolve_type(input.value_type),
+            ..input
+        }
+    }
+}
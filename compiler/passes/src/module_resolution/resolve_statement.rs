@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ModuleResolver;
+
+use leo_ast::{DefinitionStatement, ExpressionReconstructor, Statement, StatementReconstructor};
+
+impl StatementReconstructor for ModuleResolver<'_> {
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: input.place,
+                type_: self.resolve_type(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Recursion Expansion pass expands the inline functions that are annotated with `@recursive(depth)` into copies,
+//! one for each level of the recursion, so that the call graph is acyclic and the copies can be inlined.
+//! The copy at each level calls the copy at the next level instead of itself, and the copy at the maximum depth
+//! replaces its calls to itself by the default value of the return type, which must never be used.
+//!
+//! To prove that the maximum depth is not exceeded, every copy but the first takes a flag that is set if the copy is
+//! reached, i.e. if each of the calls that lead to it is on the path that its caller takes. Before each statement that
+//! calls itself, a copy records its own flag in a variable that it passes on, which the flattening pass guards with
+//! the conditions of the statement. The copy at the maximum depth asserts that its flag is not set before each such
+//! statement. Note that calls that follow an early return are considered to be on the path, so the recursive case of a
+//! function should be written in the `else` branch of its base case.
+//!
+//! Consider the following Leo code, which is type checked and unrolled.
+//! ```leo
+//! @recursive(2)
+//! inline sum(n: u8) -> u8 {
+//!     if n == 0u8 {
+//!         return 0u8;
+//!     } else {
+//!         return n + sum(n.sub_wrapped(1u8));
+//!     }
+//! }
+//! ```
+//!
+//! The expansion pass produces the following code.
+//! ```leo
+//! inline sum(n: u8) -> u8 {
+//!     let $reach: bool = false;
+//!     if n == 0u8 {
+//!         return 0u8;
+//!     } else {
+//!         $reach = true;
+//!         return n + sum$rec1($reach, n.sub_wrapped(1u8));
+//!     }
+//! }
+//!
+//! inline sum$rec1($reached: bool, n: u8) -> u8 {
+//!     let $reach: bool = false;
+//!     if n == 0u8 {
+//!         return 0u8;
+//!     } else {
+//!         $reach = $reached;
+//!         return n + sum$rec2($reach, n.sub_wrapped(1u8));
+//!     }
+//! }
+//!
+//! inline sum$rec2($reached: bool, n: u8) -> u8 {
+//!     if n == 0u8 {
+//!         return 0u8;
+//!     } else {
+//!         assert(!$reached);
+//!         return n + 0u8;
+//!     }
+//! }
+//! ```
+
+pub mod recursion_expander;
+pub use recursion_expander::*;
+
+use crate::{CallGraph, Pass, SymbolTable, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+/// The maximum depth that a `@recursive` annotation may declare.
+pub const MAX_RECURSION_DEPTH: usize = 32;
+
+impl<'a> Pass for RecursionExpander<'a> {
+    type Input = (Ast, &'a NodeBuilder, SymbolTable, CallGraph, &'a TypeTable);
+    type Output = Result<(Ast, SymbolTable, CallGraph)>;
+
+    fn do_pass((ast, node_builder, symbol_table, call_graph, type_table): Self::Input) -> Self::Output {
+        let mut reconstructor = RecursionExpander::new(node_builder, symbol_table, call_graph, type_table);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok((Ast::new(program), reconstructor.symbol_table, reconstructor.call_graph))
+    }
+}
@@ -0,0 +1,283 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CallGraph, SymbolTable, TypeTable};
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+/// The parameter of a copy of a recursive function that is set if the copy is reached.
+const REACHED: &str = "$reached";
+
+/// The variable of a copy of a recursive function that records whether the copy at the next level is reached.
+const REACH: &str = "$reach";
+
+/// Expands the recursive functions of a program into a copy for each level of their recursion.
+pub struct RecursionExpander<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// The symbol table, to which the copies are added.
+    pub(crate) symbol_table: SymbolTable,
+    /// The call graph, to which the calls of the copies are added.
+    pub(crate) call_graph: CallGraph,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// The recursive function that is being expanded.
+    pub(crate) function: Symbol,
+    /// The copy of the function that is being reconstructed.
+    pub(crate) copy: Symbol,
+    /// The copy at the next level, which the calls to the function are redirected to, or `None` at the maximum depth.
+    pub(crate) next: Option<Symbol>,
+    /// Whether the statement that is being reconstructed calls the function.
+    pub(crate) calls_itself: bool,
+}
+
+impl<'a> RecursionExpander<'a> {
+    /// Initializes a new `RecursionExpander`.
+    pub fn new(
+        node_builder: &'a NodeBuilder,
+        symbol_table: SymbolTable,
+        call_graph: CallGraph,
+        type_table: &'a TypeTable,
+    ) -> Self {
+        Self {
+            node_builder,
+            symbol_table,
+            call_graph,
+            type_table,
+            function: Symbol::intern(""),
+            copy: Symbol::intern(""),
+            next: None,
+            calls_itself: false,
+        }
+    }
+
+    /// Returns the copies of `function` for each level of its recursion, up to `depth`.
+    /// The copy at level 0 is the function itself, so that its callers are unchanged.
+    pub(crate) fn expand(&mut self, function: Function, depth: usize) -> Vec<(Symbol, Function)> {
+        let name = function.name();
+        let level_name = |level: usize| match level {
+            0 => name,
+            level => Symbol::intern(&format!("{name}$rec{level}")),
+        };
+        self.function = name;
+
+        (0..=depth)
+            .map(|level| {
+                self.copy = level_name(level);
+                self.next = (level < depth).then(|| level_name(level + 1));
+
+                let mut copy = function.clone();
+                let span = copy.span;
+                copy.identifier.name = self.copy;
+                if level > 0 {
+                    copy.input.insert(
+                        0,
+                        Input::Internal(FunctionInput {
+                            identifier: self.boolean_identifier(REACHED, span),
+                            mode: Mode::None,
+                            type_: Type::Boolean,
+                            reference: false,
+                            span,
+                            id: self.node_builder.next_id(),
+                        }),
+                    );
+                }
+
+                let (mut block, _) = self.reconstruct_block(copy.block);
+                if self.next.is_some() {
+                    block.statements.insert(
+                        0,
+                        Statement::Definition(DefinitionStatement {
+                            declaration_type: DeclarationType::Let,
+                            place: Expression::Identifier(self.boolean_identifier(REACH, span)),
+                            type_: Type::Boolean,
+                            value: self.boolean(false, span),
+                            span,
+                            id: self.node_builder.next_id(),
+                        }),
+                    );
+                }
+                copy.block = block;
+
+                if level > 0 {
+                    self.symbol_table
+                        .insert_fn(copy.name(), &copy)
+                        .expect("The copies of a recursive function have fresh names.");
+                }
+                (copy.name(), copy)
+            })
+            .collect()
+    }
+
+    /// Returns the statement that precedes a statement that calls the function: at the maximum depth, an assertion
+    /// that the copy is not reached, and otherwise an assignment that records whether the next level is reached.
+    fn record_reach(&self, span: Span) -> Statement {
+        let reached = match self.copy == self.function {
+            true => self.boolean(true, span),
+            false => Expression::Identifier(self.boolean_identifier(REACHED, span)),
+        };
+        match self.next {
+            Some(_) => Statement::Assign(Box::new(AssignStatement {
+                place: Expression::Identifier(self.boolean_identifier(REACH, span)),
+                value: reached,
+                span,
+                id: self.node_builder.next_id(),
+            })),
+            None => {
+                let id = self.node_builder.next_id();
+                self.type_table.insert(id, Type::Boolean);
+                let not_reached = Expression::Unary(UnaryExpression {
+                    receiver: Box::new(reached),
+                    op: UnaryOperation::Not,
+                    span,
+                    id,
+                });
+                Statement::Assert(AssertStatement {
+                    variant: AssertVariant::Assert(not_reached),
+                    span,
+                    id: self.node_builder.next_id(),
+                })
+            }
+        }
+    }
+
+    /// Returns the identifier `name`, whose type is `bool`.
+    fn boolean_identifier(&self, name: &str, span: Span) -> Identifier {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Boolean);
+        Identifier { name: Symbol::intern(name), span, id }
+    }
+
+    /// Returns the boolean literal `value`.
+    fn boolean(&self, value: bool, span: Span) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Boolean);
+        Expression::Literal(Literal::Boolean(value, span, id))
+    }
+
+    /// Returns the default value of `type_`, which replaces the calls beyond the maximum depth.
+    /// The type checker ensures that the return types of recursive functions have default values.
+    pub(crate) fn default_value(&self, type_: &Type, span: Span) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_.clone());
+        let zero = || "0".to_string();
+        match type_ {
+            Type::Boolean => Expression::Literal(Literal::Boolean(false, span, id)),
+            Type::Field => Expression::Literal(Literal::Field(zero(), span, id)),
+            Type::Group => Expression::Literal(Literal::Group(Box::new(GroupLiteral::Single(zero(), span, id)))),
+            Type::Integer(integer_type) => Expression::Literal(Literal::Integer(*integer_type, zero(), span, id)),
+            Type::Scalar => Expression::Literal(Literal::Scalar(zero(), span, id)),
+            Type::Array(array_type) => Expression::Array(ArrayExpression {
+                elements: (0..array_type.length())
+                    .map(|_| self.default_value(array_type.element_type(), span))
+                    .collect(),
+                span,
+                id,
+            }),
+            Type::Tuple(tuple_type) => Expression::Tuple(TupleExpression {
+                elements: tuple_type.elements().iter().map(|type_| self.default_value(type_, span)).collect(),
+                span,
+                id,
+            }),
+            Type::Identifier(identifier) => {
+                let struct_ = self.symbol_table.lookup_struct(identifier.name).expect("The struct is defined.");
+                Expression::Struct(StructExpression {
+                    name: *identifier,
+                    members: struct_
+                        .members
+                        .iter()
+                        .map(|member| StructVariableInitializer {
+                            identifier: member.identifier,
+                            expression: Some(self.default_value(&member.type_, span)),
+                            span,
+                            id: self.node_builder.next_id(),
+                        })
+                        .collect(),
+                    span,
+                    id,
+                })
+            }
+            _ => unreachable!("The return type of a recursive function has a default value."),
+        }
+    }
+}
+
+impl ExpressionReconstructor for RecursionExpander<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
+        let callee = match input.function.as_ref() {
+            Expression::Identifier(identifier) => *identifier,
+            _ => unreachable!("Parsing guarantees that a function name is always an identifier."),
+        };
+        if callee.name != self.function || input.external.is_some() {
+            // The copies call the same functions as the function.
+            self.call_graph.add_edge(self.copy, callee.name);
+            let arguments = input.arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0);
+            return (Expression::Call(CallExpression { arguments: arguments.collect(), ..input }), Default::default());
+        }
+
+        self.calls_itself = true;
+        match self.next {
+            // Call the copy at the next level, passing on whether it is reached.
+            Some(next) => {
+                self.call_graph.add_edge(self.copy, next);
+                let mut arguments = vec![Expression::Identifier(self.boolean_identifier(REACH, input.span))];
+                arguments.extend(input.arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0));
+                let function = Box::new(Expression::Identifier(Identifier { name: next, ..callee }));
+                (Expression::Call(CallExpression { function, arguments, ..input }), Default::default())
+            }
+            // Beyond the maximum depth, the call is never reached.
+            None => {
+                let output_type = self.symbol_table.lookup_fn_symbol(self.function).unwrap().output_type.clone();
+                (self.default_value(&output_type, input.span), Default::default())
+            }
+        }
+    }
+}
+
+impl StatementReconstructor for RecursionExpander<'_> {
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        // The statement that contains this block may call the function as well.
+        let calls_itself = std::mem::take(&mut self.calls_itself);
+
+        let mut statements = Vec::with_capacity(input.statements.len());
+        for statement in input.statements {
+            let (statement, _) = self.reconstruct_statement(statement);
+            if std::mem::take(&mut self.calls_itself) {
+                statements.push(self.record_reach(statement.span()));
+            }
+            statements.push(statement);
+        }
+
+        self.calls_itself = calls_itself;
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+}
+
+impl ProgramReconstructor for RecursionExpander<'_> {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        let mut functions = Vec::with_capacity(input.functions.len());
+        for (name, function) in input.functions {
+            match function.recursion_depth() {
+                Some(depth) if function.variant == Variant::Inline => functions.extend(self.expand(function, depth)),
+                _ => functions.push((name, function)),
+            }
+        }
+        ProgramScope { functions, ..input }
+    }
+}
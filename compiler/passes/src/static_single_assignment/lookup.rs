@@ -0,0 +1,224 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::StaticSingleAssigner;
+
+use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    AssertStatement,
+    AssertVariant,
+    AssociatedFunction,
+    BinaryOperation,
+    CastExpression,
+    Expression,
+    ExpressionConsumer,
+    IntegerType,
+    Literal,
+    Statement,
+    Type,
+};
+
+use snarkvm::console::{network::Testnet3, types::Field};
+use std::str::FromStr;
+
+impl StaticSingleAssigner<'_> {
+    /// Lowers the lookup `Lookup::get(table, index)` of a constant table into an evaluation of the polynomial that
+    /// interpolates the table, accumulating any statements that are generated.
+    /// The polynomial `p` of degree `n - 1` with `p(k) = table[k]` for each of the `n` indices is computed at compile time,
+    /// and evaluated at `index as field` by Horner's rule, i.e. `(c[n-1] * x + c[n-2]) * x + ... + c[0]`.
+    /// This costs `n - 2` multiplications, whereas a select over the table costs a comparison for each index and a select for each element.
+    /// The index is asserted to be within bounds, and the result is cast to the element type of the table.
+    /// A literal index selects the element statically.
+    pub(crate) fn consume_lookup(&mut self, input: AssociatedFunction) -> (Expression, Vec<Statement>) {
+        let mut arguments = input.arguments.into_iter();
+        let (table, index) = match (arguments.next(), arguments.next()) {
+            (Some(Expression::Array(table)), Some(index)) => (table, index),
+            _ => unreachable!("Loop unrolling guarantees that the table of a lookup is an array of literals."),
+        };
+        let element_type = match self.type_table.get(&input.id) {
+            Some(element_type) => element_type,
+            None => unreachable!("Type checking guarantees that all expressions are typed."),
+        };
+
+        // A literal index selects the element statically.
+        if let Expression::Literal(_) = index {
+            return self.consume_access(AccessExpression::Array(ArrayAccess {
+                array: Box::new(Expression::Array(table)),
+                index: Box::new(index),
+                span: input.span,
+                id: input.id,
+            }));
+        }
+
+        let index_type = match self.type_table.get(&index.id()) {
+            Some(Type::Integer(index_type)) => index_type,
+            _ => unreachable!("Type checking guarantees that the index of a lookup is an integer."),
+        };
+        let (index, mut statements) = self.consume_expression(index);
+
+        // Assert that the index is within bounds, i.e. `0 <= index <= n - 1`.
+        let length = table.elements.len();
+        let mut bounds = Vec::new();
+        if index_type.is_signed() {
+            let zero = self.index_literal(0, index_type);
+            bounds.push(self.boolean_binary(index.clone(), BinaryOperation::Gte, zero));
+        }
+        // Note that an index of a type whose maximum is at most `n - 1` is always within bounds.
+        if length - 1 <= max_value(index_type) {
+            let last = self.index_literal(length - 1, index_type);
+            bounds.push(self.boolean_binary(index.clone(), BinaryOperation::Lte, last));
+        }
+        if let Some(in_bounds) =
+            bounds.into_iter().reduce(|left, right| self.boolean_binary(left, BinaryOperation::And, right))
+        {
+            let (in_bounds, stmts) = self.consume_expression(in_bounds);
+            statements.extend(stmts);
+            statements.push(Statement::Assert(AssertStatement {
+                variant: AssertVariant::Assert(in_bounds),
+                span: input.span,
+                id: self.node_builder.next_id(),
+            }));
+        }
+
+        // Cast the index to a field.
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Field);
+        let (x, stmts) = self.consume_expression(Expression::Cast(CastExpression {
+            expression: Box::new(index),
+            type_: Type::Field,
+            span: Default::default(),
+            id,
+        }));
+        statements.extend(stmts);
+
+        // Evaluate the interpolating polynomial by Horner's rule.
+        let values = table.elements.iter().map(field_value).collect::<Vec<_>>();
+        let mut coefficients = interpolate(&values).into_iter().rev();
+        let leading = coefficients.next().unwrap_or_else(field_zero);
+        let mut polynomial = self.field_literal(field_string(leading));
+        for coefficient in coefficients {
+            polynomial = self.field_binary(polynomial, BinaryOperation::Mul, x.clone());
+            if coefficient != field_zero() {
+                let coefficient = self.field_literal(field_string(coefficient));
+                polynomial = self.field_binary(polynomial, BinaryOperation::Add, coefficient);
+            }
+        }
+
+        // Cast the result to the element type of the table.
+        let result = match element_type {
+            Type::Field => polynomial,
+            element_type => {
+                let id = self.node_builder.next_id();
+                self.type_table.insert(id, element_type.clone());
+                Expression::Cast(CastExpression {
+                    expression: Box::new(polynomial),
+                    type_: element_type,
+                    span: Default::default(),
+                    id,
+                })
+            }
+        };
+        let (result, stmts) = self.consume_expression(result);
+        statements.extend(stmts);
+
+        (result, statements)
+    }
+}
+
+/// Returns the coefficients `c[0], ..., c[n-1]` of the polynomial `p` of degree `n - 1` with `p(k) = values[k]` for `k` in `0..n`.
+/// The polynomial is the sum of `values[k] * L_k`, where `L_k(x)` is the product of `(x - j) / (k - j)` for `j != k`.
+fn interpolate(values: &[Field<Testnet3>]) -> Vec<Field<Testnet3>> {
+    let n = values.len();
+    let points = (0..n).map(|k| field_from_str(&k.to_string())).collect::<Vec<_>>();
+
+    // Compute the coefficients of `Z(x)`, the product of `(x - j)` for all `j`.
+    let mut vanishing = vec![field_one()];
+    for point in &points {
+        let mut product = vec![field_zero(); vanishing.len() + 1];
+        for (i, coefficient) in vanishing.iter().enumerate() {
+            product[i + 1] += *coefficient;
+            product[i] -= *coefficient * *point;
+        }
+        vanishing = product;
+    }
+
+    let mut coefficients = vec![field_zero(); n];
+    for (k, (point, value)) in points.iter().zip(values).enumerate() {
+        // Divide `Z(x)` by `(x - k)`.
+        let mut quotient = vec![field_zero(); n];
+        quotient[n - 1] = vanishing[n];
+        for i in (1..n).rev() {
+            quotient[i - 1] = vanishing[i] + *point * quotient[i];
+        }
+        // Scale the quotient by `value / (k - 0) * ... * (k - (n - 1))`, skipping `j = k`.
+        let denominator = points
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != k)
+            .fold(field_one(), |product, (_, other)| product * (*point - *other));
+        let scale = *value * denominator.inverse().expect("The points of the interpolation are distinct.");
+        for (coefficient, term) in coefficients.iter_mut().zip(quotient) {
+            *coefficient += scale * term;
+        }
+    }
+
+    coefficients
+}
+
+/// Returns the value of a field or integer literal as a field element.
+pub(super) fn field_value(element: &Expression) -> Field<Testnet3> {
+    match element {
+        Expression::Literal(Literal::Field(value, ..)) | Expression::Literal(Literal::Integer(_, value, ..)) => {
+            match value.strip_prefix('-') {
+                Some(magnitude) => -field_from_str(magnitude),
+                None => field_from_str(value),
+            }
+        }
+        _ => unreachable!("Loop unrolling guarantees that the table of a lookup is an array of literals."),
+    }
+}
+
+/// Parses an unsigned decimal number into a field element.
+fn field_from_str(value: &str) -> Field<Testnet3> {
+    Field::from_str(&format!("{}field", value.replace('_', ""))).expect("Type checking guarantees valid literals.")
+}
+
+/// Returns the decimal value of a field element, without the `field` suffix.
+pub(super) fn field_string(value: Field<Testnet3>) -> String {
+    value.to_string().trim_end_matches("field").to_string()
+}
+
+fn field_zero() -> Field<Testnet3> {
+    field_from_str("0")
+}
+
+pub(super) fn field_one() -> Field<Testnet3> {
+    field_from_str("1")
+}
+
+/// Returns the maximum value of an integer type, saturated to `usize`.
+fn max_value(type_: IntegerType) -> usize {
+    match type_ {
+        IntegerType::I8 => i8::MAX as usize,
+        IntegerType::U8 => u8::MAX as usize,
+        IntegerType::I16 => i16::MAX as usize,
+        IntegerType::U16 => u16::MAX as usize,
+        IntegerType::I32 => i32::MAX as usize,
+        IntegerType::U32 => u32::MAX as usize,
+        _ => usize::MAX,
+    }
+}
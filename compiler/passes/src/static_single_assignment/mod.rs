@@ -49,6 +49,27 @@
 //! }
 //! ```
 //! Note that the redundant assignments have no effect on the bytecode generated by the compiler.
+//!
+//! If dead branch pruning is enabled, a branch that a constant condition rules out, e.g. the then-block of `if false { ... }`,
+//! is replaced with an empty block before it is renamed.
+//!
+//! If power lowering is enabled, a power with a constant exponent, e.g. `x ** 5u8`, is unrolled into multiplications
+//! along an addition chain for the exponent, which is a shortest one for small exponents. A power of a constant `field`
+//! base and exponent, e.g. `2field ** 3field`, is evaluated at compile time.
+//!
+//! If division lowering is enabled, an unsigned division or remainder by a power of two, e.g. `x / 8u8` or `x % 8u8`,
+//! is replaced with a shift or a mask, i.e. `x >> 3u32` or `x & 7u8`.
+//!
+//! Each integer operation is performed with the overflow behavior of the build, see [`OverflowBehavior`], before it
+//! is lowered. For example, `a + b` becomes `a.add_wrapped(b)` if the build wraps around on overflow.
+//!
+//! A lookup `Lookup::get(table, index)` of a constant table is replaced with an evaluation of the polynomial that
+//! interpolates the table at `index`.
+
+mod lookup;
+
+pub mod overflow_behavior;
+pub use overflow_behavior::*;
 
 mod rename_expression;
 
@@ -56,6 +77,9 @@ mod rename_program;
 
 mod rename_statement;
 
+pub mod ssa_options;
+pub use ssa_options::*;
+
 pub mod static_single_assigner;
 pub use static_single_assigner::*;
 
@@ -65,11 +89,11 @@ use leo_ast::{Ast, NodeBuilder, ProgramConsumer};
 use leo_errors::Result;
 
 impl<'a> Pass for StaticSingleAssigner<'a> {
-    type Input = (Ast, &'a NodeBuilder, &'a Assigner, &'a SymbolTable, &'a TypeTable);
+    type Input = (Ast, &'a NodeBuilder, &'a Assigner, &'a SymbolTable, &'a TypeTable, SsaOptions);
     type Output = Result<Ast>;
 
-    fn do_pass((ast, node_builder, assigner, symbol_table, type_table): Self::Input) -> Self::Output {
-        let mut consumer = StaticSingleAssigner::new(node_builder, symbol_table, type_table, assigner);
+    fn do_pass((ast, node_builder, assigner, symbol_table, type_table, options): Self::Input) -> Self::Output {
+        let mut consumer = StaticSingleAssigner::new(node_builder, symbol_table, type_table, assigner, options);
         let program = consumer.consume_program(ast.into_repr());
 
         Ok(Ast::new(program))
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{BinaryOperation, UnaryOperation};
+
+use std::{fmt, str::FromStr};
+
+/// The integer operations that halt on overflow, paired with their wrapping variants.
+const WRAPPING_BINARY_OPERATIONS: [(BinaryOperation, BinaryOperation); 8] = [
+    (BinaryOperation::Add, BinaryOperation::AddWrapped),
+    (BinaryOperation::Sub, BinaryOperation::SubWrapped),
+    (BinaryOperation::Mul, BinaryOperation::MulWrapped),
+    (BinaryOperation::Div, BinaryOperation::DivWrapped),
+    (BinaryOperation::Rem, BinaryOperation::RemWrapped),
+    (BinaryOperation::Pow, BinaryOperation::PowWrapped),
+    (BinaryOperation::Shl, BinaryOperation::ShlWrapped),
+    (BinaryOperation::Shr, BinaryOperation::ShrWrapped),
+];
+
+/// How the arithmetic of a program behaves when its result does not fit in its integer type.
+/// It is applied to every integer operation of the program, including those that other lowerings produce.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OverflowBehavior {
+    /// Each operation behaves as it is written: `a + b` halts on overflow, and `a.add_wrapped(b)` wraps around.
+    #[default]
+    Checked,
+    /// Each operation wraps around, as if `a + b` were written `a.add_wrapped(b)`.
+    Wrapping,
+    /// Each operation halts on overflow, even if it is written to wrap around, as if `a.add_wrapped(b)` were written `a + b`.
+    /// This finds the operations that a program relies on to wrap around.
+    Trap,
+}
+
+impl OverflowBehavior {
+    /// All of the behaviors, in the order they are listed in.
+    pub const ALL: [OverflowBehavior; 3] =
+        [OverflowBehavior::Checked, OverflowBehavior::Wrapping, OverflowBehavior::Trap];
+
+    /// Returns the name of the behavior, as used on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            OverflowBehavior::Checked => "checked",
+            OverflowBehavior::Wrapping => "wrapping",
+            OverflowBehavior::Trap => "trap",
+        }
+    }
+
+    /// Returns the operation that `op` is performed as, if its operands are integers.
+    pub fn binary_operation(&self, op: BinaryOperation) -> BinaryOperation {
+        let (checked, wrapping) = match WRAPPING_BINARY_OPERATIONS.iter().find(|pair| pair.0 == op || pair.1 == op) {
+            Some(pair) => *pair,
+            None => return op,
+        };
+        match self {
+            OverflowBehavior::Checked => op,
+            OverflowBehavior::Wrapping => wrapping,
+            OverflowBehavior::Trap => checked,
+        }
+    }
+
+    /// Returns the operation that `op` is performed as, if its operand is an integer.
+    /// Note that a negation has no wrapping variant, so it always halts on overflow.
+    pub fn unary_operation(&self, op: UnaryOperation) -> UnaryOperation {
+        match (self, op) {
+            (OverflowBehavior::Wrapping, UnaryOperation::Abs) => UnaryOperation::AbsWrapped,
+            (OverflowBehavior::Trap, UnaryOperation::AbsWrapped) => UnaryOperation::Abs,
+            _ => op,
+        }
+    }
+}
+
+impl fmt::Display for OverflowBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for OverflowBehavior {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|behavior| behavior.name() == name).ok_or_else(|| {
+            let names = Self::ALL.iter().map(|behavior| format!("`{behavior}`")).collect::<Vec<_>>().join(", ");
+            format!("unknown overflow behavior `{name}`, expected one of {names}")
+        })
+    }
+}
@@ -22,13 +22,15 @@ use leo_ast::{
     ArrayExpression,
     AssociatedFunction,
     BinaryExpression,
+    BinaryOperation,
     CallExpression,
     CastExpression,
+    CoreFunction,
     Expression,
     ExpressionConsumer,
     Identifier,
     Literal,
-    MemberAccess,
+    Node,
     Statement,
     Struct,
     StructExpression,
@@ -36,6 +38,7 @@ use leo_ast::{
     TernaryExpression,
     TupleAccess,
     TupleExpression,
+    Type,
     UnaryExpression,
     UnitExpression,
 };
@@ -49,6 +52,13 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
     /// Consumes an access expression, accumulating any statements that are generated.
     fn consume_access(&mut self, input: AccessExpression) -> Self::Output {
         let (expr, mut statements) = match input {
+            // A lookup is lowered into an evaluation of the polynomial that interpolates its table.
+            AccessExpression::AssociatedFunction(function)
+                if matches!(&function.ty, Type::Identifier(module)
+                    if CoreFunction::from_symbols(module.name, function.name.name) == Some(CoreFunction::LookupGet)) =>
+            {
+                return self.consume_lookup(function);
+            }
             AccessExpression::AssociatedFunction(function) => {
                 let mut statements = Vec::new();
                 (
@@ -79,16 +89,7 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
                     }
                 }
 
-                let (expr, statements) = self.consume_expression(*member.inner);
-                (
-                    AccessExpression::Member(MemberAccess {
-                        inner: Box::new(expr),
-                        name: member.name,
-                        span: member.span,
-                        id: member.id,
-                    }),
-                    statements,
-                )
+                return self.consume_member_access(member);
             }
             AccessExpression::Tuple(tuple) => {
                 let (expr, statements) = self.consume_expression(*tuple.tuple);
@@ -102,6 +103,10 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
                     statements,
                 )
             }
+            // An access at an index that is not known at compile time is lowered into a select of the element.
+            AccessExpression::Array(input) if !matches!(*input.index, Expression::Literal(_)) => {
+                return self.consume_array_element_access(input);
+            }
             AccessExpression::Array(input) => {
                 let (array, statements) = self.consume_expression(*input.array);
 
@@ -150,7 +155,42 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
     }
 
     /// Consumes a binary expression, accumulating any statements that are generated.
-    fn consume_binary(&mut self, input: BinaryExpression) -> Self::Output {
+    fn consume_binary(&mut self, mut input: BinaryExpression) -> Self::Output {
+        // An integer operation is performed with the overflow behavior of the build.
+        if let Some(Type::Integer(_)) = self.type_table.get(&input.id) {
+            input.op = self.options.overflow_behavior.binary_operation(input.op);
+        }
+
+        // A power of two field literals may be evaluated at compile time.
+        if self.options.lower_powers
+            && input.op == BinaryOperation::Pow
+            && matches!(
+                (&*input.left, &*input.right),
+                (Expression::Literal(Literal::Field(..)), Expression::Literal(Literal::Field(..)))
+            )
+        {
+            return self.fold_field_power(input);
+        }
+
+        // A power with a constant exponent may be unrolled into multiplications.
+        if self.options.lower_powers && matches!(input.op, BinaryOperation::Pow | BinaryOperation::PowWrapped) {
+            if let Some(exponent) = Self::constant_exponent(&input.right) {
+                return self.lower_power(input, exponent);
+            }
+        }
+
+        // An unsigned division or remainder by a power of two may be lowered into a shift or a mask.
+        if self.options.lower_divisions
+            && matches!(
+                input.op,
+                BinaryOperation::Div | BinaryOperation::DivWrapped | BinaryOperation::Rem | BinaryOperation::RemWrapped
+            )
+        {
+            if let Some(expression) = self.lower_division(&input) {
+                return self.consume_expression(expression);
+            }
+        }
+
         // Reconstruct the lhs of the binary expression.
         let (left_expression, mut statements) = self.consume_expression(*input.left);
         // Reconstruct the rhs of the binary expression.
@@ -203,6 +243,13 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
 
     /// Consumes a cast expression, accumulating any statements that are generated.
     fn consume_cast(&mut self, input: CastExpression) -> Self::Output {
+        // A cast between a bit string and an unsigned integer is lowered into arithmetic on the bits.
+        if matches!(input.type_, Type::Array(_))
+            || matches!(self.type_table.get(&input.expression.id()), Some(Type::Array(_)))
+        {
+            return self.lower_bit_string_cast(input);
+        }
+
         // Reconstruct the expression being casted.
         let (expression, mut statements) = self.consume_expression(*input.expression);
 
@@ -319,6 +366,13 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
 
     /// Consumes a ternary expression, accumulating any statements that are generated.
     fn consume_ternary(&mut self, input: TernaryExpression) -> Self::Output {
+        // A chain of ternary expressions that looks up a constant may be lowered into a weighted sum.
+        if self.options.lower_multiplexers {
+            if let Some(expression) = self.lower_multiplexer(&input) {
+                return self.consume_expression(expression);
+            }
+        }
+
         // Reconstruct the condition of the ternary expression.
         let (cond_expr, mut statements) = self.consume_expression(*input.condition);
         // Reconstruct the if-true case of the ternary expression.
@@ -371,12 +425,18 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
 
     /// Consumes a unary expression, accumulating any statements that are generated.
     fn consume_unary(&mut self, input: UnaryExpression) -> Self::Output {
+        // An integer operation is performed with the overflow behavior of the build.
+        let op = match self.type_table.get(&input.id) {
+            Some(Type::Integer(_)) => self.options.overflow_behavior.unary_operation(input.op),
+            _ => input.op,
+        };
+
         // Reconstruct the operand of the unary expression.
         let (receiver, mut statements) = self.consume_expression(*input.receiver);
 
         // Construct and accumulate a new assignment statement for the unary expression.
         let (place, statement) = self.unique_simple_assign_statement(Expression::Unary(UnaryExpression {
-            op: input.op,
+            op,
             receiver: Box::new(receiver),
             span: input.span,
             id: input.id,
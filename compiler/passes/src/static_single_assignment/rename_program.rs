@@ -113,6 +113,7 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
 
         Function {
             annotations: function.annotations,
+            docs: function.docs,
             variant: function.variant,
             identifier: function.identifier,
             input: function.input,
@@ -136,6 +137,7 @@ impl ProgramScopeConsumer for StaticSingleAssigner<'_> {
             mappings: input.mappings,
             functions: input.functions.into_iter().map(|(i, f)| (i, self.consume_function(f))).collect(),
             consts: input.consts,
+            macros: input.macros,
             span: input.span,
         }
     }
@@ -151,6 +153,7 @@ impl ProgramConsumer for StaticSingleAssigner<'_> {
                 .into_iter()
                 .map(|(name, (import, span))| (name, (self.consume_program(import), span)))
                 .collect(),
+            module_imports: input.module_imports,
             stubs: input.stubs,
             program_scopes: input
                 .program_scopes
@@ -33,6 +33,7 @@ use leo_ast::{
     ExpressionStatement,
     Identifier,
     IterationStatement,
+    Literal,
     Node,
     ReturnStatement,
     Statement,
@@ -76,6 +77,9 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
 
                 (AssertVariant::AssertNeq(left, right), statements)
             }
+            AssertVariant::ConstAssert(_) => {
+                unreachable!("Loop unrolling pass removes all constant assertions in the program.")
+            }
         };
 
         // Add the assert statement to the list of produced statements.
@@ -89,16 +93,71 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         // First consume the right-hand-side of the assignment.
         let (value, mut statements) = self.consume_expression(assign.value);
 
+        // An assignment to an array element is lowered into an assignment to the whole array.
+        let (place, value) = match assign.place {
+            Expression::Access(AccessExpression::Array(access)) => {
+                let (array, value, stmts) = self.consume_array_element_assign(access, value);
+                statements.extend(stmts);
+                (Expression::Identifier(array), value)
+            }
+            place => (place, value),
+        };
+
         // Then assign a new unique name to the left-hand-side of the assignment.
         // Note that this order is necessary to ensure that the right-hand-side uses the correct name when consuming a complex assignment.
         self.is_lhs = true;
-        let place = match self.consume_expression(assign.place).0 {
-            Expression::Identifier(identifier) => identifier,
-            _ => panic!("Type checking guarantees that the left-hand-side of an assignment is an identifier."),
-        };
-        self.is_lhs = false;
+        match place {
+            Expression::Identifier(identifier) => {
+                let place = match self.consume_identifier(identifier).0 {
+                    Expression::Identifier(identifier) => identifier,
+                    _ => unreachable!("`self.consume_identifier` will always return an `Identifier`."),
+                };
+                statements.push(self.simple_assign_statement(place, value));
+            }
+            Expression::Tuple(tuple) => {
+                // Rename each of the assigned variables.
+                let elements: Vec<Expression> = tuple
+                    .elements
+                    .into_iter()
+                    .map(|element| match element {
+                        Expression::Identifier(identifier) => self.consume_identifier(identifier).0,
+                        _ => unreachable!(
+                            "Type checking guarantees that the tuple elements on the lhs of an `AssignStatement` are identifiers."
+                        ),
+                    })
+                    .collect();
+
+                // Get the type of `value`.
+                let tuple_type_ = match self.type_table.get(&value.id()) {
+                    Some(Type::Tuple(type_)) => type_,
+                    _ => unreachable!("Type checking guarantees that this expression is a tuple."),
+                };
+
+                // Update the type of each element in the tuple.
+                for (element, type_) in elements.iter().zip_eq(tuple_type_.elements()) {
+                    self.type_table.insert(element.id(), type_.clone());
+                }
 
-        statements.push(self.simple_assign_statement(place, value));
+                // Construct the lhs of the assignment.
+                let place = Expression::Tuple(TupleExpression {
+                    elements,
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                });
+
+                // Update the type of the lhs.
+                self.type_table.insert(place.id(), Type::Tuple(tuple_type_));
+
+                statements.push(Statement::Assign(Box::new(AssignStatement {
+                    place,
+                    value,
+                    span: assign.span,
+                    id: assign.id,
+                })));
+            }
+            _ => panic!("Type checking guarantees that the left-hand-side of an assignment is an identifier or tuple."),
+        }
+        self.is_lhs = false;
 
         statements
     }
@@ -115,7 +174,18 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
     ///   - `if x > 0 { x = x + 1 }` becomes `let $cond$0 = x > 0; if $cond$0 { x = x + 1; }`
     ///   - `if true { x = x + 1 }` remains the same.
     ///   - `if b { x = x + 1 }` remains the same.
+    /// At the join point, each variable that is defined before the `ConditionalStatement` and written in either block is merged by a phi function,
+    /// which selects the value from the then-block if the condition holds and the value from the otherwise-block if it does not.
+    /// A block that does not write the variable contributes the value from before the `ConditionalStatement`.
+    /// For example, `if b { x = 1u8; }` produces the phi function `x$2 = b ? x$1 : x;`.
+    /// If dead branches are pruned and the condition is constant, the variable takes the value from the taken block, without a select.
     fn consume_conditional(&mut self, conditional: ConditionalStatement) -> Self::Output {
+        // If dead branches are pruned, the branch ruled out by a constant condition is replaced with an empty block.
+        let constant = match &conditional.condition {
+            Expression::Literal(Literal::Boolean(value, ..)) if self.options.prune_dead_branches => Some(*value),
+            _ => None,
+        };
+
         // Simplify the condition and add it into the rename table.
         let (condition, mut statements) = self.consume_expression(conditional.condition);
 
@@ -126,7 +196,10 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         let then = Block {
             span: conditional.then.span,
             id: conditional.then.id,
-            statements: self.consume_block(conditional.then),
+            statements: match constant {
+                Some(false) => Vec::new(),
+                _ => self.consume_block(conditional.then),
+            },
         };
 
         // Remove the `RenameTable` for the then-block.
@@ -136,7 +209,11 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         self.push();
 
         // Consume the otherwise-block and flatten its constituent statements into the current block.
-        let otherwise = conditional.otherwise.map(|otherwise| Box::new(Statement::Block(match *otherwise {
+        let otherwise = match constant {
+            Some(true) => None,
+            _ => conditional.otherwise,
+        };
+        let otherwise = otherwise.map(|otherwise| Box::new(Statement::Block(match *otherwise {
             Statement::Block(block) => Block {
                 span: block.span,
                 id: block.id,
@@ -171,6 +248,19 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         for symbol in write_set {
             // Note that phi functions only need to be instantiated if the variable exists before the `ConditionalStatement`.
             if self.rename_table.lookup(**symbol).is_some() {
+                // If the condition is constant, the variable is merged by renaming it to its name in the taken block.
+                if let Some(taken) = constant {
+                    let table = if taken { &if_table } else { &else_table };
+                    let name = *table
+                        .lookup(**symbol)
+                        .unwrap_or_else(|| panic!("Symbol {symbol} should exist in the program."));
+                    let id = *table
+                        .lookup_id(&name)
+                        .unwrap_or_else(|| panic!("Symbol {name} should exist in the rename table."));
+                    self.rename_table.update(**symbol, name, id);
+                    continue;
+                }
+
                 // Helper to lookup an and create an argument for the phi function.
                 let create_phi_argument = |table: &RenameTable, symbol: Symbol| {
                     let name =
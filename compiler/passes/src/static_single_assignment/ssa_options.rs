@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::OverflowBehavior;
+
+/// The optional rewrites of the static single assignment pass, all of which are disabled by default.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SsaOptions {
+    /// Whether to drop the branches of conditional statements that a constant condition rules out.
+    pub prune_dead_branches: bool,
+    /// Whether to lower chains of ternary expressions that look up a `field` constant into weighted sums.
+    pub lower_multiplexers: bool,
+    /// Whether to unroll powers with a constant exponent into multiplications.
+    pub lower_powers: bool,
+    /// Whether to lower unsigned divisions and remainders by a constant power of two into shifts and masks.
+    pub lower_divisions: bool,
+    /// How integer operations behave when they overflow.
+    pub overflow_behavior: OverflowBehavior,
+}
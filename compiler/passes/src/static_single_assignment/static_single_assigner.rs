@@ -14,9 +14,34 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Assigner, RenameTable, SymbolTable, TypeTable};
+use super::lookup::{field_one, field_string, field_value};
+use crate::{Assigner, RenameTable, SsaOptions, SymbolTable, TypeTable};
 
-use leo_ast::{Expression, Identifier, Node, NodeBuilder, Statement};
+use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    ArrayExpression,
+    AssertStatement,
+    AssertVariant,
+    BinaryExpression,
+    BinaryOperation,
+    CastExpression,
+    Expression,
+    ExpressionConsumer,
+    Identifier,
+    IntegerType,
+    Literal,
+    MemberAccess,
+    Node,
+    NodeBuilder,
+    Statement,
+    TernaryExpression,
+    Type,
+};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexSet;
+use snarkvm::console::prelude::ToBits;
 
 pub struct StaticSingleAssigner<'a> {
     /// A counter used to generate unique node IDs.
@@ -31,6 +56,8 @@ pub struct StaticSingleAssigner<'a> {
     pub(crate) is_lhs: bool,
     /// A struct used to construct (unique) assignment statements.
     pub(crate) assigner: &'a Assigner,
+    /// The optional rewrites of the pass.
+    pub(crate) options: SsaOptions,
 }
 
 impl<'a> StaticSingleAssigner<'a> {
@@ -40,8 +67,17 @@ impl<'a> StaticSingleAssigner<'a> {
         symbol_table: &'a SymbolTable,
         type_table: &'a TypeTable,
         assigner: &'a Assigner,
+        options: SsaOptions,
     ) -> Self {
-        Self { node_builder, symbol_table, type_table, rename_table: RenameTable::new(None), is_lhs: false, assigner }
+        Self {
+            node_builder,
+            symbol_table,
+            type_table,
+            rename_table: RenameTable::new(None),
+            is_lhs: false,
+            assigner,
+            options,
+        }
     }
 
     /// Pushes a new scope, setting the current scope as the new scope's parent.
@@ -84,4 +120,640 @@ impl<'a> StaticSingleAssigner<'a> {
 
         (place, statement)
     }
+
+    /// Lowers an assignment to an element of an array into an assignment to the whole array, returning the array variable,
+    /// its new value, and any statements that are generated.
+    /// Each element of the new value selects between `value` and the old element.
+    /// Unless the index is a literal within bounds, the index is also asserted to be one of the indices of the array.
+    /// For example, for `a: [u8; 2]`, `a[i] = v` becomes
+    ///   `assert(i == 0u32 || i == 1u32); a = [i == 0u32 ? v : a[0u32], i == 1u32 ? v : a[1u32]];`
+    pub(crate) fn consume_array_element_assign(
+        &mut self,
+        access: ArrayAccess,
+        value: Expression,
+    ) -> (Identifier, Expression, Vec<Statement>) {
+        let array = match *access.array {
+            Expression::Identifier(identifier) => identifier,
+            _ => unreachable!("Type checking guarantees that an assigned array element belongs to a variable."),
+        };
+        let array_type = match self.type_table.get(&array.id) {
+            Some(Type::Array(array_type)) => array_type,
+            _ => unreachable!("Type checking guarantees that this expression is an array."),
+        };
+        let index_type = match self.type_table.get(&access.index.id()) {
+            Some(Type::Integer(index_type)) => index_type,
+            _ => unreachable!("Type checking guarantees that the index of an array access is an integer."),
+        };
+
+        // A literal index within bounds selects the assigned element statically.
+        let literal_index = match &*access.index {
+            Expression::Literal(Literal::Integer(_, index, ..)) => {
+                index.parse::<usize>().ok().filter(|index| *index < array_type.length())
+            }
+            _ => None,
+        };
+
+        let mut statements = Vec::new();
+        let elements =
+            match literal_index {
+                Some(index) => (0..array_type.length())
+                    .map(|i| {
+                        if i == index {
+                            value.clone()
+                        } else {
+                            self.array_element(array, i, array_type.element_type())
+                        }
+                    })
+                    .collect(),
+                None => {
+                    let (conditions, stmts) =
+                        self.consume_index_conditions(*access.index, index_type, array_type.length(), access.span);
+                    statements.extend(stmts);
+
+                    conditions
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, condition)| {
+                            let id = self.node_builder.next_id();
+                            self.type_table.insert(id, array_type.element_type().clone());
+                            Expression::Ternary(TernaryExpression {
+                                condition: Box::new(condition),
+                                if_true: Box::new(value.clone()),
+                                if_false: Box::new(self.array_element(array, i, array_type.element_type())),
+                                span: Default::default(),
+                                id,
+                            })
+                        })
+                        .collect()
+                }
+            };
+
+        // Construct the new value of the array.
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Array(array_type));
+        let (array_value, stmts) = self.consume_array(ArrayExpression { elements, span: Default::default(), id });
+        statements.extend(stmts);
+
+        (array, array_value, statements)
+    }
+
+    /// Lowers an access to an element of an array at an index that is not a literal into a select of the element,
+    /// returning the element and any statements that are generated.
+    /// The index is asserted to be one of the indices of the array, and the last element is selected otherwise.
+    /// For example, for `a: [u8; 3]`, `a[i]` becomes
+    ///   `assert(i == 0u32 || i == 1u32 || i == 2u32); i == 0u32 ? a[0u32] : i == 1u32 ? a[1u32] : a[2u32]`
+    pub(crate) fn consume_array_element_access(&mut self, access: ArrayAccess) -> (Expression, Vec<Statement>) {
+        let array_type = match self.type_table.get(&access.array.id()) {
+            Some(Type::Array(array_type)) => array_type,
+            _ => unreachable!("Type checking guarantees that this expression is an array."),
+        };
+        let index_type = match self.type_table.get(&access.index.id()) {
+            Some(Type::Integer(index_type)) => index_type,
+            _ => unreachable!("Type checking guarantees that the index of an array access is an integer."),
+        };
+
+        // Assign the array to a variable, so that its elements can be accessed.
+        let (array, mut statements) = self.consume_expression(*access.array);
+        let array = match array {
+            Expression::Identifier(identifier) => identifier,
+            _ => unreachable!("SSA guarantees that an accessed array is an identifier."),
+        };
+
+        let (conditions, stmts) =
+            self.consume_index_conditions(*access.index, index_type, array_type.length(), access.span);
+        statements.extend(stmts);
+
+        // Select the element whose index is equal to the index, starting from the last element.
+        let last = self.array_element(array, array_type.length() - 1, array_type.element_type());
+        let element = conditions.into_iter().enumerate().rev().skip(1).fold(last, |acc, (i, condition)| {
+            let id = self.node_builder.next_id();
+            self.type_table.insert(id, array_type.element_type().clone());
+            Expression::Ternary(TernaryExpression {
+                condition: Box::new(condition),
+                if_true: Box::new(self.array_element(array, i, array_type.element_type())),
+                if_false: Box::new(acc),
+                span: Default::default(),
+                id,
+            })
+        });
+        let (element, stmts) = self.consume_expression(element);
+        statements.extend(stmts);
+
+        (element, statements)
+    }
+
+    /// Compares `index` against each of the `length` indices of an array, returning the conditions and any statements
+    /// that are generated. The statements assert that the index is equal to one of the indices.
+    fn consume_index_conditions(
+        &mut self,
+        index: Expression,
+        index_type: IntegerType,
+        length: usize,
+        span: Span,
+    ) -> (Vec<Expression>, Vec<Statement>) {
+        let (index, mut statements) = self.consume_expression(index);
+
+        // Compare the index against each of the indices of the array.
+        let conditions: Vec<Expression> = (0..length)
+            .map(|i| {
+                let literal = self.index_literal(i, index_type);
+                let condition = self.boolean_binary(index.clone(), BinaryOperation::Eq, literal);
+                let (condition, stmts) = self.consume_expression(condition);
+                statements.extend(stmts);
+                condition
+            })
+            .collect();
+
+        // Assert that the index is within bounds, i.e. that it is equal to one of the indices.
+        if let Some(in_bounds) =
+            conditions.iter().cloned().reduce(|left, right| self.boolean_binary(left, BinaryOperation::Or, right))
+        {
+            let (in_bounds, stmts) = self.consume_expression(in_bounds);
+            statements.extend(stmts);
+            statements.push(Statement::Assert(AssertStatement {
+                variant: AssertVariant::Assert(in_bounds),
+                span,
+                id: self.node_builder.next_id(),
+            }));
+        }
+
+        (conditions, statements)
+    }
+
+    /// Constructs the literal `index` with the given integer type.
+    pub(crate) fn index_literal(&mut self, index: usize, type_: IntegerType) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Integer(type_));
+        Expression::Literal(Literal::Integer(type_, index.to_string(), Default::default(), id))
+    }
+
+    /// Constructs the access `array[index]`, where `index` is a `u32` literal.
+    fn array_element(&mut self, array: Identifier, index: usize, type_: &Type) -> Expression {
+        let index = self.index_literal(index, IntegerType::U32);
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_.clone());
+        Expression::Access(AccessExpression::Array(ArrayAccess {
+            array: Box::new(Expression::Identifier(array)),
+            index: Box::new(index),
+            span: Default::default(),
+            id,
+        }))
+    }
+
+    /// Constructs the binary expression `left op right`, whose type is `bool`.
+    pub(crate) fn boolean_binary(&mut self, left: Expression, op: BinaryOperation, right: Expression) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Boolean);
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span: Default::default(),
+            id,
+        })
+    }
+
+    /// Lowers a chain of ternary expressions that selects a `field` constant by the value of a variable, i.e. a lookup table, into a weighted sum.
+    /// For example, `x == 0u8 ? 1field : x == 1u8 ? 5field : 7field` becomes
+    ///   `7field + (x == 0u8) as field * (1field - 7field) + (x == 1u8) as field * (5field - 7field)`.
+    /// Since the keys are distinct, at most one of the conditions holds, so the sum selects the same constant as the chain.
+    /// The sum is linear in the conditions, so unlike the cascaded selects, it costs no constraints beyond the comparisons.
+    /// Returns `None` if `input` is not such a chain of at least two ternary expressions.
+    pub(crate) fn lower_multiplexer(&mut self, input: &TernaryExpression) -> Option<Expression> {
+        if self.type_table.get(&input.id) != Some(Type::Field) {
+            return None;
+        }
+
+        let mut subject: Option<Symbol> = None;
+        let mut keys = IndexSet::new();
+        let mut arms = Vec::new();
+        let mut current = input;
+        let default = loop {
+            // Each condition must compare the same variable against a distinct integer literal.
+            let (name, key) = match &*current.condition {
+                Expression::Binary(BinaryExpression { left, right, op: BinaryOperation::Eq, .. }) => {
+                    match (&**left, &**right) {
+                        (Expression::Identifier(identifier), Expression::Literal(Literal::Integer(_, key, ..)))
+                        | (Expression::Literal(Literal::Integer(_, key, ..)), Expression::Identifier(identifier)) => {
+                            (identifier.name, key.parse::<i128>().ok()?)
+                        }
+                        _ => return None,
+                    }
+                }
+                _ => return None,
+            };
+            if *subject.get_or_insert(name) != name || !keys.insert(key) {
+                return None;
+            }
+
+            // Each selected value must be a constant.
+            match &*current.if_true {
+                Expression::Literal(Literal::Field(..)) => {
+                    arms.push((*current.condition.clone(), *current.if_true.clone()))
+                }
+                _ => return None,
+            }
+
+            match &*current.if_false {
+                Expression::Ternary(next) => current = next,
+                Expression::Literal(Literal::Field(value, ..)) => break value.clone(),
+                _ => return None,
+            }
+        };
+
+        if arms.len() < 2 {
+            return None;
+        }
+
+        let mut sum = self.field_literal(default.clone());
+        for (condition, value) in arms {
+            let id = self.node_builder.next_id();
+            self.type_table.insert(id, Type::Field);
+            let weight = Expression::Cast(CastExpression {
+                expression: Box::new(condition),
+                type_: Type::Field,
+                span: Default::default(),
+                id,
+            });
+            let default = self.field_literal(default.clone());
+            let difference = self.field_binary(value, BinaryOperation::Sub, default);
+            let term = self.field_binary(weight, BinaryOperation::Mul, difference);
+            sum = self.field_binary(sum, BinaryOperation::Add, term);
+        }
+
+        Some(sum)
+    }
+
+    /// Constructs the literal `{value}field`.
+    pub(crate) fn field_literal(&mut self, value: String) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Field);
+        Expression::Literal(Literal::Field(value, Default::default(), id))
+    }
+
+    /// Constructs the binary expression `left op right`, whose type is `field`.
+    pub(crate) fn field_binary(&mut self, left: Expression, op: BinaryOperation, right: Expression) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Field);
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span: Default::default(),
+            id,
+        })
+    }
+
+    /// Returns the value of the exponent of a power if it is a literal that fits in a `u32`.
+    pub(crate) fn constant_exponent(exponent: &Expression) -> Option<u32> {
+        match exponent {
+            Expression::Literal(Literal::Integer(_, value, ..)) | Expression::Literal(Literal::Field(value, ..)) => {
+                value.parse().ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Unrolls the power `base ** exponent` into multiplications along an addition chain for `exponent`, accumulating any statements that are generated.
+    /// For example, `x ** 5u8` becomes `$var$0 = x * x; $var$1 = $var$0 * $var$0; $var$2 = $var$1 * x;`.
+    /// This takes at most `2 * log2(exponent)` multiplications, whereas the `pow` instruction costs a squaring and a selection for each bit of the exponent's type.
+    /// A power is multiplied with the same overflow behavior, i.e. `pow_wrapped` with `mul_wrapped`.
+    /// Each intermediate product is a smaller power of the base, so it overflows only if the power does.
+    pub(crate) fn lower_power(&mut self, input: BinaryExpression, exponent: u32) -> (Expression, Vec<Statement>) {
+        let type_ = match self.type_table.get(&input.id) {
+            Some(type_) => type_,
+            None => unreachable!("Type checking guarantees that all expressions are typed."),
+        };
+        let op = match input.op {
+            BinaryOperation::PowWrapped => BinaryOperation::MulWrapped,
+            _ => BinaryOperation::Mul,
+        };
+
+        // Any base to the power of zero is one.
+        if exponent == 0 {
+            let id = self.node_builder.next_id();
+            self.type_table.insert(id, type_.clone());
+            let one = match type_ {
+                Type::Integer(integer_type) => Literal::Integer(integer_type, "1".to_string(), Default::default(), id),
+                _ => Literal::Field("1".to_string(), Default::default(), id),
+            };
+            return self.consume_literal(one);
+        }
+
+        let (base, mut statements) = self.consume_expression(*input.left);
+
+        // Compute each power of the chain as the product of two earlier ones, starting from the base.
+        let mut powers = vec![base];
+        for (i, j) in addition_chain(exponent) {
+            let (product, stmts) = self.consume_power_product(powers[i].clone(), op, powers[j].clone(), &type_);
+            statements.extend(stmts);
+            powers.push(product);
+        }
+
+        (powers.pop().expect("The chain starts from the base."), statements)
+    }
+
+    /// Evaluates the power `base ** exponent` of two field literals at compile time, by square-and-multiply.
+    pub(crate) fn fold_field_power(&mut self, input: BinaryExpression) -> (Expression, Vec<Statement>) {
+        let base = field_value(&input.left);
+        let exponent = field_value(&input.right);
+        let power = exponent.to_bits_le().into_iter().rev().fold(field_one(), |power, bit| {
+            let square = power * power;
+            if bit {
+                square * base
+            } else {
+                square
+            }
+        });
+
+        self.consume_literal(Literal::Field(field_string(power), input.span, input.id))
+    }
+
+    /// Assigns the product `left op right` of type `type_` to a new variable, returning the variable and the assignment.
+    fn consume_power_product(
+        &mut self,
+        left: Expression,
+        op: BinaryOperation,
+        right: Expression,
+        type_: &Type,
+    ) -> (Expression, Vec<Statement>) {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_.clone());
+        let (place, statement) = self.unique_simple_assign_statement(Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span: Default::default(),
+            id,
+        }));
+
+        (Expression::Identifier(place), vec![statement])
+    }
+
+    /// Lowers a division or remainder of an unsigned integer by a power of two into a shift or a mask.
+    /// For example, `x / 8u8` becomes `x >> 3u32`, and `x % 8u8` becomes `x & 7u8`.
+    /// Unlike a division, which witnesses the quotient and remainder and checks their ranges, shifting and masking by a
+    /// constant only rewires the bits of the operand, so they cost no constraints.
+    /// The wrapping variants behave the same, since an unsigned division by a nonzero divisor cannot overflow.
+    /// Returns `None` if `input` is not such a division or remainder.
+    pub(crate) fn lower_division(&mut self, input: &BinaryExpression) -> Option<Expression> {
+        let integer_type = match self.type_table.get(&input.id) {
+            Some(Type::Integer(integer_type)) if !integer_type.is_signed() => integer_type,
+            _ => return None,
+        };
+        let divisor = match &*input.right {
+            Expression::Literal(Literal::Integer(_, value, ..)) => value.parse::<u128>().ok()?,
+            _ => return None,
+        };
+        if !divisor.is_power_of_two() {
+            return None;
+        }
+
+        let (op, right) = match input.op {
+            BinaryOperation::Div | BinaryOperation::DivWrapped => {
+                (BinaryOperation::Shr, self.index_literal(divisor.trailing_zeros() as usize, IntegerType::U32))
+            }
+            _ => {
+                let id = self.node_builder.next_id();
+                self.type_table.insert(id, Type::Integer(integer_type));
+                (
+                    BinaryOperation::BitwiseAnd,
+                    Expression::Literal(Literal::Integer(
+                        integer_type,
+                        (divisor - 1).to_string(),
+                        Default::default(),
+                        id,
+                    )),
+                )
+            }
+        };
+
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Integer(integer_type));
+        Some(Expression::Binary(BinaryExpression {
+            left: input.left.clone(),
+            right: Box::new(right),
+            op,
+            span: input.span,
+            id,
+        }))
+    }
+
+    /// Lowers a cast between a bit string, i.e. a `[bool; N]`, and an unsigned integer into arithmetic on the bits,
+    /// returning the result and any statements that are generated. The first element of a bit string is its most
+    /// significant bit. For example, for `b: [bool; 3]` and `x: u8`, `b as u8` becomes
+    ///   `(b[0u32] as u8).mul_wrapped(4u8).add_wrapped((b[1u32] as u8).mul_wrapped(2u8)).add_wrapped(b[2u32] as u8)`
+    /// and `x as [bool; 8]` becomes `[x & 128u8 != 0u8, x & 64u8 != 0u8, ..., x & 1u8 != 0u8]`.
+    /// Since each bit has a distinct weight, the wrapping operations never wrap.
+    pub(crate) fn lower_bit_string_cast(&mut self, input: CastExpression) -> (Expression, Vec<Statement>) {
+        let from = match self.type_table.get(&input.expression.id()) {
+            Some(type_) => type_,
+            None => unreachable!("Type checking guarantees that all expressions are typed."),
+        };
+        let (operand, mut statements) = self.consume_expression(*input.expression);
+
+        let lowered = match (from, input.type_) {
+            (Type::Array(array_type), Type::Integer(integer_type)) => {
+                let array = match operand {
+                    Expression::Identifier(identifier) => identifier,
+                    _ => unreachable!("SSA guarantees that a cast array is an identifier."),
+                };
+                let length = array_type.length();
+
+                let mut sum: Option<Expression> = None;
+                for i in 0..length {
+                    let bit = self.array_element(array, i, &Type::Boolean);
+                    let id = self.node_builder.next_id();
+                    self.type_table.insert(id, Type::Integer(integer_type));
+                    let mut term = Expression::Cast(CastExpression {
+                        expression: Box::new(bit),
+                        type_: Type::Integer(integer_type),
+                        span: Default::default(),
+                        id,
+                    });
+                    let shift = length - 1 - i;
+                    if shift > 0 {
+                        let weight = self.integer_literal(1 << shift, integer_type);
+                        term = self.integer_binary(term, BinaryOperation::MulWrapped, weight, integer_type);
+                    }
+                    sum = Some(match sum {
+                        Some(sum) => self.integer_binary(sum, BinaryOperation::AddWrapped, term, integer_type),
+                        None => term,
+                    });
+                }
+                match sum {
+                    Some(sum) => sum,
+                    None => unreachable!("Type checking guarantees that arrays are not empty."),
+                }
+            }
+            (Type::Integer(integer_type), Type::Array(array_type)) => {
+                let length = array_type.length();
+                let elements = (0..length)
+                    .map(|i| {
+                        let mask = self.integer_literal(1 << (length - 1 - i), integer_type);
+                        let masked =
+                            self.integer_binary(operand.clone(), BinaryOperation::BitwiseAnd, mask, integer_type);
+                        let zero = self.integer_literal(0, integer_type);
+                        self.boolean_binary(masked, BinaryOperation::Neq, zero)
+                    })
+                    .collect();
+                let id = self.node_builder.next_id();
+                self.type_table.insert(id, Type::Array(array_type));
+                Expression::Array(ArrayExpression { elements, span: Default::default(), id })
+            }
+            _ => {
+                unreachable!("Type checking guarantees that a bit string is only cast to and from an unsigned integer.")
+            }
+        };
+
+        let (result, stmts) = self.consume_expression(lowered);
+        statements.extend(stmts);
+
+        (result, statements)
+    }
+
+    /// Consumes the access of a member of a struct or record, accumulating any statements that are generated.
+    /// The member of a variable that is already accessed in this scope, or an enclosing one, reuses the variable that
+    /// holds it, e.g. `p.x + p.x` becomes `$var$0 = p.x; $var$1 = $var$0 + $var$0;`.
+    /// This is sound since a variable is never reassigned in SSA form.
+    pub(crate) fn consume_member_access(&mut self, input: MemberAccess) -> (Expression, Vec<Statement>) {
+        let (inner, mut statements) = self.consume_expression(*input.inner);
+        let variable = match &inner {
+            Expression::Identifier(identifier) => Some(identifier.name),
+            _ => None,
+        };
+
+        if let Some(place) = variable.and_then(|variable| self.rename_table.lookup_member(variable, input.name.name)) {
+            let id = self.node_builder.next_id();
+            match self.type_table.get(&input.id) {
+                Some(type_) => self.type_table.insert(id, type_),
+                None => unreachable!("Type checking guarantees that all expressions are typed."),
+            }
+            return (Expression::Identifier(Identifier { name: place, span: input.span, id }), statements);
+        }
+
+        let (place, statement) =
+            self.unique_simple_assign_statement(Expression::Access(AccessExpression::Member(MemberAccess {
+                inner: Box::new(inner),
+                name: input.name,
+                span: input.span,
+                id: input.id,
+            })));
+        statements.push(statement);
+        if let Some(variable) = variable {
+            self.rename_table.update_member(variable, input.name.name, place.name);
+        }
+
+        (Expression::Identifier(place), statements)
+    }
+
+    /// Constructs the literal `value` with the given integer type.
+    fn integer_literal(&mut self, value: u128, type_: IntegerType) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Integer(type_));
+        Expression::Literal(Literal::Integer(type_, value.to_string(), Default::default(), id))
+    }
+
+    /// Constructs the binary expression `left op right`, whose type is the given integer type.
+    fn integer_binary(
+        &mut self,
+        left: Expression,
+        op: BinaryOperation,
+        right: Expression,
+        type_: IntegerType,
+    ) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Integer(type_));
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span: Default::default(),
+            id,
+        })
+    }
+}
+
+/// The largest exponent for which a shortest addition chain is searched for.
+/// The search takes exponential time in the length of the chain, so larger exponents use the binary method.
+const MAX_SEARCHED_EXPONENT: u32 = 256;
+
+/// Returns an addition chain for `exponent`, i.e. a sequence `1 = a[0] < a[1] < ... < a[r] = exponent` in which each
+/// element is the sum of two earlier ones, as the indices `(i, j)` of the summands of each element after the first.
+/// The chain is a shortest one if `exponent` is at most [`MAX_SEARCHED_EXPONENT`], e.g. `1, 2, 3, 6, 12, 15` for `15`,
+/// which takes one step less than the binary method, `1, 2, 3, 6, 7, 14, 15`.
+fn addition_chain(exponent: u32) -> Vec<(usize, usize)> {
+    // Double for each bit below the leading one, and add one for each set bit.
+    let mut binary = Vec::new();
+    for bit in (0..31 - exponent.leading_zeros()).rev() {
+        let last = binary.len();
+        binary.push((last, last));
+        if exponent & (1 << bit) != 0 {
+            binary.push((last + 1, 0));
+        }
+    }
+    if exponent > MAX_SEARCHED_EXPONENT {
+        return binary;
+    }
+
+    // Each step at most doubles the largest element, so a chain takes at least `ceil(log2(exponent))` steps.
+    let shortest = (32 - exponent.saturating_sub(1).leading_zeros()) as usize;
+    for length in shortest..binary.len() {
+        let mut steps = Vec::new();
+        if search_addition_chain(exponent, length, &mut vec![1], &mut steps) {
+            return steps;
+        }
+    }
+    binary
+}
+
+/// Extends `chain` to an addition chain for `exponent` with at most `length` steps by depth-first search,
+/// pushing the indices of the summands of each new element to `steps`. Returns whether a chain is found.
+fn search_addition_chain(exponent: u32, length: usize, chain: &mut Vec<u32>, steps: &mut Vec<(usize, usize)>) -> bool {
+    let last = chain[chain.len() - 1];
+    if last == exponent {
+        return true;
+    }
+    // Even doubling the largest element in each remaining step does not reach the exponent.
+    if (u64::from(last) << (length - steps.len())) < u64::from(exponent) {
+        return false;
+    }
+    // Try the largest sums first, as they reach the exponent in the fewest steps.
+    for i in (0..chain.len()).rev() {
+        for j in (0..=i).rev() {
+            let sum = chain[i] + chain[j];
+            if sum <= last {
+                break;
+            }
+            if sum > exponent {
+                continue;
+            }
+            chain.push(sum);
+            steps.push((i, j));
+            if search_addition_chain(exponent, length, chain, steps) {
+                return true;
+            }
+            chain.pop();
+            steps.pop();
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_addition_chain() {
+        for (exponent, length) in [(1, 0), (2, 1), (5, 3), (15, 5), (23, 6), (127, 10), (191, 11), (1023, 18)] {
+            let mut chain = vec![1];
+            for (i, j) in addition_chain(exponent) {
+                assert!(i < chain.len() && j < chain.len());
+                chain.push(chain[i] + chain[j]);
+            }
+            assert_eq!(chain.last(), Some(&exponent));
+            assert_eq!(chain.len() - 1, length, "the chain for {exponent} is {chain:?}");
+        }
+    }
 }
@@ -17,19 +17,22 @@
 use crate::TypeChecker;
 
 use leo_ast::*;
-use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_errors::{emitter::Handler, TypeCheckerError, TypeCheckerWarning};
 use leo_span::{sym, Span};
 
 use itertools::Itertools;
-use snarkvm::console::network::{Network, Testnet3};
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
 fn return_incorrect_type(t1: Option<Type>, t2: Option<Type>, expected: &Option<Type>) -> Option<Type> {
     match (t1, t2) {
         (Some(t1), Some(t2)) if t1 == t2 => Some(t1),
         (Some(t1), Some(t2)) => {
             if let Some(expected) = expected {
-                if &t1 != expected { Some(t1) } else { Some(t2) }
+                if &t1 != expected {
+                    Some(t1)
+                } else {
+                    Some(t2)
+                }
             } else {
                 Some(t1)
             }
@@ -116,7 +119,10 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
                     return return_type;
                 } else {
-                    self.emit_err(TypeCheckerError::invalid_core_function_call(access, access.span()));
+                    self.emit_err(TypeCheckerError::invalid_core_function_call(
+                        format!("{}::{}", access.ty, access.name),
+                        access.span(),
+                    ));
                 }
             }
             AccessExpression::Tuple(access) => {
@@ -273,8 +279,13 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 self.emit_err(TypeCheckerError::array_empty(input.span()));
                 None
             }
+            // The array cannot have more than `max_array_elements` elements.
+            num_elements if num_elements > self.max_array_elements => {
+                self.emit_err(TypeCheckerError::array_too_large(num_elements, self.max_array_elements, input.span()));
+                None
+            }
             // Check that the element types match.
-            1..=Testnet3::MAX_ARRAY_ELEMENTS => {
+            _ => {
                 let mut element_types = element_types.into_iter();
                 // Note that this unwrap is safe because we already checked that the array is not empty.
                 element_types.next().unwrap().map(|first_type| {
@@ -286,15 +297,6 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                     Type::Array(ArrayType::new(first_type, NonNegativeNumber::from(input.elements.len())))
                 })
             }
-            // The array cannot have more than `MAX_ARRAY_ELEMENTS` elements.
-            num_elements => {
-                self.emit_err(TypeCheckerError::array_too_large(
-                    num_elements,
-                    Testnet3::MAX_ARRAY_ELEMENTS,
-                    input.span(),
-                ));
-                None
-            }
         };
 
         // If the expected type is known, then check that the array type is the same as the expected type.
@@ -588,6 +590,11 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                         }
                     }
 
+                    // Warn if the function is deprecated.
+                    if func.is_deprecated {
+                        self.emit_warning(TypeCheckerWarning::deprecated("function", ident.name, input.span));
+                    }
+
                     // Check that the call is not to an external `inline` function.
                     if func.variant == Variant::Inline && input.external.is_some() {
                         self.emit_err(TypeCheckerError::cannot_call_external_inline_function(input.span));
@@ -614,7 +621,10 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                         None => unreachable!("`self.function` is set every time a function is visited."),
                         Some(func) => func,
                     };
-                    self.call_graph.add_edge(caller_name, ident.name);
+                    // The calls of a recursive function to itself are expanded by the `RecursionExpander`.
+                    if !(self.is_recursive && caller_name == ident.name) {
+                        self.call_graph.add_edge(caller_name, ident.name);
+                    }
 
                     Some(ret)
                 } else {
@@ -627,12 +637,39 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_cast(&mut self, input: &'a CastExpression, expected: &Self::AdditionalInput) -> Self::Output {
+        // A bit string, i.e. a `[bool; N]`, can be cast to and from an unsigned integer.
+        if let Type::Array(array_type) = &input.type_ {
+            self.assert_type_is_valid(&input.type_, input.span());
+            let expression_type = self.visit_expression(&input.expression, &None);
+            match &expression_type {
+                Some(Type::Integer(integer_type))
+                    if !integer_type.is_signed()
+                        && array_type.element_type() == &Type::Boolean
+                        && array_type.length() == integer_type.bits() => {}
+                Some(type_) => {
+                    self.emit_err(TypeCheckerError::invalid_bit_string_cast(type_, &input.type_, input.span()))
+                }
+                None => {}
+            }
+            return Some(self.assert_and_return_type(input.type_.clone(), expected, input.span()));
+        }
+
         // Check that the target type of the cast expression is a castable type.
         self.assert_castable_type(&Some(input.type_.clone()), input.span());
 
         // Check that the expression type is a primitive type.
         let expression_type = self.visit_expression(&input.expression, &None);
-        self.assert_castable_type(&expression_type, input.expression.span());
+        match (&expression_type, &input.type_) {
+            (Some(type_ @ Type::Array(array_type)), Type::Integer(integer_type)) => {
+                if integer_type.is_signed()
+                    || array_type.element_type() != &Type::Boolean
+                    || array_type.length() > integer_type.bits()
+                {
+                    self.emit_err(TypeCheckerError::invalid_bit_string_cast(type_, &input.type_, input.span()))
+                }
+            }
+            _ => self.assert_castable_type(&expression_type, input.expression.span()),
+        }
 
         // Check that the expected type matches the target type.
         Some(self.assert_and_return_type(input.type_.clone(), expected, input.span()))
@@ -644,6 +681,12 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             // Check struct type name.
             let ret = self.check_expected_struct(struct_.identifier, additional, input.name.span());
 
+            // Warn if the struct is deprecated.
+            if struct_.is_deprecated() {
+                let kind = if struct_.is_record { "record" } else { "struct" };
+                self.emit_warning(TypeCheckerWarning::deprecated(kind, input.name, input.span()));
+            }
+
             // Check number of struct members.
             if struct_.members.len() != input.members.len() {
                 self.emit_err(TypeCheckerError::incorrect_num_struct_members(
@@ -694,10 +737,16 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_literal(&mut self, input: &'a Literal, expected: &Self::AdditionalInput) -> Self::Output {
-        fn parse_integer_literal<I: FromStr>(handler: &Handler, raw_string: &str, span: Span, type_string: &str) {
+        fn parse_integer_literal<I: FromStr + Display>(
+            handler: &Handler,
+            raw_string: &str,
+            span: Span,
+            type_string: &str,
+            (min, max): (I, I),
+        ) {
             let string = raw_string.replace('_', "");
             if string.parse::<I>().is_err() {
-                handler.emit_err(TypeCheckerError::invalid_int_value(string, type_string, span));
+                handler.emit_err(TypeCheckerError::invalid_int_value(string, type_string, min, max, span));
             }
         }
 
@@ -707,43 +756,43 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             Literal::Field(_, _, _) => self.assert_and_return_type(Type::Field, expected, input.span()),
             Literal::Integer(integer_type, string, _, _) => match integer_type {
                 IntegerType::U8 => {
-                    parse_integer_literal::<u8>(self.handler, string, input.span(), "u8");
+                    parse_integer_literal::<u8>(self.handler, string, input.span(), "u8", (u8::MIN, u8::MAX));
                     self.assert_and_return_type(Type::Integer(IntegerType::U8), expected, input.span())
                 }
                 IntegerType::U16 => {
-                    parse_integer_literal::<u16>(self.handler, string, input.span(), "u16");
+                    parse_integer_literal::<u16>(self.handler, string, input.span(), "u16", (u16::MIN, u16::MAX));
                     self.assert_and_return_type(Type::Integer(IntegerType::U16), expected, input.span())
                 }
                 IntegerType::U32 => {
-                    parse_integer_literal::<u32>(self.handler, string, input.span(), "u32");
+                    parse_integer_literal::<u32>(self.handler, string, input.span(), "u32", (u32::MIN, u32::MAX));
                     self.assert_and_return_type(Type::Integer(IntegerType::U32), expected, input.span())
                 }
                 IntegerType::U64 => {
-                    parse_integer_literal::<u64>(self.handler, string, input.span(), "u64");
+                    parse_integer_literal::<u64>(self.handler, string, input.span(), "u64", (u64::MIN, u64::MAX));
                     self.assert_and_return_type(Type::Integer(IntegerType::U64), expected, input.span())
                 }
                 IntegerType::U128 => {
-                    parse_integer_literal::<u128>(self.handler, string, input.span(), "u128");
+                    parse_integer_literal::<u128>(self.handler, string, input.span(), "u128", (u128::MIN, u128::MAX));
                     self.assert_and_return_type(Type::Integer(IntegerType::U128), expected, input.span())
                 }
                 IntegerType::I8 => {
-                    parse_integer_literal::<i8>(self.handler, string, input.span(), "i8");
+                    parse_integer_literal::<i8>(self.handler, string, input.span(), "i8", (i8::MIN, i8::MAX));
                     self.assert_and_return_type(Type::Integer(IntegerType::I8), expected, input.span())
                 }
                 IntegerType::I16 => {
-                    parse_integer_literal::<i16>(self.handler, string, input.span(), "i16");
+                    parse_integer_literal::<i16>(self.handler, string, input.span(), "i16", (i16::MIN, i16::MAX));
                     self.assert_and_return_type(Type::Integer(IntegerType::I16), expected, input.span())
                 }
                 IntegerType::I32 => {
-                    parse_integer_literal::<i32>(self.handler, string, input.span(), "i32");
+                    parse_integer_literal::<i32>(self.handler, string, input.span(), "i32", (i32::MIN, i32::MAX));
                     self.assert_and_return_type(Type::Integer(IntegerType::I32), expected, input.span())
                 }
                 IntegerType::I64 => {
-                    parse_integer_literal::<i64>(self.handler, string, input.span(), "i64");
+                    parse_integer_literal::<i64>(self.handler, string, input.span(), "i64", (i64::MIN, i64::MAX));
                     self.assert_and_return_type(Type::Integer(IntegerType::I64), expected, input.span())
                 }
                 IntegerType::I128 => {
-                    parse_integer_literal::<i128>(self.handler, string, input.span(), "i128");
+                    parse_integer_literal::<i128>(self.handler, string, input.span(), "i128", (i128::MIN, i128::MAX));
                     self.assert_and_return_type(Type::Integer(IntegerType::I128), expected, input.span())
                 }
             },
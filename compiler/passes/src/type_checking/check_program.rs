@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{DiGraphError, TypeChecker};
+use crate::{DiGraphError, TypeChecker, MAX_RECURSION_DEPTH};
 
 use leo_ast::*;
 use leo_errors::TypeCheckerError;
@@ -111,7 +111,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
         // Check that the struct dependency graph does not have any cycles.
         if let Err(DiGraphError::CycleDetected(path)) = self.struct_graph.post_order() {
-            self.emit_err(TypeCheckerError::cyclic_struct_dependency(path));
+            self.emit_recursive_struct(&path);
         }
 
         // Typecheck each mapping definition.
@@ -154,6 +154,14 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_struct(&mut self, input: &'a Struct) {
+        // Check that the struct's annotations are valid.
+        // Note that `@deprecated` is the only annotation that Leo supports on structs and records.
+        for annotation in input.annotations.iter() {
+            if annotation.identifier.name != sym::deprecated {
+                self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span));
+            }
+        }
+
         // Check for conflicting struct/record member names.
         let mut used = HashSet::new();
         // TODO: Better span to target duplicate member.
@@ -255,14 +263,47 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
     fn visit_function(&mut self, function: &'a Function) {
         // Check that the function's annotations are valid.
-        // Note that Leo does not natively support any specific annotations.
+        // Note that Leo supports `@test` on transitions, `@inline` on standard functions,
+        // `@recursive(depth)` on inline functions, and `@deprecated` on any function.
         for annotation in function.annotations.iter() {
-            // TODO: Change to compiler warning.
-            self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span))
+            match annotation.identifier.name {
+                sym::deprecated => {}
+                sym::inline if function.variant == Variant::Standard => {}
+                sym::inline => {
+                    self.emit_err(TypeCheckerError::inline_must_be_function(function.identifier, annotation.span))
+                }
+                sym::test if function.variant == Variant::Transition => {}
+                sym::test => {
+                    self.emit_err(TypeCheckerError::test_must_be_transition(function.identifier, annotation.span))
+                }
+                sym::recursive if function.variant == Variant::Inline => {
+                    if !matches!(annotation.arguments.as_slice(), [argument]
+                        if argument.number().map_or(false, |depth| (1..=MAX_RECURSION_DEPTH).contains(&depth)))
+                    {
+                        self.emit_err(TypeCheckerError::invalid_recursion_depth(MAX_RECURSION_DEPTH, annotation.span));
+                    }
+                    if !self.has_default_value(&function.output_type) {
+                        self.emit_err(TypeCheckerError::recursive_function_without_default(
+                            function.identifier,
+                            &function.output_type,
+                            annotation.span,
+                        ));
+                    }
+                }
+                sym::recursive => self.emit_err(TypeCheckerError::recursive_function_must_be_inline(
+                    function.identifier,
+                    annotation.span,
+                )),
+                // TODO: Change to compiler warning.
+                _ => self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span)),
+            }
         }
 
         self.variant = Some(function.variant);
 
+        // A function that is annotated with `@recursive` may call itself, which is not a cycle in the call graph.
+        self.is_recursive = function.variant == Variant::Inline && function.recursion_depth().is_some();
+
         // Lookup function metadata in the symbol table.
         // Note that this unwrap is safe since function metadata is stored in a prior pass.
         let function_index = self.symbol_table.borrow().lookup_fn_symbol(function.identifier.name).unwrap().id;
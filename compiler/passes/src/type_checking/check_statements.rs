@@ -18,7 +18,7 @@ use crate::{TypeChecker, VariableSymbol, VariableType};
 use itertools::Itertools;
 
 use leo_ast::*;
-use leo_errors::TypeCheckerError;
+use leo_errors::{TypeCheckerError, TypeCheckerWarning};
 use leo_span::{Span, Symbol};
 
 impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
@@ -56,34 +56,57 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
                 // Check that the types are equal.
                 self.check_eq_types(&t1, &t2, input.span());
             }
+            AssertVariant::ConstAssert(expr) => {
+                let type_ = self.visit_expression(expr, &Some(Type::Boolean));
+                self.assert_bool_type(&type_, expr.span());
+
+                // Evaluate the assertion, which must hold for the program to compile.
+                if type_ == Some(Type::Boolean) {
+                    match self.constants.evaluate(expr) {
+                        Ok(Value::Boolean(true, _)) => {}
+                        Ok(_) => self.emit_err(TypeCheckerError::const_assert_failed(expr, input.span() + expr.span())),
+                        Err(err) => self.handler.emit_err(err),
+                    }
+                }
+            }
         }
     }
 
     fn visit_assign(&mut self, input: &'a AssignStatement) {
-        let var_name = match input.place {
-            Expression::Identifier(id) => id,
+        let var_type = match &input.place {
+            Expression::Identifier(var_name) => self.visit_assignee(var_name),
+            // A tuple of variables can be assigned at once, e.g. with the result of a call that returns a tuple.
+            Expression::Tuple(tuple_expression) => {
+                let types: Vec<Option<Type>> = tuple_expression
+                    .elements
+                    .iter()
+                    .map(|element| match element {
+                        Expression::Identifier(var_name) => self.visit_assignee(var_name),
+                        _ => {
+                            self.emit_err(TypeCheckerError::lhs_tuple_element_must_be_an_identifier(element.span()));
+                            None
+                        }
+                    })
+                    .collect();
+                types.into_iter().collect::<Option<Vec<Type>>>().map(|types| Type::Tuple(TupleType::new(types)))
+            }
+            // An element of an array variable can be assigned, even at an index that is not known at compile time.
+            Expression::Access(AccessExpression::Array(access))
+                if matches!(*access.array, Expression::Identifier(_)) =>
+            {
+                match &*access.array {
+                    Expression::Identifier(var_name) if self.visit_assignee(var_name).is_some() => {
+                        self.visit_expression(&input.place, &None)
+                    }
+                    _ => None,
+                }
+            }
             _ => {
                 self.emit_err(TypeCheckerError::invalid_assignment_target(input.place.span()));
                 return;
             }
         };
 
-        let var_type = if let Some(var) = self.symbol_table.borrow_mut().lookup_variable(var_name.name) {
-            match &var.declaration {
-                VariableType::Const => self.emit_err(TypeCheckerError::cannot_assign_to_const_var(var_name, var.span)),
-                VariableType::Input(Mode::Constant) => {
-                    self.emit_err(TypeCheckerError::cannot_assign_to_const_input(var_name, var.span))
-                }
-                _ => {}
-            }
-
-            Some(var.type_.clone())
-        } else {
-            self.emit_err(TypeCheckerError::unknown_sym("variable", var_name.name, var_name.span));
-
-            None
-        };
-
         if var_type.is_some() {
             self.visit_expression(&input.value, &var_type);
         }
@@ -102,6 +125,17 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
         self.visit_expression(&input.condition, &Some(Type::Boolean));
 
+        // Warn about a branch that can never be taken.
+        match (&input.condition, &input.otherwise) {
+            (Expression::Literal(Literal::Boolean(false, span, _)), _) => {
+                self.emit_warning(TypeCheckerWarning::unreachable_branch(false, "`if`", *span))
+            }
+            (Expression::Literal(Literal::Boolean(true, span, _)), Some(_)) => {
+                self.emit_warning(TypeCheckerWarning::unreachable_branch(true, "`else`", *span))
+            }
+            _ => {}
+        }
+
         let mut then_block_has_return = false;
         let mut otherwise_block_has_return = false;
 
@@ -199,6 +233,15 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }) {
             self.handler.emit_err(err);
         }
+
+        // Record the value of the constant, so that constant assertions can refer to it.
+        match &input.value {
+            Expression::Literal(literal) => match Value::try_from(literal) {
+                Ok(value) => self.constants.bind(input.place.name, value),
+                Err(_) => self.constants.unbind(input.place.name),
+            },
+            _ => self.constants.unbind(input.place.name),
+        }
     }
 
     fn visit_definition(&mut self, input: &'a DefinitionStatement) {
@@ -326,45 +369,11 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         // Exit the scope.
         self.exit_scope(scope_index);
 
-        // Check that the literal is valid.
-        self.visit_expression(&input.start, iter_type);
-
-        // If `input.start` is a valid literal, instantiate it as a value.
-        match &input.start {
-            Expression::Literal(literal) => {
-                // Note that this check is needed because the pass attempts to make progress, even though the literal may be invalid.
-                if let Ok(value) = Value::try_from(literal) {
-                    input.start_value.replace(Some(value));
-                }
-            }
-            Expression::Identifier(id) => {
-                if let Some(var) = self.symbol_table.borrow().lookup_variable(id.name) {
-                    if VariableType::Const != var.declaration {
-                        self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(id.span));
-                    }
-                }
-            }
-            _ => self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(input.start.span())),
-        }
-
-        self.visit_expression(&input.stop, iter_type);
-
-        // If `input.stop` is a valid literal, instantiate it as a value.
-        match &input.stop {
-            Expression::Literal(literal) => {
-                // Note that this check is needed because the pass attempts to make progress, even though the literal may be invalid.
-                if let Ok(value) = Value::try_from(literal) {
-                    input.stop_value.replace(Some(value));
-                }
-            }
-            Expression::Identifier(id) => {
-                if let Some(var) = self.symbol_table.borrow().lookup_variable(id.name) {
-                    if VariableType::Const != var.declaration {
-                        self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(id.span));
-                    }
-                }
-            }
-            _ => self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(input.stop.span())),
+        // Check that the bounds, and the step if there is one, are valid literals or constants.
+        self.visit_loop_bound(&input.start, &input.start_value, iter_type);
+        self.visit_loop_bound(&input.stop, &input.stop_value, iter_type);
+        if let Some(step) = &input.step {
+            self.visit_loop_bound(step, &input.step_value, iter_type);
         }
     }
 
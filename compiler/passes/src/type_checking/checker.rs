@@ -17,8 +17,11 @@
 use crate::{CallGraph, StructGraph, SymbolTable, TypeTable, VariableSymbol, VariableType};
 
 use leo_ast::{
+    ConstantInterpreter,
     CoreConstant,
     CoreFunction,
+    Expression,
+    ExpressionVisitor,
     Finalize,
     Function,
     Identifier,
@@ -28,16 +31,19 @@ use leo_ast::{
     Node,
     Output,
     Type,
+    Value,
     Variant,
 };
-use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_errors::{emitter::Handler, TypeCheckerError, TypeCheckerWarning};
 use leo_span::{Span, Symbol};
 
-use snarkvm::console::network::{Network, Testnet3};
-
 use itertools::Itertools;
+use snarkvm::console::network::{Network, Testnet3};
 use std::cell::RefCell;
 
+/// The maximum number of elements of an array that the network supports.
+pub const MAX_ARRAY_ELEMENTS: usize = Testnet3::MAX_ARRAY_ELEMENTS;
+
 pub struct TypeChecker<'a> {
     /// The symbol table for the program.
     pub(crate) symbol_table: RefCell<SymbolTable>,
@@ -57,6 +63,12 @@ pub struct TypeChecker<'a> {
     pub(crate) has_return: bool,
     /// Whether or not the function that we are currently traversing invokes the finalize block.
     pub(crate) has_finalize: bool,
+    /// Whether or not the function that we are currently traversing is annotated with `@recursive`, and may call itself.
+    pub(crate) is_recursive: bool,
+    /// The values of the constants in scope, which constant assertions are evaluated with.
+    pub(crate) constants: ConstantInterpreter,
+    /// The maximum number of elements of an array.
+    pub(crate) max_array_elements: usize,
 
     /// Whether or not we are currently traversing a finalize block.
     pub(crate) is_finalize: bool,
@@ -110,7 +122,12 @@ const MAGNITUDE_TYPES: [Type; 3] =
 
 impl<'a> TypeChecker<'a> {
     /// Returns a new type checker given a symbol table and error handler.
-    pub fn new(symbol_table: SymbolTable, type_table: &'a TypeTable, handler: &'a Handler) -> Self {
+    pub fn new(
+        symbol_table: SymbolTable,
+        type_table: &'a TypeTable,
+        handler: &'a Handler,
+        max_array_elements: usize,
+    ) -> Self {
         let struct_names = symbol_table.structs.keys().cloned().collect();
 
         let function_names = symbol_table.functions.keys().cloned().collect();
@@ -126,6 +143,9 @@ impl<'a> TypeChecker<'a> {
             variant: None,
             has_return: false,
             has_finalize: false,
+            is_recursive: false,
+            constants: ConstantInterpreter::default(),
+            max_array_elements,
             is_finalize: false,
             is_return: false,
         }
@@ -160,6 +180,64 @@ impl<'a> TypeChecker<'a> {
         self.handler.emit_err(err);
     }
 
+    /// Emits a type checker warning.
+    pub(crate) fn emit_warning(&self, warning: TypeCheckerWarning) {
+        self.handler.emit_warning(warning.into());
+    }
+
+    /// Checks that the variable being assigned to exists and is mutable, returning its type.
+    /// Errors point at the assignment, and note where the variable is declared.
+    pub(crate) fn visit_assignee(&self, var_name: &Identifier) -> Option<Type> {
+        if let Some(var) = self.symbol_table.borrow_mut().lookup_variable(var_name.name) {
+            match &var.declaration {
+                VariableType::Const => {
+                    self.emit_err(TypeCheckerError::cannot_assign_to_const_var(var_name, var.span, var_name.span))
+                }
+                VariableType::Input(Mode::Constant) => {
+                    self.emit_err(TypeCheckerError::cannot_assign_to_const_input(var_name, var.span, var_name.span))
+                }
+                VariableType::Reference => {
+                    self.emit_err(TypeCheckerError::cannot_assign_to_reference_input(var_name, var.span, var_name.span))
+                }
+                _ => {}
+            }
+
+            Some(var.type_.clone())
+        } else {
+            self.emit_err(TypeCheckerError::unknown_sym("variable", var_name.name, var_name.span));
+
+            None
+        }
+    }
+
+    /// Type checks a bound or the step of a loop, which must be a literal or a constant.
+    /// If it is a valid literal, it is instantiated as a value.
+    pub(crate) fn visit_loop_bound(
+        &mut self,
+        bound: &'a Expression,
+        value: &RefCell<Option<Value>>,
+        iter_type: &Option<Type>,
+    ) {
+        self.visit_expression(bound, iter_type);
+
+        match bound {
+            Expression::Literal(literal) => {
+                // Note that this check is needed because the pass attempts to make progress, even though the literal may be invalid.
+                if let Ok(bound_value) = Value::try_from(literal) {
+                    value.replace(Some(bound_value));
+                }
+            }
+            Expression::Identifier(id) => {
+                if let Some(var) = self.symbol_table.borrow().lookup_variable(id.name) {
+                    if VariableType::Const != var.declaration {
+                        self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(id.span));
+                    }
+                }
+            }
+            _ => self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(bound.span())),
+        }
+    }
+
     /// Emits an error to the handler if the given type is invalid.
     fn check_type(&self, is_valid: impl Fn(&Type) -> bool, error_string: String, type_: &Option<Type>, span: Span) {
         if let Some(type_) = type_ {
@@ -1035,6 +1113,22 @@ impl<'a> TypeChecker<'a> {
                 // Return a boolean.
                 Some(Type::Boolean)
             }
+            CoreFunction::LookupGet => {
+                // Check that the first argument is an array.
+                self.assert_array_type(&arguments[0].0, arguments[0].1);
+                // Check that the second argument is an integer.
+                self.assert_int_type(&arguments[1].0, arguments[1].1);
+                match &arguments[0].0 {
+                    Some(Type::Array(array_type)) => {
+                        // Check that the elements of the array are fields or integers.
+                        let element_type = Some(array_type.element_type().clone());
+                        self.assert_field_int_type(&element_type, arguments[0].1);
+                        // Return the element type.
+                        element_type
+                    }
+                    _ => None,
+                }
+            }
         }
     }
 
@@ -1069,6 +1163,65 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Returns `true` if values of the type have a default value, which the `RecursionExpander` can construct.
+    pub(crate) fn has_default_value(&self, type_: &Type) -> bool {
+        self.has_default_value_within(type_, &mut Vec::new())
+    }
+
+    /// Returns `true` if values of the type have a default value, where `structs` are the structs whose members are
+    /// being checked. A struct that contains itself has no default value, so its members are not checked again.
+    fn has_default_value_within(&self, type_: &Type, structs: &mut Vec<Symbol>) -> bool {
+        match type_ {
+            Type::Boolean | Type::Field | Type::Group | Type::Integer(_) | Type::Scalar => true,
+            Type::Array(array_type) => self.has_default_value_within(array_type.element_type(), structs),
+            Type::Tuple(tuple_type) => {
+                tuple_type.elements().iter().all(|type_| self.has_default_value_within(type_, structs))
+            }
+            Type::Identifier(identifier) if structs.contains(&identifier.name) => false,
+            Type::Identifier(identifier) => match self.symbol_table.borrow().lookup_struct(identifier.name) {
+                Some(struct_) => {
+                    structs.push(identifier.name);
+                    let has_default_value = !struct_.is_record
+                        && struct_.members.iter().all(|member| self.has_default_value_within(&member.type_, structs));
+                    structs.pop();
+                    has_default_value
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Emits an error for a cycle of structs in which each struct contains the next, where `path` starts and ends with
+    /// the same struct. The error names the member through which each struct contains the next.
+    pub(crate) fn emit_recursive_struct(&self, path: &[Symbol]) {
+        let symbol_table = self.symbol_table.borrow();
+        let members = path
+            .iter()
+            .tuple_windows()
+            .filter_map(|(parent, child)| {
+                let struct_ = symbol_table.lookup_struct(*parent)?;
+                let member = struct_.members.iter().find(|member| {
+                    let type_ = match &member.type_ {
+                        Type::Array(array_type) => array_type.base_element_type(),
+                        type_ => type_,
+                    };
+                    matches!(type_, Type::Identifier(identifier) if identifier.name == *child)
+                })?;
+                Some((format!("`{parent}.{}: {}`", member.identifier, member.type_), member.span))
+            })
+            .collect::<Vec<_>>();
+
+        match members.first() {
+            Some((_, span)) => self.emit_err(TypeCheckerError::recursive_struct(
+                path[0],
+                members.iter().map(|(member, _)| member).join(" --> "),
+                *span,
+            )),
+            None => self.emit_err(TypeCheckerError::cyclic_struct_dependency(path.to_vec())),
+        }
+    }
+
     /// Emits an error if the type or its constituent types is not valid.
     pub(crate) fn assert_type_is_valid(&self, type_: &Type, span: Span) -> bool {
         let mut is_valid = true;
@@ -1099,10 +1252,10 @@ impl<'a> TypeChecker<'a> {
                 // Check that the array length is valid.
                 match array_type.length() {
                     0 => self.emit_err(TypeCheckerError::array_empty(span)),
-                    1..=Testnet3::MAX_ARRAY_ELEMENTS => {}
-                    length => {
-                        self.emit_err(TypeCheckerError::array_too_large(length, Testnet3::MAX_ARRAY_ELEMENTS, span))
+                    length if length > self.max_array_elements => {
+                        self.emit_err(TypeCheckerError::array_too_large(length, self.max_array_elements, span))
                     }
+                    _ => {}
                 }
                 // Check that the array element type is valid.
                 match array_type.element_type() {
@@ -1167,6 +1320,11 @@ impl<'a> TypeChecker<'a> {
                 _ => {} // Do nothing.
             }
 
+            // Only inline functions, which are inlined into their callers, can alias the arguments of their callers.
+            if input_var.is_reference() && !matches!(function.variant, Variant::Inline) {
+                self.emit_err(TypeCheckerError::reference_input_must_be_inline(input_var.span()));
+            }
+
             // If the function is not a transition function, then it cannot have a record as input
             if let Type::Identifier(identifier) = input_var.type_() {
                 if let Some(val) = self.symbol_table.borrow().lookup_struct(identifier.name) {
@@ -1181,7 +1339,10 @@ impl<'a> TypeChecker<'a> {
                 self.symbol_table.borrow_mut().insert_variable(input_var.identifier().name, VariableSymbol {
                     type_: input_var.type_(),
                     span: input_var.identifier().span(),
-                    declaration: VariableType::Input(input_var.mode()),
+                    declaration: match input_var.is_reference() {
+                        true => VariableType::Reference,
+                        false => VariableType::Input(input_var.mode()),
+                    },
                 })
             {
                 self.handler.emit_err(err);
@@ -14,6 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+//! The type checking pass checks that a program is well typed, and records the type of each expression in the type table.
+//!
+//! Leo has no implicit conversions. Every literal is written with its type, e.g. `1u8` or `1field`, and every value
+//! is only accepted where its exact type is expected: the operands of an arithmetic, logical, or comparison operator,
+//! the branches of a ternary, the arguments of a call, and the value of an assignment or `return` all have the same
+//! type as their counterpart. The only exceptions are the right operands of shifts and of `pow` on integers, which may
+//! be any of `u8`, `u16`, and `u32`, as their type does not determine the type of the result.
+//! A value of one numeric type is converted to another with a cast, e.g. `a as u64`, which is checked when it runs.
+//!
+//! Since no type is ever chosen for a value by the compiler, the linter has no rule for implicit conversions.
+
 pub mod check_expressions;
 
 pub use check_expressions::*;
@@ -33,11 +44,11 @@ use leo_ast::{Ast, ProgramVisitor};
 use leo_errors::{emitter::Handler, Result};
 
 impl<'a> Pass for TypeChecker<'a> {
-    type Input = (&'a Ast, &'a Handler, SymbolTable, &'a TypeTable);
+    type Input = (&'a Ast, &'a Handler, SymbolTable, &'a TypeTable, usize);
     type Output = Result<(SymbolTable, StructGraph, CallGraph)>;
 
-    fn do_pass((ast, handler, st, tt): Self::Input) -> Self::Output {
-        let mut visitor = TypeChecker::new(st, tt, handler);
+    fn do_pass((ast, handler, st, tt, max_array_elements): Self::Input) -> Self::Output {
+        let mut visitor = TypeChecker::new(st, tt, handler, max_array_elements);
         visitor.visit_program(ast.as_repr());
         handler.last_err().map_err(|e| *e)?;
 
@@ -0,0 +1,181 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Python bindings to the Leo compiler, e.g. for researchers who script experiments over many programs.
+//!
+//! The module `leo` parses programs into JSON ASTs, compiles them to Aleo instructions, and sets up, proves, and
+//! verifies their functions. Keys and executions are passed around as JSON strings, and every error is raised as a
+//! `leo.LeoError`. Proving and verifying release the GIL, so that several functions can be proved from threads.
+
+#![forbid(unsafe_code)]
+
+use leo_ast::NodeBuilder;
+use leo_compiler::{Compiler, FunctionKeys};
+use leo_errors::{emitter::Handler, LeoError as Error};
+use leo_span::{
+    source_map::FileName,
+    symbol::{create_session_if_not_set_then, with_session_globals},
+};
+
+use indexmap::IndexMap;
+use pyo3::{create_exception, exceptions::PyException, prelude::*};
+use snarkvm::{
+    circuit::AleoV0,
+    ledger::block::Execution,
+    prelude::{PrivateKey, Testnet3},
+};
+use std::{fmt::Display, path::PathBuf, str::FromStr};
+
+type CurrentNetwork = Testnet3;
+
+create_exception!(leo, LeoError, PyException, "An error of the Leo compiler.");
+
+/// Returns `err` as a `LeoError`.
+fn to_py_err(err: impl Display) -> PyErr {
+    LeoError::new_err(err.to_string())
+}
+
+/// Parses the JSON `json`, where `name` names the argument in errors.
+fn from_json<T: serde::de::DeserializeOwned>(json: &str, name: &str) -> PyResult<T> {
+    serde_json::from_str(json).map_err(|err| to_py_err(format!("`{name}` is invalid: {err}")))
+}
+
+/// Returns the JSON of `value`.
+fn to_json<T: serde::Serialize>(value: &T) -> PyResult<String> {
+    serde_json::to_string(value).map_err(to_py_err)
+}
+
+/// Parses the Leo program `source` and returns its AST as JSON.
+#[pyfunction]
+fn parse(source: &str) -> PyResult<String> {
+    create_session_if_not_set_then(|_| {
+        let source_file =
+            with_session_globals(|s| s.source_map.new_source(source, FileName::Custom("main.leo".into())));
+        let ast = leo_parser::parse_ast(
+            &Handler::default(),
+            &NodeBuilder::default(),
+            &source_file.src,
+            source_file.start_pos,
+        )
+        .map_err(to_py_err)?;
+        ast.to_json_string().map_err(to_py_err)
+    })
+}
+
+/// Compiles the Leo program `source`, whose program scope is `program_name`, and returns its Aleo instructions.
+/// Programs that import other programs cannot be compiled, since the imported programs are not available.
+#[pyfunction]
+fn compile(program_name: &str, source: &str) -> PyResult<String> {
+    create_session_if_not_set_then(|_| {
+        let (handler, buffer) = Handler::new_with_buf();
+        let file_name = format!("{program_name}.leo");
+        let mut compiler = Compiler::new(
+            program_name.to_string(),
+            "aleo".to_string(),
+            &handler,
+            PathBuf::from(&file_name),
+            std::env::temp_dir(),
+            None,
+            IndexMap::new(),
+        );
+        let result = compiler
+            .parse_program_from_string(source, FileName::Custom(file_name))
+            .and_then(|_| compiler.compile_parsed_program());
+        match result {
+            Ok(bytecode) => Ok(bytecode),
+            // The errors were emitted to the handler rather than returned.
+            Err(Error::LastErrorCode(_)) => Err(to_py_err(buffer.extract_errs())),
+            Err(err) => Err(to_py_err(err)),
+        }
+    })
+}
+
+/// Synthesizes the keys of the function `function` of the Aleo instructions `bytecode`, and returns them as JSON.
+/// The Aleo instructions of the programs it imports are given in `imports`.
+#[pyfunction]
+#[pyo3(signature = (bytecode, function, imports = Vec::new()))]
+fn setup(py: Python<'_>, bytecode: &str, function: &str, imports: Vec<String>) -> PyResult<String> {
+    let keys = py.allow_threads(|| Compiler::setup::<AleoV0, _>(bytecode, &imports, function, &mut rand::thread_rng()));
+    to_json(&keys.map_err(to_py_err)?)
+}
+
+/// Executes the function `function` of the Aleo instructions `bytecode` on `inputs`, signed by `private_key`, proves
+/// the execution with the JSON `keys`, and returns the execution as JSON. The state of the network, e.g. the records
+/// that are spent, is queried from `endpoint`. The Aleo instructions of the programs it imports are given in `imports`.
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+#[pyo3(signature = (bytecode, keys, private_key, function, inputs, endpoint, imports = Vec::new()))]
+fn prove(
+    py: Python<'_>,
+    bytecode: &str,
+    keys: &str,
+    private_key: &str,
+    function: &str,
+    inputs: Vec<String>,
+    endpoint: &str,
+    imports: Vec<String>,
+) -> PyResult<String> {
+    let keys: FunctionKeys<CurrentNetwork> = from_json(keys, "keys")?;
+    let private_key = PrivateKey::<CurrentNetwork>::from_str(private_key)
+        .map_err(|err| to_py_err(format!("`private_key` is invalid: {err}")))?;
+    let execution = py.allow_threads(|| {
+        Compiler::prove::<AleoV0, _>(
+            bytecode,
+            &imports,
+            &keys,
+            &private_key,
+            function,
+            &inputs,
+            endpoint,
+            &mut rand::thread_rng(),
+        )
+    });
+    to_json(&execution.map_err(to_py_err)?)
+}
+
+/// Verifies the JSON `execution` of the function `function` of the Aleo instructions `bytecode` with the JSON `keys`,
+/// and checks that its public inputs are `public_inputs`. Returns the public inputs of the execution, and raises a
+/// `LeoError` if the execution is invalid. The Aleo instructions of the programs it imports are given in `imports`.
+#[pyfunction]
+#[pyo3(signature = (bytecode, keys, function, execution, public_inputs, imports = Vec::new()))]
+fn verify(
+    py: Python<'_>,
+    bytecode: &str,
+    keys: &str,
+    function: &str,
+    execution: &str,
+    public_inputs: Vec<String>,
+    imports: Vec<String>,
+) -> PyResult<Vec<String>> {
+    let keys: FunctionKeys<CurrentNetwork> = from_json(keys, "keys")?;
+    let execution: Execution<CurrentNetwork> = from_json(execution, "execution")?;
+    py.allow_threads(|| {
+        Compiler::verify(bytecode, &imports, &keys.verifying_keys(), function, &execution, &public_inputs)
+    })
+    .map_err(to_py_err)
+}
+
+/// The Python module `leo`.
+#[pymodule]
+fn leo(py: Python<'_>, module: &PyModule) -> PyResult<()> {
+    module.add("LeoError", py.get_type::<LeoError>())?;
+    module.add_function(wrap_pyfunction!(parse, module)?)?;
+    module.add_function(wrap_pyfunction!(compile, module)?)?;
+    module.add_function(wrap_pyfunction!(setup, module)?)?;
+    module.add_function(wrap_pyfunction!(prove, module)?)?;
+    module.add_function(wrap_pyfunction!(verify, module)?)?;
+    Ok(())
+}
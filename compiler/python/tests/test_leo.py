@@ -0,0 +1,50 @@
+# Copyright (C) 2019-2023 Aleo Systems Inc.
+# This file is part of the Leo library.
+
+# The Leo library is free software: you can redistribute it and/or modify
+# it under the terms of the GNU General Public License as published by
+# the Free Software Foundation, either version 3 of the License, or
+# (at your option) any later version.
+
+# The Leo library is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU General Public License for more details.
+
+# You should have received a copy of the GNU General Public License
+# along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+import json
+
+import pytest
+
+import leo
+
+HELLO = """
+program hello.aleo {
+    transition main(public a: u32, b: u32) -> u32 {
+        return a + b;
+    }
+}
+"""
+
+
+def test_parse():
+    ast = json.loads(leo.parse(HELLO))
+    assert list(ast["program_scopes"]) == ["hello"]
+
+
+def test_parse_error():
+    with pytest.raises(leo.LeoError):
+        leo.parse("program hello.aleo { transition main( }")
+
+
+def test_compile():
+    bytecode = leo.compile("hello", HELLO)
+    assert "program hello.aleo;" in bytecode
+    assert "add r0 r1 into r2;" in bytecode
+
+
+def test_compile_error():
+    with pytest.raises(leo.LeoError):
+        leo.compile("hello", HELLO.replace("return a + b;", "return a + true;"))
@@ -173,6 +173,7 @@ symbols! {
     Keccak256,
     Keccak384,
     Keccak512,
+    Lookup,
     Mapping,
     Pedersen64,
     Pedersen128,
@@ -233,6 +234,7 @@ symbols! {
     assert,
     assert_eq,
     assert_neq,
+    const_assert,
     caller,
     console,
     Const: "const",
@@ -250,6 +252,7 @@ symbols! {
     input,
     Let: "let",
     leo,
+    Macro: "macro",
     main,
     mapping,
     Mut: "mut",
@@ -271,6 +274,17 @@ symbols! {
     stub,
     block,
     height,
+
+    // loop adapters
+    rev,
+    step,
+
+    // annotations
+    cfg,
+    deprecated,
+    feature,
+    recursive,
+    test,
 }
 
 /// An interned string.
@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The front end of the Leo compiler for WebAssembly, e.g. for in-browser playgrounds.
+//!
+//! Programs are parsed, type checked, and exported as JSON ASTs. The backend that synthesizes circuits is not part of
+//! this crate, which only depends on the crates of the front end. Since the programs that a program imports are not
+//! available, only programs that do not import other programs can be type checked.
+
+#![forbid(unsafe_code)]
+
+use leo_ast::{Ast, NodeBuilder};
+use leo_errors::{emitter::Handler, Diagnostic, LeoError, Result};
+use leo_passes::{Pass, SymbolTableCreator, TypeChecker, TypeTable, MAX_ARRAY_ELEMENTS};
+use leo_span::{
+    source_map::FileName,
+    symbol::{create_session_if_not_set_then, with_session_globals},
+};
+
+use serde_json::json;
+use wasm_bindgen::prelude::*;
+
+/// Parses the Leo program `source`, returning its AST as JSON.
+#[wasm_bindgen]
+pub fn parse(source: &str) -> Result<String, JsError> {
+    create_session_if_not_set_then(|_| {
+        let ast = parse_ast(&Handler::default(), source).map_err(|err| JsError::new(&err.to_string()))?;
+        ast.to_json_string().map_err(|err| JsError::new(&err.to_string()))
+    })
+}
+
+/// Parses and type checks the Leo program `source`, returning its errors and warnings as a JSON array.
+///
+/// Each diagnostic has a `code`, e.g. `ETYC0372003`, a `severity`, either `error` or `warning`, a `message`, the
+/// `notes` that help to resolve it, and the `location` it refers to, if any, with the lines and columns it starts and
+/// stops at, counting from 1.
+#[wasm_bindgen]
+pub fn check(source: &str) -> String {
+    create_session_if_not_set_then(|_| {
+        let (handler, buffer) = Handler::new_with_buf();
+        let result = parse_ast(&handler, source).and_then(|ast| {
+            let symbol_table = SymbolTableCreator::do_pass((&ast, &handler))?;
+            TypeChecker::do_pass((&ast, &handler, symbol_table, &TypeTable::default(), MAX_ARRAY_ELEMENTS))
+        });
+
+        // An error that was returned rather than emitted, e.g. a syntax error, is a diagnostic as well.
+        let mut diagnostics = buffer.extract_diagnostics();
+        if let Err(err) = result {
+            let diagnostic = err.to_diagnostic();
+            if !matches!(err, LeoError::LastErrorCode(_)) && !diagnostics.contains(&diagnostic) {
+                diagnostics.push(diagnostic);
+            }
+        }
+
+        json!(diagnostics.iter().map(diagnostic_to_json).collect::<Vec<_>>()).to_string()
+    })
+}
+
+/// Parses the Leo program `source`, registering it in the source map so that the spans of its diagnostics resolve.
+fn parse_ast(handler: &Handler, source: &str) -> Result<Ast> {
+    let source_file = with_session_globals(|s| s.source_map.new_source(source, FileName::Custom("main.leo".into())));
+    leo_parser::parse_ast(handler, &NodeBuilder::default(), &source_file.src, source_file.start_pos)
+}
+
+/// Returns the JSON form of `diagnostic`; see [`check`].
+fn diagnostic_to_json(diagnostic: &Diagnostic) -> serde_json::Value {
+    let location = diagnostic.span.and_then(|span| with_session_globals(|s| s.source_map.span_to_location(span)));
+    json!({
+        "code": diagnostic.code,
+        "severity": diagnostic.severity.to_string(),
+        "message": diagnostic.message,
+        "notes": diagnostic.notes,
+        "location": location.map(|location| json!({
+            "line_start": location.line_start,
+            "col_start": location.col_start,
+            "line_stop": location.line_stop,
+            "col_stop": location.col_stop,
+        })),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use serde_json::Value;
+
+    fn diagnostics(source: &str) -> Vec<Value> {
+        serde_json::from_str::<Value>(&check(source)).unwrap().as_array().unwrap().clone()
+    }
+
+    #[test]
+    fn test_parse() {
+        let json = parse("program test.aleo { transition main(a: u8) -> u8 { return a; } }").unwrap();
+        let ast = serde_json::from_str::<Value>(&json).unwrap();
+        assert!(ast["program_scopes"].as_object().unwrap().contains_key("test"));
+    }
+
+    #[test]
+    fn test_check_valid_program() {
+        assert!(diagnostics("program test.aleo { transition main(a: u8) -> u8 { return a; } }").is_empty());
+    }
+
+    #[test]
+    fn test_check_type_error() {
+        let diagnostics =
+            diagnostics("program test.aleo {\n    transition main(a: u8) -> u8 {\n        return a + 1u16;\n    }\n}");
+        assert!(!diagnostics.is_empty());
+        let diagnostic = &diagnostics[0];
+        assert!(diagnostic["code"].as_str().unwrap().starts_with("ETYC"));
+        assert_eq!(diagnostic["severity"], "error");
+        assert_eq!(diagnostic["location"]["line_start"], 3);
+    }
+
+    #[test]
+    fn test_check_syntax_error() {
+        let diagnostics = diagnostics("program test.aleo { transition main( }");
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0]["code"].as_str().unwrap().starts_with("EPAR"));
+        assert_eq!(diagnostics[0]["location"]["line_start"], 1);
+    }
+}
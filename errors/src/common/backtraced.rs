@@ -16,6 +16,7 @@
 
 use std::fmt;
 
+use crate::{Diagnostic, Severity};
 use backtrace::Backtrace;
 use color_backtrace::{BacktracePrinter, Verbosity};
 use colored::Colorize;
@@ -101,6 +102,19 @@ impl Backtraced {
             exit_code = self.code,
         )
     }
+
+    /// Returns the structured form of the message.
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        let (code, severity) =
+            if self.error { (self.error_code(), Severity::Error) } else { (self.warning_code(), Severity::Warning) };
+        Diagnostic {
+            code,
+            severity,
+            message: self.message.clone(),
+            span: None,
+            notes: self.help.iter().cloned().collect(),
+        }
+    }
 }
 
 impl fmt::Display for Backtraced {
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Span;
+
+use std::fmt;
+
+/// Whether a diagnostic prevents compilation from succeeding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// The program cannot be compiled.
+    Error,
+    /// The program can be compiled, but likely contains a mistake.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A structured view of an error or warning, for tools that inspect messages rather than print them,
+/// e.g. editors or test harnesses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The stable identifier of the message, e.g. `ETYC0372003`.
+    pub code: String,
+    /// Whether the message is an error or a warning.
+    pub severity: Severity,
+    /// The message itself.
+    pub message: String,
+    /// The location in the source the message refers to, if any.
+    pub span: Option<Span>,
+    /// Additional guidance, e.g. the help text of the message.
+    pub notes: Vec<String>,
+}
@@ -14,9 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Backtraced, INDENT};
+use crate::{Backtraced, Diagnostic, INDENT};
 
-use leo_span::{source_map::SpanLocation, symbol::with_session_globals, Span};
+use leo_span::{
+    source_map::{is_not_test_framework, SpanLocation},
+    symbol::with_session_globals,
+    Span,
+};
 
 use backtrace::Backtrace;
 use color_backtrace::{BacktracePrinter, Verbosity};
@@ -84,6 +88,11 @@ impl Formatted {
     pub fn warning_code(&self) -> String {
         self.backtrace.warning_code()
     }
+
+    /// Returns the structured form of the message, including its span.
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        Diagnostic { span: Some(self.span), ..self.backtrace.to_diagnostic() }
+    }
 }
 
 impl fmt::Display for Formatted {
@@ -122,21 +131,24 @@ impl fmt::Display for Formatted {
         let message = format!("{kind} [{code}]: {message}", message = self.backtrace.message,);
 
         // To avoid the color enabling characters for comparison with test expectations.
-        if std::env::var("LEO_TESTFRAMEWORK").unwrap_or_default().trim().to_owned().is_empty() {
-            if self.backtrace.error {
-                write!(f, "{}", message.bold().red())?;
-            } else {
-                write!(f, "{}", message.bold().yellow())?;
-            }
-        } else {
-            write!(f, "{message}")?;
+        let colored = is_not_test_framework();
+
+        // Paints `text` like the severity of the message, as rustc does for the label and the underline.
+        let severity_colored = |text: &str| match (colored, self.backtrace.error) {
+            (false, _) => text.normal(),
+            (true, true) => text.bold().red(),
+            (true, false) => text.bold().yellow(),
         };
+        // Paints the gutter, i.e. the arrow, the bars, and the line numbers.
+        let gutter = |text: String| if colored { text.bold().blue() } else { text.normal() };
+
+        write!(f, "{}", severity_colored(&message))?;
 
         write!(
             f,
-            "\n{indent     }--> {path}:{line_start}:{start}\n\
-            {indent     } |\n",
-            indent = INDENT,
+            "\n{arrow} {path}:{line_start}:{start}\n{bar}\n",
+            arrow = gutter(format!("{INDENT     }-->")),
+            bar = gutter(format!("{INDENT     } |")),
             path = &loc.source_file.name,
             line_start = loc.line_start,
             start = loc.col_start,
@@ -145,20 +157,20 @@ impl fmt::Display for Formatted {
         for (line_no, line) in contents.lines().enumerate() {
             writeln!(
                 f,
-                "{line_no:width$} | {text}",
-                width = INDENT.len(),
-                line_no = loc.line_start + line_no,
+                "{line_no} {text}",
+                line_no = gutter(format!("{:width$} |", loc.line_start + line_no, width = INDENT.len())),
                 text = line,
             )?;
         }
 
-        write!(f, "{INDENT     } |{underlined}",)?;
+        write!(f, "{}{}", gutter(format!("{INDENT     } |")), severity_colored(&underlined))?;
 
         if let Some(help) = &self.backtrace.help {
             write!(
                 f,
-                "\n{INDENT     } |\n\
-            {INDENT     } = {help}",
+                "\n{bar}\n{equals} {help}",
+                bar = gutter(format!("{INDENT     } |")),
+                equals = gutter(format!("{INDENT     } =")),
             )?;
         }
 
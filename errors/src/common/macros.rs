@@ -88,6 +88,14 @@ macro_rules! create_messages {
 
         // Steps over the list of functions with an initial code of 0.
         impl $type_ {
+            /// Returns the structured form of the message.
+            pub fn to_diagnostic(&self) -> $crate::Diagnostic {
+                match self {
+                    Self::Formatted(formatted) => formatted.to_diagnostic(),
+                    Self::Backtraced(backtraced) => backtraced.to_diagnostic(),
+                }
+            }
+
             create_messages!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
         }
     };
@@ -18,6 +18,10 @@
 pub mod backtraced;
 pub use self::backtraced::*;
 
+/// This module contains a structured view of errors and warnings.
+pub mod diagnostic;
+pub use self::diagnostic::*;
+
 /// This module contains a formatted error and its methods.
 pub mod formatted;
 pub use self::formatted::*;
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::LeoWarning;
+use crate::{Diagnostic, LeoWarning};
 
 use super::LeoError;
 use core::{default::Default, fmt};
@@ -117,6 +117,19 @@ impl BufferEmitter {
     pub fn extract_warnings(&self) -> WarningBuffer {
         self.1.take()
     }
+
+    /// Extracts all the errors and then all the warnings collected in this emitter, in structured form.
+    pub fn extract_diagnostics(&self) -> Vec<Diagnostic> {
+        // `LastErrorCode`s only mark that the errors before them have already been reported.
+        let errors = self
+            .extract_errs()
+            .into_inner()
+            .into_iter()
+            .filter(|err| !matches!(err, LeoError::LastErrorCode(_)))
+            .map(|err| err.to_diagnostic());
+        let warnings = self.extract_warnings().into_inner().into_iter().map(|warning| warning.to_diagnostic());
+        errors.chain(warnings).collect()
+    }
 }
 
 impl Emitter for BufferEmitter {
@@ -77,33 +77,33 @@ create_messages!(
     /// For when a user shadows a function.
     @formatted
     shadowed_function {
-        args: (func: impl Display),
+        args: (func: impl Display, previous: impl Display),
         msg: format!("function `{func}` shadowed by"),
-        help: None,
+        help: Some(format!("The first definition of `{func}` is at {previous}.")),
     }
 
     /// For when a user shadows a struct.
     @formatted
     shadowed_struct {
-        args: (struct_: impl Display),
+        args: (struct_: impl Display, previous: impl Display),
         msg: format!("struct `{struct_}` shadowed by"),
-        help: None,
+        help: Some(format!("The first definition of `{struct_}` is at {previous}.")),
     }
 
     /// For when a user shadows a record.
     @formatted
     shadowed_record {
-        args: (record: impl Display),
+        args: (record: impl Display, previous: impl Display),
         msg: format!("record `{record}` shadowed by"),
-        help: None,
+        help: Some(format!("The first definition of `{record}` is at {previous}.")),
     }
 
     /// For when a user shadows a variable.
     @formatted
     shadowed_variable {
-        args: (var: impl Display),
+        args: (var: impl Display, previous: impl Display),
         msg: format!("variable `{var}` shadowed by"),
-        help: None,
+        help: Some(format!("The first definition of `{var}` is at {previous}.")),
     }
 
     /// For when the symbol table fails to be represented as a JSON string.
@@ -152,4 +152,28 @@ create_messages!(
         msg: format!("There are two mismatched definitions of struct `{struct_}`."),
         help: Some("Duplicate definitions of structs are required to use external structs, but each field's name and type must match exactly.".to_string()),
     }
+
+    /// For when the AST fails to write the DOT graph file.
+    @backtraced
+    failed_to_write_ast_to_dot_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to write ast to a dot file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when a string is not a valid S-expression.
+    @backtraced
+    invalid_sexpr {
+        args: (reason: impl Display),
+        msg: format!("invalid s-expression: {reason}"),
+        help: None,
+    }
+
+    /// For when an expression cannot be evaluated by the constant interpreter.
+    @formatted
+    cannot_evaluate {
+        args: (expression: impl Display),
+        msg: format!("The expression `{expression}` cannot be evaluated as a constant."),
+        help: Some("Only operations on booleans and integers can be evaluated, other than `%` and casts.".to_string()),
+    }
 );
@@ -178,4 +178,102 @@ create_messages!(
         msg: format!("Failed to write file.\nIO Error: {error}"),
         help: None,
     }
+
+    @backtraced
+    function_not_found {
+        args: (name: impl Display),
+        msg: format!("The program does not declare a function `{name}`."),
+        help: None,
+    }
+
+    @backtraced
+    expected_one_input_set {
+        args: (path: impl Display, found: impl Display),
+        msg: format!("Expected one set of inputs in `{path}`, but found {found}."),
+        help: Some("Use `leo execute` to execute a function on several sets of inputs.".to_string()),
+    }
+
+    @backtraced
+    batch_execution_failed {
+        args: (failed: impl Display, total: impl Display),
+        msg: format!("{failed} of {total} sets of inputs failed to execute."),
+        help: None,
+    }
+
+    @backtraced
+    input_file_exists {
+        args: (path: impl Display),
+        msg: format!("The input file `{path}` already exists."),
+        help: Some("Use `--force` to overwrite it.".to_string()),
+    }
+
+    @backtraced
+    nondeterministic_build {
+        args: (file: impl Display),
+        msg: format!("Compiling `{file}` twice produced different Aleo instructions."),
+        help: None,
+    }
+
+    @backtraced
+    nondeterministic_keys {
+        args: (function: impl Display),
+        msg: format!("Synthesizing the keys of the function `{function}` twice with the same seed produced different keys or constraint systems."),
+        help: None,
+    }
+
+    @backtraced
+    unformatted_files {
+        args: (files: impl Display),
+        msg: format!("The following files are not formatted: {files}"),
+        help: Some("Run `leo fmt` to format them.".to_string()),
+    }
+
+    @backtraced
+    denied_lints {
+        args: (count: impl Display, file: impl Display),
+        msg: format!("Linting `{file}` failed, since it broke denied lint rules {count} time(s)."),
+        help: None,
+    }
+
+    @backtraced
+    unknown_lint {
+        args: (name: impl Display, names: impl Display),
+        msg: format!("There is no lint rule named `{name}`."),
+        help: Some(format!("The lint rules are {names}.")),
+    }
+
+    @backtraced
+    failed_tests {
+        args: (failed: impl Display, total: impl Display),
+        msg: format!("{failed} of {total} tests failed."),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_access_stdio {
+        args: (error: impl Display),
+        msg: format!("Failed to read from standard input or write to standard output.\nIO Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_sample_account {
+        args: (error: impl Display),
+        msg: format!("Failed to sample a new Aleo account.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    invalid_bench_baseline {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to read the benchmark baseline `{path}`.\nError: {error}"),
+        help: Some("Save a new baseline with `leo bench --save-baseline`.".to_string()),
+    }
+
+    @backtraced
+    invalid_source_file {
+        args: (path: impl Display, errors: impl Display),
+        msg: format!("Failed to read `{path}`.\n{errors}"),
+        help: None,
+    }
 );
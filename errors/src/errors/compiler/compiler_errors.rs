@@ -77,4 +77,203 @@ create_messages!(
         msg: format!("`{main_program_name}` imports `{dependency_name}.aleo`, but `{dependency_name}.aleo` is not found in `program.json`."),
         help: None,
     }
+
+    @formatted
+    module_not_found {
+        args: (module: impl Display, path: impl Debug),
+        msg: format!("Cannot find the module `{module}` at the path {path:?}."),
+        help: None,
+    }
+
+    @formatted
+    imported_item_not_found {
+        args: (item: impl Display, module: impl Display),
+        msg: format!("The module `{module}` does not declare a struct or function `{item}`."),
+        help: None,
+    }
+
+    @formatted
+    name_imported_more_than_once {
+        args: (name: impl Display, previous: impl Display),
+        msg: format!("`{name}` is imported or declared more than once."),
+        help: Some(format!("The first definition of `{name}` is at {previous}.")),
+    }
+
+    @formatted
+    cyclic_module_import {
+        args: (path: Vec<impl Display>),
+        msg: {
+            let path_string = path.into_iter().map(|name| format!("`{name}`")).collect::<Vec<String>>().join(" --> ");
+            format!("Cyclic import between modules: {path_string}")
+        },
+        help: Some("Move the items that these modules share into a module that does not import from them.".to_string()),
+    }
+
+    @formatted
+    std_module_not_found {
+        args: (module: impl Display),
+        msg: format!("The standard library does not have a module `{module}`."),
+        help: Some("The modules of the standard library are `std.bits`, `std.compare`, `std.hash`, and `std.merkle`.".to_string()),
+    }
+
+    @backtraced
+    failed_to_load_program {
+        args: (error: impl Display),
+        msg: format!("Failed to load the compiled program into snarkVM.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_setup {
+        args: (function: impl Display, error: impl Display),
+        msg: format!("Failed to synthesize the keys of the function `{function}`.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_prove {
+        args: (function: impl Display, error: impl Display),
+        msg: format!("Failed to prove an execution of the function `{function}`.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_verify {
+        args: (function: impl Display, error: impl Display),
+        msg: format!("Failed to verify an execution of the function `{function}`.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    public_inputs_mismatch {
+        args: (function: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The execution of the function `{function}` has the public inputs `[{found}]`, but `[{expected}]` were expected."),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_r1cs {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the constraint system to `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_witness {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the witness to `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_read_witness {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to read a witness from `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_encode {
+        args: (error: impl Display),
+        msg: format!("Failed to encode a proof or key.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_decode {
+        args: (error: impl Display),
+        msg: format!("Failed to decode a proof or key.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_verifier {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the verifier to `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    test_failed {
+        args: (function: impl Display, error: impl Display),
+        msg: format!("The test `{function}` failed.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @formatted
+    expected_expression_or_definition {
+        args: (),
+        msg: format!("Expected an expression, or the definition of a single variable."),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_synthesize {
+        args: (function: impl Display, error: impl Display),
+        msg: format!("Failed to synthesize the circuit of an execution of the function `{function}`.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_constraint_map {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the constraint source map to `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_read_constraint_map {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to read a constraint source map from `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_synthesis_cache {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the synthesis cache to `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_read_synthesis_cache {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to read a synthesis cache from `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_ir {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the intermediate representation to `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_read_ir {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to read an intermediate representation from `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    unsupported_ir_version {
+        args: (path: impl Display, version: impl Display, supported: impl Display),
+        msg: format!("The intermediate representation in `{path}` has version {version}, but only version {supported} is supported."),
+        help: Some("Export the intermediate representation again with this version of the compiler.".to_string()),
+    }
+
+    @backtraced
+    failed_to_write_abi {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the ABI to `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @formatted
+    ambiguous_glob_import {
+        args: (name: impl Display, first: impl Display, second: impl Display),
+        msg: format!("`{name}` is imported by the glob imports of both `{first}` and `{second}`."),
+        help: Some(format!("Import `{name}` by name from the module it should refer to.")),
+    }
 );
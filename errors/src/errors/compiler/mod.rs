@@ -17,3 +17,7 @@
 /// This module contains the Compiler error definitions.
 pub mod compiler_errors;
 pub use self::compiler_errors::*;
+
+/// This module contains the Compiler warning definitions.
+pub mod compiler_warnings;
+pub use self::compiler_warnings::*;
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// InputError enum that represents all the errors for the inputs part of `leo-ast` crate.
+    InputError,
+    code_mask: 1000i32,
+    code_prefix: "INP",
+
+    /// For when the value of an input is not of the expected type.
+    @formatted
+    unexpected_type {
+        args: (expected: impl Display, found: impl Display),
+        msg: format!("Expected a value of type `{expected}` but found `{found}`."),
+        help: None,
+    }
+
+    /// For when the value of an input is not a literal.
+    @formatted
+    invalid_value {
+        args: (value: impl Display),
+        msg: format!("`{value}` is not a value."),
+        help: Some("The values in an input file must be literals, e.g. `1u32`.".to_string()),
+    }
+
+    /// For when an integer value does not fit in its type.
+    @formatted
+    integer_out_of_range {
+        args: (value: impl Display, type_: impl Display),
+        msg: format!("The value `{value}` is out of range for `{type_}`."),
+        help: None,
+    }
+
+    /// For when an input of a function is not defined in the input file.
+    @formatted
+    missing_input {
+        args: (name: impl Display, section: impl Display),
+        msg: format!("The input `{name}` is missing from the section `[{section}]` of the input file."),
+        help: None,
+    }
+
+    /// For when an input is declared with a different type than the function's parameter.
+    @formatted
+    input_type_mismatch {
+        args: (name: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The input `{name}` has type `{found}` in the input file, but the function expects `{expected}`."),
+        help: None,
+    }
+
+    /// For when an input of a function cannot be given in an input file.
+    @formatted
+    unsupported_input {
+        args: (name: impl Display),
+        msg: format!("The input `{name}` cannot be given in an input file."),
+        help: Some("Records of other programs must be passed on the command line.".to_string()),
+    }
+
+    /// For when a definition in the `[registers]` section does not name an output of the function.
+    @formatted
+    unknown_register {
+        args: (name: impl Display, num_outputs: impl Display),
+        msg: format!("`{name}` is not an output register of the function."),
+        help: Some(format!("The function has {num_outputs} outputs, in the registers `r0`, `r1`, and so on.")),
+    }
+
+    /// For when an input is annotated with a different mode than the function's parameter.
+    @formatted
+    input_mode_mismatch {
+        args: (name: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The input `{name}` is declared `{found}` in the input file, but the function expects it to be `{expected}`."),
+        help: Some("Parameters without an annotation are private.".to_string()),
+    }
+
+    /// For when an array value has a different number of elements than its type.
+    @formatted
+    array_length_mismatch {
+        args: (expected: impl Display, found: impl Display),
+        msg: format!("Expected an array of {expected} elements, but found {found} elements."),
+        help: None,
+    }
+
+    /// For when the type of an input is not a struct or record declared by the program.
+    @formatted
+    unknown_struct {
+        args: (name: impl Display),
+        msg: format!("`{name}` is not a struct or record declared by the program."),
+        help: None,
+    }
+
+    /// For when a struct value does not give a member of the struct.
+    @formatted
+    missing_struct_member {
+        args: (struct_: impl Display, member: impl Display),
+        msg: format!("The value of struct `{struct_}` is missing the member `{member}`."),
+        help: None,
+    }
+
+    /// For when a struct value gives a member that the struct does not declare, or gives a member twice.
+    @formatted
+    unexpected_struct_member {
+        args: (struct_: impl Display, member: impl Display),
+        msg: format!("Struct `{struct_}` does not have a member `{member}`, or it is given more than once."),
+        help: None,
+    }
+
+    /// For when an input file gives an input that is not a parameter of the function.
+    @formatted
+    unexpected_input {
+        args: (name: impl Display, section: impl Display),
+        msg: format!("The input `{name}` in the section `[{section}]` of the input file is not a parameter of the function."),
+        help: None,
+    }
+
+    /// For when an input file gives an input more than once in a section.
+    @formatted
+    duplicate_input {
+        args: (name: impl Display, section: impl Display),
+        msg: format!("The input `{name}` is given more than once in the section `[{section}]` of the input file."),
+        help: None,
+    }
+
+    /// For when an output of a function differs from its expected value in the `[registers]` section.
+    @formatted
+    output_mismatch {
+        args: (register: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The output `{register}` is `{found}`, but the input file expects `{expected}`."),
+        help: None,
+    }
+);
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// LinterWarning enum that represents all the warnings of the lints run by `leo lint`.
+    LinterWarning,
+    code_mask: 11000i32,
+    code_prefix: "LNT",
+
+    /// For when a variable is defined but never read.
+    @formatted
+    unused_value {
+        args: (name: impl Display),
+        msg: format!("The value of `{name}` is never read."),
+        help: Some("Remove the definition, or allow the `unused-values` lint.".to_string()),
+    }
+
+    /// For when the condition of an `if` statement, ternary, or assertion does not depend on any variable.
+    @formatted
+    constant_condition {
+        args: (condition: impl Display),
+        msg: format!("The condition `{condition}` is constant."),
+        help: Some("Replace the condition by its value, or allow the `constant-conditions` lint.".to_string()),
+    }
+
+    /// For when a variable is only ever given a literal that fits in a narrower integer type.
+    @formatted
+    wide_integer {
+        args: (name: impl Display, type_: impl Display, narrower: impl Display),
+        msg: format!("The variable `{name}` has type `{type_}`, but its value fits in `{narrower}`."),
+        help: Some("Use the narrower type, or allow the `wide-integers` lint.".to_string()),
+    }
+);
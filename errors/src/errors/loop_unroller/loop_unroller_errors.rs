@@ -36,4 +36,25 @@ create_messages!(
         msg: format!("The array index must be constant."),
         help: None,
     }
+
+    @formatted
+    loop_step_must_be_positive {
+        args: (),
+        msg: format!("The loop step must be positive."),
+        help: None,
+    }
+
+    @formatted
+    non_constant_lookup_table {
+        args: (),
+        msg: format!("The table of `Lookup::get` must be known at compile time."),
+        help: Some("Pass an array of literals, or a constant defined as one.".to_string()),
+    }
+
+    @formatted
+    non_constant_loop_bound {
+        args: (),
+        msg: format!("The bounds and the step of a loop must be known at compile time."),
+        help: None,
+    }
 );
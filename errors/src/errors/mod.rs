@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 /// Contains the ASG error definitions.
-use crate::LeoMessageCode;
+use crate::{Diagnostic, LeoMessageCode, Severity};
 
 /// Contains the AST error definitions.
 pub mod ast;
@@ -33,6 +33,14 @@ pub use self::compiler::*;
 pub mod flattener;
 pub use self::flattener::*;
 
+/// Contains the Input error definitions.
+pub mod input;
+pub use self::input::*;
+
+/// Contains the Linter warning definitions.
+pub mod linter;
+pub use self::linter::*;
+
 /// Contains the Loop Unroller error definitions.
 pub mod loop_unroller;
 pub use self::loop_unroller::*;
@@ -53,6 +61,12 @@ pub use self::type_checker::*;
 pub mod utils;
 pub use self::utils::*;
 
+/// The identifier of errors which do not originate from Leo itself, e.g. errors raised by snarkVM.
+const EXTERNAL_ERROR_CODE: &str = "EEXT0370000";
+
+/// The exit code of errors which do not originate from Leo itself.
+const EXTERNAL_EXIT_CODE: i32 = 1;
+
 /// The LeoError type that contains all sub error types.
 /// This allows a unified error type throughout the Leo crates.
 #[derive(Debug, Error)]
@@ -66,6 +80,9 @@ pub enum LeoError {
     /// Represents an Compiler Error in a Leo Error.
     #[error(transparent)]
     CompilerError(#[from] CompilerError),
+    /// Represents an Input Error in a Leo Error.
+    #[error(transparent)]
+    InputError(#[from] InputError),
     /// Represents an Package Error in a Leo Error.
     #[error(transparent)]
     PackageError(#[from] PackageError),
@@ -102,6 +119,7 @@ impl LeoError {
             AstError(error) => error.error_code(),
             CompilerError(error) => error.error_code(),
             CliError(error) => error.error_code(),
+            InputError(error) => error.error_code(),
             ParserError(error) => error.error_code(),
             PackageError(error) => error.error_code(),
             TypeCheckerError(error) => error.error_code(),
@@ -109,7 +127,7 @@ impl LeoError {
             FlattenError(error) => error.error_code(),
             UtilError(error) => error.error_code(),
             LastErrorCode(_) => unreachable!(),
-            Anyhow(_) => unimplemented!(), // todo: implement error codes for snarkvm errors.
+            Anyhow(_) => EXTERNAL_ERROR_CODE.to_string(),
         }
     }
 
@@ -121,6 +139,7 @@ impl LeoError {
             AstError(error) => error.exit_code(),
             CompilerError(error) => error.exit_code(),
             CliError(error) => error.exit_code(),
+            InputError(error) => error.exit_code(),
             ParserError(error) => error.exit_code(),
             PackageError(error) => error.exit_code(),
             TypeCheckerError(error) => error.exit_code(),
@@ -128,7 +147,33 @@ impl LeoError {
             FlattenError(error) => error.exit_code(),
             UtilError(error) => error.exit_code(),
             LastErrorCode(code) => *code,
-            Anyhow(_) => unimplemented!(), // todo: implement exit codes for snarkvm errors.
+            Anyhow(_) => EXTERNAL_EXIT_CODE,
+        }
+    }
+
+    /// Returns the structured form of the error.
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.to_diagnostic(),
+            CompilerError(error) => error.to_diagnostic(),
+            CliError(error) => error.to_diagnostic(),
+            InputError(error) => error.to_diagnostic(),
+            ParserError(error) => error.to_diagnostic(),
+            PackageError(error) => error.to_diagnostic(),
+            TypeCheckerError(error) => error.to_diagnostic(),
+            LoopUnrollerError(error) => error.to_diagnostic(),
+            FlattenError(error) => error.to_diagnostic(),
+            UtilError(error) => error.to_diagnostic(),
+            LastErrorCode(_) => unreachable!(),
+            Anyhow(error) => Diagnostic {
+                code: EXTERNAL_ERROR_CODE.to_string(),
+                severity: Severity::Error,
+                message: error.to_string(),
+                span: None,
+                notes: Vec::new(),
+            },
         }
     }
 }
@@ -137,9 +182,18 @@ impl LeoError {
 /// This allows a unified error type throughout the Leo crates.
 #[derive(Debug, Error)]
 pub enum LeoWarning {
+    /// Represents a Compiler Warning in a Leo Warning.
+    #[error(transparent)]
+    CompilerWarning(#[from] CompilerWarning),
+    /// Represents a Linter Warning in a Leo Warning.
+    #[error(transparent)]
+    LinterWarning(#[from] LinterWarning),
     /// Represents an Parser Error in a Leo Error.
     #[error(transparent)]
     ParserWarning(#[from] ParserWarning),
+    /// Represents a Type Checker Warning in a Leo Warning.
+    #[error(transparent)]
+    TypeCheckerWarning(#[from] TypeCheckerWarning),
 }
 
 impl LeoWarning {
@@ -148,7 +202,22 @@ impl LeoWarning {
         use LeoWarning::*;
 
         match self {
+            CompilerWarning(warning) => warning.warning_code(),
+            LinterWarning(warning) => warning.warning_code(),
             ParserWarning(warning) => warning.warning_code(),
+            TypeCheckerWarning(warning) => warning.warning_code(),
+        }
+    }
+
+    /// Returns the structured form of the warning.
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        use LeoWarning::*;
+
+        match self {
+            CompilerWarning(warning) => warning.to_diagnostic(),
+            LinterWarning(warning) => warning.to_diagnostic(),
+            ParserWarning(warning) => warning.to_diagnostic(),
+            TypeCheckerWarning(warning) => warning.to_diagnostic(),
         }
     }
 }
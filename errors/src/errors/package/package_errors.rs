@@ -348,4 +348,18 @@ create_messages!(
         msg: format!("Failed to update `program.json` from the provided file path {path} - {error}"),
         help: None,
     }
+
+    @backtraced
+    missing_main_file {
+        args: (),
+        msg: "The `src/` directory must contain a `main.leo` file.".to_string(),
+        help: Some("The other `.leo` files in the `src/` directory are modules, which `main.leo` can import items from.".to_string()),
+    }
+
+    @backtraced
+    missing_private_key {
+        args: (path: impl Display),
+        msg: format!("The environment file `{path}` does not hold a valid `PRIVATE_KEY`."),
+        help: Some("Write a new private key to the `.env` file with `leo account new --write`.".to_string()),
+    }
 );
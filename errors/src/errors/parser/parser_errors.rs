@@ -335,4 +335,46 @@ create_messages!(
         msg: format!("Only external calls to `.aleo` programs are supported."),
         help: None,
     }
+
+    @formatted
+    whole_number_too_large {
+        args: (value: impl Display),
+        msg: format!("The number `{value}` is too large to be used as a length or an index."),
+        help: None,
+    }
+
+    @formatted
+    invalid_loop_adapter {
+        args: (name: impl Display),
+        msg: format!("`{name}` is not a valid loop adapter."),
+        help: Some("A parenthesized range may be followed by `.step(..)` and then `.rev()`, e.g. `(0u8..8u8).step(2u8).rev()`.".to_string()),
+    }
+
+    @formatted
+    expression_too_deep {
+        args: (limit: impl Display),
+        msg: format!("Expressions cannot be nested more than {limit} levels deep."),
+        help: Some("Split the expression by assigning its inner parts to variables.".to_string()),
+    }
+
+    @formatted
+    invalid_cfg_annotation {
+        args: (),
+        msg: "A `@cfg` annotation must name a single feature.",
+        help: Some("Write the annotation as `@cfg(feature = \"name\")`.".to_string()),
+    }
+
+    @formatted
+    invalid_statement_annotation {
+        args: (annotation: impl Display),
+        msg: format!("The annotation `{annotation}` cannot be applied to a statement."),
+        help: Some("Statements can only be annotated with `@cfg(feature = \"name\")`.".to_string()),
+    }
+
+    @backtraced
+    lexer_invalid_bit_string {
+        args: (input: impl Display),
+        msg: format!("The bit string `{input}` is invalid."),
+        help: Some("A bit string consists of `0b` followed by at least one `0` or `1`, e.g. `0b1011`.".to_string()),
+    }
 );
@@ -17,3 +17,7 @@
 /// This module contains the Input error definitions.
 pub mod type_checker_error;
 pub use self::type_checker_error::*;
+
+/// This module contains the type checker warning definitions.
+pub mod type_checker_warning;
+pub use self::type_checker_warning::*;
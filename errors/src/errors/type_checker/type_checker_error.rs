@@ -36,21 +36,21 @@ create_messages!(
     /// For when the user tries to assign to a const input.
     @formatted
     cannot_assign_to_const_input {
-        args: (input: impl Display),
+        args: (input: impl Display, binding: impl Display),
         msg: format!(
             "Cannot assign to const input `{input}`",
         ),
-        help: None,
+        help: Some(format!("`{input}` is declared as a constant input at {binding}.")),
     }
 
     /// For when the user tries to assign to a const input.
     @formatted
     cannot_assign_to_const_var {
-        args: (var: impl Display),
+        args: (var: impl Display, binding: impl Display),
         msg: format!(
             "Cannot assign to const variable `{var}`",
         ),
-        help: None,
+        help: Some(format!("`{var}` is declared as a constant at {binding}.")),
     }
 
     /// For when the user tries to assign to a const input.
@@ -106,11 +106,11 @@ create_messages!(
     /// For when an integer is not in a valid range.
     @formatted
     invalid_int_value {
-        args: (value: impl Display, type_: impl Display),
+        args: (value: impl Display, type_: impl Display, min: impl Display, max: impl Display),
         msg: format!(
             "The value {value} is not a valid `{type_}`",
         ),
-        help: None,
+        help: Some(format!("Values of type `{type_}` range from {min} to {max}.")),
     }
 
     /// For when an invalid core function is used.
@@ -515,7 +515,7 @@ create_messages!(
     @formatted
     lhs_tuple_element_must_be_an_identifier {
         args: (),
-        msg: format!("Tuples on the left-hand side of a `DefinitionStatement` can only contain identifiers."),
+        msg: format!("Tuples on the left-hand side of a `DefinitionStatement` or an `AssignStatement` can only contain identifiers."),
         help: None,
     }
 
@@ -747,4 +747,116 @@ create_messages!(
         msg: format!("`stub` name `{stub_name}` does not match program name `{program_name}`"),
         help: Some("Check that the name you used as a dependency in program.json matches the name you used to import the program in the main leo file.".to_string()),
     }
+
+    @formatted
+    test_must_be_transition {
+        args: (name: impl Display),
+        msg: format!("The test `{name}` is not a transition."),
+        help: Some("Only transitions can be annotated with `@test`.".to_string()),
+    }
+
+    @formatted
+    recursive_function_must_be_inline {
+        args: (name: impl Display),
+        msg: format!("The recursive function `{name}` is not an inline function."),
+        help: Some("Only inline functions can be annotated with `@recursive`, since their recursion is inlined.".to_string()),
+    }
+
+    @formatted
+    invalid_recursion_depth {
+        args: (max: impl Display),
+        msg: format!("A `@recursive` annotation must declare a maximum depth between 1 and {max}."),
+        help: Some("Declare the maximum depth of the recursion, e.g. `@recursive(8)`.".to_string()),
+    }
+
+    @formatted
+    recursive_function_without_default {
+        args: (name: impl Display, type_: impl Display),
+        msg: format!("The recursive function `{name}` returns `{type_}`, which has no default value."),
+        help: Some("A recursive function must return booleans, numbers, or structs, tuples, or arrays of them, since calls beyond its maximum depth are replaced by a default value.".to_string()),
+    }
+
+    @formatted
+    inline_must_be_function {
+        args: (name: impl Display),
+        msg: format!("The function `{name}` annotated with `@inline` is not a standard function."),
+        help: Some("Only functions declared with `function` can be annotated with `@inline`.".to_string()),
+    }
+
+    @formatted
+    macro_conflicts_with_function {
+        args: (name: impl Display),
+        msg: format!("The macro `{name}` has the same name as a function."),
+        help: Some("Macros are called like functions, so they must have different names.".to_string()),
+    }
+
+    @formatted
+    duplicate_macro {
+        args: (name: impl Display),
+        msg: format!("The macro `{name}` is defined more than once."),
+        help: None,
+    }
+
+    @formatted
+    duplicate_macro_parameter {
+        args: (name: impl Display, parameter: impl Display),
+        msg: format!("The macro `{name}` has more than one parameter named `{parameter}`."),
+        help: None,
+    }
+
+    @formatted
+    unbound_macro_identifier {
+        args: (name: impl Display, identifier: impl Display),
+        msg: format!("The identifier `{identifier}` in the body of the macro `{name}` is neither a parameter of the macro nor a constant of the program."),
+        help: Some("The body of a macro cannot refer to the variables at its call site. Pass them to the macro as arguments instead.".to_string()),
+    }
+
+    @formatted
+    macro_arity_mismatch {
+        args: (name: impl Display, expected: impl Display, received: impl Display),
+        msg: format!("The macro `{name}` expects {expected} arguments, but {received} were given."),
+        help: None,
+    }
+
+    @formatted
+    recursive_macro {
+        args: (name: impl Display),
+        msg: format!("The macro `{name}` expands to a call to itself."),
+        help: Some("Macros are expanded before type checking, so they cannot be recursive. Consider an inline function annotated with `@recursive` instead.".to_string()),
+    }
+
+    @formatted
+    const_assert_failed {
+        args: (expression: impl Display),
+        msg: format!("The constant assertion `{expression}` does not hold."),
+        help: None,
+    }
+
+    @formatted
+    recursive_struct {
+        args: (name: impl Display, members: impl Display),
+        msg: format!("The struct `{name}` contains itself through the members {members}."),
+        help: Some("A struct cannot contain itself, directly or through other structs and arrays, since its values would be infinitely large.".to_string()),
+    }
+
+    @formatted
+    cannot_assign_to_reference_input {
+        args: (input: impl Display, binding: impl Display),
+        msg: format!("Cannot assign to reference input `{input}`"),
+        help: Some(format!("`{input}` is declared as a reference input at {binding}, which aliases the argument of the caller. Consider taking the input by value instead.")),
+    }
+
+    @formatted
+    reference_input_must_be_inline {
+        args: (),
+        msg: format!("Only inline functions can take inputs by reference."),
+        help: Some("The inputs of other functions are copied into each call. Consider removing the `&` or using the keyword `inline` instead.".to_string()),
+    }
+
+    @formatted
+    invalid_bit_string_cast {
+        args: (from: impl Display, to: impl Display),
+        msg: format!("Cannot cast `{from}` to `{to}`."),
+        help: Some("A `[bool; N]` can be cast to an unsigned integer type with at least `N` bits, and an unsigned integer type with `N` bits to a `[bool; N]`.".to_string()),
+    }
 );
@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// TypeCheckerWarning enum that represents all the warnings for the `leo-passes` crate.
+    TypeCheckerWarning,
+    code_mask: 2000i32,
+    code_prefix: "TYC",
+
+    /// For when a branch of a conditional statement can never be taken.
+    @formatted
+    unreachable_branch {
+        args: (value: impl Display, branch: impl Display),
+        msg: format!("The condition is always `{value}`, so the {branch} branch is unreachable."),
+        help: Some("Remove the unreachable branch, or the conditional statement.".to_string()),
+    }
+
+    @formatted
+    deprecated {
+        args: (kind: impl Display, name: impl Display),
+        msg: format!("The {kind} `{name}` is deprecated."),
+        help: Some("Its declaration is annotated with `@deprecated`.".to_string()),
+    }
+);
@@ -144,4 +144,18 @@ create_messages!(
         msg: format!("Failed to retrieve from endpoint `{endpoint}`. Error: {error}"),
         help: None,
     }
+
+    @formatted
+    checksum_mismatch_error {
+        args: (dependency: impl Display, locked: impl Display, found: impl Display),
+        msg: format!("The checksum of dependency `{dependency}` is `{found}`, but `leo.lock` records `{locked}`"),
+        help: Some("If the dependency was updated deliberately, remove its entry from `leo.lock` and build again".to_string()),
+    }
+
+    @formatted
+    pinned_checksum_mismatch_error {
+        args: (dependency: impl Display, pinned: impl Display, found: impl Display, cached_path: impl Display),
+        msg: format!("The checksum of dependency `{dependency}` is `{found}`, but `program.json` pins it to `{pinned}`"),
+        help: Some(format!("Update the checksum in `program.json`, or remove the cached program at `{cached_path}` if it is corrupted")),
+    }
 );
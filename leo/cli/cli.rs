@@ -53,6 +53,11 @@ enum Commands {
         #[clap(subcommand)]
         command: Account,
     },
+    #[clap(about = "Create an input file for a function of the current package")]
+    Input {
+        #[clap(subcommand)]
+        command: InputFile,
+    },
     #[clap(about = "Create a new Leo package in a new directory")]
     New {
         #[clap(flatten)]
@@ -68,11 +73,46 @@ enum Commands {
         #[clap(flatten)]
         command: Build,
     },
+    #[clap(about = "Parse and type check the current package without generating instructions")]
+    Check {
+        #[clap(flatten)]
+        command: Check,
+    },
+    #[clap(about = "Type check the current package and check it against the lint rules")]
+    Lint {
+        #[clap(flatten)]
+        command: Lint,
+    },
+    #[clap(about = "Print the expressions that changed between two versions of a program")]
+    Diff {
+        #[clap(flatten)]
+        command: Diff,
+    },
+    #[clap(about = "Render the API documentation of the current package from its doc comments")]
+    Doc {
+        #[clap(flatten)]
+        command: Doc,
+    },
+    #[clap(about = "Format the source files of the current package")]
+    Fmt {
+        #[clap(flatten)]
+        command: Fmt,
+    },
     #[clap(about = "Clean the output directory")]
     Clean {
         #[clap(flatten)]
         command: Clean,
     },
+    #[clap(about = "Inspect the artifacts cached by builds of the current package, or by all packages")]
+    Cache {
+        #[clap(subcommand)]
+        command: Cache,
+    },
+    #[clap(about = "Evaluate expressions and definitions of variables interactively")]
+    Repl {
+        #[clap(flatten)]
+        command: Repl,
+    },
     #[clap(about = "Run a program with input variables")]
     Run {
         #[clap(flatten)]
@@ -83,6 +123,21 @@ enum Commands {
         #[clap(flatten)]
         command: Execute,
     },
+    #[clap(about = "Run the transitions of the current package annotated with `@test`")]
+    Test {
+        #[clap(flatten)]
+        command: Test,
+    },
+    #[clap(about = "Measure the time of each stage of compiling, proving, and verifying the current package")]
+    Bench {
+        #[clap(flatten)]
+        command: Bench,
+    },
+    #[clap(about = "Type check the current package again whenever its files change")]
+    Watch {
+        #[clap(flatten)]
+        command: Watch,
+    },
     #[clap(about = "Update the Leo CLI")]
     Update {
         #[clap(flatten)]
@@ -117,6 +172,7 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
     match cli.command {
         Commands::Add { command } => command.try_execute(context),
         Commands::Account { command } => command.try_execute(context),
+        Commands::Input { command } => command.try_execute(context),
         Commands::New { command } => command.try_execute(context),
         Commands::Build { command } => {
             // Enter tracing span
@@ -134,11 +190,21 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
 
             command.try_execute(context)
         }
+        Commands::Check { command } => command.try_execute(context),
         Commands::Clean { command } => command.try_execute(context),
+        Commands::Cache { command } => command.try_execute(context),
+        Commands::Diff { command } => command.try_execute(context),
+        Commands::Doc { command } => command.try_execute(context),
+        Commands::Fmt { command } => command.try_execute(context),
+        Commands::Lint { command } => command.try_execute(context),
         Commands::Example { command } => command.try_execute(context),
+        Commands::Repl { command } => command.try_execute(context),
         Commands::Run { command } => command.try_execute(context),
         Commands::Execute { command } => command.try_execute(context),
+        Commands::Test { command } => command.try_execute(context),
+        Commands::Bench { command } => command.try_execute(context),
         Commands::Update { command } => command.try_execute(context),
+        Commands::Watch { command } => command.try_execute(context),
     }
 }
 #[cfg(test)]
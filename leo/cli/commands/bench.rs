@@ -0,0 +1,292 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::cli::helpers::inputs::{read_input_sets_from_file, INPUT_FILE_EXTENSION};
+
+use leo_compiler::Compiler;
+use leo_errors::UtilError;
+use leo_package::{
+    inputs::INPUTS_DIRECTORY_NAME,
+    outputs::{OutputsDirectory, MAIN_ALEO_FILE_NAME},
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_span::Symbol;
+use retriever::Retriever;
+
+use snarkvm::{
+    circuit::AleoV0,
+    prelude::{PrivateKey, Program},
+};
+
+use indexmap::IndexMap;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use std::{
+    path::PathBuf,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+/// The mean time of each stage, in milliseconds, by the program or the function it was measured for.
+type Times = IndexMap<String, IndexMap<String, f64>>;
+
+/// Measures the time of each stage of compiling, proving, and verifying the program, and compares them to a baseline.
+///
+/// Parsing and the conversion into Aleo instructions are timed for the program, and the synthesis of the circuit, the
+/// setup of the keys, proving, and verifying are timed for each function. A function that takes inputs is run on the
+/// first set of inputs in `inputs/<function>.in`, and only its setup is timed if there is no such file.
+#[derive(Parser, Debug)]
+pub struct Bench {
+    #[clap(name = "NAME", help = "Only benchmark the functions whose names contain NAME.")]
+    pub(crate) name: Option<String>,
+    #[clap(short = 'n', long, help = "The number of times to run each stage.", default_value = "3")]
+    pub(crate) iterations: usize,
+    #[clap(long, help = "The file of the baseline to compare against.", default_value = "bench.json")]
+    pub(crate) baseline: PathBuf,
+    #[clap(long, help = "Saves the measured times as the new baseline.")]
+    pub(crate) save_baseline: bool,
+    #[clap(
+        long,
+        help = "The network endpoint to query the state from while proving.",
+        default_value = "https://api.explorer.aleo.org/v1"
+    )]
+    pub(crate) endpoint: String,
+    #[clap(flatten)]
+    pub(crate) options: BuildOptions,
+}
+
+impl Command for Bench {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        (Build { options: self.options.clone(), check_only: false, lints: None }).execute(context)
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+        let iterations = self.iterations.max(1);
+        let mut times = Times::new();
+
+        let manifest = context.open_manifest()?;
+        let program_id = manifest.program_id();
+        let program_name = program_id.name().to_string();
+
+        // The program is compiled with the stubs of its dependencies, which `leo build` has retrieved.
+        let main_sym = Symbol::intern(&program_name);
+        let mut retriever = Retriever::new(main_sym, &package_path, &context.home()?)
+            .map_err(|err| UtilError::failed_to_retrieve_dependencies(err, Default::default()))?;
+        retriever.retrieve().map_err(|err| UtilError::failed_to_retrieve_dependencies(err, Default::default()))?;
+        let (_, stubs) = retriever.prepare_local(main_sym)?;
+
+        let handler = Handler::default();
+        let outputs = OutputsDirectory::create(&package_path)?;
+        let main_file_path = package_path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+        let (mut parse, mut conversion) = (Vec::new(), Vec::new());
+        for _ in 0..iterations {
+            let mut compiler = Compiler::new(
+                program_name.clone(),
+                program_id.network().to_string(),
+                &handler,
+                main_file_path.clone(),
+                outputs.clone(),
+                Some(self.options.clone().into()),
+                stubs.clone(),
+            );
+            parse.push(time(|| compiler.parse_program())?.1);
+            conversion.push(time(|| compiler.compile_parsed_program())?.1);
+        }
+        times.insert(program_id.to_string(), IndexMap::from([mean("parse", &parse), mean("conversion", &conversion)]));
+
+        let build_directory = BuildDirectory::open(&package_path)?;
+        let bytecode_path = build_directory.join(MAIN_ALEO_FILE_NAME);
+        let bytecode = std::fs::read_to_string(&bytecode_path)
+            .map_err(|err| PackageError::failed_to_read_file(bytecode_path.display(), err))?;
+        let program = Program::<CurrentNetwork>::from_str(&bytecode).map_err(CliError::failed_to_execute_build)?;
+
+        // The functions are run by a process that loads the programs that the program imports before it.
+        let imports = BuildDirectory::imported_programs(&package_path)?;
+        let rng = &mut ChaChaRng::from_entropy();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).map_err(CliError::failed_to_sample_account)?;
+        for (function, definition) in program.functions() {
+            let function = function.to_string();
+            if !self.name.as_ref().map_or(true, |filter| function.contains(filter.as_str())) {
+                continue;
+            }
+
+            let input_path =
+                package_path.join(INPUTS_DIRECTORY_NAME).join(format!("{function}.{INPUT_FILE_EXTENSION}"));
+            let inputs = match input_path.exists() {
+                true => read_input_sets_from_file(&package_path, &input_path, &function)?
+                    .into_iter()
+                    .next()
+                    .map(|input_set| input_set.values),
+                false => definition.inputs().is_empty().then(Vec::new),
+            };
+
+            let mut setup = Vec::new();
+            let mut keys = None;
+            for _ in 0..iterations {
+                let (function_keys, duration) =
+                    time(|| Compiler::setup::<AleoV0, _>(&bytecode, &imports, &function, rng))?;
+                setup.push(duration);
+                keys = Some(function_keys);
+            }
+            // Note that this unwrap is safe, since there is at least one iteration.
+            let keys = keys.unwrap();
+
+            let inputs = match inputs {
+                Some(inputs) => inputs,
+                None => {
+                    tracing::info!("Only timed the setup of '{}', as it has no input file", function);
+                    times.insert(function, IndexMap::from([mean("setup", &setup)]));
+                    continue;
+                }
+            };
+
+            let (mut synthesis, mut prove, mut verify) = (Vec::new(), Vec::new(), Vec::new());
+            for _ in 0..iterations {
+                let witness =
+                    Compiler::witness::<AleoV0, _>(&bytecode, &imports, &private_key, &function, &inputs, rng)?;
+                synthesis
+                    .push(time(|| Compiler::synthesize::<AleoV0, _>(&bytecode, &imports, &keys, &witness, rng))?.1);
+                let (execution, duration) = time(|| {
+                    Compiler::prove_witness::<AleoV0, _>(&bytecode, &imports, &keys, &witness, &self.endpoint, rng)
+                })?;
+                prove.push(duration);
+                let public = Compiler::public_inputs(&execution);
+                let (_, duration) = time(|| {
+                    Compiler::verify(&bytecode, &imports, &keys.verifying_keys(), &function, &execution, &public)
+                })?;
+                verify.push(duration);
+            }
+            times.insert(
+                function,
+                IndexMap::from([
+                    mean("synthesis", &synthesis),
+                    mean("setup", &setup),
+                    mean("prove", &prove),
+                    mean("verify", &verify),
+                ]),
+            );
+        }
+
+        // Compare the times to the baseline, if there is one.
+        let baseline_path = package_path.join(&self.baseline);
+        let baseline = match baseline_path.exists() {
+            true => {
+                let content = std::fs::read_to_string(&baseline_path)
+                    .map_err(|err| PackageError::failed_to_read_file(baseline_path.display(), err))?;
+                serde_json::from_str::<Times>(&content)
+                    .map_err(|err| CliError::invalid_bench_baseline(baseline_path.display(), err))?
+            }
+            false => Times::new(),
+        };
+        print_table(&times, &baseline, iterations);
+
+        if self.save_baseline {
+            let content = serde_json::to_string_pretty(&times)
+                .map_err(|err| CliError::invalid_bench_baseline(baseline_path.display(), err))?;
+            std::fs::write(&baseline_path, content).map_err(CliError::failed_to_write_file)?;
+            tracing::info!("💾 Saved the baseline to '{}'", baseline_path.display());
+        }
+
+        Ok(())
+    }
+}
+
+/// Runs `stage`, and returns its result along with the time it took.
+fn time<T>(stage: impl FnOnce() -> Result<T>) -> Result<(T, Duration)> {
+    let start = Instant::now();
+    let result = stage()?;
+    Ok((result, start.elapsed()))
+}
+
+/// Returns the name of the `stage` along with the mean of its `durations`, in milliseconds.
+fn mean(stage: &str, durations: &[Duration]) -> (String, f64) {
+    let total = durations.iter().map(Duration::as_secs_f64).sum::<f64>();
+    (stage.to_string(), total * 1000.0 / durations.len().max(1) as f64)
+}
+
+/// Prints the mean time of each stage, along with its change from the baseline.
+fn print_table(times: &Times, baseline: &Times, iterations: usize) {
+    tracing::info!("⏱️  Mean times over {} iteration(s)", iterations);
+    tracing::info!("{:<24} {:<12} {:>14} {:>14} {:>10}", "Function", "Stage", "Time (ms)", "Baseline (ms)", "Change");
+    for (name, stages) in times {
+        for (stage, time) in stages {
+            let (previous, change) = change(*time, baseline.get(name).and_then(|stages| stages.get(stage)).copied());
+            tracing::info!("{:<24} {:<12} {:>14.2} {:>14} {:>10}", name, stage, time, previous, change);
+        }
+    }
+}
+
+/// Returns the `previous` time of a stage and the change of its `time` from it, as printed in the table.
+fn change(time: f64, previous: Option<f64>) -> (String, String) {
+    match previous {
+        Some(previous) if previous > 0.0 => {
+            (format!("{previous:.2}"), format!("{:+.1}%", (time - previous) * 100.0 / previous))
+        }
+        _ => ("-".to_string(), "-".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mean() {
+        let durations = [Duration::from_millis(10), Duration::from_millis(30)];
+        let (stage, time) = mean("setup", &durations);
+        assert_eq!(stage, "setup");
+        assert!((time - 20.0).abs() < 1e-9, "{time}");
+        // A stage that was not run has no time.
+        assert_eq!(mean("setup", &[]).1, 0.0);
+    }
+
+    #[test]
+    fn test_change() {
+        assert_eq!(change(15.0, Some(10.0)), ("10.00".to_string(), "+50.0%".to_string()));
+        assert_eq!(change(7.5, Some(10.0)), ("10.00".to_string(), "-25.0%".to_string()));
+        // A stage without a baseline, or with a baseline of no time, has no change.
+        assert_eq!(change(15.0, None), ("-".to_string(), "-".to_string()));
+        assert_eq!(change(15.0, Some(0.0)), ("-".to_string(), "-".to_string()));
+    }
+
+    #[test]
+    fn test_baseline() {
+        // The baseline is saved and read as JSON, in the order of the programs, functions, and stages.
+        let times = Times::from([
+            ("hello.aleo".to_string(), IndexMap::from([mean("parse", &[Duration::from_millis(2)])])),
+            (
+                "main".to_string(),
+                IndexMap::from([
+                    mean("synthesis", &[Duration::from_millis(4)]),
+                    mean("setup", &[Duration::from_millis(8)]),
+                ]),
+            ),
+        ]);
+        let content = serde_json::to_string_pretty(&times).unwrap();
+        let baseline = serde_json::from_str::<Times>(&content).unwrap();
+        assert_eq!(baseline, times);
+        assert_eq!(baseline["main"].keys().collect::<Vec<_>>(), ["synthesis", "setup"]);
+    }
+}
@@ -17,29 +17,68 @@
 use super::*;
 
 use leo_ast::Stub;
-use leo_compiler::{Compiler, CompilerOptions, OutputOptions};
+use leo_compiler::{
+    circuit_hashes,
+    Abi,
+    Compiler,
+    CompilerOptions,
+    LintConfig,
+    OutputOptions,
+    Profile,
+    SynthesisCache,
+    R1CS,
+};
 use leo_errors::UtilError;
 use leo_package::{build::BuildDirectory, outputs::OutputsDirectory, source::SourceDirectory};
 use leo_span::Symbol;
 use retriever::Retriever;
 
 use snarkvm::{
+    circuit::AleoV0,
     package::Package,
-    prelude::{ProgramID, Testnet3},
+    prelude::{Program, ProgramID, Testnet3},
 };
 
 use indexmap::IndexMap;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
 use std::{
     io::Write,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
 type CurrentNetwork = Testnet3;
 
+/// The extension of the constraint source maps that are written to the build directory, e.g. `main.map.json`.
+pub(crate) const CONSTRAINT_MAP_FILE_EXTENSION: &str = "map.json";
+
+/// The name of the file in the build directory that caches the constraint counts of the synthesized circuits.
+pub(crate) const SYNTHESIS_CACHE_FILE_NAME: &str = "synthesis.json";
+
+/// The name of the file in the build directory that holds the ABI of the program.
+pub(crate) const ABI_FILE_NAME: &str = "abi.json";
+
+/// The seed of the keys that are synthesized to check that a build is deterministic.
+const DETERMINISTIC_SEED: u64 = 0;
+
 impl From<BuildOptions> for CompilerOptions {
     fn from(options: BuildOptions) -> Self {
         let mut out_options = Self {
-            build: leo_compiler::BuildOptions { dce_enabled: options.enable_dce },
+            build: leo_compiler::BuildOptions {
+                dce_enabled: options.enable_dce,
+                canonicalization_enabled: options.enable_canonicalization,
+                cse_enabled: options.enable_cse,
+                branch_pruning_enabled: options.enable_branch_pruning,
+                early_return_guards_enabled: !options.disable_early_return_guards,
+                mux_lowering_enabled: options.enable_mux_lowering,
+                pow_lowering_enabled: options.enable_pow_lowering,
+                div_lowering_enabled: options.enable_div_lowering,
+                overflow_behavior: options.overflow,
+                max_array_elements: options.max_array_elements,
+                tests_enabled: options.enable_tests,
+                features: options.features,
+            },
             output: OutputOptions {
                 symbol_table_spans_enabled: options.enable_symbol_table_spans,
                 initial_symbol_table: options.enable_initial_symbol_table_snapshot,
@@ -53,6 +92,9 @@ impl From<BuildOptions> for CompilerOptions {
                 destructured_ast: options.enable_destructured_ast_snapshot,
                 inlined_ast: options.enable_inlined_ast_snapshot,
                 dce_ast: options.enable_dce_ast_snapshot,
+                unrolled_dot_graph: options.emit.contains(&EmitFormat::Dot),
+                ir: options.emit.contains(&EmitFormat::Ir),
+                stats: options.stats,
             },
         };
         if options.enable_all_ast_snapshots {
@@ -74,6 +116,12 @@ impl From<BuildOptions> for CompilerOptions {
 pub struct Build {
     #[clap(flatten)]
     pub(crate) options: BuildOptions,
+    /// Whether to only parse and type check the main program, without generating instructions for it.
+    #[clap(skip)]
+    pub(crate) check_only: bool,
+    /// The levels of the lints to check the main program against after type checking it, if it is only checked.
+    #[clap(skip)]
+    pub(crate) lints: Option<LintConfig>,
 }
 
 impl Command for Build {
@@ -89,6 +137,13 @@ impl Command for Build {
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        self.build(context, &Handler::default())
+    }
+}
+
+impl Build {
+    /// Builds the package, emitting the errors and warnings of the compiler to `handler`.
+    pub(crate) fn build(self, context: Context, handler: &Handler) -> Result<()> {
         // Get the package path.
         let package_path = context.dir()?;
         let home_path = context.home()?;
@@ -100,9 +155,6 @@ impl Command for Build {
         let manifest = context.open_manifest()?;
         let program_id = manifest.program_id();
 
-        // Initialize error handler
-        let handler = Handler::default();
-
         // Retrieve all local dependencies in post order
         let main_sym = Symbol::intern(&program_id.name().to_string());
         let mut retriever = Retriever::new(main_sym, &package_path, &home_path)
@@ -130,17 +182,19 @@ impl Command for Build {
             // Check the source files.
             SourceDirectory::check_files(&local_source_files)?;
 
-            // Compile all .leo files into .aleo files.
-            for file_path in local_source_files {
+            // Compile the main file into an .aleo file. The other .leo files are modules, which are compiled along with it.
+            for file_path in local_source_files.into_iter().filter(|path| SourceDirectory::is_main_file(path)) {
                 compile_leo_file(
                     file_path,
                     &ProgramID::<Testnet3>::try_from(format!("{}.aleo", dependency))
                         .map_err(|_| UtilError::snarkvm_error_building_program_id(Default::default()))?,
                     &local_outputs_directory,
                     &local_build_directory,
-                    &handler,
+                    handler,
                     self.options.clone(),
                     stubs.clone(),
+                    self.check_only && dependency == main_sym,
+                    self.lints.as_ref().filter(|_| dependency == main_sym),
                 )?;
             }
 
@@ -149,7 +203,9 @@ impl Command for Build {
         }
 
         // `Package::open` checks that the build directory and that `main.aleo` and all imported files are well-formed.
-        Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_build)?;
+        if !self.check_only {
+            Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_build)?;
+        }
 
         // // Unset the Leo panic hook.
         // let _ = std::panic::take_hook();
@@ -174,6 +230,7 @@ impl Command for Build {
 }
 
 /// Compiles a Leo file in the `src/` directory.
+/// If `check_only` is set, the file is only parsed and type checked, and then linted if `lints` is set.
 #[allow(clippy::too_many_arguments)]
 fn compile_leo_file(
     file_path: PathBuf,
@@ -183,6 +240,8 @@ fn compile_leo_file(
     handler: &Handler,
     options: BuildOptions,
     stubs: IndexMap<Symbol, Stub>,
+    check_only: bool,
+    lints: Option<&LintConfig>,
 ) -> Result<()> {
     // Construct the Leo file name with extension `foo.leo`.
     let file_name =
@@ -195,6 +254,14 @@ fn compile_leo_file(
     let mut aleo_file_path = build.to_path_buf();
     aleo_file_path.push(format!("main.{}", program_id.network()));
 
+    // Check whether expression metrics should be printed before the options are consumed.
+    let print_expression_metrics = options.enable_expression_metrics;
+    let print_stats = options.stats;
+    let deterministic = options.deterministic;
+    let flamegraph = options.emit.contains(&EmitFormat::Flamegraph);
+    let source_map = options.emit.contains(&EmitFormat::SourceMap);
+    let abi = options.emit.contains(&EmitFormat::Abi);
+
     // Create a new instance of the Leo compiler.
     let mut compiler = Compiler::new(
         program_name,
@@ -206,6 +273,25 @@ fn compile_leo_file(
         stubs,
     );
 
+    // Only parse and type check the program, if requested.
+    if check_only {
+        compiler.check()?;
+        tracing::info!("✅ Checked '{}'", file_name);
+        if print_stats {
+            tracing::info!("📊 Build statistics for '{}':\n{}", file_name, compiler.stats);
+        }
+        if let Some(lints) = lints {
+            match compiler.lint_pass(lints) {
+                0 => tracing::info!("✅ Linted '{}'", file_name),
+                denied => return Err(CliError::denied_lints(denied, file_name).into()),
+            }
+        }
+        return Ok(());
+    }
+
+    // Keep a copy of the compiler, to compile the program again if the build should be checked to be deterministic.
+    let copy = deterministic.then(|| compiler.clone());
+
     // Compile the Leo program into Aleo instructions.
     let instructions = compiler.compile()?;
 
@@ -216,5 +302,186 @@ fn compile_leo_file(
         .map_err(CliError::failed_to_load_instructions)?;
 
     tracing::info!("✅ Compiled '{}' into Aleo instructions", file_name);
+
+    // Write the ABI next to the instructions.
+    if abi {
+        if let Some(scope) = compiler.ast.ast.program_scopes.values().next() {
+            Abi::new(scope).to_json_file(&build.join(ABI_FILE_NAME))?;
+        }
+    }
+
+    if print_expression_metrics {
+        tracing::info!("📊 Expression metrics for '{}': {}", file_name, compiler.ast.ast.expression_metrics());
+    }
+
+    if let Some(mut copy) = copy {
+        check_deterministic_build(&mut copy, &instructions, file_name)?;
+    }
+
+    if flamegraph || source_map {
+        compiler.run_phase("synthesize", |compiler| {
+            write_constraint_maps(compiler, &instructions, outputs, build, file_name, flamegraph, source_map)
+        })?;
+    }
+
+    if print_stats {
+        tracing::info!("📊 Build statistics for '{}':\n{}", file_name, compiler.stats);
+    }
+
     Ok(())
 }
+
+/// Maps the constraints of each function of the program to their source. The source map of each function is written
+/// next to the instructions, to `build/<function>.map.json`, if `source_map` is set, and its profile is written to
+/// `outputs/<program>.<function>.folded`, in the folded stack format of flamegraph tools, if `flamegraph` is set.
+/// Only the functions that changed since the last build are synthesized again.
+fn write_constraint_maps(
+    compiler: &Compiler,
+    instructions: &str,
+    outputs: &Path,
+    build: &Path,
+    file_name: &str,
+    flamegraph: bool,
+    source_map: bool,
+) -> Result<()> {
+    // The circuits are synthesized by a process that only loads the program and `credits.aleo`.
+    let program = Program::<CurrentNetwork>::from_str(instructions).map_err(CliError::failed_to_execute_build)?;
+    if program.imports().keys().any(|program_id| program_id.to_string() != "credits.aleo") {
+        tracing::info!("Skipped the constraint maps of '{}', as it imports other programs", file_name);
+        return Ok(());
+    }
+
+    let cache_path = build.join(SYNTHESIS_CACHE_FILE_NAME);
+    let mut cache = SynthesisCache::from_json_file(&cache_path).unwrap_or_default();
+    cache.retain(&circuit_hashes(instructions));
+
+    let rng = &mut ChaChaRng::from_entropy();
+    for function in program.functions().keys() {
+        let function = function.to_string();
+        let spans = compiler.instruction_spans.get(&function).map_or(&[][..], Vec::as_slice);
+        let map = Compiler::constraint_map::<AleoV0, _>(instructions, &function, spans, &mut cache, rng)?;
+
+        if source_map {
+            let path = build.join(format!("{function}.{CONSTRAINT_MAP_FILE_EXTENSION}"));
+            map.to_json_file(&path)?;
+            tracing::info!(
+                "🗺️  Mapped the {} constraints of '{}' into '{}'",
+                map.num_constraints(),
+                function,
+                path.display()
+            );
+        }
+
+        if flamegraph {
+            let profile = Profile::from(&map);
+            let path = outputs.join(format!("{}.{function}.folded", compiler.program_name));
+            std::fs::write(&path, profile.to_folded()).map_err(CliError::failed_to_write_file)?;
+            tracing::info!(
+                "🔥 Profiled the {} constraints of '{}' into '{}'",
+                profile.num_constraints(),
+                function,
+                path.display()
+            );
+        }
+    }
+
+    cache.to_json_file(&cache_path)
+}
+
+/// Checks that compiling the program again produces identical Aleo instructions, and that synthesizing the keys of each
+/// of its functions twice from the same seed produces identical keys and constraint systems.
+/// The hashes of the constraint systems are printed, to compare builds on different machines.
+fn check_deterministic_build(compiler: &mut Compiler, instructions: &str, file_name: &str) -> Result<()> {
+    if compiler.compile()? != instructions {
+        return Err(CliError::nondeterministic_build(file_name).into());
+    }
+
+    // The keys are synthesized by a process that only loads the program and `credits.aleo`.
+    let program = Program::<CurrentNetwork>::from_str(instructions).map_err(CliError::failed_to_execute_build)?;
+    if program.imports().keys().any(|program_id| program_id.to_string() != "credits.aleo") {
+        tracing::info!("✅ Checked that '{}' compiles deterministically", file_name);
+        tracing::info!("Skipped the constraint systems of '{}', as it imports other programs", file_name);
+        return Ok(());
+    }
+
+    // The keys of the functions are synthesized in parallel, from generators seeded in the order of the functions.
+    let functions = program.functions().keys().map(|function| function.to_string()).collect::<Vec<_>>();
+    let synthesize = || -> Result<Vec<(String, String)>> {
+        let rng = &mut ChaChaRng::seed_from_u64(DETERMINISTIC_SEED);
+        let keys = Compiler::setup_all::<AleoV0, _>(instructions, &[], &functions, rng)?;
+        Ok(functions
+            .iter()
+            .zip(keys)
+            .map(|(function, keys)| {
+                let r1cs = R1CS::new(&program.id().to_string(), function, &keys);
+                (r1cs.checksum(), keys.verifying_key.to_string())
+            })
+            .collect())
+    };
+    let (first, second) = (synthesize()?, synthesize()?);
+    for ((function, (checksum, verifying_key)), again) in functions.iter().zip(first).zip(second) {
+        if again != (checksum.clone(), verifying_key) {
+            return Err(CliError::nondeterministic_keys(function).into());
+        }
+        tracing::info!("🔒 Constraint system of '{}': {}", function, checksum);
+    }
+
+    tracing::info!("✅ Checked that '{}' builds deterministically", file_name);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_compiler::AbiMode;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_emit_abi() {
+        let directory = tempfile::tempdir().unwrap();
+        let (outputs, build) = (directory.path().join("outputs"), directory.path().join("build"));
+        std::fs::create_dir_all(&outputs).unwrap();
+        std::fs::create_dir_all(&build).unwrap();
+        let file_path = directory.path().join("main.leo");
+        std::fs::write(
+            &file_path,
+            "program token.aleo {
+                record Token { owner: address, amount: u64 }
+                transition mint(public receiver: address, amount: u64) -> Token {
+                    return Token { owner: receiver, amount };
+                }
+            }",
+        )
+        .unwrap();
+
+        let compile = |emit| {
+            create_session_if_not_set_then(|_| {
+                compile_leo_file(
+                    file_path.clone(),
+                    &ProgramID::from_str("token.aleo").unwrap(),
+                    &outputs,
+                    &build,
+                    &Handler::default(),
+                    BuildOptions { emit, ..Default::default() },
+                    IndexMap::new(),
+                    false,
+                    None,
+                )
+                .unwrap()
+            })
+        };
+
+        // The ABI is only written if it is requested.
+        compile(vec![]);
+        assert!(!build.join(ABI_FILE_NAME).exists());
+
+        compile(vec![EmitFormat::Abi]);
+        let abi: Abi = serde_json::from_str(&std::fs::read_to_string(build.join(ABI_FILE_NAME)).unwrap()).unwrap();
+        assert_eq!(abi.program, "token.aleo");
+        assert_eq!(abi.records.len(), 1);
+        assert_eq!(abi.functions.len(), 1);
+        assert_eq!(abi.functions[0].name, "mint");
+        assert_eq!(abi.functions[0].inputs[0].mode, AbiMode::Public);
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::cli::commands::build::{ABI_FILE_NAME, CONSTRAINT_MAP_FILE_EXTENSION, SYNTHESIS_CACHE_FILE_NAME};
+use leo_package::{
+    build::BUILD_DIRECTORY_NAME,
+    outputs::{CHECKSUM_FILE_EXTENSION, OUTPUTS_DIRECTORY_NAME},
+};
+
+use std::path::{Path, PathBuf};
+
+/// The name of the directory in the Aleo home directory that caches the network dependencies of every package.
+pub(crate) const REGISTRY_DIRECTORY_NAME: &str = "registry";
+
+/// Commands to inspect the artifacts that builds cache.
+///
+/// The cache of a package is its `outputs/` directory, which holds the serialized ASTs and the checksums of the
+/// sources, and its `build/` directory, which holds the compiled instructions and the keys. The global cache is the
+/// registry in the Aleo home directory, e.g. `~/.aleo/registry`, which holds the network dependencies of all packages.
+#[derive(Parser, Debug)]
+pub enum Cache {
+    /// Lists the cached files, with their kinds and sizes.
+    Ls {
+        /// List the global cache instead of the cache of the package.
+        #[clap(short, long)]
+        global: bool,
+    },
+    /// Prints the size of each cache directory.
+    Size {
+        /// Print the size of the global cache instead of the cache of the package.
+        #[clap(short, long)]
+        global: bool,
+    },
+}
+
+impl Command for Cache {
+    type Input = ();
+    type Output = ();
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let (list, global) = match self {
+            Cache::Ls { global } => (true, global),
+            Cache::Size { global } => (false, global),
+        };
+        let directories = match global {
+            true => vec![context.home()?.join(REGISTRY_DIRECTORY_NAME)],
+            false => {
+                let path = context.dir()?;
+                vec![path.join(OUTPUTS_DIRECTORY_NAME), path.join(BUILD_DIRECTORY_NAME)]
+            }
+        };
+
+        let mut total = 0;
+        for directory in directories {
+            let files = cached_files(&directory);
+            let size = files.iter().map(|(_, size)| size).sum::<u64>();
+            total += size;
+
+            if list {
+                for (path, size) in &files {
+                    let name = path.strip_prefix(&directory).unwrap_or(path);
+                    tracing::info!("{:<14} {:>10}  {}", artifact_kind(path), format_size(*size), name.display());
+                }
+            }
+            tracing::info!("📦 {} files, {} in {}", files.len(), format_size(size), directory.display());
+        }
+        tracing::info!("📦 {} in total", format_size(total));
+
+        Ok(())
+    }
+}
+
+/// Returns each file under `directory` with its size in bytes, in the order of their paths.
+/// A directory that does not exist holds no files, and entries that cannot be read are skipped.
+fn cached_files(directory: &Path) -> Vec<(PathBuf, u64)> {
+    let mut files = Vec::new();
+    let mut unexplored = vec![directory.to_path_buf()];
+    while let Some(directory) = unexplored.pop() {
+        for entry in std::fs::read_dir(&directory).into_iter().flatten().flatten() {
+            match entry.metadata() {
+                Ok(metadata) if metadata.is_dir() => unexplored.push(entry.path()),
+                Ok(metadata) => files.push((entry.path(), metadata.len())),
+                Err(_) => {}
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Returns the kind of the artifact at `path`, from its name.
+fn artifact_kind(path: &Path) -> &'static str {
+    let name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+    if name.contains(".prover") || name.contains(".verifier") {
+        "key"
+    } else if name.ends_with(CONSTRAINT_MAP_FILE_EXTENSION) {
+        "source map"
+    } else if name == SYNTHESIS_CACHE_FILE_NAME {
+        "constraint counts"
+    } else if name == ABI_FILE_NAME {
+        "ABI"
+    } else if name.ends_with(".aleo") {
+        "instructions"
+    } else if name.contains("symbol_table") {
+        "symbol table"
+    } else if name.contains("ast") && name.ends_with(".json") {
+        "AST"
+    } else if name.ends_with(CHECKSUM_FILE_EXTENSION) {
+        "checksum"
+    } else if name.ends_with(".dot") {
+        "graph"
+    } else if name.ends_with(".folded") {
+        "profile"
+    } else {
+        "other"
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 MiB`.
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cached_files() {
+        let directory = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(directory.path().join("imports")).unwrap();
+        std::fs::write(directory.path().join("main.aleo"), "abc").unwrap();
+        std::fs::write(directory.path().join("imports").join("token.aleo"), "abcdef").unwrap();
+
+        let files = cached_files(directory.path());
+        assert_eq!(files, [
+            (directory.path().join("imports").join("token.aleo"), 6),
+            (directory.path().join("main.aleo"), 3)
+        ]);
+        // A directory that does not exist holds no files.
+        assert!(cached_files(&directory.path().join("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_clean_global() {
+        let package = tempfile::tempdir().unwrap();
+        let home = tempfile::tempdir().unwrap();
+        let registry_path = home.path().join(REGISTRY_DIRECTORY_NAME);
+        std::fs::create_dir_all(package.path().join(BUILD_DIRECTORY_NAME)).unwrap();
+        std::fs::create_dir_all(registry_path.join("testnet3")).unwrap();
+        let context = || Context::new(Some(package.path().to_path_buf()), Some(home.path().to_path_buf())).unwrap();
+
+        // The global cache is only cleaned when it is asked for.
+        Clean { global: false }.apply(context(), ()).unwrap();
+        assert!(!package.path().join(BUILD_DIRECTORY_NAME).exists());
+        assert!(registry_path.exists());
+
+        Clean { global: true }.apply(context(), ()).unwrap();
+        assert!(!registry_path.exists());
+        // Cleaning again, when there is nothing to clean, succeeds.
+        Clean { global: true }.apply(context(), ()).unwrap();
+    }
+
+    #[test]
+    fn test_artifact_kind() {
+        assert_eq!(artifact_kind(Path::new("build/main.prover.a1b2")), "key");
+        assert_eq!(artifact_kind(Path::new("build/main.aleo")), "instructions");
+        assert_eq!(artifact_kind(Path::new(SYNTHESIS_CACHE_FILE_NAME)), "constraint counts");
+        assert_eq!(artifact_kind(Path::new(ABI_FILE_NAME)), "ABI");
+        assert_eq!(artifact_kind(Path::new("outputs/main.initial_ast.json")), "AST");
+        assert_eq!(artifact_kind(Path::new("outputs/main.initial_symbol_table.json")), "symbol table");
+        assert_eq!(artifact_kind(Path::new("outputs/main.folded")), "profile");
+        assert_eq!(artifact_kind(Path::new("README.md")), "other");
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MiB");
+        assert_eq!(format_size(5 * 1024u64.pow(5)), "5120.0 TiB");
+    }
+}
@@ -15,10 +15,14 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::cli::commands::cache::REGISTRY_DIRECTORY_NAME;
 
 /// Clean outputs folder command
 #[derive(Parser, Debug)]
-pub struct Clean {}
+pub struct Clean {
+    #[clap(long, help = "Also cleans the global cache of network dependencies, which every package shares.")]
+    pub(crate) global: bool,
+}
 
 impl Command for Clean {
     type Input = ();
@@ -43,6 +47,16 @@ impl Command for Clean {
         let build_path = BuildDirectory::remove(&path)?;
         tracing::info!("🧹 Cleaned the build directory {}", build_path.dimmed());
 
+        // Removes the registry of network dependencies, e.g. `~/.aleo/registry`.
+        if self.global {
+            let registry_path = context.home()?.join(REGISTRY_DIRECTORY_NAME);
+            if registry_path.exists() {
+                std::fs::remove_dir_all(&registry_path)
+                    .map_err(|err| PackageError::failed_to_remove_directory(registry_path.display(), err))?;
+            }
+            tracing::info!("🧹 Cleaned the global cache {}", format!("(in \"{}\")", registry_path.display()).dimmed());
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use leo_ast::{Ast, NodeBuilder, Program};
+use leo_span::{source_map::FileName, symbol::with_session_globals};
+
+use std::path::{Path, PathBuf};
+
+/// Compares two versions of a program, and prints the expressions that were added, removed, or changed.
+/// Each version is a Leo file, or an AST snapshot written by `leo build`, which must include spans.
+#[derive(Parser, Debug)]
+pub struct Diff {
+    #[clap(name = "OLD", help = "The old version, as a Leo file or an AST snapshot written with `--enable-ast-spans`")]
+    pub(crate) old: PathBuf,
+    #[clap(name = "NEW", help = "The new version, as a Leo file or an AST snapshot written with `--enable-ast-spans`")]
+    pub(crate) new: PathBuf,
+}
+
+impl Command for Diff {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let path = context.dir()?;
+        let old = read_program(&path.join(&self.old))?;
+        let new = read_program(&path.join(&self.new))?;
+
+        let changes = leo_ast::diff_programs(&old, &new);
+        for change in changes.iter() {
+            tracing::info!("{change}");
+        }
+        match changes.len() {
+            0 => tracing::info!("No expressions were added, removed, or changed"),
+            count => tracing::info!("{count} changes"),
+        }
+        Ok(())
+    }
+}
+
+/// Reads the program at `path`, which is a JSON snapshot of its AST, or else a Leo file that is parsed.
+fn read_program(path: &Path) -> Result<Program> {
+    if path.extension().map_or(false, |extension| extension == "json") {
+        return Ok(Ast::from_json_file(path.to_path_buf())?.into_repr());
+    }
+
+    let source = std::fs::read_to_string(path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+    let source_file = with_session_globals(|s| s.source_map.new_source(&source, FileName::Real(path.to_path_buf())));
+    leo_parser::parse(&Handler::default(), &NodeBuilder::default(), &source_file.src, source_file.start_pos)
+}
@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use leo_ast::{signature, Function, Mode, NodeBuilder, Program, Struct, Variant};
+use leo_package::{
+    outputs::OutputsDirectory,
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_span::{source_map::FileName, symbol::with_session_globals};
+
+use std::fmt::Write;
+
+/// Renders the API documentation of the current package, i.e. the signatures and doc comments of the structs,
+/// records, mappings, and functions of its program, to `outputs/<program>.md` or `outputs/<program>.html`.
+#[derive(Parser, Debug)]
+pub struct Doc {
+    #[clap(long, help = "Render the documentation as HTML instead of Markdown")]
+    pub(crate) html: bool,
+}
+
+impl Command for Doc {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let path = context.dir()?;
+        let file_path = path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+        let source = std::fs::read_to_string(&file_path)
+            .map_err(|err| PackageError::failed_to_read_file(file_path.display(), err))?;
+
+        let handler = Handler::default();
+        let source_file = with_session_globals(|s| s.source_map.new_source(&source, FileName::Real(file_path)));
+        let program = leo_parser::parse(&handler, &NodeBuilder::default(), &source_file.src, source_file.start_pos)?;
+
+        let outputs = OutputsDirectory::create(&path)?;
+        for doc in ProgramDoc::all(&program) {
+            let (text, extension) = match self.html {
+                true => (doc.to_html(), "html"),
+                false => (doc.to_markdown(), "md"),
+            };
+            let doc_path = outputs.join(format!("{}.{extension}", doc.program));
+            std::fs::write(&doc_path, text).map_err(CliError::failed_to_write_file)?;
+            tracing::info!("✅ Documented {} in {}", doc.program, doc_path.display());
+        }
+
+        Ok(())
+    }
+}
+
+/// The API documentation of a program scope.
+struct ProgramDoc {
+    /// The program ID, e.g. `token.aleo`.
+    program: String,
+    /// The sections of the documentation, each with a title and its items.
+    sections: Vec<(&'static str, Vec<ApiItem>)>,
+}
+
+/// A documented item, e.g. a struct or a function.
+struct ApiItem {
+    /// The signature of the item, e.g. `transition mint(public amount: u64) -> Token`.
+    signature: String,
+    /// The lines of the doc comments of the item.
+    docs: Vec<String>,
+    /// The members of a struct or record, as their names, types, and doc comments.
+    members: Vec<(String, String, Vec<String>)>,
+}
+
+impl ApiItem {
+    fn from_struct(struct_: &Struct) -> Self {
+        let keyword = if struct_.is_record { "record" } else { "struct" };
+        let members = struct_
+            .members
+            .iter()
+            .map(|member| {
+                let type_ = match member.mode {
+                    Mode::None => member.type_.to_string(),
+                    mode => format!("{mode} {}", member.type_),
+                };
+                (member.identifier.to_string(), type_, member.docs.clone())
+            })
+            .collect();
+        Self { signature: format!("{keyword} {}", struct_.identifier), docs: struct_.docs.clone(), members }
+    }
+
+    fn from_function(function: &Function) -> Self {
+        let keyword = match function.variant {
+            Variant::Inline => "inline",
+            Variant::Standard => "function",
+            Variant::Transition => "transition",
+        };
+        let signature = signature(&function.identifier.to_string(), &function.input, &function.output);
+        Self { signature: format!("{keyword} {signature}"), docs: function.docs.clone(), members: Vec::new() }
+    }
+}
+
+impl ProgramDoc {
+    /// Returns the documentation of each program scope of the program.
+    fn all(program: &Program) -> Vec<Self> {
+        program
+            .program_scopes
+            .values()
+            .map(|scope| {
+                let structs = |is_record: bool| {
+                    scope
+                        .structs
+                        .iter()
+                        .filter(|(_, struct_)| struct_.is_record == is_record)
+                        .map(|(_, struct_)| ApiItem::from_struct(struct_))
+                        .collect()
+                };
+                let functions = |variant: Variant| {
+                    scope
+                        .functions
+                        .iter()
+                        .filter(|(_, function)| function.variant == variant && !function.is_test())
+                        .map(|(_, function)| ApiItem::from_function(function))
+                        .collect()
+                };
+                let mappings = scope
+                    .mappings
+                    .iter()
+                    .map(|(_, mapping)| ApiItem {
+                        signature: mapping.to_string(),
+                        docs: Vec::new(),
+                        members: Vec::new(),
+                    })
+                    .collect();
+                let sections = vec![
+                    ("Transitions", functions(Variant::Transition)),
+                    ("Functions", functions(Variant::Standard)),
+                    ("Inline functions", functions(Variant::Inline)),
+                    ("Records", structs(true)),
+                    ("Structs", structs(false)),
+                    ("Mappings", mappings),
+                ];
+                Self {
+                    program: scope.program_id.to_string(),
+                    sections: sections.into_iter().filter(|(_, items)| !items.is_empty()).collect(),
+                }
+            })
+            .collect()
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut out = format!("# `{}`\n", self.program);
+        for (title, items) in self.sections.iter() {
+            write!(out, "\n## {title}\n").unwrap();
+            for item in items {
+                write!(out, "\n### `{}`\n", item.signature).unwrap();
+                if !item.docs.is_empty() {
+                    write!(out, "\n{}\n", item.docs.join("\n")).unwrap();
+                }
+                if !item.members.is_empty() {
+                    out.push_str("\n| Member | Type | Description |\n| --- | --- | --- |\n");
+                    for (name, type_, docs) in item.members.iter() {
+                        writeln!(out, "| `{name}` | `{type_}` | {} |", docs.join(" ").replace('|', "\\|")).unwrap();
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    fn to_html(&self) -> String {
+        let program = escape(&self.program);
+        let mut out = format!("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{program}</title>\n");
+        writeln!(out, "</head>\n<body>\n<h1><code>{program}</code></h1>").unwrap();
+        for (title, items) in self.sections.iter() {
+            writeln!(out, "<h2>{title}</h2>").unwrap();
+            for item in items {
+                writeln!(out, "<h3><code>{}</code></h3>", escape(&item.signature)).unwrap();
+                if !item.docs.is_empty() {
+                    writeln!(out, "<p>{}</p>", escape(&item.docs.join("\n"))).unwrap();
+                }
+                if !item.members.is_empty() {
+                    out.push_str("<table>\n<tr><th>Member</th><th>Type</th><th>Description</th></tr>\n");
+                    for (name, type_, docs) in item.members.iter() {
+                        writeln!(
+                            out,
+                            "<tr><td><code>{}</code></td><td><code>{}</code></td><td>{}</td></tr>",
+                            escape(name),
+                            escape(type_),
+                            escape(&docs.join(" "))
+                        )
+                        .unwrap();
+                    }
+                    out.push_str("</table>\n");
+                }
+            }
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+}
+
+/// Escapes the characters of `text` that are special in HTML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
@@ -16,7 +16,15 @@
 
 use super::*;
 
-use snarkvm::{cli::Execute as SnarkVMExecute, prelude::Parser as SnarkVMParser};
+use crate::cli::helpers::inputs::{read_input_sets_from_file, InputSet};
+
+use leo_compiler::Compiler;
+use leo_package::{outputs::MAIN_ALEO_FILE_NAME, root::Env};
+
+use snarkvm::{circuit::AleoV0, cli::Execute as SnarkVMExecute};
+
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
 
 /// Build, Prove and Run Leo program with inputs
 #[derive(Parser, Debug)]
@@ -35,7 +43,11 @@ pub struct Execute {
     )]
     endpoint: String,
 
-    #[arg(short, long, help = "The inputs to the program, from a file. Overrides the INPUTS argument.")]
+    #[arg(
+        short,
+        long,
+        help = "The sets of inputs to the program, from a file or a directory of files. Overrides the INPUTS argument."
+    )]
     file: Option<String>,
 
     #[clap(flatten)]
@@ -51,49 +63,29 @@ impl Command for Execute {
     }
 
     fn prelude(&self, context: Context) -> Result<Self::Input> {
-        (Build { options: self.compiler_options.clone() }).execute(context)
+        (Build { options: self.compiler_options.clone(), check_only: false, lints: None }).execute(context)
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
-        let mut inputs = self.inputs;
-
-        // Compose the `execute` command.
-        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
-
-        // Add the inputs to the arguments.
-        match self.file {
+        // Read the sets of inputs, each of which is executed in turn.
+        let input_sets = match self.file {
             Some(file) => {
-                // Get the contents from the file.
+                // Read the inputs from the file, or from each file in the directory.
                 let path = context.dir()?.join(file);
-                let raw_content = std::fs::read_to_string(&path)
-                    .map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
-                // Parse the values from the file.
-                let mut content = raw_content.as_str();
-                let mut values = vec![];
-                while let Ok((remaining, value)) = snarkvm::prelude::Value::<CurrentNetwork>::parse(content) {
-                    content = remaining;
-                    values.push(value);
-                }
-                // Check that the remaining content is empty.
-                if !content.trim().is_empty() {
-                    return Err(PackageError::failed_to_read_input_file(path.display()).into());
-                }
-                // Convert the values to strings.
-                let mut inputs_from_file = values.into_iter().map(|value| value.to_string()).collect::<Vec<String>>();
-                // Add the inputs from the file to the arguments.
-                arguments.append(&mut inputs_from_file);
+                read_input_sets_from_file(&context.dir()?, &path, &self.name)?
             }
-            None => arguments.append(&mut inputs),
-        }
+            None => vec![InputSet { values: self.inputs, input: None }],
+        };
 
         // Add the compiler options to the arguments.
+        let mut options = Vec::new();
         if self.compiler_options.offline {
-            arguments.push(String::from("--offline"));
+            options.push(String::from("--offline"));
         }
 
         // Add the endpoint to the arguments.
-        arguments.push(String::from("--endpoint"));
-        arguments.push(self.endpoint);
+        options.push(String::from("--endpoint"));
+        options.push(self.endpoint);
 
         // Open the Leo build/ directory
         let path = context.dir()?;
@@ -106,14 +98,89 @@ impl Command for Execute {
         // Unset the Leo panic hook
         let _ = std::panic::take_hook();
 
-        // Call the `execute` command.
-        println!();
-        let command = SnarkVMExecute::try_parse_from(&arguments).map_err(CliError::failed_to_parse_execute)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_execute)?;
+        // Execute a single set of inputs, failing with its error.
+        if input_sets.len() == 1 {
+            return execute(&self.name, &input_sets[0], &options);
+        }
+
+        // Synthesize the keys of the function once, and prove the execution on each set of inputs with them.
+        let bytecode_path = build_directory.join(MAIN_ALEO_FILE_NAME);
+        let bytecode = std::fs::read_to_string(&bytecode_path)
+            .map_err(|err| PackageError::failed_to_read_file(bytecode_path.display(), err))?;
+        let imports = BuildDirectory::imported_programs(&path)?;
+        let private_key = Env::<CurrentNetwork>::private_key_at(&path)?;
+        let rng = &mut ChaChaRng::from_entropy();
+        let keys = Compiler::setup::<AleoV0, _>(&bytecode, &imports, &self.name, rng)?;
+        let verifying_keys = keys.verifying_keys();
+        let values = input_sets.iter().map(|input_set| input_set.values.clone()).collect::<Vec<_>>();
+        let executions = Compiler::prove_all::<AleoV0, _>(
+            &bytecode,
+            &imports,
+            &keys,
+            &private_key,
+            &self.name,
+            &values,
+            &self.endpoint,
+            rng,
+        );
+
+        // Verify each execution, and report how many of the sets of inputs failed.
+        let total = input_sets.len();
+        let mut failed = 0;
+        for (index, (input_set, execution)) in input_sets.iter().zip(executions).enumerate() {
+            let verified = execution.and_then(|execution| {
+                // The proof is verified against the public inputs of a Leo input file, when the set was read from one.
+                let public_inputs = match input_set.input {
+                    Some(_) => input_set.public_values(),
+                    None => Compiler::public_inputs(&execution),
+                };
+                Compiler::verify(&bytecode, &imports, &verifying_keys, &self.name, &execution, &public_inputs)
+            });
+            match verified {
+                Ok(public_inputs) => {
+                    tracing::info!(
+                        "✅ Executed set {} of {total} of inputs, with public inputs [{}]",
+                        index + 1,
+                        public_inputs.join(", ")
+                    )
+                }
+                Err(err) => {
+                    tracing::error!("Set {} of {total} of inputs failed: {err}", index + 1);
+                    failed += 1;
+                }
+            }
+        }
+        tracing::info!("Executed {} of {total} sets of inputs successfully", total - failed);
 
-        // Log the output of the `execute` command.
-        tracing::info!("{}", res);
+        match failed {
+            0 => Ok(()),
+            _ => Err(CliError::batch_execution_failed(failed, total).into()),
+        }
+    }
+}
 
-        Ok(())
+/// Calls the `execute` command of snarkVM on the function `name` with `input_set`, and logs its output.
+fn execute(name: &str, input_set: &InputSet, options: &[String]) -> Result<()> {
+    // The public inputs are the statement that the proof of the execution is verified against.
+    let public_values = input_set.public_values();
+    if !public_values.is_empty() {
+        tracing::info!("Public inputs [{}]", public_values.join(", "));
     }
+
+    // Compose the `execute` command.
+    let arguments = std::iter::once(SNARKVM_COMMAND)
+        .chain(std::iter::once(name))
+        .chain(input_set.values.iter().map(String::as_str))
+        .chain(options.iter().map(String::as_str))
+        .collect::<Vec<_>>();
+
+    // Call the `execute` command.
+    println!();
+    let command = SnarkVMExecute::try_parse_from(&arguments).map_err(CliError::failed_to_parse_execute)?;
+    let res = command.parse().map_err(CliError::failed_to_execute_execute)?;
+
+    // Log the output of the `execute` command.
+    tracing::info!("{}", res);
+
+    Ok(())
 }
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use leo_ast::NodeBuilder;
+use leo_package::source::SourceDirectory;
+use leo_span::{source_map::FileName, symbol::with_session_globals};
+
+use std::path::Path;
+
+/// Formats the source files of the current package, keeping their comments.
+#[derive(Parser, Debug)]
+pub struct Fmt {
+    #[clap(long, help = "Check that the source files are formatted, without changing them")]
+    pub(crate) check: bool,
+}
+
+impl Command for Fmt {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let path = context.dir()?;
+        let source_files = SourceDirectory::files(&path)?;
+
+        let mut unformatted = Vec::new();
+        for file_path in source_files {
+            let source = std::fs::read_to_string(&file_path)
+                .map_err(|err| PackageError::failed_to_read_file(file_path.display(), err))?;
+
+            let formatted = format_source(&file_path, &source)?;
+            if formatted == source {
+                continue;
+            }
+
+            match self.check {
+                true => unformatted.push(file_path.display().to_string()),
+                false => {
+                    std::fs::write(&file_path, formatted).map_err(CliError::failed_to_write_file)?;
+                    tracing::info!("✅ Formatted {}", file_path.display());
+                }
+            }
+        }
+
+        match unformatted.is_empty() {
+            true => Ok(()),
+            false => Err(CliError::unformatted_files(unformatted.join(", ")).into()),
+        }
+    }
+}
+
+/// Parses the source file at `path`, which is the main file or a module, and prints it in the formatter's style.
+fn format_source(path: &Path, source: &str) -> Result<String> {
+    let handler = Handler::default();
+    let node_builder = NodeBuilder::default();
+    let source_file = with_session_globals(|s| s.source_map.new_source(source, FileName::Real(path.to_path_buf())));
+
+    let comments = leo_parser::parse_comments(&source_file.src, source_file.start_pos)?;
+
+    match SourceDirectory::is_main_file(path) {
+        true => {
+            let program = leo_parser::parse(&handler, &node_builder, &source_file.src, source_file.start_pos)?;
+            Ok(leo_ast::format_program_with_comments(&program, comments))
+        }
+        false => {
+            let module = leo_parser::parse_module(&handler, &node_builder, &source_file.src, source_file.start_pos)?;
+            Ok(leo_ast::format_module_with_comments(&module, comments))
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::cli::helpers::inputs::{read_function_signature, with_buffered_errors, INPUT_FILE_EXTENSION};
+use leo_ast::{input_file_template, NodeBuilder};
+use leo_package::{
+    inputs::{InputsDirectory, INPUTS_DIRECTORY_NAME},
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+
+/// Commands to manage input files.
+#[derive(Parser, Debug)]
+pub enum InputFile {
+    /// Generates an input file for a function, with the zero value of each input.
+    New {
+        /// The name of the function.
+        #[clap(default_value = "main")]
+        name: String,
+        /// Overwrite the input file if it exists.
+        #[clap(short = 'f', long)]
+        force: bool,
+    },
+}
+
+impl Command for InputFile {
+    type Input = ();
+    type Output = ();
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        match self {
+            InputFile::New { name, force } => {
+                let path = context.dir()?;
+
+                // Generate the input file from the signature of the function.
+                let main_file_path = path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+                let (function, structs) = with_buffered_errors(&main_file_path, |handler| {
+                    read_function_signature(&path, handler, &NodeBuilder::default(), &name)
+                })?;
+                let template = input_file_template(&function, &structs);
+
+                // Write the input file to the inputs/ directory, e.g. `inputs/main.in`.
+                InputsDirectory::create(&path)?;
+                let input_file_path = path.join(INPUTS_DIRECTORY_NAME).join(format!("{name}.{INPUT_FILE_EXTENSION}"));
+                if input_file_path.exists() && !force {
+                    return Err(CliError::input_file_exists(input_file_path.display()).into());
+                }
+                std::fs::write(&input_file_path, template).map_err(CliError::failed_to_write_file)?;
+
+                tracing::info!("✅ Created the input file {}", input_file_path.display());
+                Ok(())
+            }
+        }
+    }
+}
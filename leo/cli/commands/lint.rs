@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use leo_compiler::{LintConfig, LintLevel, LintRule};
+
+/// Type checks the program, and then checks it against the lint rules.
+#[derive(Parser, Debug)]
+pub struct Lint {
+    #[clap(short = 'A', long = "allow", value_name = "RULE", help = "Do not check a lint rule")]
+    pub(crate) allow: Vec<String>,
+    #[clap(short = 'W', long = "warn", value_name = "RULE", help = "Warn when a lint rule is broken")]
+    pub(crate) warn: Vec<String>,
+    #[clap(short = 'D', long = "deny", value_name = "RULE", help = "Fail when a lint rule is broken")]
+    pub(crate) deny: Vec<String>,
+    #[clap(flatten)]
+    pub(crate) options: BuildOptions,
+}
+
+impl Command for Lint {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Configure the rules. A rule that is given several levels gets the strictest of them.
+        let mut lints = LintConfig::default();
+        for (names, level) in
+            [(&self.allow, LintLevel::Allow), (&self.warn, LintLevel::Warn), (&self.deny, LintLevel::Deny)]
+        {
+            for name in names {
+                let rule = name.parse::<LintRule>().map_err(|_| {
+                    let names = LintRule::ALL.iter().map(|rule| format!("`{rule}`")).collect::<Vec<_>>().join(", ");
+                    CliError::unknown_lint(name, names)
+                })?;
+                lints.set(rule, level);
+            }
+        }
+
+        (Build { options: self.options, check_only: true, lints: Some(lints) }).execute(context)
+    }
+}
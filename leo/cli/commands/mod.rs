@@ -20,9 +20,18 @@ pub use add::Add;
 pub mod account;
 pub use account::Account;
 
+pub mod bench;
+pub use bench::Bench;
+
 pub mod build;
 pub use build::Build;
 
+pub mod cache;
+pub use cache::Cache;
+
+pub mod check;
+pub use check::Check;
+
 pub mod clean;
 pub use clean::Clean;
 
@@ -32,27 +41,52 @@ pub use example::Example;
 pub mod execute;
 pub use execute::Execute;
 
+pub mod diff;
+pub use diff::Diff;
+
+pub mod doc;
+pub use doc::Doc;
+
 // pub mod deploy;
 // pub use deploy::Deploy;
 
+pub mod fmt;
+pub use fmt::Fmt;
+
+pub mod input;
+pub use input::InputFile;
+
+pub mod lint;
+pub use lint::Lint;
+
 pub mod new;
 pub use new::New;
 
 // pub mod node;
 // pub use node::Node;
 
+pub mod repl;
+pub use repl::Repl;
+
 pub mod run;
 pub use run::Run;
 
+pub mod test;
+pub use test::Test;
+
 pub mod update;
 pub use update::Update;
 
+pub mod watch;
+pub use watch::Watch;
+
 use super::*;
 use crate::cli::helpers::context::*;
+use leo_compiler::OverflowBehavior;
 use leo_errors::{emitter::Handler, CliError, PackageError, Result};
 use leo_package::{build::*, outputs::OutputsDirectory, package::*};
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use colored::Colorize;
 use tracing::span::Span;
 
@@ -136,6 +170,28 @@ pub struct BuildOptions {
     pub enable_ast_spans: bool,
     #[clap(long, help = "Enable spans in symbol table snapshots.")]
     pub enable_dce: bool,
+    #[clap(long, help = "Enables canonicalization of equivalent expression forms in the compiler.")]
+    pub enable_canonicalization: bool,
+    #[clap(long, help = "Enables common subexpression elimination in the compiler.")]
+    pub enable_cse: bool,
+    #[clap(long, help = "Enables pruning of conditional branches ruled out by a constant condition in the compiler.")]
+    pub enable_branch_pruning: bool,
+    #[clap(long, help = "Keeps the assertions that follow an early return when the return is taken.")]
+    pub disable_early_return_guards: bool,
+    #[clap(long, help = "Lowers chains of ternary expressions that look up a field constant into weighted sums.")]
+    pub enable_mux_lowering: bool,
+    #[clap(long, help = "Unrolls powers with a constant exponent into multiplications.")]
+    pub enable_pow_lowering: bool,
+    #[clap(long, help = "Lowers unsigned divisions and remainders by a power of two into shifts and masks.")]
+    pub enable_div_lowering: bool,
+    #[clap(
+        long,
+        default_value_t,
+        help = "How integer operations behave when they overflow: `checked` as written, `wrapping`, or `trap`."
+    )]
+    pub overflow: OverflowBehavior,
+    #[clap(long, help = "The maximum number of elements of an array, at most the number that the network supports.")]
+    pub max_array_elements: Option<usize>,
     #[clap(long, help = "Writes all AST snapshots for the different compiler phases.")]
     pub enable_all_ast_snapshots: bool,
     #[clap(long, help = "Writes AST snapshot of the initial parse.")]
@@ -152,4 +208,40 @@ pub struct BuildOptions {
     pub enable_inlined_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the dead code eliminated (DCE) AST.")]
     pub enable_dce_ast_snapshot: bool,
+    #[clap(long, help = "Prints the node count, depth, and operation counts of the compiled expressions.")]
+    pub enable_expression_metrics: bool,
+    #[clap(long, help = "Prints the time, node counts, and peak memory of each phase of the build.")]
+    pub stats: bool,
+    #[clap(long, value_enum, help = "Writes additional artifacts of the compiler to the build directory.")]
+    pub emit: Vec<EmitFormat>,
+    #[clap(
+        long,
+        help = "Checks that the build is deterministic, and prints the hashes of the constraint systems of the functions."
+    )]
+    pub deterministic: bool,
+    #[clap(
+        long,
+        value_delimiter = ',',
+        help = "Compiles the items and statements annotated with `@cfg(feature = \"...\")` for these features."
+    )]
+    pub features: Vec<String>,
+    /// Whether to compile the transitions annotated with `@test`, which is only done by `leo test`.
+    #[clap(skip)]
+    pub enable_tests: bool,
+}
+
+/// Additional artifacts which the compiler can write to the build directory.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmitFormat {
+    /// A Graphviz DOT graph of the expression trees of the unrolled AST, annotated with their types.
+    Dot,
+    /// A profile of the constraints of each function by the statements they originate from, as folded stacks that
+    /// flamegraph tools render.
+    Flamegraph,
+    /// A source map of each function, which relates each constraint to the expression it originates from.
+    SourceMap,
+    /// The intermediate representation of the program, i.e. its flat SSA form, for external optimizers and analyzers.
+    Ir,
+    /// The ABI of the transitions of the program, for wallets and dapps that construct their inputs.
+    Abi,
 }
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use leo_ast::{Literal, NodeID};
+use leo_compiler::Evaluator;
+
+use snarkvm::circuit::AleoV0;
+
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use std::io::Write;
+
+/// Evaluates expressions and definitions of variables interactively, e.g. `let x: u8 = 2u8 ** 3u8;` and then `x + 1u8`.
+/// Reading ends at the end of the input, e.g. on Ctrl-D.
+#[derive(Parser, Debug)]
+pub struct Repl {
+    #[clap(long, help = "Synthesize the circuit of each expression, and print its number of constraints")]
+    pub(crate) synthesize: bool,
+}
+
+impl Command for Repl {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
+        let mut evaluator = Evaluator::default();
+        let mut rng = ChaChaRng::from_entropy();
+
+        let mut line = String::new();
+        loop {
+            print!("> ");
+            std::io::stdout().flush().map_err(CliError::failed_to_access_stdio)?;
+
+            line.clear();
+            if std::io::stdin().read_line(&mut line).map_err(CliError::failed_to_access_stdio)? == 0 {
+                println!();
+                return Ok(());
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            // Errors found while checking the input are printed by the handler as they are emitted.
+            let handler = Handler::default();
+            let synthesis_rng = self.synthesize.then_some(&mut rng);
+            match evaluator.evaluate::<AleoV0, _>(&handler, line.trim(), synthesis_rng) {
+                Ok(evaluation) => {
+                    let value = Literal::from_value(evaluation.value, NodeID::default());
+                    match evaluation.name {
+                        Some(name) => println!("{name}: {} = {value}", evaluation.type_),
+                        None => println!("{value}: {}", evaluation.type_),
+                    }
+                    if let Some(num_constraints) = evaluation.num_constraints {
+                        println!("{num_constraints} constraints");
+                    }
+                }
+                Err(err) if !handler.had_errors() => eprintln!("{err}"),
+                Err(_) => {}
+            }
+        }
+    }
+}
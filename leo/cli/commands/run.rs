@@ -16,7 +16,9 @@
 
 use super::*;
 
-use snarkvm::{cli::Run as SnarkVMRun, prelude::Parser as SnarkVMParser};
+use crate::cli::helpers::inputs::read_inputs_from_file;
+
+use snarkvm::cli::Run as SnarkVMRun;
 
 /// Build, Prove and Run Leo program with inputs
 #[derive(Parser, Debug)]
@@ -43,36 +45,21 @@ impl Command for Run {
     }
 
     fn prelude(&self, context: Context) -> Result<Self::Input> {
-        (Build { options: self.compiler_options.clone() }).execute(context)
+        (Build { options: self.compiler_options.clone(), check_only: false, lints: None }).execute(context)
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
         let mut inputs = self.inputs;
 
         // Compose the `run` command.
-        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
+        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name.clone()];
 
         // Add the inputs to the arguments.
         match self.file {
             Some(file) => {
-                // Get the contents from the file.
+                // Read the inputs from the file.
                 let path = context.dir()?.join(file);
-                let raw_content = std::fs::read_to_string(&path)
-                    .map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
-                // Parse the values from the file.
-                let mut content = raw_content.as_str();
-                let mut values = vec![];
-                while let Ok((remaining, value)) = snarkvm::prelude::Value::<CurrentNetwork>::parse(content) {
-                    content = remaining;
-                    values.push(value);
-                }
-                // Check that the remaining content is empty.
-                if !content.trim().is_empty() {
-                    return Err(PackageError::failed_to_read_input_file(path.display()).into());
-                }
-                // Convert the values to strings.
-                let mut inputs_from_file = values.into_iter().map(|value| value.to_string()).collect::<Vec<String>>();
-                // Add the inputs from the file to the arguments.
+                let mut inputs_from_file = read_inputs_from_file(&context.dir()?, &path, &self.name)?;
                 arguments.append(&mut inputs_from_file);
             }
             None => arguments.append(&mut inputs),
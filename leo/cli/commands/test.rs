@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::cli::helpers::inputs::{read_input_sets_from_file, with_buffered_errors, InputSet, INPUT_FILE_EXTENSION};
+
+use leo_ast::NodeBuilder;
+use leo_compiler::Compiler;
+use leo_package::{
+    inputs::INPUTS_DIRECTORY_NAME,
+    outputs::MAIN_ALEO_FILE_NAME,
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_span::{source_map::FileName, symbol::with_session_globals};
+
+use snarkvm::circuit::AleoV0;
+
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+
+/// Builds the program with its tests, and runs each transition annotated with `@test`.
+/// A test that takes inputs is run on each set of inputs in `inputs/<test>.in`, and one that takes none is run once.
+#[derive(Parser, Debug)]
+pub struct Test {
+    #[clap(name = "NAME", help = "Only run the tests whose names contain NAME.")]
+    pub(crate) name: Option<String>,
+    #[clap(flatten)]
+    pub(crate) options: BuildOptions,
+}
+
+impl Command for Test {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        let options = BuildOptions { enable_tests: true, ..self.options.clone() };
+        (Build { options, check_only: false, lints: None }).execute(context)
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+        let build_directory = BuildDirectory::open(&package_path)?;
+        let bytecode_path = build_directory.join(MAIN_ALEO_FILE_NAME);
+        let bytecode = std::fs::read_to_string(&bytecode_path)
+            .map_err(|err| PackageError::failed_to_read_file(bytecode_path.display(), err))?;
+        let imports = BuildDirectory::imported_programs(&package_path)?;
+
+        let tests = find_tests(&package_path)?
+            .into_iter()
+            .filter(|(name, _)| self.name.as_ref().map_or(true, |filter| name.contains(filter.as_str())))
+            .collect::<Vec<_>>();
+        if tests.is_empty() {
+            tracing::info!("No tests to run");
+            return Ok(());
+        }
+
+        let rng = &mut ChaChaRng::from_entropy();
+        let total = tests.len();
+        let mut failed = 0;
+        for (name, has_inputs) in tests {
+            // A test without inputs is run once, and the inputs of any other test are read from its input file.
+            let input_sets = match has_inputs {
+                false => Ok(vec![InputSet::default()]),
+                true => {
+                    let path = package_path.join(INPUTS_DIRECTORY_NAME).join(format!("{name}.{INPUT_FILE_EXTENSION}"));
+                    read_input_sets_from_file(&package_path, &path, &name)
+                }
+            };
+
+            let report = input_sets.and_then(|input_sets| {
+                let values = input_sets.iter().map(|input_set| input_set.values.clone()).collect::<Vec<_>>();
+                Ok((Compiler::run_test::<AleoV0, _>(&bytecode, &imports, &name, &values, rng)?, input_sets))
+            });
+            let (report, input_sets) = match report {
+                Ok(report) => report,
+                Err(err) => {
+                    tracing::error!("❌ {name}: {err}");
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            let mut passed = true;
+            for (index, (result, input_set)) in report.results.iter().zip(input_sets.iter()).enumerate() {
+                let set = match report.results.len() {
+                    1 => String::new(),
+                    _ => format!(" (set {} of {})", index + 1, report.results.len()),
+                };
+                // The outputs are checked against the `[registers]` section of the input file, if it has one.
+                let result = match result {
+                    Ok(outputs) => input_set.check_outputs(outputs).map(|()| outputs).map_err(|err| err.to_string()),
+                    Err(err) => Err(err.to_string()),
+                };
+                match result {
+                    Ok(outputs) => tracing::info!(
+                        "✅ {name}{set}: {} constraints, outputs [{}]",
+                        report.num_constraints,
+                        outputs.join(", ")
+                    ),
+                    Err(err) => {
+                        tracing::error!("❌ {name}{set}: {err}");
+                        passed = false;
+                    }
+                }
+            }
+            if !passed {
+                failed += 1;
+            }
+        }
+        tracing::info!("{} of {total} tests passed", total - failed);
+
+        match failed {
+            0 => Ok(()),
+            _ => Err(CliError::failed_tests(failed, total).into()),
+        }
+    }
+}
+
+/// Returns the name of each test declared in `src/main.leo`, and whether it takes inputs.
+fn find_tests(package_path: &std::path::Path) -> Result<Vec<(String, bool)>> {
+    let main_file_path = package_path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+    let program_string = std::fs::read_to_string(&main_file_path)
+        .map_err(|err| PackageError::failed_to_read_file(main_file_path.display(), err))?;
+    let program_sf =
+        with_session_globals(|s| s.source_map.new_source(&program_string, FileName::Real(main_file_path.clone())));
+    let ast = with_buffered_errors(&main_file_path, |handler| {
+        leo_parser::parse_ast(handler, &NodeBuilder::default(), &program_sf.src, program_sf.start_pos)
+    })?;
+
+    Ok(ast
+        .ast
+        .program_scopes
+        .values()
+        .flat_map(|scope| scope.functions.iter())
+        .filter(|(_, function)| function.is_test())
+        .map(|(name, function)| (name.to_string(), !function.input.is_empty()))
+        .collect())
+}
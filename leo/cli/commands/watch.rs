@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::cli::commands::build::SYNTHESIS_CACHE_FILE_NAME;
+use leo_compiler::{circuit_hashes, Compiler, SynthesisCache};
+use leo_errors::LeoError;
+use leo_package::{inputs::INPUTS_DIRECTORY_NAME, outputs::MAIN_ALEO_FILE_NAME, source::SOURCE_DIRECTORY_NAME};
+
+use snarkvm::{circuit::AleoV0, prelude::Program};
+
+use indexmap::IndexMap;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
+
+/// Type checks the package again whenever its source files, input files, or manifest change.
+///
+/// Each check only prints the errors and warnings that the previous check did not, and counts those that were fixed.
+/// Dependencies are retrieved as in `leo build`, so those that are cached are not retrieved again.
+#[derive(Parser, Debug)]
+pub struct Watch {
+    #[clap(
+        long,
+        help = "Also compile the program and synthesize the circuit of each function, printing their constraints"
+    )]
+    pub(crate) synthesize: bool,
+    #[clap(long, help = "The interval between checks for changes, in milliseconds", default_value = "500")]
+    pub(crate) interval: u64,
+    #[clap(flatten)]
+    pub(crate) options: BuildOptions,
+}
+
+impl Command for Watch {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+
+        let mut files = BTreeMap::new();
+        let mut diagnostics = Vec::new();
+        let mut constraints = IndexMap::new();
+        loop {
+            let current_files = watched_files(&package_path);
+            if current_files != files {
+                let changed = current_files
+                    .keys()
+                    .chain(files.keys())
+                    .filter(|path| current_files.get(*path) != files.get(*path))
+                    .collect::<BTreeSet<_>>();
+                if !files.is_empty() {
+                    for path in changed {
+                        tracing::info!("🔄 Changed {}", path.strip_prefix(&package_path).unwrap_or(path).display());
+                    }
+                }
+                files = current_files;
+
+                diagnostics = self.check(&context, &diagnostics, &mut constraints);
+            }
+            std::thread::sleep(Duration::from_millis(self.interval));
+        }
+    }
+}
+
+impl Watch {
+    /// Checks the package, printing the errors and warnings that are not in `previous`, and returns all of them.
+    fn check(&self, context: &Context, previous: &[String], constraints: &mut IndexMap<String, usize>) -> Vec<String> {
+        let (handler, buffer) = Handler::new_with_buf();
+        let build = Build { options: self.options.clone(), check_only: !self.synthesize, lints: None };
+        let result = build.build(context.clone(), &handler).and_then(|_| match self.synthesize {
+            true => synthesize(context, constraints),
+            false => Ok(()),
+        });
+
+        // An error that was returned rather than emitted, e.g. a syntax error, is a diagnostic as well.
+        let mut current = buffer
+            .extract_errs()
+            .into_inner()
+            .into_iter()
+            .filter(|err| !matches!(err, LeoError::LastErrorCode(_)))
+            .map(|err| err.to_string())
+            .chain(buffer.extract_warnings().into_inner().into_iter().map(|warning| warning.to_string()))
+            .collect::<Vec<_>>();
+        if let Err(err) = result {
+            if !matches!(err, LeoError::LastErrorCode(_)) && !current.contains(&err.to_string()) {
+                current.push(err.to_string());
+            }
+        }
+
+        let new = current.iter().filter(|diagnostic| !previous.contains(diagnostic)).collect::<Vec<_>>();
+        for diagnostic in &new {
+            eprintln!("{diagnostic}");
+        }
+        let fixed = previous.iter().filter(|diagnostic| !current.contains(diagnostic)).count();
+        match current.is_empty() {
+            true => tracing::info!("👀 No errors or warnings ({fixed} fixed), waiting for changes"),
+            false => tracing::info!(
+                "👀 {} errors and warnings ({} new, {fixed} fixed), waiting for changes",
+                current.len(),
+                new.len()
+            ),
+        }
+
+        current
+    }
+}
+
+/// Synthesizes the circuit of each function of the compiled program, printing its number of constraints and how it
+/// changed since the last synthesis, which is recorded in `constraints`. Only the functions that changed since the last
+/// build are synthesized again.
+fn synthesize(context: &Context, constraints: &mut IndexMap<String, usize>) -> Result<()> {
+    let build_directory = BuildDirectory::open(&context.dir()?)?;
+    let bytecode_path = build_directory.join(MAIN_ALEO_FILE_NAME);
+    let bytecode = std::fs::read_to_string(&bytecode_path)
+        .map_err(|err| PackageError::failed_to_read_file(bytecode_path.display(), err))?;
+
+    // The circuits are synthesized by a process that only loads the program and `credits.aleo`.
+    let program = Program::<CurrentNetwork>::from_str(&bytecode).map_err(CliError::failed_to_execute_build)?;
+    if program.imports().keys().any(|program_id| program_id.to_string() != "credits.aleo") {
+        tracing::info!("Skipped the circuits of the program, as it imports other programs");
+        return Ok(());
+    }
+
+    let cache_path = build_directory.join(SYNTHESIS_CACHE_FILE_NAME);
+    let mut cache = SynthesisCache::from_json_file(&cache_path).unwrap_or_default();
+    cache.retain(&circuit_hashes(&bytecode));
+
+    // The circuits of the functions that are not in the cache are synthesized in parallel.
+    let functions = program.functions().keys().map(|function| function.to_string()).collect::<Vec<_>>();
+    let counts = Compiler::num_constraints_all::<AleoV0, _>(
+        &bytecode,
+        &functions,
+        &mut cache,
+        &mut ChaChaRng::seed_from_u64(0),
+    )?;
+    cache.to_json_file(&cache_path)?;
+    for (function, num_constraints) in functions.into_iter().zip(counts) {
+        match constraints.insert(function.clone(), num_constraints) {
+            Some(previous) if previous != num_constraints => {
+                let change = num_constraints as i64 - previous as i64;
+                tracing::info!("🔒 '{function}' has {num_constraints} constraints ({change:+})");
+            }
+            _ => tracing::info!("🔒 '{function}' has {num_constraints} constraints"),
+        }
+    }
+    Ok(())
+}
+
+/// Returns the time at which each file in the source and input directories, and the manifest, was last modified.
+fn watched_files(package_path: &Path) -> BTreeMap<PathBuf, SystemTime> {
+    let mut files = BTreeMap::new();
+    let mut directories = vec![package_path.join(SOURCE_DIRECTORY_NAME), package_path.join(INPUTS_DIRECTORY_NAME)];
+    while let Some(directory) = directories.pop() {
+        for path in std::fs::read_dir(directory).into_iter().flatten().filter_map(|entry| entry.ok().map(|e| e.path()))
+        {
+            if path.is_dir() {
+                directories.push(path);
+            } else if let Ok(modified) = path.metadata().and_then(|metadata| metadata.modified()) {
+                files.insert(path, modified);
+            }
+        }
+    }
+
+    let manifest_path = package_path.join("program.json");
+    if let Ok(modified) = manifest_path.metadata().and_then(|metadata| metadata.modified()) {
+        files.insert(manifest_path, modified);
+    }
+    files
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use leo_package::source::MAIN_FILENAME;
+
+    use std::fs::File;
+
+    #[test]
+    fn test_watched_files() {
+        let package = tempfile::tempdir().unwrap();
+        let package_path = package.path();
+        let main_file_path = package_path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+        let module_path = package_path.join(SOURCE_DIRECTORY_NAME).join("utils").join("math.leo");
+        let input_path = package_path.join(INPUTS_DIRECTORY_NAME).join("main.in");
+        let manifest_path = package_path.join("program.json");
+        for path in [&main_file_path, &module_path, &input_path, &manifest_path, &package_path.join("build/main.aleo")]
+        {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+
+        // The files of the build directory are written by the check itself, so they are not watched.
+        let files = watched_files(package_path);
+        let paths = files.keys().cloned().collect::<BTreeSet<_>>();
+        assert_eq!(paths, BTreeSet::from([main_file_path.clone(), module_path, input_path, manifest_path]));
+
+        // A file that is modified again is seen as changed.
+        let modified = files[&main_file_path] + Duration::from_secs(1);
+        File::options().write(true).open(&main_file_path).unwrap().set_modified(modified).unwrap();
+        let changed = watched_files(package_path);
+        assert_ne!(changed, files);
+        assert_eq!(changed[&main_file_path], modified);
+    }
+}
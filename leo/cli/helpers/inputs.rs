@@ -0,0 +1,356 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use leo_ast::{Function, InputAst, NodeBuilder, ProgramInput, Struct};
+use leo_errors::{emitter::Handler, CliError, LeoError, PackageError, Result};
+use leo_package::source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME};
+use leo_span::{source_map::FileName, symbol::with_session_globals, Symbol};
+
+use snarkvm::prelude::Parser as SnarkVMParser;
+
+use std::path::Path;
+
+/// The extension of Leo input files, whose inputs are given in typed sections, e.g. `[main]`.
+pub const INPUT_FILE_EXTENSION: &str = "in";
+
+/// A set of inputs to a function, read from a file.
+#[derive(Clone, Debug, Default)]
+pub struct InputSet {
+    /// The values of the inputs, as arguments to snarkVM.
+    pub values: Vec<String>,
+    /// The inputs as read from a Leo input file, with their modes and the expected outputs of the function.
+    pub input: Option<ProgramInput>,
+}
+
+impl InputSet {
+    /// Checks the `outputs` of the function on these inputs against the `[registers]` section of their input file.
+    pub fn check_outputs(&self, outputs: &[String]) -> Result<()> {
+        match &self.input {
+            Some(input) => Ok(input.check_outputs(outputs)?),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the values of the public inputs, which the proof of an execution on these inputs is verified against.
+    /// Only the inputs read from a Leo input file are known to be public.
+    pub fn public_values(&self) -> Vec<String> {
+        match &self.input {
+            Some(input) => input.public_values().iter().map(|value| value.to_string()).collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Reads the inputs to the function `name` from the file at `path`, as arguments to snarkVM.
+/// The file must hold exactly one set of inputs.
+pub fn read_inputs_from_file(package_path: &Path, path: &Path, name: &str) -> Result<Vec<String>> {
+    let mut input_sets = read_input_sets_from_file(package_path, path, name)?;
+    match input_sets.len() {
+        1 => Ok(input_sets.remove(0).values),
+        found => Err(CliError::expected_one_input_set(path.display(), found).into()),
+    }
+}
+
+/// Reads the sets of inputs to the function `name` from the file or directory at `path`.
+/// A Leo input file, e.g. `inputs/main.in`, is checked against the signature of the function in `src/main.leo`, and
+/// holds a set of inputs for each `[main]` section. Any other file holds one set of inputs, as a sequence of Aleo values.
+/// A directory holds the files of the input sets, which are read in the order of their names.
+pub fn read_input_sets_from_file(package_path: &Path, path: &Path, name: &str) -> Result<Vec<InputSet>> {
+    if path.is_dir() {
+        let mut paths = std::fs::read_dir(path)
+            .map_err(PackageError::failed_to_read_inputs_directory)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file())
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        let mut input_sets = Vec::new();
+        for path in paths {
+            input_sets.extend(read_input_sets_from_file(package_path, &path, name)?);
+        }
+        return Ok(input_sets);
+    }
+
+    let raw_content =
+        std::fs::read_to_string(path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+
+    if path.extension().map_or(false, |extension| extension == INPUT_FILE_EXTENSION) {
+        return read_input_sets_from_input_file(package_path, path, &raw_content, name);
+    }
+
+    // Parse the values from the file.
+    let mut content = raw_content.as_str();
+    let mut values = vec![];
+    while let Ok((remaining, value)) = snarkvm::prelude::Value::<CurrentNetwork>::parse(content) {
+        content = remaining;
+        values.push(value);
+    }
+    // Check that the remaining content is empty.
+    if !content.trim().is_empty() {
+        return Err(PackageError::failed_to_read_input_file(path.display()).into());
+    }
+    // Convert the values to strings.
+    Ok(vec![InputSet { values: values.into_iter().map(|value| value.to_string()).collect(), input: None }])
+}
+
+/// Reads the sets of inputs to the function `name` from the Leo input file at `path`, checking them against its signature.
+fn read_input_sets_from_input_file(
+    package_path: &Path,
+    path: &Path,
+    content: &str,
+    name: &str,
+) -> Result<Vec<InputSet>> {
+    with_buffered_errors(path, |handler| {
+        let node_builder = NodeBuilder::default();
+
+        // Parse the program, to find the signature of the function.
+        let (function, structs) = read_function_signature(package_path, handler, &node_builder, name)?;
+
+        // Parse the input file.
+        let input_sf = with_session_globals(|s| s.source_map.new_source(content, FileName::Real(path.to_path_buf())));
+        let input_ast: InputAst = leo_parser::parse_input(handler, &node_builder, &input_sf.src, input_sf.start_pos)?;
+
+        input_ast
+            .input_sets(Symbol::intern(name))
+            .iter()
+            .map(|input_set| -> Result<InputSet> {
+                let input = ProgramInput::new(input_set, &function, &structs, handler)?;
+                if !input.registers.is_empty() {
+                    let registers = input
+                        .registers
+                        .iter()
+                        .map(|(register, value)| format!("{register} = {value}"))
+                        .collect::<Vec<_>>();
+                    tracing::info!("Expecting the outputs {}", registers.join(", "));
+                }
+
+                let values = input.values.iter().map(|(_, value)| value.to_string()).collect();
+                Ok(InputSet { values, input: Some(input) })
+            })
+            .collect()
+    })
+}
+
+/// Runs `read` on the file at `path` with a handler that collects the errors it emits, and returns them as one error.
+/// The default handler prints each error as it is emitted, so the error that is returned after them would report them
+/// again. The warnings are logged.
+pub fn with_buffered_errors<T>(path: &Path, read: impl FnOnce(&Handler) -> Result<T>) -> Result<T> {
+    let (handler, buffer) = Handler::new_with_buf();
+    let result = read(&handler);
+    for warning in buffer.extract_warnings().into_inner() {
+        tracing::warn!("{warning}");
+    }
+
+    let mut errors = buffer.extract_errs();
+    match result {
+        Ok(value) => Ok(value),
+        Err(LeoError::LastErrorCode(_)) => Err(CliError::invalid_source_file(path.display(), errors).into()),
+        Err(err) if errors.last_entry().is_none() => Err(err),
+        Err(err) => {
+            errors.push(err);
+            Err(CliError::invalid_source_file(path.display(), errors).into())
+        }
+    }
+}
+
+/// Returns the function `name` declared in `src/main.leo`, with the structs its inputs may refer to.
+pub fn read_function_signature(
+    package_path: &Path,
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    name: &str,
+) -> Result<(Function, Vec<(Symbol, Struct)>)> {
+    let main_file_path = package_path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+    let program_string = std::fs::read_to_string(&main_file_path)
+        .map_err(|err| PackageError::failed_to_read_file(main_file_path.display(), err))?;
+    let program_sf = with_session_globals(|s| s.source_map.new_source(&program_string, FileName::Real(main_file_path)));
+    let ast = leo_parser::parse_ast(handler, node_builder, &program_sf.src, program_sf.start_pos)?;
+
+    ast.ast
+        .program_scopes
+        .into_values()
+        .find_map(|scope| {
+            scope
+                .functions
+                .into_iter()
+                .find(|(function_name, _)| *function_name == Symbol::intern(name))
+                .map(|(_, function)| (function, scope.structs))
+        })
+        .ok_or_else(|| CliError::function_not_found(name).into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use leo_package::inputs::INPUTS_DIRECTORY_NAME;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const PROGRAM: &str = "program test.aleo {
+    transition main(public a: u32, b: u32, public c: bool) -> u32 {
+        return a + b;
+    }
+}
+";
+
+    const AGGREGATES_PROGRAM: &str = "program test.aleo {
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    transition main(public a: [u8; 2], p: Point) -> u32 {
+        return p.x + p.y;
+    }
+}
+";
+
+    const RECORDS_PROGRAM: &str = "program test.aleo {
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    transition main(t: Token, public a: u64) -> u64 {
+        return t.amount + a;
+    }
+}
+";
+
+    const ADDRESS: &str = "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc";
+
+    /// Writes a package with the `program` and the input file `inputs/main.in` to a temporary directory.
+    fn write_package(program: &str, input_file: &str) -> tempfile::TempDir {
+        let directory = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(directory.path().join(SOURCE_DIRECTORY_NAME)).unwrap();
+        std::fs::write(directory.path().join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME), program).unwrap();
+        std::fs::create_dir_all(directory.path().join(INPUTS_DIRECTORY_NAME)).unwrap();
+        std::fs::write(directory.path().join(INPUTS_DIRECTORY_NAME).join("main.in"), input_file).unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_public_values() {
+        let package = write_package(
+            PROGRAM,
+            "[main]\na: u32 = 1u32;\nb: u32 = 2u32;\nc: bool = true;\n\n\
+             [main]\npublic a: u32 = 3u32;\nprivate b: u32 = 4u32;\nc: bool = false;\n",
+        );
+        let path = package.path().join(INPUTS_DIRECTORY_NAME).join("main.in");
+
+        create_session_if_not_set_then(|_| {
+            let input_sets = read_input_sets_from_file(package.path(), &path, "main").unwrap();
+            assert_eq!(input_sets.len(), 2);
+            assert_eq!(input_sets[0].values, ["1u32", "2u32", "true"]);
+            assert_eq!(input_sets[0].public_values(), ["1u32", "true"]);
+            assert_eq!(input_sets[1].values, ["3u32", "4u32", "false"]);
+            assert_eq!(input_sets[1].public_values(), ["3u32", "false"]);
+        });
+
+        // The modes of inputs that are given as a sequence of Aleo values are not known.
+        let path = package.path().join("values.txt");
+        std::fs::write(&path, "1u32 2u32 true").unwrap();
+        let input_sets = read_input_sets_from_file(package.path(), &path, "main").unwrap();
+        assert_eq!(input_sets[0].values, ["1u32", "2u32", "true"]);
+        assert!(input_sets[0].public_values().is_empty());
+    }
+
+    #[test]
+    fn test_aggregate_values() {
+        let package =
+            write_package(AGGREGATES_PROGRAM, "[main]\na: [u8; 2] = [1u8, 2u8];\np: Point = { y: 2u32, x: 1u32 };\n");
+        let path = package.path().join(INPUTS_DIRECTORY_NAME).join("main.in");
+
+        create_session_if_not_set_then(|_| {
+            let input_sets = read_input_sets_from_file(package.path(), &path, "main").unwrap();
+            assert_eq!(input_sets.len(), 1);
+            assert_eq!(input_sets[0].values, ["[1u8, 2u8]", "{ x: 1u32, y: 2u32 }"]);
+            assert_eq!(input_sets[0].public_values(), ["[1u8, 2u8]"]);
+        });
+    }
+
+    #[test]
+    fn test_mismatched_input_file() {
+        // The array has too few elements, and the struct lacks a member.
+        let package = write_package(AGGREGATES_PROGRAM, "[main]\na: [u8; 2] = [1u8];\np: Point = { x: 1u32 };\n");
+        let path = package.path().join(INPUTS_DIRECTORY_NAME).join("main.in");
+
+        create_session_if_not_set_then(|_| {
+            let err = read_input_sets_from_file(package.path(), &path, "main").unwrap_err().to_string();
+            assert!(err.contains(&path.display().to_string()));
+            assert_eq!(err.matches("Expected an array of 2 elements, but found 1 elements.").count(), 1);
+            assert_eq!(err.matches("The value of struct `Point` is missing the member `y`.").count(), 1);
+        });
+
+        // Each missing, unexpected, and mistyped entry is reported once, in the one error that is returned.
+        let package = write_package(PROGRAM, "[main]\na: u32 = 1u32;\nb: u64 = 2u64;\nd: u32 = 3u32;\n");
+        let path = package.path().join(INPUTS_DIRECTORY_NAME).join("main.in");
+
+        create_session_if_not_set_then(|_| {
+            let err = read_input_sets_from_file(package.path(), &path, "main").unwrap_err().to_string();
+            assert_eq!(err.matches("The input `b` has type `u64`").count(), 1);
+            assert_eq!(err.matches("The input `c` is missing").count(), 1);
+            assert_eq!(err.matches("The input `d` in the section `[main]`").count(), 1);
+        });
+    }
+
+    #[test]
+    fn test_record_values() {
+        // The `[records]` section is shared by both input sets.
+        let package = write_package(
+            RECORDS_PROGRAM,
+            &format!(
+                "[records]\nt: Token = {{ amount: 5u64, owner: {ADDRESS} }};\n\n\
+                 [main]\na: u64 = 1u64;\n\n[main]\na: u64 = 2u64;\n"
+            ),
+        );
+        let path = package.path().join(INPUTS_DIRECTORY_NAME).join("main.in");
+
+        create_session_if_not_set_then(|_| {
+            let input_sets = read_input_sets_from_file(package.path(), &path, "main").unwrap();
+            assert_eq!(input_sets.len(), 2);
+            let record = format!("{{ owner: {ADDRESS}.private, amount: 5u64.private, _nonce: 0group.public }}");
+            assert_eq!(input_sets[0].values, [record.as_str(), "1u64"]);
+            assert_eq!(input_sets[0].public_values(), ["1u64"]);
+            assert_eq!(input_sets[1].values, [record.as_str(), "2u64"]);
+        });
+
+        // A record is not read from the section of the function.
+        let package = write_package(
+            RECORDS_PROGRAM,
+            &format!("[main]\nt: Token = {{ owner: {ADDRESS}, amount: 5u64 }};\na: u64 = 1u64;\n"),
+        );
+        let path = package.path().join(INPUTS_DIRECTORY_NAME).join("main.in");
+
+        create_session_if_not_set_then(|_| {
+            let err = read_input_sets_from_file(package.path(), &path, "main").unwrap_err().to_string();
+            assert_eq!(err.matches("The input `t` is missing from the section `[records]`").count(), 1);
+            assert_eq!(err.matches("The input `t` in the section `[main]`").count(), 1);
+        });
+
+        // The template of `leo input new` puts the record in the `[records]` section, and can be read back.
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let (function, structs) =
+                read_function_signature(package.path(), &handler, &NodeBuilder::default(), "main").unwrap();
+            let template = leo_ast::input_file_template(&function, &structs);
+            assert!(template.contains("[records]\nt: Token = "), "{template}");
+            std::fs::write(&path, template).unwrap();
+            let input_sets = read_input_sets_from_file(package.path(), &path, "main").unwrap();
+            assert_eq!(input_sets[0].values[1], "0u64");
+        });
+    }
+}
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::imports::IMPORTS_DIRECTORY_NAME;
 use leo_errors::{PackageError, Result};
 
 use std::{
@@ -65,4 +66,27 @@ impl BuildDirectory {
 
         Ok(format!("(in \"{}\")", path.display()))
     }
+
+    /// Returns the Aleo instructions of the programs that the program imports, from the `imports/` directory of the
+    /// build directory at the provided path, in the order of their file names.
+    pub fn imported_programs(path: &Path) -> Result<Vec<String>> {
+        let imports_path = Self::open(path)?.join(IMPORTS_DIRECTORY_NAME);
+        if !imports_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut file_paths = fs::read_dir(&imports_path)
+            .map_err(|err| PackageError::failed_to_read_file(imports_path.display(), err))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().map_or(false, |extension| extension == "aleo"))
+            .collect::<Vec<_>>();
+        file_paths.sort();
+
+        file_paths
+            .iter()
+            .map(|path| {
+                fs::read_to_string(path).map_err(|err| PackageError::failed_to_read_file(path.display(), err).into())
+            })
+            .collect()
+    }
 }
@@ -19,7 +19,7 @@ use leo_errors::{PackageError, Result};
 use snarkvm::console::{account::PrivateKey, prelude::Network};
 
 use serde::Deserialize;
-use std::{borrow::Cow, fs::File, io::Write, marker::PhantomData, path::Path};
+use std::{borrow::Cow, fs::File, io::Write, marker::PhantomData, path::Path, str::FromStr};
 
 pub static ENV_FILENAME: &str = ".env";
 
@@ -57,6 +57,21 @@ impl<N: Network> Env<N> {
         Ok(())
     }
 
+    /// Reads the private key from the `PRIVATE_KEY` variable of the `.env` file at `path`.
+    pub fn private_key_at(path: &Path) -> Result<PrivateKey<N>> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            path.to_mut().push(ENV_FILENAME);
+        }
+
+        let data = std::fs::read_to_string(&path).map_err(PackageError::io_error_env_file)?;
+        data.lines()
+            .filter_map(|line| line.trim().strip_prefix("PRIVATE_KEY="))
+            .last()
+            .and_then(|private_key| PrivateKey::<N>::from_str(private_key.trim()).ok())
+            .ok_or_else(|| PackageError::missing_private_key(path.display()).into())
+    }
+
     fn template() -> Result<String> {
         // Initialize an RNG.
         let rng = &mut rand::thread_rng();
@@ -67,3 +82,24 @@ impl<N: Network> Env<N> {
         Ok(format!("NETWORK=testnet3\nPRIVATE_KEY={private_key}\n"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm::prelude::Testnet3;
+
+    #[test]
+    fn test_private_key_at() {
+        let directory = tempfile::tempdir().unwrap();
+        let env = Env::<CurrentNetwork>::new().unwrap();
+        let expected = env.data.lines().find_map(|line| line.strip_prefix("PRIVATE_KEY=")).unwrap().to_string();
+        env.write_to(directory.path()).unwrap();
+
+        let private_key = Env::<CurrentNetwork>::private_key_at(directory.path()).unwrap();
+        assert_eq!(private_key.to_string(), expected);
+
+        Env::<CurrentNetwork>::from("NETWORK=testnet3\n".to_string()).write_to(directory.path()).unwrap();
+        assert!(Env::<CurrentNetwork>::private_key_at(directory.path()).is_err());
+    }
+}
@@ -57,11 +57,17 @@ impl SourceDirectory {
     }
 
     /// Check that the files in the source directory are valid.
+    /// The source directory must contain the main file, and may contain modules that the main file imports items from.
     pub fn check_files(paths: &[PathBuf]) -> Result<()> {
         match paths.len() {
             0 => Err(PackageError::empty_source_directory().into()),
-            1 if paths[0].as_path().ends_with(MAIN_FILENAME) => Ok(()),
-            _ => Err(PackageError::source_directory_can_contain_only_one_file().into()),
+            _ if paths.iter().any(|path| Self::is_main_file(path)) => Ok(()),
+            _ => Err(PackageError::missing_main_file().into()),
         }
     }
+
+    /// Returns `true` if the path is the path of the main file, i.e. `src/main.leo`.
+    pub fn is_main_file(path: &Path) -> bool {
+        path.ends_with(Path::new(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME))
+    }
 }
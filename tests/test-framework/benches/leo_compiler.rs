@@ -89,7 +89,20 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
         PathBuf::from(String::new()),
         PathBuf::from(String::new()),
         Some(CompilerOptions {
-            build: BuildOptions { dce_enabled: true },
+            build: BuildOptions {
+                dce_enabled: true,
+                canonicalization_enabled: false,
+                cse_enabled: false,
+                branch_pruning_enabled: false,
+                early_return_guards_enabled: false,
+                mux_lowering_enabled: false,
+                pow_lowering_enabled: false,
+                div_lowering_enabled: false,
+                overflow_behavior: Default::default(),
+                max_array_elements: None,
+                tests_enabled: false,
+                features: Vec::new(),
+            },
             output: OutputOptions {
                 symbol_table_spans_enabled: false,
                 initial_symbol_table: false,
@@ -103,6 +116,9 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
                 destructured_ast: false,
                 inlined_ast: false,
                 dce_ast: false,
+                unrolled_dot_graph: false,
+                ir: false,
+                stats: false,
             },
         }),
         IndexMap::new(),
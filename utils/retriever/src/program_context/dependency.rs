@@ -26,11 +26,14 @@ pub struct Dependency {
     location: Location,
     network: Option<Network>,
     path: Option<PathBuf>,
+    // The expected checksum of a network dependency, which pins the program that is retrieved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    checksum: Option<String>,
 }
 
 impl Dependency {
     pub fn new(name: String, location: Location, network: Option<Network>, path: Option<PathBuf>) -> Self {
-        Self { name, location, network, path }
+        Self { name, location, network, path, checksum: None }
     }
 
     pub fn name(&self) -> &String {
@@ -48,6 +51,10 @@ impl Dependency {
     pub fn path(&self) -> &Option<PathBuf> {
         &self.path
     }
+
+    pub fn checksum(&self) -> &Option<String> {
+        &self.checksum
+    }
 }
 
 impl From<&Dependency> for Symbol {
@@ -28,6 +28,20 @@ pub struct LockFileEntry {
     dependencies: Vec<String>,
 }
 
+impl LockFileEntry {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+
+    pub fn checksum(&self) -> &String {
+        &self.checksum
+    }
+}
+
 impl From<&ProgramContext> for LockFileEntry {
     fn from(context: &ProgramContext) -> Self {
         LockFileEntry {
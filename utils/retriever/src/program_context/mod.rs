@@ -44,6 +44,7 @@ pub struct ProgramContext {
     compiled_file_path: Option<PathBuf>,
     dependencies: Option<Vec<Symbol>>,
     checksum: Option<String>,
+    pinned_checksum: Option<String>,
     stub: Option<Stub>,
     post_order: Option<IndexSet<Symbol>>,
 }
@@ -60,6 +61,7 @@ impl ProgramContext {
             compiled_file_path: Some(path.join("build/main.aleo")),
             dependencies: Some(dependencies.iter().map(Symbol::from).collect()),
             checksum: None,
+            pinned_checksum: None,
             stub: None,
             post_order: None,
         }
@@ -132,6 +134,11 @@ impl ProgramContext {
         }
     }
 
+    // Method to extract 'pinned_checksum', the checksum that the dependency is pinned to in `program.json`, if any.
+    pub fn pinned_checksum(&self) -> &Option<String> {
+        &self.pinned_checksum
+    }
+
     // Method to add 'stub'
     pub fn add_stub(&mut self, stub: Stub) -> bool {
         if self.stub.is_some() {
@@ -178,6 +185,7 @@ impl From<Dependency> for ProgramContext {
             compiled_file_path: None,
             dependencies: None,
             checksum: None,
+            pinned_checksum: dependency.checksum().clone(),
             stub: None,
             post_order: None,
         }
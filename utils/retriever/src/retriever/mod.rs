@@ -37,6 +37,8 @@ pub struct Retriever {
     contexts: IndexMap<Symbol, ProgramContext>,
     project_path: PathBuf,
     registry_path: PathBuf,
+    // The checksums of the network dependencies recorded in the `leo.lock` file of the main program
+    locked_checksums: IndexMap<String, String>,
 }
 
 impl Retriever {
@@ -49,7 +51,10 @@ impl Retriever {
             contexts.insert(Symbol::from(&dep), ProgramContext::from(dep));
         }
 
-        Ok(Self { name, contexts, project_path: path.clone(), registry_path: home.join("registry") })
+        // Network dependencies must match the versions recorded by the previous build
+        let locked_checksums = read_locked_checksums(path)?;
+
+        Ok(Self { name, contexts, project_path: path.clone(), registry_path: home.join("registry"), locked_checksums })
     }
 
     pub fn get_context(&self, name: &Symbol) -> &ProgramContext {
@@ -95,6 +100,22 @@ impl Retriever {
 
                         cur_context.add_checksum();
 
+                        // Check that the program is the one pinned in `program.json`, if any
+                        check_pinned_checksum(
+                            cur_context.pinned_checksum(),
+                            cur_context.full_name(),
+                            cur_context.checksum(),
+                            cur_context.compiled_file_path(),
+                        )?;
+
+                        // Check that the program is the one recorded in `leo.lock`, if any
+                        check_locked_checksum(
+                            &self.locked_checksums,
+                            &cur_context.name().to_string(),
+                            cur_context.full_name(),
+                            cur_context.checksum(),
+                        )?;
+
                         nested_dependencies
                     }
                     Location::Local => {
@@ -340,6 +361,67 @@ impl Retriever {
     }
 }
 
+// Read the checksums of the network dependencies from the `leo.lock` file, if it exists.
+// Local dependencies are rebuilt from source, so their checksums are not checked.
+fn read_locked_checksums(path: &Path) -> Result<IndexMap<String, String>, UtilError> {
+    let lock_path = path.join("leo.lock");
+    if !lock_path.exists() {
+        return Ok(IndexMap::new());
+    }
+
+    let content = fs::read_to_string(&lock_path).map_err(|err| {
+        UtilError::util_file_io_error(
+            format!("Could not read {}", lock_path.to_str().unwrap()),
+            err,
+            Default::default(),
+        )
+    })?;
+    let lock_file: IndexMap<String, Vec<LockFileEntry>> =
+        toml::from_str(&content).map_err(|err| UtilError::toml_serizalization_error(err, Default::default()))?;
+
+    Ok(lock_file
+        .get("package")
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.location() == &Location::Network)
+        .map(|entry| (entry.name().clone(), entry.checksum().clone()))
+        .collect())
+}
+
+// Check that the checksum of a network dependency is the one pinned for it in `program.json`, if one is pinned.
+fn check_pinned_checksum(
+    pinned: &Option<String>,
+    full_name: &str,
+    checksum: &str,
+    cached_path: &Path,
+) -> Result<(), UtilError> {
+    match pinned {
+        Some(pinned) if pinned != checksum => Err(UtilError::pinned_checksum_mismatch_error(
+            full_name,
+            pinned,
+            checksum,
+            cached_path.display(),
+            Default::default(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+// Check that the checksum of a network dependency is the one recorded for it in `leo.lock`, if one is recorded.
+fn check_locked_checksum(
+    locked_checksums: &IndexMap<String, String>,
+    name: &str,
+    full_name: &str,
+    checksum: &str,
+) -> Result<(), UtilError> {
+    match locked_checksums.get(name) {
+        Some(locked) if locked != checksum => {
+            Err(UtilError::checksum_mismatch_error(full_name, locked, checksum, Default::default()))
+        }
+        _ => Ok(()),
+    }
+}
+
 // Retrieve local
 fn retrieve_local(name: &String, path: &PathBuf) -> Result<Vec<Dependency>, UtilError> {
     // Create the lock file if it doesn't exist
@@ -499,3 +581,55 @@ fn fetch_from_network(program: &String, network: Network) -> Result<String, Util
         Err(UtilError::network_error(url, response.status(), Default::default()))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const LOCK_FILE: &str = r#"
+[[package]]
+name = "token"
+network = "testnet3"
+location = "network"
+checksum = "aaaa"
+dependencies = []
+
+[[package]]
+name = "helper"
+location = "local"
+path = "../helper"
+checksum = "bbbb"
+dependencies = []
+"#;
+
+    #[test]
+    fn test_locked_checksums() {
+        let directory = tempfile::tempdir().unwrap();
+        // Without a lock file, no checksums are recorded.
+        assert!(read_locked_checksums(directory.path()).unwrap().is_empty());
+
+        fs::write(directory.path().join("leo.lock"), LOCK_FILE).unwrap();
+        let locked = read_locked_checksums(directory.path()).unwrap();
+        // Only the checksums of network dependencies are read.
+        assert_eq!(locked, IndexMap::from([("token".to_string(), "aaaa".to_string())]));
+
+        // A matching entry.
+        assert!(check_locked_checksum(&locked, "token", "token.aleo", "aaaa").is_ok());
+        // A mismatching entry.
+        let err = check_locked_checksum(&locked, "token", "token.aleo", "cccc").unwrap_err();
+        assert!(err.to_string().contains("is `cccc`, but `leo.lock` records `aaaa`"), "{err}");
+        // A missing entry, e.g. a new dependency or a local one.
+        assert!(check_locked_checksum(&locked, "other", "other.aleo", "cccc").is_ok());
+        assert!(check_locked_checksum(&locked, "helper", "helper.aleo", "cccc").is_ok());
+    }
+
+    #[test]
+    fn test_pinned_checksums() {
+        let cached_path = Path::new("registry/testnet3/token.aleo");
+        let pinned = Some("aaaa".to_string());
+        assert!(check_pinned_checksum(&pinned, "token.aleo", "aaaa", cached_path).is_ok());
+        let err = check_pinned_checksum(&pinned, "token.aleo", "cccc", cached_path).unwrap_err();
+        assert!(err.to_string().contains("is `cccc`, but `program.json` pins it to `aaaa`"), "{err}");
+        assert!(check_pinned_checksum(&None, "token.aleo", "cccc", cached_path).is_ok());
+    }
+}